@@ -1,6 +1,38 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20260217_000001_init_schema;
+mod m20261019_000001_create_todo_events;
+mod m20261019_000002_add_undo_columns_to_todo_events;
+mod m20261019_000003_create_todo_comments;
+mod m20261019_000004_create_todo_attachments;
+mod m20261019_000005_create_projects_and_share_grants;
+mod m20261019_000006_add_assignee_to_todos;
+mod m20261019_000007_create_workspaces;
+mod m20261019_000008_create_todo_dependencies;
+mod m20261019_000009_create_time_entries;
+mod m20261019_000010_add_estimate_and_completed_at_to_todos;
+mod m20261019_000011_backfill_todo_completed_at;
+mod m20261019_000012_create_todo_checklist_items;
+mod m20261019_000013_create_todo_templates;
+mod m20261019_000014_add_due_at_to_todos;
+mod m20261019_000015_create_reminders;
+mod m20261019_000016_create_notifications;
+mod m20261019_000017_create_webhooks;
+mod m20261019_000018_create_outbox_events;
+mod m20261019_000019_add_sync_tracking_to_todos;
+mod m20261019_000020_create_idempotency_keys;
+mod m20261019_000021_create_calendar_feeds;
+mod m20261019_000022_add_external_uid_to_todos;
+mod m20261019_000023_create_app_passwords;
+mod m20261019_000024_add_external_uid_to_todo_tombstones;
+mod m20261019_000025_add_txid_to_outbox_events;
+mod m20261019_000026_create_todo_trash;
+mod m20261019_000027_add_grantee_ids_to_todo_tombstones;
+mod m20261019_000028_create_email_outbox;
+mod m20261019_000029_add_claim_id_to_idempotency_keys;
+mod m20261019_000030_scope_todo_external_uid_to_workspace;
+mod m20261019_000031_create_blob_purge_queue;
+mod m20261019_000032_add_due_offset_to_todo_templates;
 
 /// Registri urutan migrasi schema database.
 pub struct Migrator;
@@ -8,6 +40,40 @@ pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20260217_000001_init_schema::Migration)]
+        vec![
+            Box::new(m20260217_000001_init_schema::Migration),
+            Box::new(m20261019_000001_create_todo_events::Migration),
+            Box::new(m20261019_000002_add_undo_columns_to_todo_events::Migration),
+            Box::new(m20261019_000003_create_todo_comments::Migration),
+            Box::new(m20261019_000004_create_todo_attachments::Migration),
+            Box::new(m20261019_000005_create_projects_and_share_grants::Migration),
+            Box::new(m20261019_000006_add_assignee_to_todos::Migration),
+            Box::new(m20261019_000007_create_workspaces::Migration),
+            Box::new(m20261019_000008_create_todo_dependencies::Migration),
+            Box::new(m20261019_000009_create_time_entries::Migration),
+            Box::new(m20261019_000010_add_estimate_and_completed_at_to_todos::Migration),
+            Box::new(m20261019_000011_backfill_todo_completed_at::Migration),
+            Box::new(m20261019_000012_create_todo_checklist_items::Migration),
+            Box::new(m20261019_000013_create_todo_templates::Migration),
+            Box::new(m20261019_000014_add_due_at_to_todos::Migration),
+            Box::new(m20261019_000015_create_reminders::Migration),
+            Box::new(m20261019_000016_create_notifications::Migration),
+            Box::new(m20261019_000017_create_webhooks::Migration),
+            Box::new(m20261019_000018_create_outbox_events::Migration),
+            Box::new(m20261019_000019_add_sync_tracking_to_todos::Migration),
+            Box::new(m20261019_000020_create_idempotency_keys::Migration),
+            Box::new(m20261019_000021_create_calendar_feeds::Migration),
+            Box::new(m20261019_000022_add_external_uid_to_todos::Migration),
+            Box::new(m20261019_000023_create_app_passwords::Migration),
+            Box::new(m20261019_000024_add_external_uid_to_todo_tombstones::Migration),
+            Box::new(m20261019_000025_add_txid_to_outbox_events::Migration),
+            Box::new(m20261019_000026_create_todo_trash::Migration),
+            Box::new(m20261019_000027_add_grantee_ids_to_todo_tombstones::Migration),
+            Box::new(m20261019_000028_create_email_outbox::Migration),
+            Box::new(m20261019_000029_add_claim_id_to_idempotency_keys::Migration),
+            Box::new(m20261019_000030_scope_todo_external_uid_to_workspace::Migration),
+            Box::new(m20261019_000031_create_blob_purge_queue::Migration),
+            Box::new(m20261019_000032_add_due_offset_to_todo_templates::Migration),
+        ]
     }
 }
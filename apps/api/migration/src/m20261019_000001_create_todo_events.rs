@@ -0,0 +1,105 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_events` untuk audit log perubahan todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_events` beserta index untuk query history per todo.
+    ///
+    /// `todo_id` sengaja tanpa FK ke `todos` agar history tetap ada setelah todo dihapus.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TodoEvents::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TodoEvents::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TodoEvents::UserId).uuid().not_null())
+                    .col(ColumnDef::new(TodoEvents::ActorId).uuid().not_null())
+                    .col(ColumnDef::new(TodoEvents::EventType).string().not_null())
+                    .col(ColumnDef::new(TodoEvents::Changes).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(TodoEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_events_users")
+                            .from(TodoEvents::Table, TodoEvents::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_events_todo_id_created_at")
+                    .table(TodoEvents::Table)
+                    .col(TodoEvents::TodoId)
+                    .col(TodoEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_events_created_at")
+                    .table(TodoEvents::Table)
+                    .col(TodoEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_events ADD CONSTRAINT chk_todo_events_event_type \
+                 CHECK (event_type IN ('created', 'updated', 'completed', 'deleted'));",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_events`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoEvents::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoEvents {
+    Table,
+    Id,
+    TodoId,
+    UserId,
+    ActorId,
+    EventType,
+    Changes,
+    CreatedAt,
+}
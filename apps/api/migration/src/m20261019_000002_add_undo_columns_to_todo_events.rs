@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi kolom penanda undo pada `todo_events`.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan:
+    /// - `reverts_event_id`: event kompensasi menunjuk event yang dibatalkan,
+    /// - `undone_at`: waktu event dibatalkan lewat undo,
+    /// - index untuk mencari event terakhir milik pelaku.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoEvents::Table)
+                    .add_column(ColumnDef::new(TodoEvents::RevertsEventId).uuid().null())
+                    .add_column(
+                        ColumnDef::new(TodoEvents::UndoneAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_events_user_id_actor_id_created_at")
+                    .table(TodoEvents::Table)
+                    .col(TodoEvents::UserId)
+                    .col(TodoEvents::ActorId)
+                    .col(TodoEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus index dan kolom undo.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todo_events_user_id_actor_id_created_at")
+                    .table(TodoEvents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoEvents::Table)
+                    .drop_column(TodoEvents::RevertsEventId)
+                    .drop_column(TodoEvents::UndoneAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TodoEvents {
+    Table,
+    UserId,
+    ActorId,
+    CreatedAt,
+    RevertsEventId,
+    UndoneAt,
+}
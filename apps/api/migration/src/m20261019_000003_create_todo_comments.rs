@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_comments` untuk thread komentar pada todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_comments`, index per todo, dan constraint body tidak kosong.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoComments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TodoComments::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TodoComments::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TodoComments::AuthorId).uuid().not_null())
+                    .col(ColumnDef::new(TodoComments::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(TodoComments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TodoComments::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_comments_todos")
+                            .from(TodoComments::Table, TodoComments::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_comments_users")
+                            .from(TodoComments::Table, TodoComments::AuthorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_comments_todo_id_created_at")
+                    .table(TodoComments::Table)
+                    .col(TodoComments::TodoId)
+                    .col(TodoComments::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_comments ADD CONSTRAINT chk_todo_comments_body_not_empty CHECK (char_length(trim(body)) > 0);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_comments`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoComments::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoComments {
+    Table,
+    Id,
+    TodoId,
+    AuthorId,
+    Body,
+    CreatedAt,
+    UpdatedAt,
+}
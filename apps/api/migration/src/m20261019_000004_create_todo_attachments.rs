@@ -0,0 +1,129 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_attachments` untuk metadata file lampiran todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_attachments`, index per todo dan per uploader (hitung kuota),
+    /// serta constraint ukuran file tidak negatif.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoAttachments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TodoAttachments::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TodoAttachments::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TodoAttachments::UploaderId).uuid().not_null())
+                    .col(ColumnDef::new(TodoAttachments::FileName).string().not_null())
+                    .col(ColumnDef::new(TodoAttachments::ContentType).string().not_null())
+                    .col(ColumnDef::new(TodoAttachments::SizeBytes).big_integer().not_null())
+                    .col(ColumnDef::new(TodoAttachments::StorageKey).string().not_null())
+                    .col(
+                        ColumnDef::new(TodoAttachments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_attachments_todos")
+                            .from(TodoAttachments::Table, TodoAttachments::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_attachments_users")
+                            .from(TodoAttachments::Table, TodoAttachments::UploaderId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_attachments_todo_id_created_at")
+                    .table(TodoAttachments::Table)
+                    .col(TodoAttachments::TodoId)
+                    .col(TodoAttachments::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_attachments_uploader_id")
+                    .table(TodoAttachments::Table)
+                    .col(TodoAttachments::UploaderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_attachments_storage_key_unique")
+                    .table(TodoAttachments::Table)
+                    .col(TodoAttachments::StorageKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_attachments ADD CONSTRAINT chk_todo_attachments_size_bytes_non_negative CHECK (size_bytes >= 0);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_attachments`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoAttachments::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoAttachments {
+    Table,
+    Id,
+    TodoId,
+    UploaderId,
+    FileName,
+    ContentType,
+    SizeBytes,
+    StorageKey,
+    CreatedAt,
+}
@@ -0,0 +1,281 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi project, relasi todo ke project, dan share grant antar user.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat:
+    /// - tabel `projects` milik satu owner,
+    /// - kolom `todos.project_id` (opsional, di-null-kan saat project dihapus),
+    /// - tabel `share_grants` untuk akses viewer/editor/owner ke project atau todo,
+    /// - index event per pelaku agar undo lintas owner tetap cepat.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Projects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Projects::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Projects::OwnerId).uuid().not_null())
+                    .col(ColumnDef::new(Projects::Name).string_len(200).not_null())
+                    .col(
+                        ColumnDef::new(Projects::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Projects::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_projects_users")
+                            .from(Projects::Table, Projects::OwnerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_projects_owner_id_created_at")
+                    .table(Projects::Table)
+                    .col(Projects::OwnerId)
+                    .col(Projects::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::ProjectId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_todos_projects")
+                            .from_tbl(Todos::Table)
+                            .from_col(Todos::ProjectId)
+                            .to_tbl(Projects::Table)
+                            .to_col(Projects::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_project_id")
+                    .table(Todos::Table)
+                    .col(Todos::ProjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ShareGrants::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ShareGrants::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ShareGrants::ProjectId).uuid().null())
+                    .col(ColumnDef::new(ShareGrants::TodoId).uuid().null())
+                    .col(ColumnDef::new(ShareGrants::GranteeId).uuid().not_null())
+                    .col(ColumnDef::new(ShareGrants::Role).string_len(16).not_null())
+                    .col(ColumnDef::new(ShareGrants::GrantedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(ShareGrants::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ShareGrants::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_share_grants_projects")
+                            .from(ShareGrants::Table, ShareGrants::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_share_grants_todos")
+                            .from(ShareGrants::Table, ShareGrants::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_share_grants_grantee")
+                            .from(ShareGrants::Table, ShareGrants::GranteeId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Satu grant per (resource, grantee); unique parsial karena kolom resource nullable.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX uq_share_grants_project_id_grantee_id ON share_grants (project_id, grantee_id) WHERE project_id IS NOT NULL;",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX uq_share_grants_todo_id_grantee_id ON share_grants (todo_id, grantee_id) WHERE todo_id IS NOT NULL;",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_share_grants_grantee_id")
+                    .table(ShareGrants::Table)
+                    .col(ShareGrants::GranteeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE share_grants ADD CONSTRAINT chk_share_grants_single_resource CHECK (num_nonnulls(project_id, todo_id) = 1);",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE share_grants ADD CONSTRAINT chk_share_grants_role CHECK (role IN ('viewer', 'editor', 'owner'));",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_events_actor_id_created_at")
+                    .table(TodoEvents::Table)
+                    .col(TodoEvents::ActorId)
+                    .col(TodoEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan urutan terbalik: index event, `share_grants`, kolom `project_id`, lalu `projects`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todo_events_actor_id_created_at")
+                    .table(TodoEvents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ShareGrants::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todos_project_id")
+                    .table(Todos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .drop_foreign_key(Alias::new("fk_todos_projects"))
+                    .drop_column(Todos::ProjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Projects::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+    ProjectId,
+}
+
+#[derive(DeriveIden)]
+enum TodoEvents {
+    Table,
+    ActorId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+    OwnerId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ShareGrants {
+    Table,
+    Id,
+    ProjectId,
+    TodoId,
+    GranteeId,
+    Role,
+    GrantedBy,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi assignee todo dan jenis event `assigned` di history.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan:
+    /// - `todos.assignee_id` (di-null-kan bila user assignee dihapus),
+    /// - index untuk view "assigned to me",
+    /// - nilai `assigned` pada constraint `todo_events.event_type`.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::AssigneeId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_todos_assignee")
+                            .from_tbl(Todos::Table)
+                            .from_col(Todos::AssigneeId)
+                            .to_tbl(Users::Table)
+                            .to_col(Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_assignee_id")
+                    .table(Todos::Table)
+                    .col(Todos::AssigneeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_events DROP CONSTRAINT chk_todo_events_event_type, \
+                 ADD CONSTRAINT chk_todo_events_event_type \
+                 CHECK (event_type IN ('created', 'updated', 'completed', 'assigned', 'deleted'));",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: event `assigned` diturunkan menjadi `updated` sebelum constraint lama dipasang lagi.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE todo_events SET event_type = 'updated' WHERE event_type = 'assigned'; \
+                 ALTER TABLE todo_events DROP CONSTRAINT chk_todo_events_event_type, \
+                 ADD CONSTRAINT chk_todo_events_event_type \
+                 CHECK (event_type IN ('created', 'updated', 'completed', 'deleted'));",
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todos_assignee_id")
+                    .table(Todos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .drop_foreign_key(Alias::new("fk_todos_assignee"))
+                    .drop_column(Todos::AssigneeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    AssigneeId,
+}
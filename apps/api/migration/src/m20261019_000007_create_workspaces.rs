@@ -0,0 +1,365 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi workspace tim: membership, undangan, dan relasi project/todo ke workspace.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat:
+    /// - tabel `workspaces`,
+    /// - tabel `workspace_members` dengan role owner/admin/member/guest,
+    /// - tabel `workspace_invitations` (token disimpan sebagai hash SHA-256),
+    /// - kolom `projects.workspace_id` dan `todos.workspace_id` (null = ruang pribadi).
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Workspaces::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Workspaces::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Workspaces::Name).string_len(200).not_null())
+                    .col(
+                        ColumnDef::new(Workspaces::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Workspaces::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorkspaceMembers::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(WorkspaceMembers::WorkspaceId).uuid().not_null())
+                    .col(ColumnDef::new(WorkspaceMembers::UserId).uuid().not_null())
+                    .col(ColumnDef::new(WorkspaceMembers::Role).string_len(16).not_null())
+                    .col(
+                        ColumnDef::new(WorkspaceMembers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(WorkspaceMembers::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_workspace_members")
+                            .col(WorkspaceMembers::WorkspaceId)
+                            .col(WorkspaceMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_workspace_members_workspaces")
+                            .from(WorkspaceMembers::Table, WorkspaceMembers::WorkspaceId)
+                            .to(Workspaces::Table, Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_workspace_members_users")
+                            .from(WorkspaceMembers::Table, WorkspaceMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_workspace_members_user_id")
+                    .table(WorkspaceMembers::Table)
+                    .col(WorkspaceMembers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE workspace_members ADD CONSTRAINT chk_workspace_members_role CHECK (role IN ('owner', 'admin', 'member', 'guest'));",
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorkspaceInvitations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WorkspaceInvitations::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(WorkspaceInvitations::WorkspaceId).uuid().not_null())
+                    .col(ColumnDef::new(WorkspaceInvitations::Email).string_len(255).not_null())
+                    .col(ColumnDef::new(WorkspaceInvitations::Role).string_len(16).not_null())
+                    .col(ColumnDef::new(WorkspaceInvitations::TokenHash).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(WorkspaceInvitations::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(WorkspaceInvitations::InvitedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(WorkspaceInvitations::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WorkspaceInvitations::RespondedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WorkspaceInvitations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_workspace_invitations_workspaces")
+                            .from(WorkspaceInvitations::Table, WorkspaceInvitations::WorkspaceId)
+                            .to(Workspaces::Table, Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_workspace_invitations_users")
+                            .from(WorkspaceInvitations::Table, WorkspaceInvitations::InvitedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_workspace_invitations_token_hash")
+                    .table(WorkspaceInvitations::Table)
+                    .col(WorkspaceInvitations::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_workspace_invitations_workspace_id_created_at")
+                    .table(WorkspaceInvitations::Table)
+                    .col(WorkspaceInvitations::WorkspaceId)
+                    .col(WorkspaceInvitations::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Pemilik workspace tidak bisa diundang; owner baru dibuat lewat ubah role member.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE workspace_invitations ADD CONSTRAINT chk_workspace_invitations_role CHECK (role IN ('admin', 'member', 'guest'));",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE workspace_invitations ADD CONSTRAINT chk_workspace_invitations_status CHECK (status IN ('pending', 'accepted', 'declined', 'revoked'));",
+            )
+            .await?;
+
+        // Workspace tidak bisa dihapus selama masih berisi project atau todo.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Projects::Table)
+                    .add_column(ColumnDef::new(Projects::WorkspaceId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_projects_workspaces")
+                            .from_tbl(Projects::Table)
+                            .from_col(Projects::WorkspaceId)
+                            .to_tbl(Workspaces::Table)
+                            .to_col(Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_projects_workspace_id")
+                    .table(Projects::Table)
+                    .col(Projects::WorkspaceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::WorkspaceId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_todos_workspaces")
+                            .from_tbl(Todos::Table)
+                            .from_col(Todos::WorkspaceId)
+                            .to_tbl(Workspaces::Table)
+                            .to_col(Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_workspace_id")
+                    .table(Todos::Table)
+                    .col(Todos::WorkspaceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan urutan terbalik: kolom `workspace_id`, undangan, member, lalu `workspaces`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todos_workspace_id")
+                    .table(Todos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .drop_foreign_key(Alias::new("fk_todos_workspaces"))
+                    .drop_column(Todos::WorkspaceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_projects_workspace_id")
+                    .table(Projects::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Projects::Table)
+                    .drop_foreign_key(Alias::new("fk_projects_workspaces"))
+                    .drop_column(Projects::WorkspaceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WorkspaceInvitations::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WorkspaceMembers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Workspaces::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    WorkspaceId,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    WorkspaceId,
+}
+
+#[derive(DeriveIden)]
+enum Workspaces {
+    Table,
+    Id,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WorkspaceMembers {
+    Table,
+    WorkspaceId,
+    UserId,
+    Role,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WorkspaceInvitations {
+    Table,
+    Id,
+    WorkspaceId,
+    Email,
+    Role,
+    TokenHash,
+    Status,
+    InvitedBy,
+    ExpiresAt,
+    RespondedAt,
+    CreatedAt,
+}
@@ -0,0 +1,107 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_dependencies` untuk relasi "blocked by" antar todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_dependencies` (satu baris = `todo_id` diblokir oleh `blocked_by_id`),
+    /// index arah sebaliknya, dan constraint agar todo tidak memblokir dirinya sendiri.
+    /// Deteksi siklus yang lebih panjang dilakukan di service.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoDependencies::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(TodoDependencies::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TodoDependencies::BlockedById).uuid().not_null())
+                    .col(ColumnDef::new(TodoDependencies::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TodoDependencies::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_todo_dependencies")
+                            .col(TodoDependencies::TodoId)
+                            .col(TodoDependencies::BlockedById),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_dependencies_todos")
+                            .from(TodoDependencies::Table, TodoDependencies::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_dependencies_blocked_by")
+                            .from(TodoDependencies::Table, TodoDependencies::BlockedById)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_dependencies_users")
+                            .from(TodoDependencies::Table, TodoDependencies::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_dependencies_blocked_by_id")
+                    .table(TodoDependencies::Table)
+                    .col(TodoDependencies::BlockedById)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_dependencies ADD CONSTRAINT chk_todo_dependencies_not_self CHECK (todo_id <> blocked_by_id);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_dependencies`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoDependencies::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoDependencies {
+    Table,
+    TodoId,
+    BlockedById,
+    CreatedBy,
+    CreatedAt,
+}
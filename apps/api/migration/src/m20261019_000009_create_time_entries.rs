@@ -0,0 +1,142 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `time_entries` untuk pencatatan waktu kerja per todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat:
+    /// - tabel `time_entries` (`stopped_at` null = timer masih berjalan),
+    /// - index per todo dan per user,
+    /// - unique index parsial agar satu user hanya punya satu timer berjalan,
+    /// - constraint `stopped_at` tidak boleh sebelum `started_at`.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TimeEntries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TimeEntries::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TimeEntries::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TimeEntries::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TimeEntries::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TimeEntries::StoppedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(TimeEntries::Note).string_len(500).null())
+                    .col(
+                        ColumnDef::new(TimeEntries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TimeEntries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_time_entries_todos")
+                            .from(TimeEntries::Table, TimeEntries::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_time_entries_users")
+                            .from(TimeEntries::Table, TimeEntries::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_time_entries_todo_id_started_at")
+                    .table(TimeEntries::Table)
+                    .col(TimeEntries::TodoId)
+                    .col(TimeEntries::StartedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_time_entries_user_id_started_at")
+                    .table(TimeEntries::Table)
+                    .col(TimeEntries::UserId)
+                    .col(TimeEntries::StartedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX uq_time_entries_running_user_id ON time_entries (user_id) WHERE stopped_at IS NULL;",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE time_entries ADD CONSTRAINT chk_time_entries_range CHECK (stopped_at IS NULL OR stopped_at >= started_at);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `time_entries`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TimeEntries::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TimeEntries {
+    Table,
+    Id,
+    TodoId,
+    UserId,
+    StartedAt,
+    StoppedAt,
+    Note,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi estimasi usaha dan waktu selesai todo untuk laporan burndown.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan:
+    /// - `todos.estimate_value` + `todos.estimate_unit` (`minutes` atau `points`), keduanya
+    ///   terisi atau keduanya kosong,
+    /// - `todos.completed_at` yang diisi saat todo ditandai selesai,
+    /// - index `(project_id, completed_at)` untuk query burndown per project.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::EstimateValue).integer().null())
+                    .add_column(ColumnDef::new(Todos::EstimateUnit).string_len(16).null())
+                    .add_column(ColumnDef::new(Todos::CompletedAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todos ADD CONSTRAINT chk_todos_estimate CHECK (\
+                 (estimate_value IS NULL AND estimate_unit IS NULL) OR \
+                 (estimate_value > 0 AND estimate_unit IN ('minutes', 'points')));",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_project_id_completed_at")
+                    .table(Todos::Table)
+                    .col(Todos::ProjectId)
+                    .col(Todos::CompletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: menghapus index, constraint, lalu kolom baru.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todos_project_id_completed_at")
+                    .table(Todos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE todos DROP CONSTRAINT chk_todos_estimate;")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .drop_column(Todos::CompletedAt)
+                    .drop_column(Todos::EstimateUnit)
+                    .drop_column(Todos::EstimateValue)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    ProjectId,
+    EstimateValue,
+    EstimateUnit,
+    CompletedAt,
+}
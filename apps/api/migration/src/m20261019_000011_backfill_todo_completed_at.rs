@@ -0,0 +1,28 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi pengisian `todos.completed_at` untuk todo yang selesai sebelum kolom tersebut ada.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Mengisi `completed_at` dari `updated_at` (perkiraan terbaik) untuk todo selesai
+    /// yang belum punya waktu selesai.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE todos SET completed_at = updated_at \
+                 WHERE iscompleted AND completed_at IS NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback tidak mengubah data: nilai hasil backfill tidak bisa dibedakan
+    /// dari waktu selesai asli.
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_checklist_items` untuk checklist ringan di dalam todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_checklist_items`, index urutan per todo,
+    /// dan constraint teks tidak kosong serta posisi non-negatif.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoChecklistItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TodoChecklistItems::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TodoChecklistItems::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(TodoChecklistItems::Text).string_len(500).not_null())
+                    .col(
+                        ColumnDef::new(TodoChecklistItems::Checked)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(TodoChecklistItems::Position).integer().not_null())
+                    .col(
+                        ColumnDef::new(TodoChecklistItems::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TodoChecklistItems::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_checklist_items_todos")
+                            .from(TodoChecklistItems::Table, TodoChecklistItems::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_checklist_items_todo_id_position")
+                    .table(TodoChecklistItems::Table)
+                    .col(TodoChecklistItems::TodoId)
+                    .col(TodoChecklistItems::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_checklist_items \
+                 ADD CONSTRAINT chk_todo_checklist_items_text_not_empty CHECK (char_length(trim(text)) > 0), \
+                 ADD CONSTRAINT chk_todo_checklist_items_position CHECK (position >= 0);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_checklist_items`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoChecklistItems::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoChecklistItems {
+    Table,
+    Id,
+    TodoId,
+    Text,
+    Checked,
+    Position,
+    CreatedAt,
+    UpdatedAt,
+}
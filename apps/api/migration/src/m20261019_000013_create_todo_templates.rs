@@ -0,0 +1,137 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_templates` untuk template todo yang bisa dipakai ulang.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_templates` (di ruang pribadi pembuat atau di workspace),
+    /// index per workspace/pembuat, dan constraint estimasi serta checklist berbentuk array.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TodoTemplates::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TodoTemplates::OwnerId).uuid().not_null())
+                    .col(ColumnDef::new(TodoTemplates::WorkspaceId).uuid().null())
+                    .col(ColumnDef::new(TodoTemplates::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(TodoTemplates::Title).string_len(200).not_null())
+                    .col(ColumnDef::new(TodoTemplates::Desc).text().null())
+                    .col(ColumnDef::new(TodoTemplates::EstimateValue).integer().null())
+                    .col(ColumnDef::new(TodoTemplates::EstimateUnit).string_len(16).null())
+                    .col(
+                        ColumnDef::new(TodoTemplates::Checklist)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .col(
+                        ColumnDef::new(TodoTemplates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TodoTemplates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_templates_users")
+                            .from(TodoTemplates::Table, TodoTemplates::OwnerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_todo_templates_workspaces")
+                            .from(TodoTemplates::Table, TodoTemplates::WorkspaceId)
+                            .to(Workspaces::Table, Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_templates_workspace_id")
+                    .table(TodoTemplates::Table)
+                    .col(TodoTemplates::WorkspaceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_templates_owner_id")
+                    .table(TodoTemplates::Table)
+                    .col(TodoTemplates::OwnerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE todo_templates \
+                 ADD CONSTRAINT chk_todo_templates_estimate CHECK (\
+                 (estimate_value IS NULL AND estimate_unit IS NULL) OR \
+                 (estimate_value > 0 AND estimate_unit IN ('minutes', 'points'))), \
+                 ADD CONSTRAINT chk_todo_templates_checklist_array CHECK (jsonb_typeof(checklist) = 'array');",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_templates`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoTemplates::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Workspaces {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TodoTemplates {
+    Table,
+    Id,
+    OwnerId,
+    WorkspaceId,
+    Name,
+    Title,
+    Desc,
+    EstimateValue,
+    EstimateUnit,
+    Checklist,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tenggat waktu (`due_at`) todo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todos.due_at` (opsional) beserta index-nya.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::DueAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_due_at")
+                    .table(Todos::Table)
+                    .col(Todos::DueAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: menghapus index lalu kolom `due_at`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_todos_due_at").table(Todos::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(Table::alter().table(Todos::Table).drop_column(Todos::DueAt).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    DueAt,
+}
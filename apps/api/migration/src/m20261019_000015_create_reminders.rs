@@ -0,0 +1,137 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `reminders` untuk pengingat todo yang dikirim scheduler.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `reminders`:
+    /// - waktu kirim berupa `remind_at` absolut atau `offset_minutes` sebelum `todos.due_at`
+    ///   (tepat salah satu),
+    /// - status pengiriman beserta jumlah percobaan dan jadwal retry,
+    /// - partial index baris `pending` yang dipindai scheduler.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reminders::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Reminders::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Reminders::TodoId).uuid().not_null())
+                    .col(ColumnDef::new(Reminders::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Reminders::RemindAt).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Reminders::OffsetMinutes).integer().null())
+                    .col(
+                        ColumnDef::new(Reminders::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(Reminders::Attempts).integer().not_null().default(0))
+                    .col(ColumnDef::new(Reminders::NextAttemptAt).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Reminders::LastError).text().null())
+                    .col(ColumnDef::new(Reminders::SentAt).timestamp_with_time_zone().null())
+                    .col(
+                        ColumnDef::new(Reminders::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Reminders::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reminders_todos")
+                            .from(Reminders::Table, Reminders::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reminders_users")
+                            .from(Reminders::Table, Reminders::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reminders_todo_id_user_id")
+                    .table(Reminders::Table)
+                    .col(Reminders::TodoId)
+                    .col(Reminders::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        let conn = manager.get_connection();
+        conn.execute_unprepared(
+            "CREATE INDEX idx_reminders_pending ON reminders (remind_at) WHERE status = 'pending';",
+        )
+        .await?;
+        conn.execute_unprepared(
+            "ALTER TABLE reminders \
+             ADD CONSTRAINT chk_reminders_schedule CHECK (\
+             (remind_at IS NOT NULL AND offset_minutes IS NULL) OR \
+             (remind_at IS NULL AND offset_minutes BETWEEN 0 AND 43200)), \
+             ADD CONSTRAINT chk_reminders_status CHECK (status IN ('pending', 'sent', 'skipped', 'failed')), \
+             ADD CONSTRAINT chk_reminders_attempts CHECK (attempts >= 0);",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `reminders`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reminders::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Reminders {
+    Table,
+    Id,
+    TodoId,
+    UserId,
+    RemindAt,
+    OffsetMinutes,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastError,
+    SentAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,165 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi inbox notifikasi in-app dan preferensi notifikasi per user.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat:
+    /// - tabel `notifications` (todo/pelaku dikosongkan bila datanya dihapus),
+    /// - index per user urut waktu dan partial index notifikasi belum dibaca,
+    /// - tabel `notification_preferences` dengan kunci `(user_id, kind)`;
+    ///   jenis tanpa baris dianggap aktif.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notifications::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Notifications::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Notifications::Kind).string_len(32).not_null())
+                    .col(ColumnDef::new(Notifications::TodoId).uuid().null())
+                    .col(ColumnDef::new(Notifications::ActorId).uuid().null())
+                    .col(ColumnDef::new(Notifications::Message).text().not_null())
+                    .col(ColumnDef::new(Notifications::ReadAt).timestamp_with_time_zone().null())
+                    .col(
+                        ColumnDef::new(Notifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_users")
+                            .from(Notifications::Table, Notifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_todos")
+                            .from(Notifications::Table, Notifications::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_actors")
+                            .from(Notifications::Table, Notifications::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_id_created_at")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .col(Notifications::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        let conn = manager.get_connection();
+        conn.execute_unprepared(
+            "CREATE INDEX idx_notifications_unread ON notifications (user_id) WHERE read_at IS NULL;",
+        )
+        .await?;
+        conn.execute_unprepared(
+            "ALTER TABLE notifications ADD CONSTRAINT chk_notifications_kind \
+             CHECK (kind IN ('assigned', 'commented', 'reminder'));",
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreferences::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(NotificationPreferences::UserId).uuid().not_null())
+                    .col(ColumnDef::new(NotificationPreferences::Kind).string_len(32).not_null())
+                    .col(ColumnDef::new(NotificationPreferences::Enabled).boolean().not_null())
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_notification_preferences")
+                            .col(NotificationPreferences::UserId)
+                            .col(NotificationPreferences::Kind),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preferences_users")
+                            .from(NotificationPreferences::Table, NotificationPreferences::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus kedua tabel.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NotificationPreferences::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Notifications::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Notifications {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    TodoId,
+    ActorId,
+    Message,
+    ReadAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum NotificationPreferences {
+    Table,
+    UserId,
+    Kind,
+    Enabled,
+    UpdatedAt,
+}
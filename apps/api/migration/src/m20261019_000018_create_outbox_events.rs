@@ -0,0 +1,135 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `outbox_events` untuk event domain yang ditulis satu transaksi dengan datanya.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `outbox_events` dengan urutan `sequence`, index partial antrean `pending`,
+    /// index pembersihan event yang sudah diproses, dan constraint status.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OutboxEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OutboxEvents::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxEvents::Sequence)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment(),
+                    )
+                    .col(ColumnDef::new(OutboxEvents::EventType).string_len(64).not_null())
+                    .col(ColumnDef::new(OutboxEvents::AggregateId).uuid().not_null())
+                    .col(ColumnDef::new(OutboxEvents::Payload).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(OutboxEvents::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxEvents::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxEvents::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxEvents::HandledBy)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .col(ColumnDef::new(OutboxEvents::LastError).text().null())
+                    .col(
+                        ColumnDef::new(OutboxEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxEvents::ProcessedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_events_sequence")
+                    .table(OutboxEvents::Table)
+                    .col(OutboxEvents::Sequence)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_events_aggregate_id")
+                    .table(OutboxEvents::Table)
+                    .col(OutboxEvents::AggregateId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX idx_outbox_events_pending ON outbox_events (next_attempt_at, sequence) \
+                 WHERE status = 'pending';\
+                 CREATE INDEX idx_outbox_events_processed_at ON outbox_events (processed_at) \
+                 WHERE status = 'processed';\
+                 ALTER TABLE outbox_events \
+                 ADD CONSTRAINT chk_outbox_events_status CHECK (status IN ('pending', 'processed', 'failed')), \
+                 ADD CONSTRAINT chk_outbox_events_handled_by_array CHECK (jsonb_typeof(handled_by) = 'array');",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `outbox_events`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OutboxEvents::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum OutboxEvents {
+    Table,
+    Id,
+    Sequence,
+    EventType,
+    AggregateId,
+    Payload,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    HandledBy,
+    LastError,
+    CreatedAt,
+    ProcessedAt,
+}
@@ -0,0 +1,172 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi penanda perubahan todo untuk sinkronisasi inkremental client offline.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todos.sync_txid`, tabel `todo_tombstones`, dan trigger yang mengisi
+    /// keduanya dengan id transaksi Postgres setiap kali todo dibuat, diubah, dipindah workspace,
+    /// atau dihapus. Baris lama di-backfill dengan id transaksi migrasi ini.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(
+                        ColumnDef::new(Todos::SyncTxid)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("UPDATE todos SET sync_txid = pg_current_xact_id()::text::bigint;")
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todos_sync_txid")
+                    .table(Todos::Table)
+                    .col(Todos::SyncTxid)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoTombstones::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(TodoTombstones::TodoId).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(TodoTombstones::UserId).uuid().not_null())
+                    .col(ColumnDef::new(TodoTombstones::WorkspaceId).uuid().null())
+                    .col(ColumnDef::new(TodoTombstones::ProjectId).uuid().null())
+                    .col(ColumnDef::new(TodoTombstones::SyncTxid).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(TodoTombstones::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_tombstones_sync_txid")
+                    .table(TodoTombstones::Table)
+                    .col(TodoTombstones::SyncTxid)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+CREATE OR REPLACE FUNCTION todos_track_sync() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, pg_current_xact_id()::text::bigint, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+        RETURN OLD;
+    END IF;
+
+    NEW.sync_txid := pg_current_xact_id()::text::bigint;
+    IF TG_OP = 'INSERT' THEN
+        DELETE FROM todo_tombstones WHERE todo_id = NEW.id;
+    ELSIF NEW.workspace_id IS DISTINCT FROM OLD.workspace_id THEN
+        -- Todo yang pindah workspace terlihat terhapus dari sudut pandang workspace lamanya.
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, NEW.sync_txid, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE TRIGGER trg_todos_sync_before_write BEFORE INSERT OR UPDATE ON todos \
+                 FOR EACH ROW EXECUTE FUNCTION todos_track_sync();",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE TRIGGER trg_todos_sync_after_delete AFTER DELETE ON todos \
+                 FOR EACH ROW EXECUTE FUNCTION todos_track_sync();",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: menghapus trigger, function, tabel tombstone, lalu kolom `sync_txid`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP TRIGGER IF EXISTS trg_todos_sync_after_delete ON todos; \
+                 DROP TRIGGER IF EXISTS trg_todos_sync_before_write ON todos; \
+                 DROP FUNCTION IF EXISTS todos_track_sync();",
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TodoTombstones::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("idx_todos_sync_txid").table(Todos::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(Table::alter().table(Todos::Table).drop_column(Todos::SyncTxid).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    SyncTxid,
+}
+
+#[derive(DeriveIden)]
+enum TodoTombstones {
+    Table,
+    TodoId,
+    UserId,
+    WorkspaceId,
+    ProjectId,
+    SyncTxid,
+    DeletedAt,
+}
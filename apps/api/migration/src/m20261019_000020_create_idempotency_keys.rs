@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `idempotency_keys` untuk replay response request `POST` yang dikirim ulang.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `idempotency_keys` dengan primary key `(scope, key)` dan index kedaluwarsa.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IdempotencyKeys::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(IdempotencyKeys::Scope).string_len(64).not_null())
+                    .col(ColumnDef::new(IdempotencyKeys::Key).string_len(255).not_null())
+                    .col(ColumnDef::new(IdempotencyKeys::Fingerprint).string_len(64).not_null())
+                    .col(ColumnDef::new(IdempotencyKeys::StatusCode).small_integer().null())
+                    .col(ColumnDef::new(IdempotencyKeys::ContentType).string_len(255).null())
+                    .col(ColumnDef::new(IdempotencyKeys::ResponseBody).binary().null())
+                    .col(
+                        ColumnDef::new(IdempotencyKeys::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(IdempotencyKeys::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_idempotency_keys")
+                            .col(IdempotencyKeys::Scope)
+                            .col(IdempotencyKeys::Key),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_idempotency_keys_expires_at")
+                    .table(IdempotencyKeys::Table)
+                    .col(IdempotencyKeys::ExpiresAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `idempotency_keys`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IdempotencyKeys::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum IdempotencyKeys {
+    Table,
+    Scope,
+    Key,
+    Fingerprint,
+    StatusCode,
+    ContentType,
+    ResponseBody,
+    CreatedAt,
+    ExpiresAt,
+}
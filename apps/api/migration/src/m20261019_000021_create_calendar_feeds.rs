@@ -0,0 +1,134 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `calendar_feeds` untuk URL langganan iCalendar bertoken rahasia.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel feed kalender per user dengan hash token unik, filter workspace/project
+    /// opsional, dan penanda pencabutan.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CalendarFeeds::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CalendarFeeds::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CalendarFeeds::UserId).uuid().not_null())
+                    .col(ColumnDef::new(CalendarFeeds::WorkspaceId).uuid().null())
+                    .col(ColumnDef::new(CalendarFeeds::ProjectId).uuid().null())
+                    .col(ColumnDef::new(CalendarFeeds::Name).string_len(100).null())
+                    .col(ColumnDef::new(CalendarFeeds::TokenHash).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(CalendarFeeds::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CalendarFeeds::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CalendarFeeds::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_calendar_feeds_users")
+                            .from(CalendarFeeds::Table, CalendarFeeds::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_calendar_feeds_workspaces")
+                            .from(CalendarFeeds::Table, CalendarFeeds::WorkspaceId)
+                            .to(Workspaces::Table, Workspaces::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_calendar_feeds_projects")
+                            .from(CalendarFeeds::Table, CalendarFeeds::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_calendar_feeds_token_hash")
+                    .table(CalendarFeeds::Table)
+                    .col(CalendarFeeds::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_calendar_feeds_user_id")
+                    .table(CalendarFeeds::Table)
+                    .col(CalendarFeeds::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `calendar_feeds`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CalendarFeeds::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Workspaces {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum CalendarFeeds {
+    Table,
+    Id,
+    UserId,
+    WorkspaceId,
+    ProjectId,
+    Name,
+    TokenHash,
+    CreatedAt,
+    LastUsedAt,
+    RevokedAt,
+}
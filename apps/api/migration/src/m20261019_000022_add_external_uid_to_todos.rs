@@ -0,0 +1,55 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi id eksternal todo hasil impor iCalendar.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todos.external_uid` (UID `VTODO`, opsional) beserta unique partial index
+    /// per pemilik, sehingga impor ulang file yang sama memperbarui todo yang sudah ada.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Todos::Table)
+                    .add_column(ColumnDef::new(Todos::ExternalUid).string_len(255).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_todos_user_id_external_uid ON todos (user_id, external_uid) \
+                 WHERE external_uid IS NOT NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: menghapus index lalu kolom `external_uid`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_todos_user_id_external_uid")
+                    .table(Todos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(Table::alter().table(Todos::Table).drop_column(Todos::ExternalUid).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    ExternalUid,
+}
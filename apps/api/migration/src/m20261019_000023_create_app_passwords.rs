@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `app_passwords` untuk autentikasi client non-browser (CalDAV, API key).
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel app password per user dengan hash rahasia unik, waktu pemakaian terakhir,
+    /// dan penanda pencabutan.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AppPasswords::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AppPasswords::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AppPasswords::UserId).uuid().not_null())
+                    .col(ColumnDef::new(AppPasswords::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(AppPasswords::SecretHash).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(AppPasswords::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(AppPasswords::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(AppPasswords::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_app_passwords_users")
+                            .from(AppPasswords::Table, AppPasswords::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_app_passwords_secret_hash")
+                    .table(AppPasswords::Table)
+                    .col(AppPasswords::SecretHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_app_passwords_user_id")
+                    .table(AppPasswords::Table)
+                    .col(AppPasswords::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `app_passwords`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AppPasswords::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum AppPasswords {
+    Table,
+    Id,
+    UserId,
+    Name,
+    SecretHash,
+    CreatedAt,
+    LastUsedAt,
+    RevokedAt,
+}
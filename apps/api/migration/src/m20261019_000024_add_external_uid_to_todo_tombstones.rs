@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi UID eksternal pada tombstone todo agar resource CalDAV yang terhapus tetap bisa
+/// dilaporkan dengan href aslinya.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todo_tombstones.external_uid` lalu mengganti function `todos_track_sync`
+    /// agar ikut menyalin `todos.external_uid` saat todo dihapus atau pindah workspace.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoTombstones::Table)
+                    .add_column(ColumnDef::new(TodoTombstones::ExternalUid).string_len(255).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+CREATE OR REPLACE FUNCTION todos_track_sync() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, pg_current_xact_id()::text::bigint, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+        RETURN OLD;
+    END IF;
+
+    NEW.sync_txid := pg_current_xact_id()::text::bigint;
+    IF TG_OP = 'INSERT' THEN
+        DELETE FROM todo_tombstones WHERE todo_id = NEW.id;
+    ELSIF NEW.workspace_id IS DISTINCT FROM OLD.workspace_id THEN
+        -- Todo yang pindah workspace terlihat terhapus dari sudut pandang workspace lamanya.
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, NEW.sync_txid, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: mengembalikan function `todos_track_sync` versi sebelumnya lalu menghapus kolom.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+CREATE OR REPLACE FUNCTION todos_track_sync() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, pg_current_xact_id()::text::bigint, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+        RETURN OLD;
+    END IF;
+
+    NEW.sync_txid := pg_current_xact_id()::text::bigint;
+    IF TG_OP = 'INSERT' THEN
+        DELETE FROM todo_tombstones WHERE todo_id = NEW.id;
+    ELSIF NEW.workspace_id IS DISTINCT FROM OLD.workspace_id THEN
+        -- Todo yang pindah workspace terlihat terhapus dari sudut pandang workspace lamanya.
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, NEW.sync_txid, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#,
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoTombstones::Table)
+                    .drop_column(TodoTombstones::ExternalUid)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TodoTombstones {
+    Table,
+    ExternalUid,
+}
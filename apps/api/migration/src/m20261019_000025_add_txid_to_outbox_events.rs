@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi id transaksi penulis event outbox agar stream bisa melanjutkan berdasarkan
+/// visibilitas commit, bukan urutan `sequence`.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `outbox_events.txid` yang terisi otomatis dengan id transaksi Postgres
+    /// saat insert. Baris lama di-backfill dengan id transaksi migrasi ini.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OutboxEvents::Table)
+                    .add_column(
+                        ColumnDef::new(OutboxEvents::Txid)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE outbox_events SET txid = pg_current_xact_id()::text::bigint;\
+                 ALTER TABLE outbox_events ALTER COLUMN txid SET DEFAULT pg_current_xact_id()::text::bigint;",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_events_txid")
+                    .table(OutboxEvents::Table)
+                    .col(OutboxEvents::Txid)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus index dan kolom `txid`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_outbox_events_txid")
+                    .table(OutboxEvents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OutboxEvents::Table)
+                    .drop_column(OutboxEvents::Txid)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum OutboxEvents {
+    Table,
+    Txid,
+}
@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi tabel `todo_trash` yang menyimpan data turunan todo terhapus selama masa undo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `todo_trash` berisi salinan baris turunan (komentar, lampiran, dependency,
+    /// time entry, checklist, reminder) per todo dan kunci blob lampirannya, dengan index waktu
+    /// hapus untuk pembersihan.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TodoTrash::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(TodoTrash::TodoId).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(TodoTrash::Children).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(TodoTrash::StorageKeys)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .col(
+                        ColumnDef::new(TodoTrash::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_todo_trash_deleted_at")
+                    .table(TodoTrash::Table)
+                    .col(TodoTrash::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `todo_trash`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TodoTrash::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TodoTrash {
+    Table,
+    TodoId,
+    Children,
+    StorageKeys,
+    DeletedAt,
+}
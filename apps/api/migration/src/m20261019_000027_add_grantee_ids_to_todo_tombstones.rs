@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi daftar penerima share langsung pada tombstone todo, agar user yang hanya menerima
+/// share todo tetap mendapat tombstone saat todo tersebut dihapus.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todo_tombstones.grantee_ids`, mengganti function `todos_track_sync`
+    /// agar menyalin grantee dari `share_grants`, lalu memindah trigger hapus ke `BEFORE DELETE`
+    /// karena grant todo ikut terhapus (cascade) sebelum trigger `AFTER DELETE` berjalan.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE todo_tombstones ADD COLUMN grantee_ids UUID[] NOT NULL DEFAULT '{}';")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+CREATE OR REPLACE FUNCTION todos_track_sync() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, grantee_ids, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, ARRAY(SELECT grantee_id FROM share_grants WHERE todo_id = OLD.id), pg_current_xact_id()::text::bigint, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            grantee_ids = EXCLUDED.grantee_ids,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+        RETURN OLD;
+    END IF;
+
+    NEW.sync_txid := pg_current_xact_id()::text::bigint;
+    IF TG_OP = 'INSERT' THEN
+        DELETE FROM todo_tombstones WHERE todo_id = NEW.id;
+    ELSIF NEW.workspace_id IS DISTINCT FROM OLD.workspace_id THEN
+        -- Todo yang pindah workspace terlihat terhapus dari sudut pandang workspace lamanya.
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, grantee_ids, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, ARRAY(SELECT grantee_id FROM share_grants WHERE todo_id = OLD.id), NEW.sync_txid, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            grantee_ids = EXCLUDED.grantee_ids,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP TRIGGER IF EXISTS trg_todos_sync_after_delete ON todos; \
+                 CREATE TRIGGER trg_todos_sync_before_delete BEFORE DELETE ON todos \
+                 FOR EACH ROW EXECUTE FUNCTION todos_track_sync();",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback: mengembalikan trigger `AFTER DELETE` dan function `todos_track_sync` versi
+    /// sebelumnya lalu menghapus kolom.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP TRIGGER IF EXISTS trg_todos_sync_before_delete ON todos; \
+                 CREATE TRIGGER trg_todos_sync_after_delete AFTER DELETE ON todos \
+                 FOR EACH ROW EXECUTE FUNCTION todos_track_sync();",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+CREATE OR REPLACE FUNCTION todos_track_sync() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, pg_current_xact_id()::text::bigint, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+        RETURN OLD;
+    END IF;
+
+    NEW.sync_txid := pg_current_xact_id()::text::bigint;
+    IF TG_OP = 'INSERT' THEN
+        DELETE FROM todo_tombstones WHERE todo_id = NEW.id;
+    ELSIF NEW.workspace_id IS DISTINCT FROM OLD.workspace_id THEN
+        -- Todo yang pindah workspace terlihat terhapus dari sudut pandang workspace lamanya.
+        INSERT INTO todo_tombstones (todo_id, user_id, workspace_id, project_id, external_uid, sync_txid, deleted_at)
+        VALUES (OLD.id, OLD.user_id, OLD.workspace_id, OLD.project_id, OLD.external_uid, NEW.sync_txid, NOW())
+        ON CONFLICT (todo_id) DO UPDATE SET
+            user_id = EXCLUDED.user_id,
+            workspace_id = EXCLUDED.workspace_id,
+            project_id = EXCLUDED.project_id,
+            external_uid = EXCLUDED.external_uid,
+            sync_txid = EXCLUDED.sync_txid,
+            deleted_at = EXCLUDED.deleted_at;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#,
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoTombstones::Table)
+                    .drop_column(TodoTombstones::GranteeIds)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TodoTombstones {
+    Table,
+    GranteeIds,
+}
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi antrean email keluar untuk kanal notifikasi email.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `email_outbox` (baris ikut terhapus bersama user, todo dikosongkan bila
+    /// dihapus) dan index antrean `(status, created_at)` untuk pengirim email.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EmailOutbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EmailOutbox::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()"))
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(EmailOutbox::UserId).uuid().not_null())
+                    .col(ColumnDef::new(EmailOutbox::ToAddress).string_len(255).not_null())
+                    .col(ColumnDef::new(EmailOutbox::Subject).string_len(255).not_null())
+                    .col(ColumnDef::new(EmailOutbox::Body).text().not_null())
+                    .col(ColumnDef::new(EmailOutbox::Kind).string_len(32).not_null())
+                    .col(ColumnDef::new(EmailOutbox::TodoId).uuid().null())
+                    .col(
+                        ColumnDef::new(EmailOutbox::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(EmailOutbox::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(EmailOutbox::SentAt).timestamp_with_time_zone().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_outbox_users")
+                            .from(EmailOutbox::Table, EmailOutbox::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_outbox_todos")
+                            .from(EmailOutbox::Table, EmailOutbox::TodoId)
+                            .to(Todos::Table, Todos::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_email_outbox_status_created_at")
+                    .table(EmailOutbox::Table)
+                    .col(EmailOutbox::Status)
+                    .col(EmailOutbox::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EmailOutbox::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Todos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EmailOutbox {
+    Table,
+    Id,
+    UserId,
+    ToAddress,
+    Subject,
+    Body,
+    Kind,
+    TodoId,
+    Status,
+    CreatedAt,
+    SentAt,
+}
@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi cakupan UID eksternal todo dari per pemilik menjadi per pemilik dan workspace,
+/// agar file `.ics` yang sama bisa diimpor ke beberapa workspace.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Mengganti `idx_todos_user_id_external_uid` dengan unique partial index
+    /// `(user_id, workspace_id, external_uid)`; ruang pribadi (`workspace_id` NULL) dipetakan ke
+    /// UUID nil agar tetap dianggap satu workspace.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP INDEX IF EXISTS idx_todos_user_id_external_uid; \
+                 CREATE UNIQUE INDEX idx_todos_user_id_workspace_id_external_uid ON todos \
+                 (user_id, COALESCE(workspace_id, '00000000-0000-0000-0000-000000000000'::uuid), external_uid) \
+                 WHERE external_uid IS NOT NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback ke index per pemilik; gagal bila UID yang sama sudah dipakai di beberapa workspace.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP INDEX IF EXISTS idx_todos_user_id_workspace_id_external_uid; \
+                 CREATE UNIQUE INDEX idx_todos_user_id_external_uid ON todos (user_id, external_uid) \
+                 WHERE external_uid IS NOT NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi antrean blob lampiran yang menunggu dihapus dari storage.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Membuat tabel `blob_purge_queue` berisi kunci blob yang metadatanya sudah dihapus;
+    /// loop pembersihan tempat sampah menghapus blob lalu barisnya.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlobPurgeQueue::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BlobPurgeQueue::StorageKey)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BlobPurgeQueue::QueuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus tabel `blob_purge_queue`.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BlobPurgeQueue::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BlobPurgeQueue {
+    Table,
+    StorageKey,
+    QueuedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrasi offset tenggat pada `todo_templates`, agar todo dari template bisa langsung
+/// mendapat `due_at` relatif terhadap waktu instantiate.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// Menambahkan kolom `todo_templates.due_offset_minutes`; template lama tanpa tenggat.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoTemplates::Table)
+                    .add_column(ColumnDef::new(TodoTemplates::DueOffsetMinutes).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback dengan menghapus kolom.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TodoTemplates::Table)
+                    .drop_column(TodoTemplates::DueOffsetMinutes)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TodoTemplates {
+    Table,
+    DueOffsetMinutes,
+}
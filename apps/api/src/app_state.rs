@@ -1,20 +1,87 @@
+use std::sync::Arc;
+
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
 
+use crate::config::{
+    attachment::AttachmentSettings, dependency::DependencySettings, history::HistorySettings,
+    workspace::WorkspaceSettings,
+};
+use crate::infrastructure::storage::BlobStore;
+use crate::infrastructure::webhook::DestinationPolicy;
+use crate::modules::collab::hub::CollabHub;
+use crate::modules::event_stream::hub::TodoEventHub;
+
 /// State bersama yang di-inject ke seluruh handler Axum.
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
     /// User default untuk mode no-auth.
     pub default_user_id: Uuid,
+    /// Konfigurasi audit log todo (retensi dan redaksi `desc`).
+    pub history: HistorySettings,
+    /// Batas ukuran dan kuota lampiran.
+    pub attachments: AttachmentSettings,
+    /// Backend penyimpanan isi lampiran.
+    pub blob_store: Arc<dyn BlobStore>,
+    /// Masa berlaku undangan workspace.
+    pub workspaces: WorkspaceSettings,
+    /// Aturan penyelesaian todo yang masih diblokir.
+    pub dependencies: DependencySettings,
+    /// Fan-out event todo ke stream SSE; diisi listener yang dijalankan di `main.rs`.
+    pub todo_events: TodoEventHub,
+    /// Presence dan soft lock kolaborasi WebSocket di proses ini.
+    pub collab: CollabHub,
+    /// Alamat yang boleh dipakai sebagai URL webhook; default hanya alamat publik.
+    pub webhook_destinations: DestinationPolicy,
 }
 
 impl AppState {
     /// Membentuk state aplikasi setelah dependency startup siap.
-    pub fn new(db: DatabaseConnection, default_user_id: Uuid) -> Self {
+    pub fn new(
+        db: DatabaseConnection,
+        default_user_id: Uuid,
+        history: HistorySettings,
+        attachments: AttachmentSettings,
+        blob_store: Arc<dyn BlobStore>,
+        workspaces: WorkspaceSettings,
+        dependencies: DependencySettings,
+    ) -> Self {
         Self {
             db,
             default_user_id,
+            history,
+            attachments,
+            blob_store,
+            workspaces,
+            dependencies,
+            todo_events: TodoEventHub::new(),
+            collab: CollabHub::new(),
+            webhook_destinations: DestinationPolicy::default(),
         }
     }
+
+    /// Mengganti kebijakan alamat tujuan webhook, mis. dengan allowlist dari konfigurasi.
+    pub fn with_webhook_destinations(mut self, destinations: DestinationPolicy) -> Self {
+        self.webhook_destinations = destinations;
+        self
+    }
+
+    /// State dengan konfigurasi default untuk integration test.
+    /// Lampiran disimpan di folder sementara yang unik per pemanggilan.
+    #[cfg(test)]
+    pub fn for_tests(db: DatabaseConnection, default_user_id: Uuid) -> Self {
+        use crate::infrastructure::storage::local::LocalBlobStore;
+
+        let root = std::env::temp_dir().join(format!("todo-attachments-{}", Uuid::new_v4()));
+        Self::new(
+            db,
+            default_user_id,
+            HistorySettings::default(),
+            AttachmentSettings::local(root.clone()),
+            Arc::new(LocalBlobStore::new(root)),
+            WorkspaceSettings::default(),
+            DependencySettings::default(),
+        )
+    }
 }
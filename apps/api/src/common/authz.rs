@@ -0,0 +1,607 @@
+//! Kebijakan akses terpusat: daftar permission, pemetaan role ke permission,
+//! dan satu fungsi `authorize` yang dipakai seluruh service.
+//!
+//! Role share (`viewer`/`editor`/`owner`) berlaku untuk todo dan project; role workspace
+//! (`guest`/`member`/`admin`/`owner`) berlaku untuk workspace itu sendiri. Akses member ke isi
+//! workspace diturunkan lewat `WorkspaceRole::content_role` di `sharing::service`.
+
+use sea_orm::{ConnectionTrait, DbErr};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::modules::project::entity::Model as Project;
+use crate::modules::sharing::dto::ShareRole;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::workspace::dto::WorkspaceRole;
+use crate::modules::workspace::repository as workspace_repository;
+
+/// Hasil pengecekan akses yang dipakai service domain lain.
+///
+/// `NotFound` juga dipakai bila user tidak punya akses sama sekali,
+/// agar keberadaan resource milik orang lain tidak bocor.
+#[derive(Debug)]
+pub enum AccessError {
+    NotFound,
+    /// User bisa melihat resource tetapi role-nya kurang untuk aksi ini.
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for AccessError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Jenis resource yang menjadi sasaran sebuah permission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Todo,
+    Project,
+    Workspace,
+}
+
+/// Aksi yang bisa diizinkan atau ditolak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Melihat todo beserta komentar, lampiran, dan history-nya.
+    TodoRead,
+    /// Mengubah todo, menugaskan, mengunggah lampiran, dan undo.
+    TodoWrite,
+    /// Menulis komentar pada todo.
+    CommentWrite,
+    TodoDelete,
+    /// Mengelola share grant todo.
+    TodoShare,
+    ProjectRead,
+    /// Menambahkan todo ke project.
+    ProjectWrite,
+    /// Mengganti nama dan menghapus project.
+    ProjectManage,
+    /// Mengelola share grant project.
+    ProjectShare,
+    /// Melihat detail dan daftar member workspace.
+    WorkspaceRead,
+    /// Membuat project/todo di workspace.
+    WorkspaceWrite,
+    /// Mengganti nama workspace.
+    WorkspaceManage,
+    /// Membuat, melihat, dan mencabut undangan.
+    MemberInvite,
+    /// Mengubah role dan mengeluarkan member non-owner.
+    MemberManage,
+    /// Memberi, mencabut, atau mengeluarkan owner.
+    OwnerManage,
+}
+
+impl Permission {
+    /// Seluruh permission, dipakai untuk pengujian tabel.
+    #[cfg(test)]
+    pub const ALL: [Self; 15] = [
+        Self::TodoRead,
+        Self::TodoWrite,
+        Self::CommentWrite,
+        Self::TodoDelete,
+        Self::TodoShare,
+        Self::ProjectRead,
+        Self::ProjectWrite,
+        Self::ProjectManage,
+        Self::ProjectShare,
+        Self::WorkspaceRead,
+        Self::WorkspaceWrite,
+        Self::WorkspaceManage,
+        Self::MemberInvite,
+        Self::MemberManage,
+        Self::OwnerManage,
+    ];
+
+    /// Nama permission, dipakai di log penolakan akses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TodoRead => "todo.read",
+            Self::TodoWrite => "todo.write",
+            Self::CommentWrite => "comment.write",
+            Self::TodoDelete => "todo.delete",
+            Self::TodoShare => "todo.share",
+            Self::ProjectRead => "project.read",
+            Self::ProjectWrite => "project.write",
+            Self::ProjectManage => "project.manage",
+            Self::ProjectShare => "project.share",
+            Self::WorkspaceRead => "workspace.read",
+            Self::WorkspaceWrite => "workspace.write",
+            Self::WorkspaceManage => "workspace.manage",
+            Self::MemberInvite => "member.invite",
+            Self::MemberManage => "member.manage",
+            Self::OwnerManage => "owner.manage",
+        }
+    }
+
+    /// Jenis resource tempat permission ini diperiksa.
+    pub fn scope(self) -> Scope {
+        match self {
+            Self::TodoRead | Self::TodoWrite | Self::CommentWrite | Self::TodoDelete | Self::TodoShare => {
+                Scope::Todo
+            }
+            Self::ProjectRead | Self::ProjectWrite | Self::ProjectManage | Self::ProjectShare => Scope::Project,
+            Self::WorkspaceRead
+            | Self::WorkspaceWrite
+            | Self::WorkspaceManage
+            | Self::MemberInvite
+            | Self::MemberManage
+            | Self::OwnerManage => Scope::Workspace,
+        }
+    }
+}
+
+/// Permission yang dimiliki role share atas todo/project.
+pub fn share_role_permissions(role: ShareRole) -> &'static [Permission] {
+    use Permission::*;
+    match role {
+        ShareRole::Viewer => &[TodoRead, ProjectRead],
+        ShareRole::Editor => &[TodoRead, TodoWrite, CommentWrite, ProjectRead, ProjectWrite],
+        ShareRole::Owner => &[
+            TodoRead,
+            TodoWrite,
+            CommentWrite,
+            TodoDelete,
+            TodoShare,
+            ProjectRead,
+            ProjectWrite,
+            ProjectManage,
+            ProjectShare,
+        ],
+    }
+}
+
+/// Permission yang dimiliki role workspace atas workspace itu sendiri.
+pub fn workspace_role_permissions(role: WorkspaceRole) -> &'static [Permission] {
+    use Permission::*;
+    match role {
+        WorkspaceRole::Guest => &[WorkspaceRead],
+        WorkspaceRole::Member => &[WorkspaceRead, WorkspaceWrite],
+        WorkspaceRole::Admin => &[WorkspaceRead, WorkspaceWrite, WorkspaceManage, MemberInvite, MemberManage],
+        WorkspaceRole::Owner => &[
+            WorkspaceRead,
+            WorkspaceWrite,
+            WorkspaceManage,
+            MemberInvite,
+            MemberManage,
+            OwnerManage,
+        ],
+    }
+}
+
+/// Resource yang diperiksa; todo dan project sudah dimuat oleh pemanggil.
+#[derive(Debug, Clone, Copy)]
+pub enum Resource<'a> {
+    Todo(&'a Todo),
+    Project(&'a Project),
+    Workspace(Uuid),
+}
+
+impl Resource<'_> {
+    fn scope(&self) -> Scope {
+        match self {
+            Self::Todo(_) => Scope::Todo,
+            Self::Project(_) => Scope::Project,
+            Self::Workspace(_) => Scope::Workspace,
+        }
+    }
+}
+
+/// Role efektif aktor pada resource yang lolos pengecekan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Share(ShareRole),
+    Workspace(WorkspaceRole),
+}
+
+impl Role {
+    /// Role share; `Viewer` bila resource berupa workspace (tidak terjadi untuk todo/project).
+    pub fn share_role(self) -> ShareRole {
+        match self {
+            Self::Share(role) => role,
+            Self::Workspace(_) => ShareRole::Viewer,
+        }
+    }
+
+    /// Role workspace; `Guest` bila resource berupa todo/project.
+    pub fn workspace_role(self) -> WorkspaceRole {
+        match self {
+            Self::Workspace(role) => role,
+            Self::Share(_) => WorkspaceRole::Guest,
+        }
+    }
+
+    /// Apakah role ini memberi `action`.
+    pub fn allows(self, action: Permission) -> bool {
+        let granted = match self {
+            Self::Share(role) => share_role_permissions(role),
+            Self::Workspace(role) => workspace_role_permissions(role),
+        };
+        granted.contains(&action)
+    }
+}
+
+/// Satu-satunya pintu pengecekan akses: mengembalikan role efektif `actor_id` pada `resource`
+/// bila role tersebut memberi `action`.
+///
+/// Tanpa akses sama sekali (termasuk bukan member workspace) dianggap `NotFound`;
+/// punya akses tetapi permission kurang, atau permission tidak cocok dengan jenis resource,
+/// dianggap `Forbidden`.
+pub async fn authorize<C: ConnectionTrait>(
+    conn: &C,
+    actor_id: Uuid,
+    action: Permission,
+    resource: Resource<'_>,
+) -> Result<Role, AccessError> {
+    let role = match resource {
+        Resource::Todo(todo) => sharing_service::todo_role(conn, actor_id, todo).await?.map(Role::Share),
+        Resource::Project(project) => sharing_service::project_role(conn, actor_id, project)
+            .await?
+            .map(Role::Share),
+        Resource::Workspace(workspace_id) => workspace_repository::find_member(conn, workspace_id, actor_id)
+            .await?
+            // Role workspace tak dikenal diperlakukan sebagai guest.
+            .map(|member| Role::Workspace(WorkspaceRole::parse(&member.role).unwrap_or(WorkspaceRole::Guest))),
+    };
+    if action.scope() != resource.scope() {
+        return Err(AccessError::Forbidden);
+    }
+    let checked = check(role, action);
+    if matches!(checked, Err(AccessError::Forbidden)) {
+        debug!(actor_id = %actor_id, permission = action.as_str(), "permission denied");
+    }
+    checked
+}
+
+/// Membandingkan role efektif dengan permission yang dibutuhkan.
+/// Tanpa role sama sekali dianggap `NotFound`, role tanpa permission dianggap `Forbidden`.
+pub fn check(role: Option<Role>, action: Permission) -> Result<Role, AccessError> {
+    match role {
+        None => Err(AccessError::NotFound),
+        Some(role) if !role.allows(action) => Err(AccessError::Forbidden),
+        Some(role) => Ok(role),
+    }
+}
+
+// Modul tests tabel role × permission dan `authorize` end to end terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use sea_orm::{Database, DatabaseConnection};
+
+    use crate::modules::history::dto::AuditContext;
+    use crate::modules::project::repository as project_repository;
+    use crate::modules::sharing::dto::ShareTarget;
+    use crate::modules::sharing::repository as sharing_repository;
+    use crate::modules::todo::dto::NewTodo;
+    use crate::modules::todo::repository as todo_repository;
+
+    use super::*;
+    use Permission::*;
+
+    // Tabel lengkap role × permission; setiap baris adalah permission yang diharapkan.
+    const SHARE_TABLE: [(ShareRole, &[Permission]); 3] = [
+        (ShareRole::Viewer, &[TodoRead, ProjectRead]),
+        (ShareRole::Editor, &[TodoRead, TodoWrite, CommentWrite, ProjectRead, ProjectWrite]),
+        (
+            ShareRole::Owner,
+            &[
+                TodoRead,
+                TodoWrite,
+                CommentWrite,
+                TodoDelete,
+                TodoShare,
+                ProjectRead,
+                ProjectWrite,
+                ProjectManage,
+                ProjectShare,
+            ],
+        ),
+    ];
+
+    const WORKSPACE_TABLE: [(WorkspaceRole, &[Permission]); 4] = [
+        (WorkspaceRole::Guest, &[WorkspaceRead]),
+        (WorkspaceRole::Member, &[WorkspaceRead, WorkspaceWrite]),
+        (
+            WorkspaceRole::Admin,
+            &[WorkspaceRead, WorkspaceWrite, WorkspaceManage, MemberInvite, MemberManage],
+        ),
+        (
+            WorkspaceRole::Owner,
+            &[
+                WorkspaceRead,
+                WorkspaceWrite,
+                WorkspaceManage,
+                MemberInvite,
+                MemberManage,
+                OwnerManage,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn share_roles_should_match_permission_table() {
+        for (role, expected) in SHARE_TABLE {
+            for action in Permission::ALL {
+                assert_eq!(
+                    Role::Share(role).allows(action),
+                    expected.contains(&action),
+                    "{role:?} / {}",
+                    action.as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn workspace_roles_should_match_permission_table() {
+        for (role, expected) in WORKSPACE_TABLE {
+            for action in Permission::ALL {
+                assert_eq!(
+                    Role::Workspace(role).allows(action),
+                    expected.contains(&action),
+                    "{role:?} / {}",
+                    action.as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stronger_roles_should_keep_weaker_permissions() {
+        for pair in SHARE_TABLE.windows(2) {
+            assert!(pair[0].1.iter().all(|action| pair[1].1.contains(action)));
+        }
+        for pair in WORKSPACE_TABLE.windows(2) {
+            assert!(pair[0].1.iter().all(|action| pair[1].1.contains(action)));
+        }
+    }
+
+    #[test]
+    fn share_roles_should_only_grant_todo_and_project_permissions() {
+        for (_, granted) in SHARE_TABLE {
+            assert!(granted.iter().all(|action| action.scope() != Scope::Workspace));
+        }
+        for (_, granted) in WORKSPACE_TABLE {
+            assert!(granted.iter().all(|action| action.scope() == Scope::Workspace));
+        }
+    }
+
+    #[test]
+    fn permission_names_should_be_unique() {
+        for (index, action) in Permission::ALL.iter().enumerate() {
+            assert!(Permission::ALL[index + 1..].iter().all(|other| other.as_str() != action.as_str()));
+        }
+    }
+
+    #[test]
+    fn check_should_hide_inaccessible_and_reject_insufficient() {
+        assert!(matches!(check(None, TodoRead), Err(AccessError::NotFound)));
+        assert!(matches!(
+            check(Some(Role::Share(ShareRole::Viewer)), TodoWrite),
+            Err(AccessError::Forbidden)
+        ));
+        assert!(matches!(
+            check(Some(Role::Share(ShareRole::Owner)), TodoWrite),
+            Ok(Role::Share(ShareRole::Owner))
+        ));
+        assert!(matches!(
+            check(Some(Role::Workspace(WorkspaceRole::Admin)), OwnerManage),
+            Err(AccessError::Forbidden)
+        ));
+    }
+
+    // Hasil `authorize` yang diharapkan untuk satu baris tabel.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Outcome {
+        Allowed(Role),
+        NotFound,
+        Forbidden,
+    }
+
+    fn outcome(result: Result<Role, AccessError>) -> Outcome {
+        match result {
+            Ok(role) => Outcome::Allowed(role),
+            Err(AccessError::NotFound) => Outcome::NotFound,
+            Err(AccessError::Forbidden) => Outcome::Forbidden,
+            Err(AccessError::Database(err)) => panic!("unexpected database error: {err}"),
+        }
+    }
+
+    // Aktor acak per test; nama field menggambarkan hubungan masing-masing dengan data fixture.
+    struct Actors {
+        owner: Uuid,
+        admin: Uuid,
+        member: Uuid,
+        guest: Uuid,
+        /// Guest workspace dengan grant `editor` pada todo workspace.
+        todo_editor: Uuid,
+        /// Guest workspace dengan grant `viewer` pada project workspace.
+        project_viewer: Uuid,
+        /// Bukan member, hanya punya grant `viewer` pada todo pribadi owner.
+        personal_viewer: Uuid,
+        stranger: Uuid,
+    }
+
+    struct Fixture {
+        actors: Actors,
+        workspace_id: Uuid,
+        project: Project,
+        todo: Todo,
+        personal_todo: Todo,
+    }
+
+    async fn insert_todo(db: &DatabaseConnection, owner: Uuid, workspace_id: Option<Uuid>, project_id: Option<Uuid>) -> Todo {
+        let audit = AuditContext {
+            actor_id: owner,
+            redact_desc: false,
+            reverts_event_id: None,
+        };
+        let new_todo = NewTodo {
+            id: Uuid::new_v4(),
+            user_id: owner,
+            workspace_id,
+            project_id,
+            assignee_id: None,
+            title: "authz".to_string(),
+            desc: None,
+            estimate: None,
+            due_at: None,
+            completed_at: None,
+            external_uid: None,
+        };
+        todo_repository::create_todo(db, new_todo, &audit)
+            .await
+            .expect("todo insert must succeed")
+    }
+
+    async fn build_fixture(db: &DatabaseConnection) -> Fixture {
+        let actors = Actors {
+            owner: Uuid::new_v4(),
+            admin: Uuid::new_v4(),
+            member: Uuid::new_v4(),
+            guest: Uuid::new_v4(),
+            todo_editor: Uuid::new_v4(),
+            project_viewer: Uuid::new_v4(),
+            personal_viewer: Uuid::new_v4(),
+            stranger: Uuid::new_v4(),
+        };
+        let everyone = [
+            actors.owner,
+            actors.admin,
+            actors.member,
+            actors.guest,
+            actors.todo_editor,
+            actors.project_viewer,
+            actors.personal_viewer,
+            actors.stranger,
+        ];
+        for user_id in everyone {
+            todo_repository::ensure_default_user(db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let workspace = workspace_repository::create_workspace(db, actors.owner, "authz".to_string())
+            .await
+            .expect("workspace must be created");
+        let members = [
+            (actors.admin, WorkspaceRole::Admin),
+            (actors.member, WorkspaceRole::Member),
+            (actors.guest, WorkspaceRole::Guest),
+            (actors.todo_editor, WorkspaceRole::Guest),
+            (actors.project_viewer, WorkspaceRole::Guest),
+        ];
+        for (user_id, role) in members {
+            workspace_repository::add_member(db, workspace.id, user_id, role)
+                .await
+                .expect("member must be added");
+        }
+        let project = project_repository::create_project(db, actors.owner, Some(workspace.id), "authz".to_string())
+            .await
+            .expect("project must be created");
+        let todo = insert_todo(db, actors.owner, Some(workspace.id), Some(project.id)).await;
+        let personal_todo = insert_todo(db, actors.owner, None, None).await;
+        let grants = [
+            (ShareTarget::Todo(todo.id), actors.todo_editor, ShareRole::Editor),
+            (ShareTarget::Project(project.id), actors.project_viewer, ShareRole::Viewer),
+            (ShareTarget::Todo(personal_todo.id), actors.personal_viewer, ShareRole::Viewer),
+        ];
+        for (target, grantee, role) in grants {
+            sharing_repository::upsert_grant(db, target, grantee, role, actors.owner)
+                .await
+                .expect("grant must be stored");
+        }
+        Fixture {
+            actors,
+            workspace_id: workspace.id,
+            project,
+            todo,
+            personal_todo,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn authorize_should_resolve_every_resource_kind_end_to_end() {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let fixture = build_fixture(&db).await;
+        let Actors {
+            owner,
+            admin,
+            member,
+            guest,
+            todo_editor,
+            project_viewer,
+            personal_viewer,
+            stranger,
+        } = fixture.actors;
+        let todo = Resource::Todo(&fixture.todo);
+        let personal_todo = Resource::Todo(&fixture.personal_todo);
+        let project = Resource::Project(&fixture.project);
+        let workspace = Resource::Workspace(fixture.workspace_id);
+        let missing_workspace = Resource::Workspace(Uuid::new_v4());
+        let share = |role| Outcome::Allowed(Role::Share(role));
+        let member_of = |role| Outcome::Allowed(Role::Workspace(role));
+
+        let table: Vec<(&str, Uuid, Permission, Resource<'_>, Outcome)> = vec![
+            // Todo di workspace: role turunan workspace, grant todo, dan grant project.
+            ("workspace owner deletes todo", owner, TodoDelete, todo, share(ShareRole::Owner)),
+            ("admin shares todo", admin, TodoShare, todo, share(ShareRole::Owner)),
+            ("member edits todo", member, TodoWrite, todo, share(ShareRole::Editor)),
+            ("member cannot delete todo", member, TodoDelete, todo, Outcome::Forbidden),
+            ("guest without grant", guest, TodoRead, todo, Outcome::NotFound),
+            ("todo grant edits", todo_editor, CommentWrite, todo, share(ShareRole::Editor)),
+            ("todo grant cannot share", todo_editor, TodoShare, todo, Outcome::Forbidden),
+            ("project grant reads todo", project_viewer, TodoRead, todo, share(ShareRole::Viewer)),
+            ("project grant cannot edit todo", project_viewer, TodoWrite, todo, Outcome::Forbidden),
+            ("stranger reads todo", stranger, TodoRead, todo, Outcome::NotFound),
+            // Todo pribadi: hanya pemilik dan grant langsung.
+            ("owner deletes personal todo", owner, TodoDelete, personal_todo, share(ShareRole::Owner)),
+            ("personal grant reads", personal_viewer, TodoRead, personal_todo, share(ShareRole::Viewer)),
+            ("personal grant cannot comment", personal_viewer, CommentWrite, personal_todo, Outcome::Forbidden),
+            ("workspace admin on personal todo", admin, TodoRead, personal_todo, Outcome::NotFound),
+            // Project di workspace.
+            ("owner manages project", owner, ProjectManage, project, share(ShareRole::Owner)),
+            ("member adds to project", member, ProjectWrite, project, share(ShareRole::Editor)),
+            ("member cannot manage project", member, ProjectManage, project, Outcome::Forbidden),
+            ("project grant reads project", project_viewer, ProjectRead, project, share(ShareRole::Viewer)),
+            ("project grant cannot write", project_viewer, ProjectWrite, project, Outcome::Forbidden),
+            ("todo grant does not reach project", todo_editor, ProjectRead, project, Outcome::NotFound),
+            ("guest without grant on project", guest, ProjectRead, project, Outcome::NotFound),
+            ("stranger reads project", stranger, ProjectRead, project, Outcome::NotFound),
+            // Workspace itu sendiri.
+            ("owner manages owners", owner, OwnerManage, workspace, member_of(WorkspaceRole::Owner)),
+            ("admin manages members", admin, MemberManage, workspace, member_of(WorkspaceRole::Admin)),
+            ("admin cannot manage owners", admin, OwnerManage, workspace, Outcome::Forbidden),
+            ("member creates content", member, WorkspaceWrite, workspace, member_of(WorkspaceRole::Member)),
+            ("member cannot invite", member, MemberInvite, workspace, Outcome::Forbidden),
+            ("guest reads workspace", guest, WorkspaceRead, workspace, member_of(WorkspaceRole::Guest)),
+            ("guest cannot write", guest, WorkspaceWrite, workspace, Outcome::Forbidden),
+            ("share grant is not membership", project_viewer, WorkspaceWrite, workspace, Outcome::Forbidden),
+            ("non-member reads workspace", stranger, WorkspaceRead, workspace, Outcome::NotFound),
+            ("personal grant is not membership", personal_viewer, WorkspaceRead, workspace, Outcome::NotFound),
+            ("unknown workspace", owner, WorkspaceRead, missing_workspace, Outcome::NotFound),
+            // Permission yang tidak cocok dengan jenis resource selalu ditolak, walau role-nya kuat.
+            ("project permission on todo", owner, ProjectRead, todo, Outcome::Forbidden),
+            ("workspace permission on todo", owner, WorkspaceRead, todo, Outcome::Forbidden),
+            ("todo permission on project", owner, TodoRead, project, Outcome::Forbidden),
+            ("workspace permission on project", owner, WorkspaceManage, project, Outcome::Forbidden),
+            ("todo permission on workspace", owner, TodoRead, workspace, Outcome::Forbidden),
+            ("project permission on workspace", admin, ProjectRead, workspace, Outcome::Forbidden),
+            ("mismatch without access", stranger, ProjectManage, todo, Outcome::Forbidden),
+        ];
+
+        for (case, actor, action, resource, expected) in table {
+            let actual = outcome(authorize(&db, actor, action, resource).await);
+            assert_eq!(actual, expected, "{case}: {} on {:?}", action.as_str(), resource.scope());
+        }
+    }
+}
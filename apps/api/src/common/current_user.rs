@@ -0,0 +1,88 @@
+use axum::{extract::FromRequestParts, http::request::Parts};
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::modules::user::repository as user_repository;
+use crate::modules::workspace::repository as workspace_repository;
+
+use super::error::ApiError;
+
+/// Header yang dipakai client untuk memilih user pelaku selama fase no-auth.
+pub const USER_ID_HEADER: &str = "x-user-id";
+
+/// Header yang dipakai client untuk memilih workspace aktif.
+pub const WORKSPACE_ID_HEADER: &str = "x-workspace-id";
+
+/// User pelaku request beserta workspace aktifnya.
+///
+/// Fase no-auth: diambil dari header `X-User-Id` bila dikirim (harus user terdaftar),
+/// selain itu memakai `DEFAULT_USER_ID`. Saat auth tersedia, cukup extractor ini yang diganti.
+///
+/// Workspace aktif diambil dari header `X-Workspace-Id` dan user wajib menjadi member-nya;
+/// tanpa header, request berjalan di ruang pribadi user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentUser {
+    pub id: Uuid,
+    /// Workspace aktif; `None` berarti ruang pribadi.
+    pub workspace_id: Option<Uuid>,
+}
+
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let id = resolve_user_id(parts, state).await?;
+        let workspace_id = resolve_workspace_id(parts, state, id).await?;
+        Ok(Self { id, workspace_id })
+    }
+}
+
+// User dari `X-User-Id`, atau user default bila header tidak dikirim.
+async fn resolve_user_id(parts: &Parts, state: &AppState) -> Result<Uuid, ApiError> {
+    let Some(raw) = parts.headers.get(USER_ID_HEADER) else {
+        return Ok(state.default_user_id);
+    };
+    let id = raw
+        .to_str()
+        .ok()
+        .and_then(|value| Uuid::parse_str(value.trim()).ok())
+        .ok_or_else(|| ApiError::unauthorized("X-User-Id must be a valid uuid"))?;
+    if id == state.default_user_id {
+        return Ok(id);
+    }
+
+    let exists = user_repository::user_exists(&state.db, id).await.map_err(|err| {
+        error!(error = %err, "current user lookup failed");
+        ApiError::internal("unexpected database error")
+    })?;
+    if !exists {
+        warn!(user_id = %id, "unknown user in X-User-Id header");
+        return Err(ApiError::unauthorized("unknown user"));
+    }
+    Ok(id)
+}
+
+// Workspace dari `X-Workspace-Id`; bukan member dijawab `404` agar keberadaannya tidak bocor.
+async fn resolve_workspace_id(parts: &Parts, state: &AppState, user_id: Uuid) -> Result<Option<Uuid>, ApiError> {
+    let Some(raw) = parts.headers.get(WORKSPACE_ID_HEADER) else {
+        return Ok(None);
+    };
+    let workspace_id = raw
+        .to_str()
+        .ok()
+        .and_then(|value| Uuid::parse_str(value.trim()).ok())
+        .ok_or_else(|| ApiError::bad_request("X-Workspace-Id must be a valid uuid"))?;
+
+    let member = workspace_repository::find_member(&state.db, workspace_id, user_id)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "workspace membership lookup failed");
+            ApiError::internal("unexpected database error")
+        })?;
+    if member.is_none() {
+        warn!(user_id = %user_id, workspace_id = %workspace_id, "user is not a member of active workspace");
+        return Err(ApiError::not_found("workspace not found"));
+    }
+    Ok(Some(workspace_id))
+}
@@ -4,6 +4,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
+use serde_json::Value;
 
 /// Error standar API agar format error JSON konsisten di semua endpoint.
 #[derive(Debug)]
@@ -11,6 +12,7 @@ pub struct ApiError {
     status: StatusCode,
     code: &'static str,
     message: String,
+    details: Option<Value>,
 }
 
 /// Envelope response error: `{ "error": { ... } }`.
@@ -24,6 +26,9 @@ pub struct ErrorEnvelope {
 pub struct ErrorBody {
     pub code: String,
     pub message: String,
+    /// Data tambahan yang bisa dipakai client, mis. id todo yang memblokir.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
 }
 
 impl ApiError {
@@ -33,6 +38,27 @@ impl ApiError {
             status: StatusCode::BAD_REQUEST,
             code: "BAD_REQUEST",
             message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 401 untuk identitas user yang tidak valid atau tidak dikenal.
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            code: "UNAUTHORIZED",
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 403 untuk aksi yang tidak diizinkan bagi user saat ini.
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::FORBIDDEN,
+            code: "FORBIDDEN",
+            message: message.into(),
+            details: None,
         }
     }
 
@@ -42,6 +68,48 @@ impl ApiError {
             status: StatusCode::NOT_FOUND,
             code: "NOT_FOUND",
             message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 409 untuk request yang bentrok dengan state resource saat ini.
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::CONFLICT,
+            code: "CONFLICT",
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 412 untuk `If-Match`/`If-None-Match` yang tidak terpenuhi.
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::PRECONDITION_FAILED,
+            code: "PRECONDITION_FAILED",
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 413 untuk body/file yang melebihi batas ukuran atau kuota.
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::PAYLOAD_TOO_LARGE,
+            code: "PAYLOAD_TOO_LARGE",
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Error 422 untuk request yang formatnya valid tetapi tidak bisa diproses, mis. `Idempotency-Key`
+    /// yang dipakai ulang dengan body berbeda.
+    pub fn unprocessable_entity(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            code: "UNPROCESSABLE_ENTITY",
+            message: message.into(),
+            details: None,
         }
     }
 
@@ -51,8 +119,15 @@ impl ApiError {
             status: StatusCode::INTERNAL_SERVER_ERROR,
             code: "INTERNAL_ERROR",
             message: message.into(),
+            details: None,
         }
     }
+
+    /// Menyertakan data tambahan pada body error.
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
 }
 
 impl IntoResponse for ApiError {
@@ -61,6 +136,7 @@ impl IntoResponse for ApiError {
             error: ErrorBody {
                 code: self.code.to_string(),
                 message: self.message,
+                details: self.details,
             },
         };
         (self.status, Json(body)).into_response()
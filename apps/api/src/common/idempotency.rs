@@ -0,0 +1,449 @@
+//! Middleware `Idempotency-Key` untuk request `POST`: response pertama disimpan dan di-replay
+//! saat client mengirim ulang request yang sama dengan key yang sama.
+
+use std::time::Duration;
+
+use axum::{
+    body::{Body, Bytes, to_bytes},
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::config::idempotency::IdempotencySettings;
+use crate::infrastructure::db::idempotency::{self as store, StoredKey};
+
+use super::current_user::{USER_ID_HEADER, WORKSPACE_ID_HEADER};
+use super::error::ApiError;
+
+/// Header key idempotensi yang dikirim client.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// Header penanda response hasil replay.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+const MAX_KEY_LEN: usize = 255;
+/// Key yang masih "diproses" lebih lama dari ini dianggap ditinggalkan dan boleh diklaim ulang.
+const IN_FLIGHT_TIMEOUT_SECS: i64 = 60;
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// State middleware idempotensi; dipasang lewat `axum::middleware::from_fn_with_state`.
+#[derive(Clone)]
+pub struct Idempotency {
+    db: DatabaseConnection,
+    settings: IdempotencySettings,
+}
+
+impl Idempotency {
+    pub fn new(db: DatabaseConnection, settings: IdempotencySettings) -> Self {
+        Self { db, settings }
+    }
+}
+
+/// Middleware `Idempotency-Key`.
+///
+/// Hanya berlaku untuk `POST` yang membawa header `Idempotency-Key`; request lain diteruskan apa adanya.
+/// Request `multipart/*` (upload file) juga diteruskan tanpa dibuffer karena body-nya bisa jauh
+/// lebih besar dari `max_body_bytes`.
+/// Key dicakup per user (`X-User-Id`). Request pertama diproses dan response-nya disimpan selama
+/// TTL; kiriman ulang dengan method, path, workspace, dan body yang sama mendapat response yang sama
+/// (ditandai `Idempotent-Replayed: true`). Key yang sama dengan request berbeda ditolak `422`,
+/// dan kiriman ulang saat request pertama masih berjalan ditolak `409`.
+/// Response `5xx` tidak disimpan sehingga request bisa dicoba lagi.
+pub async fn enforce(State(idempotency): State<Idempotency>, request: Request, next: Next) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let Some(raw_key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    if is_multipart(request.headers()) {
+        return next.run(request).await;
+    }
+    let key = match parse_key(raw_key) {
+        Ok(key) => key,
+        Err(err) => return err.into_response(),
+    };
+    // User tidak valid dibiarkan ditolak extractor `CurrentUser` seperti biasa.
+    let Some(scope) = request_scope(request.headers()) else {
+        return next.run(request).await;
+    };
+
+    let (parts, body) = request.into_parts();
+    let limit = usize::try_from(idempotency.settings.max_body_bytes).unwrap_or(usize::MAX);
+    let Ok(body) = to_bytes(body, limit).await else {
+        return ApiError::payload_too_large("request body is too large for an idempotent request").into_response();
+    };
+    let fingerprint = fingerprint(&parts, &body);
+
+    let now = Utc::now().fixed_offset();
+    let expires_at = now + idempotency.settings.ttl();
+    let stale_before = now - chrono::Duration::seconds(IN_FLIGHT_TIMEOUT_SECS);
+    let claimed = store::claim_key(&idempotency.db, &scope, &key, &fingerprint, now, expires_at, stale_before).await;
+    let claim_id = match claimed {
+        Ok(Some(claim_id)) => claim_id,
+        Ok(None) => return existing_key_response(&idempotency.db, &scope, &key, &fingerprint).await,
+        Err(err) => {
+            error!(error = %err, "failed to claim idempotency key");
+            return ApiError::internal("unexpected database error").into_response();
+        }
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if response.status().is_server_error() {
+        release(&idempotency.db, &scope, &key, claim_id).await;
+        return response;
+    }
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(err) => {
+            error!(error = %err, "failed to buffer response for idempotency key");
+            release(&idempotency.db, &scope, &key, claim_id).await;
+            return ApiError::internal("unexpected server error").into_response();
+        }
+    };
+    if body.len() > limit {
+        warn!(size = body.len(), "response too large to store for idempotency key");
+        release(&idempotency.db, &scope, &key, claim_id).await;
+    } else {
+        let content_type = parts
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let stored = store::complete_key(
+            &idempotency.db,
+            &scope,
+            &key,
+            claim_id,
+            i16::try_from(parts.status.as_u16()).unwrap_or(i16::MAX),
+            content_type,
+            body.to_vec(),
+        )
+        .await;
+        if let Err(err) = stored {
+            error!(error = %err, "failed to store idempotent response");
+            release(&idempotency.db, &scope, &key, claim_id).await;
+        }
+    }
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Loop background untuk menghapus key yang sudah kedaluwarsa.
+pub async fn run_cleanup(idempotency: Idempotency) {
+    let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+    loop {
+        interval.tick().await;
+        match store::delete_expired_keys(&idempotency.db, Utc::now().fixed_offset()).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(deleted, "expired idempotency keys pruned"),
+            Err(err) => error!(error = %err, "failed to prune expired idempotency keys"),
+        }
+    }
+}
+
+// Key wajib non-empty setelah trim dan maksimal 255 karakter ASCII yang terlihat.
+fn parse_key(raw: &HeaderValue) -> Result<String, ApiError> {
+    let key = raw.to_str().map(str::trim).unwrap_or_default();
+    if key.is_empty() || key.len() > MAX_KEY_LEN || !key.chars().all(|ch| ch.is_ascii_graphic()) {
+        return Err(ApiError::bad_request(
+            "Idempotency-Key must be 1 to 255 visible ASCII characters",
+        ));
+    }
+    Ok(key.to_string())
+}
+
+// Cakupan key: user dari `X-User-Id`, atau `default` untuk user default mode no-auth.
+fn request_scope(headers: &HeaderMap) -> Option<String> {
+    match headers.get(USER_ID_HEADER) {
+        None => Some("default".to_string()),
+        Some(raw) => raw
+            .to_str()
+            .ok()
+            .and_then(|value| Uuid::parse_str(value.trim()).ok())
+            .map(|id| id.to_string()),
+    }
+}
+
+// Upload multipart di-stream langsung ke handler; tidak ikut idempotensi.
+fn is_multipart(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim_start().to_ascii_lowercase().starts_with("multipart/"))
+}
+
+// SHA-256 dari method, path + query, workspace aktif, dan body.
+fn fingerprint(parts: &Parts, body: &Bytes) -> String {
+    let workspace = parts
+        .headers
+        .get(WORKSPACE_ID_HEADER)
+        .map(HeaderValue::as_bytes)
+        .unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(parts.method.as_str().as_bytes());
+    hasher.update([0]);
+    hasher.update(parts.uri.to_string().as_bytes());
+    hasher.update([0]);
+    hasher.update(workspace);
+    hasher.update([0]);
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+// Respons untuk key yang sudah pernah diklaim: replay, 422, atau 409.
+async fn existing_key_response(conn: &DatabaseConnection, scope: &str, key: &str, fingerprint: &str) -> Response {
+    let stored = match store::find_key(conn, scope, key).await {
+        Ok(stored) => stored,
+        Err(err) => {
+            error!(error = %err, "failed to load idempotency key");
+            return ApiError::internal("unexpected database error").into_response();
+        }
+    };
+    match stored {
+        Some(stored) if stored.fingerprint != fingerprint => {
+            warn!("idempotency key reused with a different request");
+            ApiError::unprocessable_entity("Idempotency-Key was already used with a different request")
+                .into_response()
+        }
+        Some(StoredKey {
+            status_code: Some(status_code),
+            content_type,
+            response_body,
+            ..
+        }) => {
+            info!(status = status_code, "idempotent response replayed");
+            replay(status_code, content_type, response_body.unwrap_or_default())
+        }
+        // Masih diproses, atau baru saja dilepas setelah gagal.
+        _ => ApiError::conflict("a request with this Idempotency-Key is still being processed").into_response(),
+    }
+}
+
+fn replay(status_code: i16, content_type: Option<String>, body: Vec<u8>) -> Response {
+    let status = u16::try_from(status_code)
+        .ok()
+        .and_then(|code| StatusCode::from_u16(code).ok())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let mut response = (status, body).into_response();
+    let headers = response.headers_mut();
+    match content_type.and_then(|value| HeaderValue::from_str(&value).ok()) {
+        Some(content_type) => {
+            headers.insert(header::CONTENT_TYPE, content_type);
+        }
+        None => {
+            headers.remove(header::CONTENT_TYPE);
+        }
+    }
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+async fn release(conn: &DatabaseConnection, scope: &str, key: &str, claim_id: Uuid) {
+    if let Err(err) = store::release_key(conn, scope, key, claim_id).await {
+        error!(error = %err, "failed to release idempotency key");
+    }
+}
+
+// Modul tests untuk parsing key, fingerprint, dan integrasi middleware dengan route todo.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{Router, middleware};
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::app_state::AppState;
+    use crate::config::idempotency::MAX_TTL_HOURS;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    fn parts(method: &str, uri: &str, workspace: Option<&str>) -> Parts {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(workspace) = workspace {
+            builder = builder.header(WORKSPACE_ID_HEADER, workspace);
+        }
+        builder.body(Body::empty()).expect("request should be valid").into_parts().0
+    }
+
+    #[test]
+    fn parse_key_should_accept_visible_ascii_up_to_limit() {
+        assert_eq!(parse_key(&HeaderValue::from_static(" abc-123 ")).ok(), Some("abc-123".to_string()));
+        assert!(parse_key(&HeaderValue::from_static("   ")).is_err());
+        assert!(parse_key(&HeaderValue::from_static("with space")).is_err());
+        let long = HeaderValue::from_str(&"k".repeat(MAX_KEY_LEN + 1)).expect("valid header");
+        assert!(parse_key(&long).is_err());
+    }
+
+    #[test]
+    fn request_scope_should_use_user_header_or_default() {
+        let user_id = Uuid::new_v4();
+        let mut headers = HeaderMap::new();
+        assert_eq!(request_scope(&headers).as_deref(), Some("default"));
+        headers.insert(USER_ID_HEADER, HeaderValue::from_str(&user_id.to_string()).expect("valid header"));
+        assert_eq!(request_scope(&headers), Some(user_id.to_string()));
+        headers.insert(USER_ID_HEADER, HeaderValue::from_static("not-a-uuid"));
+        assert_eq!(request_scope(&headers), None);
+    }
+
+    #[test]
+    fn is_multipart_should_match_multipart_content_types_only() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_multipart(&headers));
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("Multipart/Form-Data; boundary=x"));
+        assert!(is_multipart(&headers));
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        assert!(!is_multipart(&headers));
+    }
+
+    #[test]
+    fn ttl_should_be_clamped_to_valid_range() {
+        let huge = IdempotencySettings {
+            ttl_hours: u64::MAX,
+            ..IdempotencySettings::default()
+        };
+        let max_hours = i64::try_from(MAX_TTL_HOURS).expect("max ttl fits in i64");
+        assert_eq!(huge.ttl(), chrono::Duration::hours(max_hours));
+        let zero = IdempotencySettings {
+            ttl_hours: 0,
+            ..IdempotencySettings::default()
+        };
+        assert_eq!(zero.ttl(), chrono::Duration::hours(1));
+    }
+
+    #[test]
+    fn fingerprint_should_change_with_path_workspace_and_body() {
+        let body = Bytes::from_static(b"{\"title\":\"a\"}");
+        let base = fingerprint(&parts("POST", "/api/todos", None), &body);
+        assert_eq!(base, fingerprint(&parts("POST", "/api/todos", None), &body));
+        assert_ne!(base, fingerprint(&parts("POST", "/api/projects", None), &body));
+        assert_ne!(base, fingerprint(&parts("POST", "/api/todos", Some("w")), &body));
+        assert_ne!(base, fingerprint(&parts("POST", "/api/todos", None), &Bytes::from_static(b"{}")));
+    }
+
+    async fn build_test_app() -> (Router, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let idempotency = Idempotency::new(db.clone(), IdempotencySettings::default());
+        let app = Router::new()
+            .nest("/todos", todo_handler::routes())
+            .with_state(AppState::for_tests(db, user_id))
+            .layer(middleware::from_fn_with_state(idempotency, enforce));
+        (app, user_id)
+    }
+
+    async fn post_todo(app: &Router, user_id: Uuid, key: &str, body: Value) -> (StatusCode, HeaderMap, Value) {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header(USER_ID_HEADER, user_id.to_string())
+            .header(IDEMPOTENCY_KEY_HEADER, key)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        (status, headers, serde_json::from_slice(&bytes).expect("body must be valid json"))
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn idempotency_key_should_replay_and_reject_mismatched_retries() {
+        let (app, user_id) = build_test_app().await;
+        let key = Uuid::new_v4().to_string();
+
+        let (status, headers, first) = post_todo(&app, user_id, &key, json!({ "title": "only once" })).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(headers.get(IDEMPOTENT_REPLAYED_HEADER).is_none());
+
+        let (status, headers, retry) = post_todo(&app, user_id, &key, json!({ "title": "only once" })).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(headers.get(IDEMPOTENT_REPLAYED_HEADER).map(HeaderValue::as_bytes), Some(&b"true"[..]));
+        assert_eq!(retry["data"]["id"], first["data"]["id"]);
+
+        let (status, _, body) = post_todo(&app, user_id, &key, json!({ "title": "something else" })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["error"]["code"], "UNPROCESSABLE_ENTITY");
+
+        // Validasi gagal (4xx) juga di-replay; key lain membuat todo baru.
+        let bad_key = Uuid::new_v4().to_string();
+        let (status, _, _) = post_todo(&app, user_id, &bad_key, json!({ "title": " " })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, headers, _) = post_todo(&app, user_id, &bad_key, json!({ "title": " " })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(headers.get(IDEMPOTENT_REPLAYED_HEADER).is_some());
+
+        let (_, _, other) = post_todo(&app, user_id, &Uuid::new_v4().to_string(), json!({ "title": "only once" })).await;
+        assert_ne!(other["data"]["id"], first["data"]["id"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn stale_claim_should_not_overwrite_or_release_taken_over_key() {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let scope = Uuid::new_v4().to_string();
+        let key = Uuid::new_v4().to_string();
+        let now = Utc::now().fixed_offset();
+        let expires_at = now + chrono::Duration::hours(1);
+
+        let stale = store::claim_key(&db, &scope, &key, "first", now, expires_at, now - chrono::Duration::minutes(1))
+            .await
+            .expect("claim must succeed")
+            .expect("new key must be claimed");
+        // Klaim pertama dianggap ditinggalkan sehingga request kedua mengambil alih key.
+        let current = store::claim_key(&db, &scope, &key, "second", now, expires_at, now)
+            .await
+            .expect("claim must succeed")
+            .expect("stale key must be taken over");
+        assert_ne!(stale, current);
+
+        store::complete_key(&db, &scope, &key, stale, 201, None, b"stale".to_vec())
+            .await
+            .expect("update must succeed");
+        store::release_key(&db, &scope, &key, stale)
+            .await
+            .expect("delete must succeed");
+        let stored = store::find_key(&db, &scope, &key)
+            .await
+            .expect("query must succeed")
+            .expect("key must still exist");
+        assert_eq!(stored.fingerprint, "second");
+        assert_eq!(stored.status_code, None);
+
+        store::complete_key(&db, &scope, &key, current, 201, None, b"fresh".to_vec())
+            .await
+            .expect("update must succeed");
+        let stored = store::find_key(&db, &scope, &key)
+            .await
+            .expect("query must succeed")
+            .expect("key must exist");
+        assert_eq!(stored.status_code, Some(201));
+        assert_eq!(stored.response_body.as_deref(), Some(&b"fresh"[..]));
+        store::release_key(&db, &scope, &key, current)
+            .await
+            .expect("delete must succeed");
+    }
+}
@@ -1,3 +1,7 @@
 //! Komponen lintas modul yang dipakai bersama.
 
+pub mod authz;
+pub mod current_user;
 pub mod error;
+pub mod idempotency;
+pub mod response;
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+/// Wrapper sukses untuk semua response API.
+#[derive(Debug, Serialize)]
+pub struct SuccessResponse<T> {
+    pub data: T,
+}
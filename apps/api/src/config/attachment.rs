@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+
+use super::env::parse_u64_with_default;
+
+/// Batas default ukuran satu file lampiran (10 MiB).
+const DEFAULT_MAX_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// Batas default total lampiran per user (100 MiB).
+const DEFAULT_USER_QUOTA_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Konfigurasi fitur lampiran todo dan backend penyimpanannya.
+#[derive(Debug, Clone)]
+pub struct AttachmentSettings {
+    pub max_size_bytes: u64,
+    pub user_quota_bytes: u64,
+    /// Folder sementara untuk menampung upload sebelum dikirim ke backend.
+    pub tmp_dir: PathBuf,
+    pub backend: StorageBackend,
+}
+
+/// Pilihan backend penyimpanan isi file lampiran.
+#[derive(Debug, Clone)]
+pub enum StorageBackend {
+    /// Disimpan di filesystem lokal di bawah `root`.
+    Local { root: PathBuf },
+    /// Disimpan di object storage S3-compatible (AWS S3, MinIO, dll).
+    S3(S3Settings),
+}
+
+/// Konfigurasi koneksi object storage S3-compatible (path-style).
+#[derive(Debug, Clone)]
+pub struct S3Settings {
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl AttachmentSettings {
+    /// Membaca konfigurasi lampiran dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        let backend = match std::env::var("ATTACHMENT_STORAGE_BACKEND")
+            .unwrap_or_else(|_| "local".to_string())
+            .as_str()
+        {
+            "local" => StorageBackend::Local {
+                root: std::env::var("ATTACHMENT_LOCAL_DIR")
+                    .unwrap_or_else(|_| "./data/attachments".to_string())
+                    .into(),
+            },
+            "s3" => StorageBackend::S3(S3Settings {
+                endpoint: std::env::var("S3_ENDPOINT").context("missing S3_ENDPOINT")?,
+                bucket: std::env::var("S3_BUCKET").context("missing S3_BUCKET")?,
+                region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+                access_key_id: std::env::var("S3_ACCESS_KEY_ID").context("missing S3_ACCESS_KEY_ID")?,
+                secret_access_key: std::env::var("S3_SECRET_ACCESS_KEY")
+                    .context("missing S3_SECRET_ACCESS_KEY")?,
+            }),
+            other => bail!("invalid value for ATTACHMENT_STORAGE_BACKEND: {other}"),
+        };
+
+        let max_size_bytes = parse_u64_with_default("ATTACHMENT_MAX_SIZE_BYTES", DEFAULT_MAX_SIZE_BYTES)?;
+        let user_quota_bytes =
+            parse_u64_with_default("ATTACHMENT_USER_QUOTA_BYTES", DEFAULT_USER_QUOTA_BYTES)?;
+        if max_size_bytes > user_quota_bytes {
+            bail!("ATTACHMENT_MAX_SIZE_BYTES cannot be greater than ATTACHMENT_USER_QUOTA_BYTES");
+        }
+
+        Ok(Self {
+            max_size_bytes,
+            user_quota_bytes,
+            tmp_dir: std::env::var("ATTACHMENT_TMP_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| std::env::temp_dir()),
+            backend,
+        })
+    }
+
+    /// Nama backend untuk log startup (tanpa kredensial).
+    pub fn backend_name(&self) -> &'static str {
+        match self.backend {
+            StorageBackend::Local { .. } => "local",
+            StorageBackend::S3(_) => "s3",
+        }
+    }
+
+    /// Konfigurasi backend lokal di folder tertentu untuk test.
+    #[cfg(test)]
+    pub fn local(root: PathBuf) -> Self {
+        Self {
+            max_size_bytes: DEFAULT_MAX_SIZE_BYTES,
+            user_quota_bytes: DEFAULT_USER_QUOTA_BYTES,
+            tmp_dir: std::env::temp_dir(),
+            backend: StorageBackend::Local { root },
+        }
+    }
+}
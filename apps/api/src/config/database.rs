@@ -1,5 +1,7 @@
 use anyhow::{Context, Result, bail};
 
+use super::env::{parse_u32_with_default, parse_u64_with_default};
+
 /// Konfigurasi koneksi database yang dibaca saat startup.
 #[derive(Debug, Clone)]
 pub struct DatabaseSettings {
@@ -40,22 +42,6 @@ impl DatabaseSettings {
     }
 }
 
-// Helper functions untuk parsing dan manipulasi string konfigurasi database.
-fn parse_u32_with_default(key: &str, default: u32) -> Result<u32> {
-    match std::env::var(key) {
-        Ok(raw) => raw.parse::<u32>().with_context(|| format!("invalid value for {key}: {raw}")),
-        Err(_) => Ok(default),
-    }
-}
-
-// Sama seperti `parse_u32_with_default` tapi untuk tipe `u64`.
-fn parse_u64_with_default(key: &str, default: u64) -> Result<u64> {
-    match std::env::var(key) {
-        Ok(raw) => raw.parse::<u64>().with_context(|| format!("invalid value for {key}: {raw}")),
-        Err(_) => Ok(default),
-    }
-}
-
 // Menambahkan parameter sslmode pada connection string bila belum ada, sesuai dengan konfigurasi yang diberikan.
 fn with_ssl_mode(database_url: &str, ssl_mode: &str) -> String {
     if database_url.contains("sslmode=") {
@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use super::env::parse_bool_with_default;
+
+/// Konfigurasi relasi "blocked by" antar todo.
+#[derive(Debug, Clone)]
+pub struct DependencySettings {
+    /// Bila `true`, todo tidak bisa ditandai selesai selama masih ada blocker yang belum selesai.
+    pub block_completion: bool,
+}
+
+impl DependencySettings {
+    /// Membaca konfigurasi dependency dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            block_completion: parse_bool_with_default("TODO_DEPENDENCY_BLOCK_COMPLETION", true)?,
+        })
+    }
+}
+
+impl Default for DependencySettings {
+    fn default() -> Self {
+        Self { block_completion: true }
+    }
+}
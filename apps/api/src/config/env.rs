@@ -0,0 +1,42 @@
+use anyhow::{Context, Result, bail};
+
+// Helper functions untuk parsing environment variable dengan nilai default.
+pub fn parse_u32_with_default(key: &str, default: u32) -> Result<u32> {
+    match std::env::var(key) {
+        Ok(raw) => raw.parse::<u32>().with_context(|| format!("invalid value for {key}: {raw}")),
+        Err(_) => Ok(default),
+    }
+}
+
+// Sama seperti `parse_u32_with_default` tapi untuk tipe `u64`.
+pub fn parse_u64_with_default(key: &str, default: u64) -> Result<u64> {
+    match std::env::var(key) {
+        Ok(raw) => raw.parse::<u64>().with_context(|| format!("invalid value for {key}: {raw}")),
+        Err(_) => Ok(default),
+    }
+}
+
+// Menerima `true/false/1/0` (case-insensitive) agar mudah diisi dari `.env` maupun compose.
+pub fn parse_bool_with_default(key: &str, default: bool) -> Result<bool> {
+    match std::env::var(key) {
+        Ok(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => bail!("invalid value for {key}: {raw}"),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+// Daftar dipisah koma; spasi di sekitar item dan item kosong diabaikan.
+pub fn parse_list(key: &str) -> Vec<String> {
+    match std::env::var(key) {
+        Ok(raw) => raw
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
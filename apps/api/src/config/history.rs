@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use super::env::{parse_bool_with_default, parse_u32_with_default, parse_u64_with_default};
+
+/// Batas default umur event yang masih boleh di-undo.
+const DEFAULT_UNDO_WINDOW_SECS: u64 = 300;
+
+/// Konfigurasi audit log perubahan todo (`todo_events`).
+#[derive(Debug, Clone)]
+pub struct HistorySettings {
+    /// Umur maksimal event dalam hari; `None` berarti event disimpan selamanya.
+    pub retention_days: Option<u32>,
+    /// Bila `true`, isi `desc` tidak ikut disimpan di diff event.
+    pub redact_desc: bool,
+    /// Umur maksimal event (detik) yang masih bisa dibatalkan lewat undo.
+    pub undo_window_secs: u64,
+}
+
+impl HistorySettings {
+    /// Membaca konfigurasi history dari environment variable.
+    /// `TODO_HISTORY_RETENTION_DAYS=0` (default) mematikan pembersihan otomatis.
+    pub fn from_env() -> Result<Self> {
+        let retention_days = parse_u32_with_default("TODO_HISTORY_RETENTION_DAYS", 0)?;
+        Ok(Self {
+            retention_days: (retention_days > 0).then_some(retention_days),
+            redact_desc: parse_bool_with_default("TODO_HISTORY_REDACT_DESC", false)?,
+            undo_window_secs: parse_u64_with_default("TODO_UNDO_WINDOW_SECS", DEFAULT_UNDO_WINDOW_SECS)?,
+        })
+    }
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self {
+            retention_days: None,
+            redact_desc: false,
+            undo_window_secs: DEFAULT_UNDO_WINDOW_SECS,
+        }
+    }
+}
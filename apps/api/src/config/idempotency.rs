@@ -0,0 +1,43 @@
+use anyhow::Result;
+
+use super::env::parse_u64_with_default;
+
+/// Batas atas TTL key (10 tahun) agar perhitungan waktu kedaluwarsa tidak overflow.
+pub const MAX_TTL_HOURS: u64 = 10 * 365 * 24;
+
+/// Konfigurasi header `Idempotency-Key` pada request `POST`.
+#[derive(Debug, Clone)]
+pub struct IdempotencySettings {
+    /// Lama key dan response-nya disimpan untuk replay, dalam jam (1 sampai `MAX_TTL_HOURS`).
+    pub ttl_hours: u64,
+    /// Ukuran maksimal body request (dan response yang disimpan) yang bisa diproses idempoten.
+    pub max_body_bytes: u64,
+}
+
+impl IdempotencySettings {
+    /// Membaca konfigurasi idempotency dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            ttl_hours: parse_u64_with_default("TODO_IDEMPOTENCY_TTL_HOURS", defaults.ttl_hours)?
+                .clamp(1, MAX_TTL_HOURS),
+            max_body_bytes: parse_u64_with_default("TODO_IDEMPOTENCY_MAX_BODY_BYTES", defaults.max_body_bytes)?
+                .max(1),
+        })
+    }
+
+    /// TTL key sebagai durasi, dibatasi ke rentang yang valid.
+    pub fn ttl(&self) -> chrono::Duration {
+        let hours = self.ttl_hours.clamp(1, MAX_TTL_HOURS);
+        chrono::Duration::hours(i64::try_from(hours).unwrap_or(1))
+    }
+}
+
+impl Default for IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            ttl_hours: 24,
+            max_body_bytes: 1024 * 1024,
+        }
+    }
+}
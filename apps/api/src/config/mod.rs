@@ -1,3 +1,12 @@
 //! Modul konfigurasi aplikasi berbasis environment variable.
 
+pub mod attachment;
 pub mod database;
+pub mod dependency;
+pub mod env;
+pub mod history;
+pub mod idempotency;
+pub mod outbox;
+pub mod reminder;
+pub mod webhook;
+pub mod workspace;
@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use super::env::{parse_bool_with_default, parse_u32_with_default, parse_u64_with_default};
+
+/// Konfigurasi relay outbox event domain.
+#[derive(Debug, Clone)]
+pub struct OutboxSettings {
+    /// Bila `false`, instance ini tidak menjalankan relay (event tetap tercatat di outbox).
+    pub relay_enabled: bool,
+    /// Jeda antar polling outbox, dalam detik.
+    pub poll_interval_secs: u64,
+    /// Jumlah maksimal event yang dikunci dan diproses per batch.
+    pub batch_size: u64,
+    /// Batas percobaan sebelum event ditandai `failed`.
+    pub max_attempts: u32,
+    /// Lama event `processed` disimpan sebelum dibersihkan, dalam jam.
+    pub retention_hours: u64,
+}
+
+impl OutboxSettings {
+    /// Membaca konfigurasi outbox dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            relay_enabled: parse_bool_with_default("TODO_OUTBOX_RELAY_ENABLED", defaults.relay_enabled)?,
+            poll_interval_secs: parse_u64_with_default("TODO_OUTBOX_POLL_SECS", defaults.poll_interval_secs)?.max(1),
+            batch_size: parse_u64_with_default("TODO_OUTBOX_BATCH_SIZE", defaults.batch_size)?.max(1),
+            max_attempts: parse_u32_with_default("TODO_OUTBOX_MAX_ATTEMPTS", defaults.max_attempts)?.max(1),
+            retention_hours: parse_u64_with_default("TODO_OUTBOX_RETENTION_HOURS", defaults.retention_hours)?.max(1),
+        })
+    }
+}
+
+impl Default for OutboxSettings {
+    fn default() -> Self {
+        Self {
+            relay_enabled: true,
+            poll_interval_secs: 1,
+            batch_size: 100,
+            max_attempts: 10,
+            retention_hours: 168,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use super::env::{parse_bool_with_default, parse_u32_with_default, parse_u64_with_default};
+
+/// Konfigurasi scheduler pengiriman reminder todo.
+#[derive(Debug, Clone)]
+pub struct ReminderSettings {
+    /// Bila `false`, instance ini tidak menjalankan scheduler (reminder tetap bisa dibuat).
+    pub scheduler_enabled: bool,
+    /// Jeda antar polling reminder yang jatuh tempo, dalam detik.
+    pub poll_interval_secs: u64,
+    /// Jumlah maksimal reminder yang dikunci dan dikirim per batch.
+    pub batch_size: u64,
+    /// Batas percobaan kirim sebelum reminder ditandai `failed`.
+    pub max_attempts: u32,
+}
+
+impl ReminderSettings {
+    /// Membaca konfigurasi reminder dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            scheduler_enabled: parse_bool_with_default("TODO_REMINDER_SCHEDULER_ENABLED", defaults.scheduler_enabled)?,
+            poll_interval_secs: parse_u64_with_default("TODO_REMINDER_POLL_SECS", defaults.poll_interval_secs)?.max(1),
+            batch_size: parse_u64_with_default("TODO_REMINDER_BATCH_SIZE", defaults.batch_size)?.max(1),
+            max_attempts: parse_u32_with_default("TODO_REMINDER_MAX_ATTEMPTS", defaults.max_attempts)?.max(1),
+        })
+    }
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self {
+            scheduler_enabled: true,
+            poll_interval_secs: 30,
+            batch_size: 50,
+            max_attempts: 5,
+        }
+    }
+}
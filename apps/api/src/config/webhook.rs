@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::infrastructure::webhook::DestinationPolicy;
+
+use super::env::{parse_bool_with_default, parse_list, parse_u32_with_default, parse_u64_with_default};
+
+/// Konfigurasi worker pengiriman webhook keluar.
+#[derive(Debug, Clone)]
+pub struct WebhookSettings {
+    /// Bila `false`, instance ini tidak mengirim webhook (event tetap masuk antrean).
+    pub worker_enabled: bool,
+    /// Jeda antar polling antrean pengiriman, dalam detik.
+    pub poll_interval_secs: u64,
+    /// Jumlah maksimal pengiriman yang dikunci dan dikirim paralel per batch.
+    pub batch_size: u64,
+    /// Batas percobaan kirim sebelum pengiriman dipindah ke dead-letter (`dead`).
+    pub max_attempts: u32,
+    /// Batas waktu satu request HTTP ke endpoint penerima, dalam detik.
+    pub timeout_secs: u64,
+    /// Host yang tetap boleh dituju walau mengarah ke alamat internal (loopback, private,
+    /// link-local). Kosong di produksi; diisi untuk pengembangan lokal dan test.
+    pub allowed_hosts: Vec<String>,
+}
+
+impl WebhookSettings {
+    /// Membaca konfigurasi webhook dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            worker_enabled: parse_bool_with_default("TODO_WEBHOOK_WORKER_ENABLED", defaults.worker_enabled)?,
+            poll_interval_secs: parse_u64_with_default("TODO_WEBHOOK_POLL_SECS", defaults.poll_interval_secs)?.max(1),
+            batch_size: parse_u64_with_default("TODO_WEBHOOK_BATCH_SIZE", defaults.batch_size)?.max(1),
+            max_attempts: parse_u32_with_default("TODO_WEBHOOK_MAX_ATTEMPTS", defaults.max_attempts)?.max(1),
+            timeout_secs: parse_u64_with_default("TODO_WEBHOOK_TIMEOUT_SECS", defaults.timeout_secs)?.max(1),
+            allowed_hosts: parse_list("TODO_WEBHOOK_ALLOWED_HOSTS"),
+        })
+    }
+
+    /// Kebijakan alamat tujuan webhook sesuai allowlist konfigurasi.
+    pub fn destination_policy(&self) -> DestinationPolicy {
+        DestinationPolicy::new(self.allowed_hosts.clone())
+    }
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            worker_enabled: true,
+            poll_interval_secs: 5,
+            batch_size: 20,
+            max_attempts: 8,
+            timeout_secs: 10,
+            allowed_hosts: Vec::new(),
+        }
+    }
+}
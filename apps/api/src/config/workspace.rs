@@ -0,0 +1,33 @@
+use anyhow::Result;
+
+use super::env::parse_u64_with_default;
+
+/// Masa berlaku default undangan workspace (7 hari).
+const DEFAULT_INVITATION_TTL_HOURS: u64 = 7 * 24;
+
+/// Konfigurasi workspace tim.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSettings {
+    /// Lama undangan bisa diterima sejak dibuat, dalam jam.
+    pub invitation_ttl_hours: u64,
+}
+
+impl WorkspaceSettings {
+    /// Membaca konfigurasi workspace dari environment variable.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            invitation_ttl_hours: parse_u64_with_default(
+                "WORKSPACE_INVITATION_TTL_HOURS",
+                DEFAULT_INVITATION_TTL_HOURS,
+            )?,
+        })
+    }
+}
+
+impl Default for WorkspaceSettings {
+    fn default() -> Self {
+        Self {
+            invitation_ttl_hours: DEFAULT_INVITATION_TTL_HOURS,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, Statement};
+use uuid::Uuid;
+
+/// Isi tabel `idempotency_keys` untuk satu key.
+#[derive(Debug, Clone)]
+pub struct StoredKey {
+    /// Hash SHA-256 (hex) method, path, header konteks, dan body request pertama.
+    pub fingerprint: String,
+    /// `None` selama request pertama masih diproses.
+    pub status_code: Option<i16>,
+    pub content_type: Option<String>,
+    pub response_body: Option<Vec<u8>>,
+}
+
+/// Mencoba mengklaim key untuk diproses. Mengembalikan token klaim bila key baru, sudah kedaluwarsa,
+/// atau ditinggalkan prosesnya lebih lama dari `stale_before` (mis. instance mati di tengah request);
+/// `None` bila key sedang atau sudah diproses request lain. Token dipakai `complete_key` dan
+/// `release_key` agar pemilik klaim lama tidak menimpa klaim yang sudah diambil alih.
+pub async fn claim_key<C: ConnectionTrait>(
+    conn: &C,
+    scope: &str,
+    key: &str,
+    fingerprint: &str,
+    now: DateTime<FixedOffset>,
+    expires_at: DateTime<FixedOffset>,
+    stale_before: DateTime<FixedOffset>,
+) -> Result<Option<Uuid>, DbErr> {
+    let claim_id = Uuid::new_v4();
+    let sql = "INSERT INTO idempotency_keys (scope, key, fingerprint, created_at, expires_at, claim_id) \
+               VALUES ($1, $2, $3, $4, $5, $7) \
+               ON CONFLICT (scope, key) DO UPDATE SET \
+                   fingerprint = EXCLUDED.fingerprint, status_code = NULL, content_type = NULL, \
+                   response_body = NULL, created_at = EXCLUDED.created_at, expires_at = EXCLUDED.expires_at, \
+                   claim_id = EXCLUDED.claim_id \
+               WHERE idempotency_keys.expires_at <= $4 \
+                  OR (idempotency_keys.status_code IS NULL AND idempotency_keys.created_at <= $6)";
+    let result = conn
+        .execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [
+                scope.into(),
+                key.into(),
+                fingerprint.into(),
+                now.into(),
+                expires_at.into(),
+                stale_before.into(),
+                claim_id.into(),
+            ],
+        ))
+        .await?;
+    Ok((result.rows_affected() == 1).then_some(claim_id))
+}
+
+/// Mengambil key yang tersimpan.
+pub async fn find_key<C: ConnectionTrait>(conn: &C, scope: &str, key: &str) -> Result<Option<StoredKey>, DbErr> {
+    let row = conn
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT fingerprint, status_code, content_type, response_body \
+             FROM idempotency_keys WHERE scope = $1 AND key = $2",
+            [scope.into(), key.into()],
+        ))
+        .await?;
+    row.map(|row| {
+        Ok(StoredKey {
+            fingerprint: row.try_get("", "fingerprint")?,
+            status_code: row.try_get("", "status_code")?,
+            content_type: row.try_get("", "content_type")?,
+            response_body: row.try_get("", "response_body")?,
+        })
+    })
+    .transpose()
+}
+
+/// Menyimpan response request pertama agar bisa di-replay; tidak berubah apa pun bila klaim
+/// `claim_id` sudah diambil alih request lain.
+pub async fn complete_key<C: ConnectionTrait>(
+    conn: &C,
+    scope: &str,
+    key: &str,
+    claim_id: Uuid,
+    status_code: i16,
+    content_type: Option<String>,
+    response_body: Vec<u8>,
+) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "UPDATE idempotency_keys SET status_code = $3, content_type = $4, response_body = $5 \
+         WHERE scope = $1 AND key = $2 AND claim_id = $6",
+        [
+            scope.into(),
+            key.into(),
+            status_code.into(),
+            content_type.into(),
+            response_body.into(),
+            claim_id.into(),
+        ],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Melepas key agar request berikutnya dengan key yang sama diproses ulang; hanya berlaku
+/// selama klaim `claim_id` belum diambil alih request lain.
+pub async fn release_key<C: ConnectionTrait>(conn: &C, scope: &str, key: &str, claim_id: Uuid) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "DELETE FROM idempotency_keys WHERE scope = $1 AND key = $2 AND claim_id = $3",
+        [scope.into(), key.into(), claim_id.into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Menghapus key yang sudah kedaluwarsa; mengembalikan jumlah baris terhapus.
+pub async fn delete_expired_keys<C: ConnectionTrait>(conn: &C, now: DateTime<FixedOffset>) -> Result<u64, DbErr> {
+    let result = conn
+        .execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM idempotency_keys WHERE expires_at <= $1",
+            [now.into()],
+        ))
+        .await?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,43 @@
+use sea_orm::sqlx::postgres::PgListener;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, DbErr, Statement};
+
+/// Listener `LISTEN` Postgres untuk satu channel, memakai koneksi terpisah dari pool aplikasi.
+pub struct ChannelListener {
+    inner: PgListener,
+}
+
+impl ChannelListener {
+    /// Membuka koneksi listener dan mulai `LISTEN` pada `channel`.
+    pub async fn connect(conn: &DatabaseConnection, channel: &str) -> Result<Self, DbErr> {
+        let mut inner = PgListener::connect_with(conn.get_postgres_connection_pool())
+            .await
+            .map_err(listener_error)?;
+        inner.listen(channel).await.map_err(listener_error)?;
+        Ok(Self { inner })
+    }
+
+    /// Payload notifikasi berikutnya. `None` berarti koneksi sempat terputus sehingga
+    /// notifikasi selama putus hilang; koneksi dan `LISTEN` dipulihkan otomatis
+    /// pada panggilan berikutnya.
+    pub async fn recv(&mut self) -> Result<Option<String>, DbErr> {
+        let notification = self.inner.try_recv().await.map_err(listener_error)?;
+        Ok(notification.map(|notification| notification.payload().to_string()))
+    }
+}
+
+/// Mengirim `NOTIFY` ke `channel`. Bila `conn` berupa transaksi, notifikasi baru terkirim
+/// saat commit dan dibuang saat rollback.
+pub async fn notify<C: ConnectionTrait>(conn: &C, channel: &str, payload: &str) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_notify($1, $2)",
+        [channel.into(), payload.into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+// Error sqlx dari listener dibungkus agar pemanggil cukup menangani `DbErr`.
+fn listener_error(err: sea_orm::sqlx::Error) -> DbErr {
+    DbErr::Custom(format!("postgres listener failed: {err}"))
+}
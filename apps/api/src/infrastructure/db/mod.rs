@@ -1,3 +1,5 @@
 //! Modul helper koneksi database.
 
 pub mod connection;
+pub mod idempotency;
+pub mod listener;
@@ -1,3 +1,6 @@
 //! Adapter infrastruktur (database dan integrasi eksternal).
 
 pub mod db;
+pub mod notifier;
+pub mod storage;
+pub mod webhook;
@@ -0,0 +1,98 @@
+//! Adapter pengiriman notifikasi ke user dengan kanal yang bisa diganti.
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Jenis event yang dinotifikasikan ke user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationKind {
+    /// User ditugasi sebuah todo.
+    Assigned,
+    /// Ada komentar baru pada todo milik/tugas user.
+    Commented,
+    /// Reminder todo jatuh tempo.
+    Reminder,
+}
+
+impl NotificationKind {
+    pub const ALL: [Self; 3] = [Self::Assigned, Self::Commented, Self::Reminder];
+
+    /// Nilai yang disimpan di database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Assigned => "assigned",
+            Self::Commented => "commented",
+            Self::Reminder => "reminder",
+        }
+    }
+
+    /// Parsing nilai dari database atau query string.
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == raw)
+    }
+}
+
+/// Pesan yang dikirim ke satu user lewat `Notifier`.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub user_id: Uuid,
+    pub kind: NotificationKind,
+    pub todo_id: Option<Uuid>,
+    /// User yang memicu event; `None` untuk event sistem seperti reminder.
+    pub actor_id: Option<Uuid>,
+    pub message: String,
+}
+
+/// Kontrak kanal pengiriman notifikasi (inbox in-app, email, webhook, ...).
+///
+/// `notify` dipanggil di dalam transaksi pemanggil (mis. transaksi klaim reminder): tulisan
+/// database kanal ikut ter-commit bersama status pemanggil, atau ikut batal bila pemanggil
+/// gagal, sehingga notifikasi tidak terkirim dua kali. Efek di luar database bisa terulang
+/// saat retry, jadi kanal seperti itu sebaiknya aman menerima notifikasi yang sama dua kali.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError>;
+}
+
+/// `Notifier` yang meneruskan notifikasi ke beberapa kanal berurutan.
+///
+/// Kegagalan satu kanal menggagalkan seluruh pengiriman; karena semua kanal menulis lewat
+/// transaksi yang sama, pemanggil yang me-rollback (mis. savepoint reminder) ikut membatalkan
+/// tulisan kanal lain sehingga retry tidak menggandakan notifikasi.
+pub struct FanOutNotifier {
+    channels: Vec<Arc<dyn Notifier>>,
+}
+
+impl FanOutNotifier {
+    pub fn new(channels: Vec<Arc<dyn Notifier>>) -> Self {
+        Self { channels }
+    }
+}
+
+#[async_trait]
+impl Notifier for FanOutNotifier {
+    async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+        for channel in &self.channels {
+            channel.notify(txn, notification).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Error dari kanal notifikasi; pesannya disimpan sebagai alasan gagal kirim.
+#[derive(Debug)]
+pub struct NotifyError(pub String);
+
+impl fmt::Display for NotifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "notification delivery failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for NotifyError {}
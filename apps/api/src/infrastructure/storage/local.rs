@@ -0,0 +1,142 @@
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tokio::{fs, io::AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+use super::{BlobError, BlobStore, BlobStream};
+
+/// `BlobStore` berbasis filesystem lokal; `key` dipetakan ke path relatif di bawah `root`.
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    // Menolak key yang bisa keluar dari `root` (absolut atau mengandung `..`).
+    fn path_for(&self, key: &str) -> Result<PathBuf, BlobError> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_safe {
+            return Err(BlobError::InvalidKey(key.to_string()));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    /// Ditulis ke file `.partial` dulu lalu di-rename agar pembaca tidak melihat file setengah jadi.
+    async fn put(
+        &self,
+        key: &str,
+        mut body: BlobStream,
+        content_length: u64,
+        _content_type: &str,
+    ) -> Result<(), BlobError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let partial = path.with_file_name(format!(
+            "{}.partial",
+            path.file_name().and_then(|name| name.to_str()).unwrap_or("blob")
+        ));
+
+        let result = async {
+            let mut file = fs::File::create(&partial).await?;
+            let mut written = 0u64;
+            while let Some(chunk) = body.next().await {
+                let chunk = chunk?;
+                written += chunk.len() as u64;
+                file.write_all(&chunk).await?;
+            }
+            file.flush().await?;
+            if written != content_length {
+                return Err(BlobError::Backend(format!(
+                    "expected {content_length} bytes but received {written}"
+                )));
+            }
+            fs::rename(&partial, &path).await?;
+            Ok(())
+        }
+        .await;
+
+        if result.is_err() {
+            let _ = fs::remove_file(&partial).await;
+        }
+        result
+    }
+
+    async fn get(&self, key: &str) -> Result<BlobStream, BlobError> {
+        let file = fs::File::open(self.path_for(key)?).await?;
+        Ok(Box::pin(ReaderStream::new(file)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobError> {
+        match fs::remove_file(self.path_for(key)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+// Modul tests untuk backend filesystem lokal.
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use futures_util::{TryStreamExt, stream};
+
+    use super::*;
+
+    fn temp_store() -> LocalBlobStore {
+        LocalBlobStore::new(std::env::temp_dir().join(format!("blob-test-{}", uuid::Uuid::new_v4())))
+    }
+
+    fn body(chunks: &[&'static str]) -> BlobStream {
+        Box::pin(stream::iter(
+            chunks
+                .iter()
+                .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn put_get_delete_should_roundtrip() {
+        let store = temp_store();
+
+        store
+            .put("a/b/c.txt", body(&["hello ", "world"]), 11, "text/plain")
+            .await
+            .expect("put should succeed");
+        let chunks: Vec<Bytes> = store
+            .get("a/b/c.txt")
+            .await
+            .expect("get should succeed")
+            .try_collect()
+            .await
+            .expect("stream should be readable");
+        assert_eq!(chunks.concat(), b"hello world");
+
+        store.delete("a/b/c.txt").await.expect("delete should succeed");
+        assert!(matches!(store.get("a/b/c.txt").await, Err(BlobError::NotFound)));
+        store.delete("a/b/c.txt").await.expect("second delete should be a no-op");
+    }
+
+    #[tokio::test]
+    async fn put_should_reject_escaping_keys() {
+        let store = temp_store();
+        let result = store.put("../escape", body(&["x"]), 1, "text/plain").await;
+        assert!(matches!(result, Err(BlobError::InvalidKey(_))));
+    }
+}
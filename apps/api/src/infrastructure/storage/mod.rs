@@ -0,0 +1,74 @@
+//! Adapter penyimpanan isi file (blob) dengan backend yang bisa diganti.
+
+pub mod local;
+pub mod s3;
+
+use std::{fmt, io, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::Stream;
+
+use crate::config::attachment::StorageBackend;
+
+/// Stream isi blob; dipakai untuk upload maupun download agar file tidak dibuffer penuh di memori.
+pub type BlobStream = Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send>>;
+
+/// Kontrak penyimpanan blob yang dipakai modul fitur (mis. lampiran todo).
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Menyimpan isi `body` pada `key`; `content_length` wajib sama dengan total byte stream.
+    async fn put(
+        &self,
+        key: &str,
+        body: BlobStream,
+        content_length: u64,
+        content_type: &str,
+    ) -> Result<(), BlobError>;
+
+    /// Membuka stream isi blob pada `key`.
+    async fn get(&self, key: &str) -> Result<BlobStream, BlobError>;
+
+    /// Menghapus blob pada `key`; tidak error bila blob sudah tidak ada.
+    async fn delete(&self, key: &str) -> Result<(), BlobError>;
+}
+
+/// Error yang bisa muncul dari backend penyimpanan blob.
+#[derive(Debug)]
+pub enum BlobError {
+    NotFound,
+    InvalidKey(String),
+    Io(io::Error),
+    Backend(String),
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "blob not found"),
+            Self::InvalidKey(key) => write!(f, "invalid blob key: {key}"),
+            Self::Io(err) => write!(f, "blob io error: {err}"),
+            Self::Backend(message) => write!(f, "blob backend error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for BlobError {}
+
+impl From<io::Error> for BlobError {
+    fn from(value: io::Error) -> Self {
+        if value.kind() == io::ErrorKind::NotFound {
+            Self::NotFound
+        } else {
+            Self::Io(value)
+        }
+    }
+}
+
+/// Membentuk implementasi `BlobStore` sesuai backend yang dikonfigurasi.
+pub fn build_blob_store(backend: &StorageBackend) -> Arc<dyn BlobStore> {
+    match backend {
+        StorageBackend::Local { root } => Arc::new(local::LocalBlobStore::new(root.clone())),
+        StorageBackend::S3(settings) => Arc::new(s3::S3BlobStore::new(settings.clone())),
+    }
+}
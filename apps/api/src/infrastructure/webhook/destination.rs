@@ -0,0 +1,202 @@
+//! Penjaga alamat tujuan webhook agar URL penerima tidak bisa dipakai menjangkau jaringan internal.
+
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::Url;
+
+/// Tujuan webhook ditolak: host tidak bisa di-resolve atau mengarah ke alamat internal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DestinationError {
+    Unresolvable(String),
+    Internal(IpAddr),
+}
+
+impl fmt::Display for DestinationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unresolvable(host) => write!(f, "webhook host {host} cannot be resolved"),
+            Self::Internal(ip) => write!(f, "webhook destination {ip} is not a public address"),
+        }
+    }
+}
+
+impl std::error::Error for DestinationError {}
+
+/// Kebijakan alamat tujuan webhook: hanya alamat publik, kecuali host yang di-allowlist.
+///
+/// Dipakai saat registrasi URL ([`DestinationPolicy::check_url`]) dan sebagai resolver DNS
+/// klien pengirim, sehingga host yang berganti alamat setelah registrasi tetap diperiksa
+/// pada alamat yang benar-benar dihubungi.
+#[derive(Debug, Clone, Default)]
+pub struct DestinationPolicy {
+    allowed_hosts: Arc<[String]>,
+}
+
+impl DestinationPolicy {
+    /// `allowed_hosts` berisi host persis seperti di URL (mis. `127.0.0.1`, `localhost`, `[::1]`)
+    /// yang boleh mengarah ke alamat internal; ditujukan untuk test dan pengembangan lokal.
+    pub fn new(allowed_hosts: Vec<String>) -> Self {
+        let allowed_hosts: Vec<String> = allowed_hosts
+            .into_iter()
+            .map(|host| host.trim().to_ascii_lowercase())
+            .filter(|host| !host.is_empty())
+            .collect();
+        Self {
+            allowed_hosts: allowed_hosts.into(),
+        }
+    }
+
+    /// Memastikan host URL, setelah resolusi DNS, hanya mengarah ke alamat publik.
+    pub async fn check_url(&self, url: &Url) -> Result<(), DestinationError> {
+        let host = url.host_str().unwrap_or_default();
+        if self.allows(host) {
+            return Ok(());
+        }
+        if ip_host(url).is_some() {
+            return self.check_ip_host(url);
+        }
+        let port = url.port_or_known_default().unwrap_or(80);
+        let addrs = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|_| DestinationError::Unresolvable(host.to_string()))?;
+        self.filter(host, addrs).map(|_| ())
+    }
+
+    /// Pemeriksaan untuk URL dengan IP literal, yang tidak melewati resolver DNS klien.
+    pub fn check_ip_host(&self, url: &Url) -> Result<(), DestinationError> {
+        let Some(ip) = ip_host(url) else {
+            return Ok(());
+        };
+        if self.allows(url.host_str().unwrap_or_default()) || !is_internal(ip) {
+            return Ok(());
+        }
+        Err(DestinationError::Internal(ip))
+    }
+
+    fn allows(&self, host: &str) -> bool {
+        self.allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host))
+    }
+
+    // Seluruh alamat hasil resolusi harus publik; satu alamat internal cukup untuk menolak host.
+    fn filter(
+        &self,
+        host: &str,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+    ) -> Result<Vec<SocketAddr>, DestinationError> {
+        let addrs: Vec<SocketAddr> = addrs.into_iter().collect();
+        if addrs.is_empty() {
+            return Err(DestinationError::Unresolvable(host.to_string()));
+        }
+        if !self.allows(host)
+            && let Some(internal) = addrs.iter().map(SocketAddr::ip).find(|ip| is_internal(*ip))
+        {
+            return Err(DestinationError::Internal(internal));
+        }
+        Ok(addrs)
+    }
+}
+
+// Host IP literal pada URL; IPv6 ditulis dalam kurung siku (`[::1]`).
+fn ip_host(url: &Url) -> Option<IpAddr> {
+    let host = url.host_str()?;
+    host.strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(host)
+        .parse()
+        .ok()
+}
+
+impl Resolve for DestinationPolicy {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.clone();
+        Box::pin(async move {
+            let host = name.as_str();
+            let resolved = tokio::net::lookup_host((host, 0)).await?;
+            let addrs = policy.filter(host, resolved)?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// `true` untuk alamat yang tidak boleh dituju webhook: loopback, private, link-local,
+/// shared address (CGNAT), unspecified, broadcast, multicast, dan dokumentasi.
+pub fn is_internal(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || first == 0
+                || (first == 100 && (second & 0xc0) == 64)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_internal(IpAddr::V4(mapped)),
+            None => {
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+                    || ip.is_multicast()
+            }
+        },
+    }
+}
+
+// Modul tests untuk klasifikasi alamat dan allowlist tujuan webhook.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_internal_should_flag_non_public_ranges() {
+        for internal in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.10",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(is_internal(internal.parse().expect("ip")), "{internal}");
+        }
+        for public in ["93.184.216.34", "1.1.1.1", "172.32.0.1", "2606:4700:4700::1111"] {
+            assert!(!is_internal(public.parse().expect("ip")), "{public}");
+        }
+    }
+
+    #[tokio::test]
+    async fn check_url_should_reject_internal_hosts_unless_allowlisted() {
+        let strict = DestinationPolicy::default();
+        for internal in ["http://127.0.0.1:8080/hook", "http://[::1]/hook", "http://localhost/hook"] {
+            let url = Url::parse(internal).expect("url");
+            assert!(strict.check_url(&url).await.is_err(), "{internal}");
+        }
+        let public = Url::parse("https://93.184.216.34/hook").expect("url");
+        assert_eq!(strict.check_url(&public).await, Ok(()));
+
+        let local = DestinationPolicy::new(vec![" 127.0.0.1 ".to_string(), "LOCALHOST".to_string()]);
+        for allowed in ["http://127.0.0.1:8080/hook", "http://localhost/hook"] {
+            assert_eq!(local.check_url(&Url::parse(allowed).expect("url")).await, Ok(()), "{allowed}");
+        }
+        let other = Url::parse("http://10.0.0.5/hook").expect("url");
+        assert_eq!(
+            local.check_url(&other).await,
+            Err(DestinationError::Internal("10.0.0.5".parse().expect("ip")))
+        );
+    }
+}
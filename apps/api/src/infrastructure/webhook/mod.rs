@@ -0,0 +1,215 @@
+//! Klien HTTP untuk mengirim webhook keluar bertanda tangan HMAC-SHA256.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use reqwest::header;
+use sha2::Sha256;
+use uuid::Uuid;
+
+pub mod destination;
+
+pub use destination::DestinationPolicy;
+
+/// Header tanda tangan: `t=<unix detik>,v1=<hex HMAC-SHA256(secret, "<t>.<body>")>`.
+pub const SIGNATURE_HEADER: &str = "x-todo-signature";
+/// Header jenis event, mis. `todo.created`.
+pub const EVENT_HEADER: &str = "x-todo-event";
+/// Header id pengiriman; sama di setiap retry sehingga penerima bisa deduplikasi.
+pub const DELIVERY_HEADER: &str = "x-todo-delivery";
+/// Potongan body response error yang disimpan sebagai alasan gagal.
+const MAX_ERROR_BODY_LEN: usize = 500;
+
+/// Satu request webhook yang siap dikirim.
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookRequest<'a> {
+    pub url: &'a str,
+    pub secret: &'a str,
+    pub delivery_id: Uuid,
+    pub event_type: &'a str,
+    pub body: &'a str,
+}
+
+/// Pengiriman gagal: status HTTP non-2xx (bila ada response) beserta alasannya.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookError {
+    pub status: Option<u16>,
+    pub message: String,
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "webhook delivery failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// Pengirim webhook dengan batas waktu per request.
+///
+/// Alamat tujuan diperiksa `DestinationPolicy` saat koneksi dibuka (resolver DNS klien),
+/// jadi endpoint yang host-nya kemudian mengarah ke jaringan internal tidak dihubungi.
+#[derive(Debug, Clone)]
+pub struct WebhookSender {
+    client: reqwest::Client,
+    destinations: DestinationPolicy,
+}
+
+impl WebhookSender {
+    pub fn new(timeout: Duration, destinations: DestinationPolicy) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(destinations.clone()))
+            .build()
+            .expect("webhook http client must build");
+        Self { client, destinations }
+    }
+
+    /// POST body JSON bertanda tangan ke endpoint penerima.
+    /// Mengembalikan status HTTP bila penerima membalas 2xx.
+    pub async fn send(&self, request: WebhookRequest<'_>, timestamp: i64) -> Result<u16, WebhookError> {
+        let url = reqwest::Url::parse(request.url).map_err(|err| WebhookError {
+            status: None,
+            message: err.to_string(),
+        })?;
+        self.destinations.check_ip_host(&url).map_err(|err| WebhookError {
+            status: None,
+            message: err.to_string(),
+        })?;
+        let signature = sign_payload(request.secret, timestamp, request.body);
+        let response = self
+            .client
+            .post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(EVENT_HEADER, request.event_type)
+            .header(DELIVERY_HEADER, request.delivery_id.to_string())
+            .body(request.body.to_string())
+            .send()
+            .await
+            .map_err(|err| WebhookError {
+                status: None,
+                message: err.to_string(),
+            })?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(status.as_u16());
+        }
+        let body = response.text().await.unwrap_or_default();
+        let body: String = body.chars().take(MAX_ERROR_BODY_LEN).collect();
+        Err(WebhookError {
+            status: Some(status.as_u16()),
+            message: format!("endpoint responded with {status}: {body}"),
+        })
+    }
+}
+
+/// Nilai header `x-todo-signature` untuk body dan timestamp tertentu.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac accepts any key length");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    format!("t={timestamp},v1={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+// Modul tests untuk tanda tangan dan pengiriman terhadap penerima lokal.
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{Router, extract::State, http::HeaderMap, http::StatusCode, routing::post};
+
+    use super::*;
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    #[test]
+    fn sign_payload_should_match_reference_hmac() {
+        assert_eq!(
+            sign_payload("whsec_test", 1_700_000_000, r#"{"type":"webhook.test"}"#),
+            "t=1700000000,v1=cf7d053522b08300fae293c3bcbf4e183d4a9538620c18dc9a46d063337936fa"
+        );
+    }
+
+    // Penerima lokal: `/ok` mencatat request, `/fail` selalu membalas 500.
+    async fn spawn_receiver() -> (String, Received) {
+        async fn accept(State(received): State<Received>, headers: HeaderMap, body: String) -> StatusCode {
+            received.lock().expect("lock").push((headers, body));
+            StatusCode::NO_CONTENT
+        }
+
+        let received = Received::default();
+        let app = Router::new()
+            .route("/ok", post(accept))
+            .route("/fail", post(|| async { (StatusCode::INTERNAL_SERVER_ERROR, "boom") }))
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("receiver must bind");
+        let addr = listener.local_addr().expect("receiver must have an address");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://{addr}"), received)
+    }
+
+    #[tokio::test]
+    async fn sender_should_sign_request_and_report_failures() {
+        let (base, received) = spawn_receiver().await;
+        let sender = WebhookSender::new(
+            Duration::from_secs(5),
+            DestinationPolicy::new(vec!["127.0.0.1".to_string()]),
+        );
+        let delivery_id = Uuid::new_v4();
+        let request = WebhookRequest {
+            url: &format!("{base}/ok"),
+            secret: "whsec_test",
+            delivery_id,
+            event_type: "webhook.test",
+            body: r#"{"type":"webhook.test"}"#,
+        };
+
+        assert_eq!(sender.send(request, 1_700_000_000).await, Ok(204));
+        let (headers, body) = received.lock().expect("lock")[0].clone();
+        assert_eq!(body, request.body);
+        assert_eq!(headers[EVENT_HEADER], "webhook.test");
+        assert_eq!(headers[DELIVERY_HEADER], delivery_id.to_string().as_str());
+        assert_eq!(
+            headers[SIGNATURE_HEADER],
+            sign_payload("whsec_test", 1_700_000_000, request.body).as_str()
+        );
+
+        let failing = WebhookRequest {
+            url: &format!("{base}/fail"),
+            ..request
+        };
+        let err = sender.send(failing, 1_700_000_000).await.expect_err("500 must fail");
+        assert_eq!(err.status, Some(500));
+        assert!(err.message.contains("boom"));
+
+        let unreachable = WebhookRequest {
+            url: "http://127.0.0.1:9/ok",
+            ..request
+        };
+        assert_eq!(sender.send(unreachable, 1_700_000_000).await.expect_err("must fail").status, None);
+    }
+
+    #[tokio::test]
+    async fn sender_should_refuse_internal_destinations_outside_allowlist() {
+        let (base, received) = spawn_receiver().await;
+        let sender = WebhookSender::new(Duration::from_secs(5), DestinationPolicy::default());
+        let port = base.rsplit(':').next().expect("receiver port");
+        for url in [format!("{base}/ok"), format!("http://localhost:{port}/ok")] {
+            let request = WebhookRequest {
+                url: &url,
+                secret: "whsec_test",
+                delivery_id: Uuid::new_v4(),
+                event_type: "webhook.test",
+                body: "{}",
+            };
+            let err = sender.send(request, 1_700_000_000).await.expect_err("internal host must be refused");
+            assert_eq!(err.status, None, "{url}");
+        }
+        assert!(received.lock().expect("lock").is_empty());
+    }
+}
@@ -1,9 +1,12 @@
 //! Titik masuk API: inisialisasi konfigurasi, koneksi DB, router, dan middleware.
 
+use std::sync::Arc;
+
 use anyhow::Result;
 use axum::{
-    http::{HeaderValue, Method},
+    http::{HeaderName, HeaderValue, Method, header},
     Json, Router,
+    middleware,
     routing::get,
 };
 use serde::Serialize;
@@ -18,9 +21,44 @@ mod infrastructure;
 mod modules;
 
 use app_state::AppState;
-use config::database::DatabaseSettings;
-use infrastructure::db::connection::connect_database;
+use common::idempotency::{self, Idempotency};
+use config::{
+    attachment::AttachmentSettings, database::DatabaseSettings, dependency::DependencySettings,
+    history::HistorySettings, idempotency::IdempotencySettings, outbox::OutboxSettings, reminder::ReminderSettings, webhook::WebhookSettings,
+    workspace::WorkspaceSettings,
+};
+use infrastructure::{
+    db::connection::connect_database,
+    notifier::{FanOutNotifier, Notifier},
+    storage::build_blob_store,
+};
+use modules::app_password::handler as app_password_handler;
+use modules::attachment::handler as attachment_handler;
+use modules::caldav::handler as caldav_handler;
+use modules::calendar::handler as calendar_handler;
+use modules::checklist::handler as checklist_handler;
+use modules::collab::{handler as collab_handler, service as collab_service};
+use modules::comment::handler as comment_handler;
+use modules::dependency::handler as dependency_handler;
+use modules::event_stream::{handler as event_stream_handler, service as event_stream_service};
+use modules::history::{handler as history_handler, service as history_service};
+use modules::notification::{
+    handler as notification_handler,
+    notifier::{EmailOutboxNotifier, InboxNotifier},
+};
+use modules::outbox::service as outbox_service;
+use modules::project::handler as project_handler;
+use modules::reminder::{handler as reminder_handler, service as reminder_service};
+use modules::report::handler as report_handler;
+use modules::sharing::handler as sharing_handler;
+use modules::sync::handler as sync_handler;
+use modules::template::handler as template_handler;
+use modules::time_entry::handler as time_entry_handler;
 use modules::todo::{handler as todo_handler, repository as todo_repository};
+use modules::trash::service as trash_service;
+use modules::undo::handler as undo_handler;
+use modules::webhook::{handler as webhook_handler, notifier::WebhookNotifier, service as webhook_service};
+use modules::workspace::handler as workspace_handler;
 
 #[derive(Debug, Serialize)]
 struct HealthResponse {
@@ -60,24 +98,114 @@ async fn main() -> Result<()> {
         std::env::var("WEB_ORIGIN").unwrap_or_else(|_| "http://localhost:3000".to_string());
     let cors_origin = web_origin.parse::<HeaderValue>()?;
     let db_settings = DatabaseSettings::from_env()?;
+    let history_settings = HistorySettings::from_env()?;
+    let attachment_settings = AttachmentSettings::from_env()?;
+    let workspace_settings = WorkspaceSettings::from_env()?;
+    let dependency_settings = DependencySettings::from_env()?;
+    let reminder_settings = ReminderSettings::from_env()?;
+    let webhook_settings = WebhookSettings::from_env()?;
+    let outbox_settings = OutboxSettings::from_env()?;
+    let idempotency_settings = IdempotencySettings::from_env()?;
+    let blob_store = build_blob_store(&attachment_settings.backend);
     let db = connect_database(&db_settings).await?;
     // Mode no-auth memakai satu user tetap agar semua operasi todo konsisten.
     let default_user_id = resolve_default_user_id()?;
     todo_repository::ensure_default_user(&db, default_user_id).await?;
-    let app_state = AppState::new(db, default_user_id);
+    let idempotency = Idempotency::new(db.clone(), idempotency_settings);
+    let app_state = AppState::new(
+        db.clone(),
+        default_user_id,
+        history_settings.clone(),
+        attachment_settings.clone(),
+        blob_store,
+        workspace_settings.clone(),
+        dependency_settings,
+    )
+    .with_webhook_destinations(webhook_settings.destination_policy());
+
+    if history_settings.retention_days.is_some() {
+        tokio::spawn(history_service::run_retention_loop(db.clone(), history_settings.clone()));
+    }
+    tokio::spawn(trash_service::run_purge_loop(
+        db.clone(),
+        app_state.blob_store.clone(),
+        history_settings.clone(),
+    ));
+    tokio::spawn(event_stream_service::run_listener(db.clone(), app_state.todo_events.clone()));
+    tokio::spawn(collab_service::run_lock_sweeper(app_state.collab.clone()));
+    tokio::spawn(idempotency::run_cleanup(idempotency.clone()));
+    if outbox_settings.relay_enabled {
+        let subscribers = outbox_service::default_subscribers();
+        tokio::spawn(outbox_service::run_relay(db.clone(), outbox_settings, subscribers));
+    }
+    if webhook_settings.worker_enabled {
+        tokio::spawn(webhook_service::run_worker(db.clone(), webhook_settings));
+    }
+    if reminder_settings.scheduler_enabled {
+        let notifier: Arc<dyn Notifier> = Arc::new(FanOutNotifier::new(vec![
+            Arc::new(InboxNotifier),
+            Arc::new(EmailOutboxNotifier),
+            Arc::new(WebhookNotifier),
+        ]));
+        tokio::spawn(reminder_service::run_scheduler(db, reminder_settings, notifier));
+    }
 
     let api_router = Router::new()
         .route("/hello", get(hello))
         .route("/health", get(health))
-        .nest("/todos", todo_handler::routes());
+        .merge(undo_handler::routes())
+        .merge(sharing_handler::routes())
+        .merge(todo_handler::user_routes())
+        .merge(time_entry_handler::user_routes())
+        .merge(report_handler::user_routes())
+        .merge(sync_handler::routes())
+        .merge(calendar_handler::user_routes())
+        .merge(caldav_handler::routes())
+        .merge(workspace_handler::invitation_routes())
+        .nest("/workspaces", workspace_handler::routes())
+        .nest("/templates", template_handler::routes())
+        .nest("/notifications", notification_handler::routes())
+        .nest("/webhooks", webhook_handler::routes())
+        .nest("/collab", collab_handler::routes())
+        .nest("/calendar", calendar_handler::routes())
+        .nest("/app-passwords", app_password_handler::routes())
+        .nest(
+            "/projects",
+            project_handler::routes()
+                .merge(sharing_handler::project_routes())
+                .merge(report_handler::project_routes()),
+        )
+        .nest(
+            "/todos",
+            todo_handler::routes()
+                .merge(event_stream_handler::routes())
+                .merge(history_handler::routes())
+                .merge(comment_handler::routes())
+                .merge(checklist_handler::routes())
+                .merge(dependency_handler::routes())
+                .merge(reminder_handler::routes())
+                .merge(time_entry_handler::routes())
+                .merge(attachment_handler::routes())
+                .merge(sharing_handler::todo_routes())
+                .merge(undo_handler::todo_routes()),
+        );
 
     let app = Router::new()
         .nest("/api", api_router)
         .with_state(app_state)
+        .layer(middleware::from_fn_with_state(idempotency, idempotency::enforce))
         .layer(
             CorsLayer::new()
                 .allow_origin(cors_origin)
-                .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE]),
+                .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+                .allow_headers([
+                    header::CONTENT_TYPE,
+                    HeaderName::from_static(common::current_user::USER_ID_HEADER),
+                    HeaderName::from_static(common::current_user::WORKSPACE_ID_HEADER),
+                    HeaderName::from_static(event_stream_handler::LAST_EVENT_ID_HEADER),
+                    HeaderName::from_static(idempotency::IDEMPOTENCY_KEY_HEADER),
+                ])
+                .expose_headers([HeaderName::from_static(idempotency::IDEMPOTENT_REPLAYED_HEADER)]),
         )
         .layer(TraceLayer::new_for_http());
 
@@ -88,6 +216,21 @@ async fn main() -> Result<()> {
     tracing::info!("CORS allowed origin: {web_origin}");
     tracing::info!("Database connected: {}", db_settings.redacted_database_url());
     tracing::info!("Default user id for no-auth mode: {default_user_id}");
+    tracing::info!(
+        retention_days = ?history_settings.retention_days,
+        redact_desc = history_settings.redact_desc,
+        "Todo history settings loaded"
+    );
+    tracing::info!(
+        backend = attachment_settings.backend_name(),
+        max_size_bytes = attachment_settings.max_size_bytes,
+        user_quota_bytes = attachment_settings.user_quota_bytes,
+        "Attachment settings loaded"
+    );
+    tracing::info!(
+        invitation_ttl_hours = workspace_settings.invitation_ttl_hours,
+        "Workspace settings loaded"
+    );
     axum::serve(listener, app).await?;
 
     Ok(())
@@ -0,0 +1,62 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity;
+
+/// Payload pembuatan app password.
+#[derive(Debug, Deserialize)]
+pub struct CreateAppPasswordRequest {
+    pub name: String,
+}
+
+/// App password baru beserta rahasia aslinya (hanya tersedia saat dibuat).
+#[derive(Debug)]
+pub struct IssuedAppPassword {
+    pub app_password: entity::Model,
+    pub secret: String,
+}
+
+/// Kredensial dari header `Authorization`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// `Basic`: username berupa id atau email user, password berupa app password.
+    Basic { username: String, password: String },
+    /// `Bearer`: app password dipakai langsung sebagai API key.
+    Bearer(String),
+}
+
+/// Bentuk data app password yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct AppPasswordResponse {
+    pub id: Uuid,
+    pub name: String,
+    /// Hanya dikirim sekali pada response pembuatan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<IssuedAppPassword> for AppPasswordResponse {
+    /// Response pembuatan, satu-satunya yang menyertakan rahasia.
+    fn from(value: IssuedAppPassword) -> Self {
+        Self {
+            password: Some(value.secret),
+            ..Self::from(value.app_password)
+        }
+    }
+}
+
+impl From<entity::Model> for AppPasswordResponse {
+    /// Mapping model database ke response HTTP tanpa rahasia.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            password: None,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `app_passwords`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "app_passwords")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Label perangkat/aplikasi, mis. `Ponsel`.
+    pub name: String,
+    /// Hash SHA-256 (hex) rahasia; rahasia asli hanya dikembalikan saat dibuat.
+    pub secret_hash: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    /// App password yang dicabut tidak bisa dipakai lagi.
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,94 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{AppPasswordResponse, CreateAppPasswordRequest};
+use super::service::{self, AppPasswordError};
+
+/// Registrasi route app password, di-mount di bawah prefix `/app-passwords`.
+///
+/// - `POST /` buat app password (rahasia hanya dikembalikan di sini)
+/// - `GET /` daftar app password aktif
+/// - `DELETE /{id}` cabut app password
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_app_password).get(list_app_passwords))
+        .route("/{id}", delete(revoke_app_password))
+}
+
+/// POST `/app-passwords`
+///
+/// Input: `CreateAppPasswordRequest`.
+/// Output: `201 Created` + `SuccessResponse<AppPasswordResponse>` beserta `password`.
+pub async fn create_app_password(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateAppPasswordRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<AppPasswordResponse>>), ApiError> {
+    let issued = service::create_app_password(&state.db, user.id, payload)
+        .await
+        .map_err(map_app_password_error)?;
+    info!(app_password_id = %issued.app_password.id, "app password created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: issued.into() })))
+}
+
+/// GET `/app-passwords`
+/// Output: `200 OK` + daftar app password aktif milik user saat ini (tanpa rahasia).
+pub async fn list_app_passwords(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<AppPasswordResponse>>>, ApiError> {
+    let app_passwords = service::list_app_passwords(&state.db, user.id)
+        .await
+        .map_err(map_app_password_error)?;
+    info!(count = app_passwords.len(), "app passwords listed");
+    Ok(Json(SuccessResponse {
+        data: app_passwords.into_iter().map(AppPasswordResponse::from).collect(),
+    }))
+}
+
+/// DELETE `/app-passwords/{id}`
+/// Output: `204 No Content`, `404` bila tidak ada atau sudah dicabut.
+pub async fn revoke_app_password(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(app_password_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    service::revoke_app_password(&state.db, user.id, app_password_id)
+        .await
+        .map_err(map_app_password_error)?;
+    info!(app_password_id = %app_password_id, "app password revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain app password ke `ApiError` agar response error konsisten.
+fn map_app_password_error(err: AppPasswordError) -> ApiError {
+    match err {
+        AppPasswordError::Validation(message) => {
+            warn!(reason = %message, "app password validation failed");
+            ApiError::bad_request(message)
+        }
+        AppPasswordError::NotFound => {
+            warn!("app password not found");
+            ApiError::not_found("app password not found")
+        }
+        AppPasswordError::Unauthorized => {
+            warn!("app password authentication failed");
+            ApiError::unauthorized("invalid credentials")
+        }
+        AppPasswordError::Database(db_err) => {
+            error!(error = %db_err, "app password database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Modul app password: rahasia per perangkat untuk client non-browser (CalDAV, API key).
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,84 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan app password baru; hanya hash rahasia yang disimpan.
+pub async fn create_app_password(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    name: String,
+    secret_hash: String,
+) -> Result<Model, DbErr> {
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        name: Set(name),
+        secret_hash: Set(secret_hash),
+        created_at: Set(Utc::now().fixed_offset()),
+        last_used_at: Set(None),
+        revoked_at: Set(None),
+    };
+    active.insert(conn).await
+}
+
+/// App password aktif (belum dicabut) milik user, urut waktu dibuat.
+pub async fn list_active_app_passwords(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::RevokedAt.is_null())
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// App password aktif milik user berdasarkan id.
+pub async fn find_active_app_password(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    app_password_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(app_password_id))
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::RevokedAt.is_null())
+        .one(conn)
+        .await
+}
+
+/// App password aktif berdasarkan hash rahasia.
+pub async fn find_active_app_password_by_secret_hash(
+    conn: &DatabaseConnection,
+    secret_hash: &str,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::SecretHash.eq(secret_hash))
+        .filter(Column::RevokedAt.is_null())
+        .one(conn)
+        .await
+}
+
+/// Menandai app password dicabut.
+pub async fn revoke_app_password(
+    conn: &DatabaseConnection,
+    app_password: Model,
+    now: DateTime<FixedOffset>,
+) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = app_password.into();
+    active.revoked_at = Set(Some(now));
+    active.update(conn).await
+}
+
+/// Mencatat waktu terakhir app password dipakai.
+pub async fn touch_app_password(
+    conn: &DatabaseConnection,
+    app_password: Model,
+    now: DateTime<FixedOffset>,
+) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = app_password.into();
+    active.last_used_at = Set(Some(now));
+    active.update(conn).await
+}
@@ -0,0 +1,183 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::{Duration, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::modules::user::repository as user_repository;
+
+use super::dto::{CreateAppPasswordRequest, Credentials, IssuedAppPassword};
+use super::entity::Model;
+use super::repository;
+
+const MAX_NAME_LEN: usize = 100;
+// Jeda minimum antar pembaruan `last_used_at` agar client yang sering sinkron tidak menulis tiap request.
+const TOUCH_INTERVAL_MINUTES: i64 = 5;
+
+/// Error domain app password yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum AppPasswordError {
+    Validation(String),
+    NotFound,
+    /// Header `Authorization` tidak ada, tidak valid, atau app password sudah dicabut.
+    Unauthorized,
+    Database(DbErr),
+}
+
+impl From<DbErr> for AppPasswordError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Membuat app password baru; rahasia asli hanya dikembalikan di sini.
+pub async fn create_app_password(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: CreateAppPasswordRequest,
+) -> Result<IssuedAppPassword, AppPasswordError> {
+    let name = normalize_name(request.name)?;
+    let secret = generate_secret();
+    let app_password = repository::create_app_password(conn, user_id, name, hash_secret(&secret)).await?;
+    Ok(IssuedAppPassword { app_password, secret })
+}
+
+/// App password aktif milik user.
+pub async fn list_app_passwords(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, AppPasswordError> {
+    repository::list_active_app_passwords(conn, user_id)
+        .await
+        .map_err(AppPasswordError::from)
+}
+
+/// Mencabut app password milik user; client yang memakainya langsung ditolak.
+pub async fn revoke_app_password(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    app_password_id: Uuid,
+) -> Result<(), AppPasswordError> {
+    let app_password = repository::find_active_app_password(conn, user_id, app_password_id)
+        .await?
+        .ok_or(AppPasswordError::NotFound)?;
+    repository::revoke_app_password(conn, app_password, Utc::now().fixed_offset()).await?;
+    Ok(())
+}
+
+/// Mengautentikasi nilai header `Authorization` dan mengembalikan id user pemilik app password.
+/// Untuk `Basic`, username harus id atau email pemilik agar app password tidak bisa
+/// dipakai atas nama user lain.
+pub async fn authenticate(conn: &DatabaseConnection, authorization: Option<&str>) -> Result<Uuid, AppPasswordError> {
+    let credentials = authorization
+        .and_then(parse_authorization)
+        .ok_or(AppPasswordError::Unauthorized)?;
+    let (username, secret) = match credentials {
+        Credentials::Basic { username, password } => (Some(username), password),
+        Credentials::Bearer(secret) => (None, secret),
+    };
+    let app_password = repository::find_active_app_password_by_secret_hash(conn, &hash_secret(&secret))
+        .await?
+        .ok_or(AppPasswordError::Unauthorized)?;
+    if let Some(username) = username {
+        let owner = user_repository::find_user_by_id(conn, app_password.user_id)
+            .await?
+            .ok_or(AppPasswordError::Unauthorized)?;
+        let username = username.trim();
+        let matches_id = Uuid::parse_str(username).is_ok_and(|id| id == owner.id);
+        if !matches_id && !username.eq_ignore_ascii_case(&owner.email) {
+            return Err(AppPasswordError::Unauthorized);
+        }
+    }
+
+    let user_id = app_password.user_id;
+    let now = Utc::now().fixed_offset();
+    let stale = app_password
+        .last_used_at
+        .is_none_or(|last_used_at| now - last_used_at >= Duration::minutes(TOUCH_INTERVAL_MINUTES));
+    if stale {
+        repository::touch_app_password(conn, app_password, now).await?;
+    }
+    Ok(user_id)
+}
+
+/// Membaca skema `Basic` atau `Bearer` (tidak peka huruf besar) dari header `Authorization`.
+pub fn parse_authorization(raw: &str) -> Option<Credentials> {
+    let (scheme, value) = raw.trim().split_once(' ')?;
+    let value = value.trim();
+    if scheme.eq_ignore_ascii_case("bearer") {
+        return (!value.is_empty()).then(|| Credentials::Bearer(value.to_string()));
+    }
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(STANDARD.decode(value).ok()?).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+    if password.is_empty() {
+        return None;
+    }
+    Some(Credentials::Basic {
+        username: username.to_string(),
+        password: password.to_string(),
+    })
+}
+
+/// Nama app password di-trim, wajib, max 100 karakter.
+pub fn normalize_name(raw: String) -> Result<String, AppPasswordError> {
+    let name = raw.trim();
+    if name.is_empty() {
+        return Err(AppPasswordError::Validation("name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(AppPasswordError::Validation(format!(
+            "name must be at most {MAX_NAME_LEN} characters"
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// Hash SHA-256 (hex) rahasia; hanya nilai ini yang disimpan di database.
+pub fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.trim().as_bytes()))
+}
+
+// Rahasia acak 256-bit dari dua UUID v4.
+fn generate_secret() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+// Modul tests untuk parsing kredensial dan validasi app password.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_authorization_should_read_basic_and_bearer() {
+        let basic = format!("Basic {}", STANDARD.encode("ana@example.com:s3cret:with-colon"));
+        assert_eq!(
+            parse_authorization(&basic),
+            Some(Credentials::Basic {
+                username: "ana@example.com".to_string(),
+                password: "s3cret:with-colon".to_string(),
+            })
+        );
+        assert_eq!(parse_authorization("bearer abc"), Some(Credentials::Bearer("abc".to_string())));
+        assert_eq!(parse_authorization("Basic !!!"), None);
+        assert_eq!(parse_authorization(&format!("Basic {}", STANDARD.encode("ana:"))), None);
+        assert_eq!(parse_authorization("Digest abc"), None);
+        assert_eq!(parse_authorization("Bearer"), None);
+    }
+
+    #[test]
+    fn normalize_name_should_require_label() {
+        assert_eq!(normalize_name("  Ponsel ".to_string()).ok(), Some("Ponsel".to_string()));
+        assert!(normalize_name("   ".to_string()).is_err());
+        assert!(normalize_name("x".repeat(MAX_NAME_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn generated_secrets_should_be_unique_and_hashed() {
+        let secret = generate_secret();
+        assert_eq!(secret.len(), 64);
+        assert_ne!(secret, generate_secret());
+        assert_eq!(hash_secret(&secret).len(), 64);
+        assert_ne!(hash_secret(&secret), secret);
+    }
+}
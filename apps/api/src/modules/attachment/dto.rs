@@ -0,0 +1,32 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::entity;
+
+/// Bentuk metadata lampiran yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub uploader_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for AttachmentResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            uploader_id: value.uploader_id,
+            file_name: value.file_name,
+            content_type: value.content_type,
+            size_bytes: value.size_bytes,
+            created_at: value.created_at,
+        }
+    }
+}
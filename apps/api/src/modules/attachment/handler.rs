@@ -0,0 +1,577 @@
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+};
+use futures_util::TryStreamExt;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::AttachmentResponse;
+use super::service::{self, AttachmentError, UploadRequest};
+
+/// Toleransi ukuran header/boundary multipart di atas batas ukuran file.
+const MULTIPART_OVERHEAD_BYTES: u64 = 64 * 1024;
+
+/// Registrasi route lampiran, di-mount di bawah prefix `/todos`.
+///
+/// - `POST /{id}/attachments` upload lampiran (multipart, field `file`)
+/// - `GET /{id}/attachments` daftar metadata lampiran
+/// - `GET /{id}/attachments/{attachment_id}/content` download isi lampiran
+/// - `DELETE /{id}/attachments/{attachment_id}` hapus lampiran
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/attachments",
+            // Batas body default Axum dimatikan; ukuran dibatasi service sambil streaming.
+            post(upload_attachment)
+                .layer(DefaultBodyLimit::disable())
+                .get(list_attachments),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}/content",
+            get(download_attachment),
+        )
+        .route("/{id}/attachments/{attachment_id}", delete(delete_attachment))
+}
+
+/// POST `/todos/{id}/attachments`
+///
+/// Input: `multipart/form-data` dengan field `file`.
+/// Output: `201 Created` + metadata lampiran, `413` bila melebihi ukuran/kuota.
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<SuccessResponse<AttachmentResponse>>), ApiError> {
+    // Tolak lebih awal bila `Content-Length` sudah jelas melebihi batas.
+    let declared_len = headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    if declared_len.is_some_and(|len| len > state.attachments.max_size_bytes + MULTIPART_OVERHEAD_BYTES) {
+        return Err(map_attachment_error(AttachmentError::TooLarge(
+            state.attachments.max_size_bytes,
+        )));
+    }
+
+    let field = loop {
+        match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some("file") => break field,
+            Ok(Some(_)) => continue,
+            Ok(None) => return Err(ApiError::bad_request("multipart field file is required")),
+            Err(err) => return Err(ApiError::bad_request(format!("invalid multipart body: {err}"))),
+        }
+    };
+    let upload = UploadRequest {
+        file_name: field.file_name().map(str::to_string),
+        content_type: field.content_type().map(str::to_string),
+        body: field.map_err(std::io::Error::other),
+    };
+
+    let attachment = service::upload_attachment(
+        &state.db,
+        state.blob_store.as_ref(),
+        &state.attachments,
+        user.id,
+        todo_id,
+        upload,
+    )
+    .await
+    .map_err(map_attachment_error)?;
+    info!(
+        todo_id = %todo_id,
+        attachment_id = %attachment.id,
+        size_bytes = attachment.size_bytes,
+        "attachment uploaded"
+    );
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: attachment.into() })))
+}
+
+/// GET `/todos/{id}/attachments`
+/// Output: `200 OK` + daftar metadata lampiran.
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<AttachmentResponse>>>, ApiError> {
+    let attachments = service::list_attachments(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_attachment_error)?;
+    info!(todo_id = %todo_id, count = attachments.len(), "attachments listed");
+    let data = attachments.into_iter().map(AttachmentResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// GET `/todos/{id}/attachments/{attachment_id}/content`
+/// Output: `200 OK` dengan isi file di-stream dan `Content-Type` sesuai metadata.
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, ApiError> {
+    let content = service::open_attachment(
+        &state.db,
+        state.blob_store.as_ref(),
+        user.id,
+        todo_id,
+        attachment_id,
+    )
+    .await
+    .map_err(map_attachment_error)?;
+    info!(todo_id = %todo_id, attachment_id = %attachment_id, "attachment download started");
+
+    let attachment = content.attachment;
+    let mut response = Body::from_stream(content.body).into_response();
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&attachment.content_type)
+            .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream")),
+    );
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(attachment.size_bytes));
+    headers.insert(header::CONTENT_DISPOSITION, content_disposition(&attachment.file_name));
+    headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    Ok(response)
+}
+
+/// DELETE `/todos/{id}/attachments/{attachment_id}`
+/// Output: `204 No Content`.
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_attachment(
+        &state.db,
+        state.blob_store.as_ref(),
+        user.id,
+        todo_id,
+        attachment_id,
+    )
+    .await
+    .map_err(map_attachment_error)?;
+    info!(todo_id = %todo_id, attachment_id = %attachment_id, "attachment deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Selalu `attachment` agar file (mis. HTML) tidak dirender inline oleh browser.
+fn content_disposition(file_name: &str) -> HeaderValue {
+    let ascii_name: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect();
+    HeaderValue::from_str(&format!(
+        "attachment; filename=\"{ascii_name}\"; filename*=UTF-8''{encoded}"
+    ))
+    .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+/// Mapping error domain lampiran ke `ApiError`.
+fn map_attachment_error(err: AttachmentError) -> ApiError {
+    match err {
+        AttachmentError::Validation(message) => {
+            warn!(reason = %message, "attachment validation failed");
+            ApiError::bad_request(message)
+        }
+        AttachmentError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        AttachmentError::NotFound => {
+            warn!("attachment not found");
+            ApiError::not_found("attachment not found")
+        }
+        AttachmentError::Forbidden => {
+            warn!("attachment action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        AttachmentError::TooLarge(max_size_bytes) => {
+            warn!(max_size_bytes, "attachment too large");
+            ApiError::payload_too_large(format!("attachment must be at most {max_size_bytes} bytes"))
+        }
+        AttachmentError::QuotaExceeded => {
+            warn!("attachment quota exceeded");
+            ApiError::payload_too_large("attachment quota exceeded")
+        }
+        AttachmentError::Storage(blob_err) => {
+            error!(error = %blob_err, "attachment storage operation failed");
+            ApiError::internal("unexpected storage error")
+        }
+        AttachmentError::Io(io_err) => {
+            error!(error = %io_err, "attachment temporary file operation failed");
+            ApiError::internal("unexpected storage error")
+        }
+        AttachmentError::Database(db_err) => {
+            error!(error = %db_err, "attachment database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint lampiran terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::Arc;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Statement};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::config::attachment::StorageBackend;
+    use crate::modules::attachment::repository as attachment_repository;
+    use crate::infrastructure::storage::{BlobError, BlobStore, BlobStream};
+    use crate::modules::comment::handler as comment_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+    use crate::modules::trash::service as trash_service;
+    use crate::modules::undo::handler as undo_handler;
+
+    use super::*;
+
+    const BOUNDARY: &str = "attachment-test-boundary";
+
+    // Storage yang menyimpan dan membaca normal tetapi selalu gagal menghapus.
+    struct FailingDeleteStore(Arc<dyn BlobStore>);
+
+    #[async_trait::async_trait]
+    impl BlobStore for FailingDeleteStore {
+        async fn put(&self, key: &str, body: BlobStream, content_length: u64, content_type: &str) -> Result<(), BlobError> {
+            self.0.put(key, body, content_length, content_type).await
+        }
+
+        async fn get(&self, key: &str) -> Result<BlobStream, BlobError> {
+            self.0.get(key).await
+        }
+
+        async fn delete(&self, _key: &str) -> Result<(), BlobError> {
+            Err(BlobError::Backend("storage unavailable".to_string()))
+        }
+    }
+
+    async fn build_test_app() -> (Router, AppState) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        let state = AppState::for_tests(db, default_user_id);
+        let app = Router::new()
+            .nest(
+                "/todos",
+                todo_handler::routes()
+                    .merge(routes())
+                    .merge(comment_handler::routes())
+                    .merge(undo_handler::todo_routes()),
+            )
+            .with_state(state.clone());
+        (app, state)
+    }
+
+    fn multipart_body(field: &str, file_name: &str, content_type: &str, content: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"{file_name}\"\r\nContent-Type: {content_type}\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(content);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn upload(app: &Router, todo_id: &str, body: Vec<u8>) -> axum::response::Response {
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/todos/{todo_id}/attachments"))
+            .header("content-type", format!("multipart/form-data; boundary={BOUNDARY}"))
+            .body(Body::from(body))
+            .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn send(app: &Router, method: &str, uri: String) -> axum::response::Response {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_bytes(response: axum::response::Response) -> Vec<u8> {
+        to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable")
+            .to_vec()
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        serde_json::from_slice(&read_bytes(response).await).expect("body must be valid json")
+    }
+
+    #[test]
+    fn content_disposition_should_escape_file_name() {
+        let value = content_disposition("laporan \"akhir\".pdf");
+        assert_eq!(
+            value.to_str().expect("header must be ascii"),
+            "attachment; filename=\"laporan _akhir_.pdf\"; filename*=UTF-8''laporan%20%22akhir%22.pdf"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn attachment_flow_should_succeed() {
+        let (app, _) = build_test_app().await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "with files" }).to_string()))
+            .expect("request should be valid");
+        let todo = read_json(app.clone().oneshot(request).await.expect("response expected")).await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        let created = upload(
+            &app,
+            &todo_id,
+            multipart_body("file", "notes.txt", "text/plain", b"hello attachment"),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let created = read_json(created).await;
+        assert_eq!(created["data"]["file_name"], "notes.txt");
+        assert_eq!(created["data"]["size_bytes"], 16);
+        assert!(created["data"].get("storage_key").is_none());
+        let attachment_id = created["data"]["id"].as_str().expect("attachment id must exist").to_string();
+
+        let missing_field = upload(
+            &app,
+            &todo_id,
+            multipart_body("other", "notes.txt", "text/plain", b"ignored"),
+        )
+        .await;
+        assert_eq!(missing_field.status(), StatusCode::BAD_REQUEST);
+
+        let too_large = upload(
+            &app,
+            &todo_id,
+            multipart_body("file", "big.bin", "application/octet-stream", &vec![0; 10 * 1024 * 1024 + 1]),
+        )
+        .await;
+        assert_eq!(too_large.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let list = read_json(send(&app, "GET", format!("/todos/{todo_id}/attachments")).await).await;
+        assert_eq!(list["data"].as_array().map(Vec::len), Some(1));
+
+        let content = send(
+            &app,
+            "GET",
+            format!("/todos/{todo_id}/attachments/{attachment_id}/content"),
+        )
+        .await;
+        assert_eq!(content.status(), StatusCode::OK);
+        assert_eq!(content.headers()[header::CONTENT_TYPE], "text/plain");
+        assert_eq!(read_bytes(content).await, b"hello attachment");
+
+        let deleted = send(
+            &app,
+            "DELETE",
+            format!("/todos/{todo_id}/attachments/{attachment_id}"),
+        )
+        .await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+
+        let gone = send(
+            &app,
+            "GET",
+            format!("/todos/{todo_id}/attachments/{attachment_id}/content"),
+        )
+        .await;
+        assert_eq!(gone.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn undo_delete_should_restore_attachment_until_undo_window_expires() {
+        let (app, state) = build_test_app().await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "deleted with files" }).to_string()))
+            .expect("request should be valid");
+        let todo = read_json(app.clone().oneshot(request).await.expect("response expected")).await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        let created = upload(&app, &todo_id, multipart_body("file", "keep.txt", "text/plain", b"keep me")).await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let attachment_id = read_json(created).await["data"]["id"]
+            .as_str()
+            .expect("attachment id must exist")
+            .to_string();
+        let content_uri = format!("/todos/{todo_id}/attachments/{attachment_id}/content");
+        let comment = Request::builder()
+            .method("POST")
+            .uri(format!("/todos/{todo_id}/comments"))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "body": "still here" }).to_string()))
+            .expect("request should be valid");
+        let comment = app.clone().oneshot(comment).await.expect("response expected");
+        assert_eq!(comment.status(), StatusCode::CREATED);
+
+        assert_eq!(send(&app, "DELETE", format!("/todos/{todo_id}")).await.status(), StatusCode::NO_CONTENT);
+        let undone = send(&app, "POST", format!("/todos/{todo_id}/undo")).await;
+        assert_eq!(undone.status(), StatusCode::OK);
+        let content = send(&app, "GET", content_uri.clone()).await;
+        assert_eq!(content.status(), StatusCode::OK);
+        assert_eq!(read_bytes(content).await, b"keep me");
+        let comments = read_json(send(&app, "GET", format!("/todos/{todo_id}/comments")).await).await;
+        assert_eq!(comments["data"][0]["body"], "still here");
+
+        // Setelah masa undo habis, tempat sampah dibersihkan beserta blob lampirannya.
+        assert_eq!(send(&app, "DELETE", format!("/todos/{todo_id}")).await.status(), StatusCode::NO_CONTENT);
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("todo id must be a uuid");
+        let row = state
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE todo_trash SET deleted_at = NOW() - INTERVAL '1 day' WHERE todo_id = $1 \
+                 RETURNING storage_keys->>0 AS storage_key",
+                [todo_uuid.into()],
+            ))
+            .await
+            .expect("trash update must succeed")
+            .expect("deleted todo must be in the trash");
+        let storage_key: String = row.try_get("", "storage_key").expect("storage key must exist");
+        trash_service::purge_expired(&state.db, state.blob_store.as_ref(), &state.history)
+            .await
+            .expect("purge must succeed");
+        assert!(matches!(state.blob_store.get(&storage_key).await, Err(BlobError::NotFound)));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn concurrent_uploads_should_not_exceed_user_quota() {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let mut state = AppState::for_tests(db, user_id);
+        state.attachments.max_size_bytes = 10;
+        state.attachments.user_quota_bytes = 10;
+        let StorageBackend::Local { root } = state.attachments.backend.clone() else {
+            panic!("test state must use local storage");
+        };
+        let app = Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(state);
+        let request = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "quota race" }).to_string()))
+            .expect("request should be valid");
+        let todo = read_json(app.clone().oneshot(request).await.expect("response expected")).await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        let (first, second) = tokio::join!(
+            upload(&app, &todo_id, multipart_body("file", "a.txt", "text/plain", b"aaaaaa")),
+            upload(&app, &todo_id, multipart_body("file", "b.txt", "text/plain", b"bbbbbb")),
+        );
+        let mut statuses = [first.status(), second.status()];
+        statuses.sort();
+        assert_eq!(statuses, [StatusCode::CREATED, StatusCode::PAYLOAD_TOO_LARGE]);
+
+        let list = read_json(send(&app, "GET", format!("/todos/{todo_id}/attachments")).await).await;
+        assert_eq!(list["data"].as_array().map(Vec::len), Some(1));
+        // Blob milik upload yang ditolak tidak tertinggal di storage.
+        let stored = std::fs::read_dir(root.join(format!("attachments/{user_id}/{todo_id}")))
+            .map(|entries| entries.count())
+            .unwrap_or_default();
+        assert_eq!(stored, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn failed_blob_delete_should_be_retried_by_purge_loop() {
+        let (_, state) = build_test_app().await;
+        let real_store = state.blob_store.clone();
+        let mut failing_state = state.clone();
+        failing_state.blob_store = Arc::new(FailingDeleteStore(real_store.clone()));
+        let app = Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(failing_state);
+        let request = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "flaky storage" }).to_string()))
+            .expect("request should be valid");
+        let todo = read_json(app.clone().oneshot(request).await.expect("response expected")).await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        let created = upload(&app, &todo_id, multipart_body("file", "gone.txt", "text/plain", b"bye")).await;
+        let attachment_id = read_json(created).await["data"]["id"]
+            .as_str()
+            .expect("attachment id must exist")
+            .to_string();
+        let attachment_uuid = Uuid::parse_str(&attachment_id).expect("attachment id must be a uuid");
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("todo id must be a uuid");
+        let storage_key = attachment_repository::find_attachment_by_id(&state.db, todo_uuid, attachment_uuid)
+            .await
+            .expect("query must succeed")
+            .expect("attachment must exist")
+            .storage_key;
+
+        let deleted = send(&app, "DELETE", format!("/todos/{todo_id}/attachments/{attachment_id}")).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+        let listed = read_json(send(&app, "GET", format!("/todos/{todo_id}/attachments")).await).await;
+        assert_eq!(listed["data"], json!([]));
+        assert!(real_store.get(&storage_key).await.is_ok());
+
+        trash_service::purge_queued_blobs(&state.db, real_store.as_ref())
+            .await
+            .expect("purge must succeed");
+        assert!(matches!(real_store.get(&storage_key).await, Err(BlobError::NotFound)));
+        let queued = state
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT storage_key FROM blob_purge_queue WHERE storage_key = $1",
+                [storage_key.into()],
+            ))
+            .await
+            .expect("query must succeed");
+        assert!(queued.is_none());
+    }
+}
@@ -0,0 +1,7 @@
+//! Modul lampiran todo: metadata di Postgres, isi file lewat `BlobStore`.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
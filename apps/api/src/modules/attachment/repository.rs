@@ -0,0 +1,92 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    QueryFilter, QueryOrder, QuerySelect, Set, Statement, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Data metadata lampiran yang siap disimpan.
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub uploader_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+}
+
+/// Menyimpan metadata lampiran setelah isi file berhasil disimpan di `BlobStore`.
+pub async fn create_attachment<C: ConnectionTrait>(
+    conn: &C,
+    attachment: NewAttachment,
+) -> Result<Model, DbErr> {
+    let active = ActiveModel {
+        id: Set(attachment.id),
+        todo_id: Set(attachment.todo_id),
+        uploader_id: Set(attachment.uploader_id),
+        file_name: Set(attachment.file_name),
+        content_type: Set(attachment.content_type),
+        size_bytes: Set(attachment.size_bytes),
+        storage_key: Set(attachment.storage_key),
+        created_at: Set(Utc::now().fixed_offset()),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil seluruh lampiran satu todo, urut terlama lebih dulu.
+pub async fn list_attachments(conn: &DatabaseConnection, todo_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil satu lampiran berdasarkan id dalam scope todo tertentu.
+pub async fn find_attachment_by_id(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::Id.eq(attachment_id))
+        .one(conn)
+        .await
+}
+
+/// Menghapus metadata lampiran berdasarkan id.
+pub async fn delete_attachment<C: ConnectionTrait>(conn: &C, attachment_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(attachment_id).exec(conn).await?;
+    Ok(())
+}
+
+/// Mengambil advisory lock transaksi untuk kuota lampiran satu uploader, agar cek kuota dan
+/// insert metadata tidak balapan dengan upload lain dari user yang sama.
+/// Lock dilepas otomatis saat transaksi `conn` selesai.
+pub async fn lock_uploader_quota<C: ConnectionTrait>(conn: &C, uploader_id: Uuid) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))",
+        [format!("attachment_quota:{uploader_id}").into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Total ukuran lampiran yang sudah diupload user (untuk cek kuota).
+pub async fn total_size_by_uploader<C: ConnectionTrait>(conn: &C, uploader_id: Uuid) -> Result<u64, DbErr> {
+    // `SUM(bigint)` di Postgres menghasilkan `numeric`, jadi di-cast kembali ke `BIGINT`.
+    let total: Option<i64> = Entity::find()
+        .select_only()
+        .column_as(Expr::cust("COALESCE(SUM(size_bytes), 0)::BIGINT"), "total")
+        .filter(Column::UploaderId.eq(uploader_id))
+        .into_tuple()
+        .one(conn)
+        .await?;
+    Ok(total.and_then(|total| u64::try_from(total).ok()).unwrap_or_default())
+}
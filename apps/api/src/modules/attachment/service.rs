@@ -0,0 +1,367 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+use tokio::{fs, io::AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::config::attachment::AttachmentSettings;
+use crate::infrastructure::storage::{BlobError, BlobStore, BlobStream};
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::trash::repository as trash_repository;
+
+use super::entity::Model;
+use super::repository::{self, NewAttachment};
+
+const MAX_FILE_NAME_LEN: usize = 255;
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Error domain lampiran yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum AttachmentError {
+    Validation(String),
+    TodoNotFound,
+    NotFound,
+    /// Role user pada todo kurang untuk aksi ini.
+    Forbidden,
+    /// File melebihi batas ukuran per file (byte).
+    TooLarge(u64),
+    /// Total lampiran user akan melebihi kuota.
+    QuotaExceeded,
+    Storage(BlobError),
+    Io(io::Error),
+    Database(DbErr),
+}
+
+impl From<DbErr> for AttachmentError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for AttachmentError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<BlobError> for AttachmentError {
+    fn from(value: BlobError) -> Self {
+        match value {
+            BlobError::NotFound => Self::NotFound,
+            other => Self::Storage(other),
+        }
+    }
+}
+
+/// File upload yang belum divalidasi, isi dibaca bertahap dari `body`.
+pub struct UploadRequest<S> {
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub body: S,
+}
+
+/// Isi lampiran yang siap di-stream ke client.
+pub struct AttachmentContent {
+    pub attachment: Model,
+    pub body: BlobStream,
+}
+
+/// Menyimpan lampiran baru untuk todo; butuh `todo.write`.
+/// Kuota dihitung per uploader.
+///
+/// Isi file ditampung ke file sementara sambil dihitung ukurannya; upload dihentikan
+/// begitu melewati batas ukuran file atau sisa kuota user, sebelum body terbaca penuh.
+/// Kuota dicek ulang saat metadata disimpan (di bawah lock kuota uploader) karena upload lain
+/// dari user yang sama bisa selesai lebih dulu; bila gagal, blob yang sudah tersimpan dihapus.
+pub async fn upload_attachment<S>(
+    conn: &DatabaseConnection,
+    store: &dyn BlobStore,
+    settings: &AttachmentSettings,
+    uploader_id: Uuid,
+    todo_id: Uuid,
+    upload: UploadRequest<S>,
+) -> Result<Model, AttachmentError>
+where
+    S: Stream<Item = Result<Bytes, io::Error>> + Unpin,
+{
+    sharing_service::authorize_todo(conn, uploader_id, todo_id, Permission::TodoWrite).await?;
+    let file_name = normalize_file_name(upload.file_name)?;
+    let content_type = normalize_content_type(upload.content_type)?;
+
+    let used = repository::total_size_by_uploader(conn, uploader_id).await?;
+    let remaining_quota = settings.user_quota_bytes.saturating_sub(used);
+    let limit = settings.max_size_bytes.min(remaining_quota);
+
+    let (spooled, size_bytes) = match spool_to_temp(&settings.tmp_dir, upload.body, limit).await {
+        Ok(spooled) => spooled,
+        Err(SpoolError::LimitExceeded) if limit < settings.max_size_bytes => {
+            return Err(AttachmentError::QuotaExceeded);
+        }
+        Err(SpoolError::LimitExceeded) => return Err(AttachmentError::TooLarge(settings.max_size_bytes)),
+        Err(SpoolError::Read(err)) => {
+            return Err(AttachmentError::Validation(format!("failed to read upload: {err}")));
+        }
+        Err(SpoolError::Write(err)) => return Err(AttachmentError::Io(err)),
+    };
+
+    let attachment_id = Uuid::new_v4();
+    let storage_key = format!("attachments/{uploader_id}/{todo_id}/{attachment_id}");
+    let file = fs::File::open(&spooled.0).await.map_err(AttachmentError::Io)?;
+    store
+        .put(&storage_key, Box::pin(ReaderStream::new(file)), size_bytes, &content_type)
+        .await?;
+
+    let attachment = NewAttachment {
+        id: attachment_id,
+        todo_id,
+        uploader_id,
+        file_name,
+        content_type,
+        size_bytes: i64::try_from(size_bytes).unwrap_or(i64::MAX),
+        storage_key: storage_key.clone(),
+    };
+    let created = insert_within_quota(conn, settings.user_quota_bytes, attachment, size_bytes).await;
+    if created.is_err() {
+        // Metadata gagal disimpan: blob dibersihkan agar tidak jadi yatim.
+        if let Err(err) = store.delete(&storage_key).await {
+            warn!(error = %err, storage_key = %storage_key, "failed to clean up orphan blob");
+        }
+    }
+    created
+}
+
+// Menyimpan metadata lampiran bila total lampiran uploader (dihitung di bawah lock kuota)
+// masih muat menampung `size_bytes`.
+async fn insert_within_quota(
+    conn: &DatabaseConnection,
+    quota_bytes: u64,
+    attachment: NewAttachment,
+    size_bytes: u64,
+) -> Result<Model, AttachmentError> {
+    let txn = conn.begin().await?;
+    repository::lock_uploader_quota(&txn, attachment.uploader_id).await?;
+    let used = repository::total_size_by_uploader(&txn, attachment.uploader_id).await?;
+    if used.saturating_add(size_bytes) > quota_bytes {
+        return Err(AttachmentError::QuotaExceeded);
+    }
+    let created = repository::create_attachment(&txn, attachment).await?;
+    txn.commit().await?;
+    Ok(created)
+}
+
+/// Mengambil daftar metadata lampiran satu todo; butuh `todo.read`.
+pub async fn list_attachments(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Vec<Model>, AttachmentError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    repository::list_attachments(conn, todo_id)
+        .await
+        .map_err(AttachmentError::from)
+}
+
+/// Membuka isi lampiran untuk di-stream ke client; butuh `todo.read`.
+pub async fn open_attachment(
+    conn: &DatabaseConnection,
+    store: &dyn BlobStore,
+    user_id: Uuid,
+    todo_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<AttachmentContent, AttachmentError> {
+    let attachment = find_attachment(conn, user_id, todo_id, attachment_id, Permission::TodoRead).await?;
+    let body = store.get(&attachment.storage_key).await?;
+    Ok(AttachmentContent { attachment, body })
+}
+
+/// Menghapus metadata lampiran dan menandai blob-nya di `blob_purge_queue` dalam satu transaksi,
+/// lalu mencoba menghapus blob langsung; blob yang gagal dihapus dibersihkan loop tempat sampah.
+/// Butuh `todo.write`.
+pub async fn delete_attachment(
+    conn: &DatabaseConnection,
+    store: &dyn BlobStore,
+    user_id: Uuid,
+    todo_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<(), AttachmentError> {
+    let attachment = find_attachment(conn, user_id, todo_id, attachment_id, Permission::TodoWrite).await?;
+    let txn = conn.begin().await?;
+    repository::delete_attachment(&txn, attachment.id).await?;
+    trash_repository::queue_blob_purge(&txn, &attachment.storage_key).await?;
+    txn.commit().await?;
+
+    let storage_keys = [attachment.storage_key];
+    let purged = purge_blobs(store, &storage_keys).await;
+    if let Err(err) = trash_repository::dequeue_blobs(conn, &purged).await {
+        warn!(error = %err, "failed to dequeue purged attachment blob");
+    }
+    Ok(())
+}
+
+/// Menghapus isi lampiran secara best-effort; kegagalan hanya dicatat di log.
+/// Mengembalikan kunci yang sudah tidak ada lagi di storage.
+pub async fn purge_blobs(store: &dyn BlobStore, storage_keys: &[String]) -> Vec<String> {
+    let mut purged = Vec::new();
+    for key in storage_keys {
+        match store.delete(key).await {
+            Ok(()) | Err(BlobError::NotFound) => purged.push(key.clone()),
+            Err(err) => warn!(storage_key = %key, error = %err, "failed to purge attachment blob"),
+        }
+    }
+    purged
+}
+
+/// Mengambil nama file tanpa komponen path dan memvalidasi panjangnya.
+pub fn normalize_file_name(raw: Option<String>) -> Result<String, AttachmentError> {
+    let raw = raw.unwrap_or_default();
+    let file_name = raw
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    if file_name.is_empty() || file_name == "." || file_name == ".." {
+        return Err(AttachmentError::Validation("file name must not be empty".to_string()));
+    }
+    if file_name.len() > MAX_FILE_NAME_LEN {
+        return Err(AttachmentError::Validation(format!(
+            "file name must be at most {MAX_FILE_NAME_LEN} characters"
+        )));
+    }
+    Ok(file_name)
+}
+
+/// Validasi bentuk `type/subtype`; fallback ke `application/octet-stream` bila kosong.
+pub fn normalize_content_type(raw: Option<String>) -> Result<String, AttachmentError> {
+    let Some(raw) = raw.map(|value| value.trim().to_ascii_lowercase()).filter(|value| !value.is_empty())
+    else {
+        return Ok(DEFAULT_CONTENT_TYPE.to_string());
+    };
+    let essence = raw.split(';').next().unwrap_or_default().trim();
+    let is_valid = essence.split_once('/').is_some_and(|(kind, subtype)| {
+        let token = |part: &str| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+        };
+        token(kind) && token(subtype)
+    });
+    if !is_valid {
+        return Err(AttachmentError::Validation(format!("invalid content type: {raw}")));
+    }
+    Ok(raw)
+}
+
+// Mengambil lampiran setelah memastikan user punya permission `action` pada todo induk.
+async fn find_attachment(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    attachment_id: Uuid,
+    action: Permission,
+) -> Result<Model, AttachmentError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, action).await?;
+    repository::find_attachment_by_id(conn, todo_id, attachment_id)
+        .await?
+        .ok_or(AttachmentError::NotFound)
+}
+
+#[derive(Debug)]
+enum SpoolError {
+    LimitExceeded,
+    Read(io::Error),
+    Write(io::Error),
+}
+
+// File sementara yang otomatis dihapus saat keluar scope.
+struct TempFile(PathBuf);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+// Menulis stream ke file sementara sambil menghitung byte; berhenti membaca begitu melewati `limit`.
+async fn spool_to_temp<S>(dir: &Path, mut body: S, limit: u64) -> Result<(TempFile, u64), SpoolError>
+where
+    S: Stream<Item = Result<Bytes, io::Error>> + Unpin,
+{
+    fs::create_dir_all(dir).await.map_err(SpoolError::Write)?;
+    let temp = TempFile(dir.join(format!("upload-{}.tmp", Uuid::new_v4())));
+    let mut file = fs::File::create(&temp.0).await.map_err(SpoolError::Write)?;
+    let mut written = 0u64;
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(SpoolError::Read)?;
+        written += chunk.len() as u64;
+        if written > limit {
+            return Err(SpoolError::LimitExceeded);
+        }
+        file.write_all(&chunk).await.map_err(SpoolError::Write)?;
+    }
+    file.flush().await.map_err(SpoolError::Write)?;
+    Ok((temp, written))
+}
+
+// Modul tests untuk unit testing validasi dan spooling upload.
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::*;
+
+    fn chunks(parts: &[&'static [u8]]) -> impl Stream<Item = Result<Bytes, io::Error>> + Unpin {
+        stream::iter(
+            parts
+                .iter()
+                .map(|part| Ok(Bytes::from_static(part)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn normalize_file_name_should_strip_path_components() {
+        let name = normalize_file_name(Some("C:\\logs\\..\\app.log".to_string())).expect("name should be valid");
+        assert_eq!(name, "app.log");
+        assert!(normalize_file_name(Some("../".to_string())).is_err());
+    }
+
+    #[test]
+    fn normalize_content_type_should_default_and_validate() {
+        assert_eq!(normalize_content_type(None).expect("default"), DEFAULT_CONTENT_TYPE);
+        assert_eq!(
+            normalize_content_type(Some("Image/PNG".to_string())).expect("valid"),
+            "image/png"
+        );
+        assert!(normalize_content_type(Some("not a mime".to_string())).is_err());
+    }
+
+    #[tokio::test]
+    async fn spool_to_temp_should_stop_once_limit_is_exceeded() {
+        let dir = std::env::temp_dir();
+        let result = spool_to_temp(&dir, chunks(&[b"1234", b"5678"]), 6).await;
+        assert!(matches!(result, Err(SpoolError::LimitExceeded)));
+
+        let (temp, size) = spool_to_temp(&dir, chunks(&[b"1234", b"56"]), 6)
+            .await
+            .expect("spool within limit should succeed");
+        assert_eq!(size, 6);
+        let path = temp.0.clone();
+        assert!(path.exists());
+        drop(temp);
+        assert!(!path.exists());
+    }
+}
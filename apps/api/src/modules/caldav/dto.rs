@@ -0,0 +1,208 @@
+use crate::modules::project::entity::Model as Project;
+use crate::modules::sharing::dto::ShareRole;
+
+use super::xml::{self, CALDAV_NS, DAV_NS, Element};
+
+/// Project yang diekspos sebagai calendar collection beserta role user padanya.
+#[derive(Debug, Clone)]
+pub struct DavCalendar {
+    pub project: Project,
+    pub role: ShareRole,
+}
+
+impl DavCalendar {
+    /// Viewer hanya boleh membaca; role lain boleh menambah, mengubah, dan menghapus todo.
+    pub fn writable(&self) -> bool {
+        self.role != ShareRole::Viewer
+    }
+}
+
+/// Nama properti WebDAV yang diminta client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropName {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl PropName {
+    pub fn new(namespace: &str, name: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Properti yang diminta `PROPFIND` atau `REPORT`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropRequest {
+    /// `allprop`, `propname`, atau body kosong: server memilih properti bawaan.
+    AllProp,
+    Props(Vec<PropName>),
+}
+
+impl PropRequest {
+    /// Membaca body `PROPFIND`; body kosong berarti `allprop`.
+    pub fn parse_propfind(body: &str) -> Result<Self, String> {
+        if body.trim().is_empty() {
+            return Ok(Self::AllProp);
+        }
+        let root = xml::parse(body)?;
+        if !root.is(DAV_NS, "propfind") {
+            return Err("PROPFIND body must be a DAV:propfind element".to_string());
+        }
+        Ok(Self::from_parent(&root))
+    }
+
+    // `DAV:prop` di dalam elemen induk; tanpa `DAV:prop` berarti `allprop`.
+    fn from_parent(parent: &Element) -> Self {
+        match parent.child(DAV_NS, "prop") {
+            Some(prop) => Self::Props(
+                prop.children
+                    .iter()
+                    .map(|child| PropName::new(&child.namespace, &child.name))
+                    .collect(),
+            ),
+            None => Self::AllProp,
+        }
+    }
+}
+
+/// `REPORT` yang didukung.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report {
+    /// `CALDAV:calendar-query`; `component` adalah komponen yang difilter di dalam `VCALENDAR`.
+    CalendarQuery {
+        props: PropRequest,
+        component: Option<String>,
+    },
+    /// `CALDAV:calendar-multiget` untuk daftar href tertentu.
+    CalendarMultiget { props: PropRequest, hrefs: Vec<String> },
+    /// `DAV:sync-collection`; `sync_token` kosong berarti sinkronisasi awal.
+    SyncCollection {
+        props: PropRequest,
+        sync_token: Option<String>,
+    },
+    /// Jenis report lain; berisi nama elemen root.
+    Unsupported(String),
+}
+
+impl Report {
+    /// Membaca body `REPORT`.
+    pub fn parse(body: &str) -> Result<Self, String> {
+        let root = xml::parse(body)?;
+        let props = PropRequest::from_parent(&root);
+        let report = if root.is(CALDAV_NS, "calendar-query") {
+            let component = root
+                .child(CALDAV_NS, "filter")
+                .and_then(|filter| filter.child(CALDAV_NS, "comp-filter"))
+                .and_then(|calendar| calendar.child(CALDAV_NS, "comp-filter"))
+                .and_then(|component| component.attribute("name"))
+                .map(str::to_ascii_uppercase);
+            Self::CalendarQuery { props, component }
+        } else if root.is(CALDAV_NS, "calendar-multiget") {
+            let hrefs = root
+                .children
+                .iter()
+                .filter(|child| child.is(DAV_NS, "href"))
+                .map(|href| href.text.trim().to_string())
+                .collect();
+            Self::CalendarMultiget { props, hrefs }
+        } else if root.is(DAV_NS, "sync-collection") {
+            let sync_token = root
+                .child(DAV_NS, "sync-token")
+                .map(|token| token.text.trim().to_string())
+                .filter(|token| !token.is_empty());
+            Self::SyncCollection { props, sync_token }
+        } else {
+            Self::Unsupported(root.name)
+        };
+        Ok(report)
+    }
+}
+
+/// Precondition `If-Match`/`If-None-Match` request tulis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WritePreconditions {
+    pub if_match: Option<String>,
+    pub if_none_match: Option<String>,
+}
+
+/// Isi request `PUT` calendar object resource.
+#[derive(Debug, Clone)]
+pub struct PutResource {
+    /// Nama resource dari path, tanpa akhiran `.ics`.
+    pub name: String,
+    pub body: String,
+    pub preconditions: WritePreconditions,
+}
+
+/// Satu `DAV:response` di dalam `multistatus`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DavResponse {
+    /// Properti yang ditemukan (nilai berupa XML dalam elemen properti) dan yang tidak dikenal.
+    Propstat {
+        href: String,
+        found: Vec<(PropName, String)>,
+        missing: Vec<PropName>,
+    },
+    /// Resource tidak ada atau sudah terhapus (`404`).
+    Missing { href: String },
+}
+
+// Modul tests untuk parsing body PROPFIND/REPORT.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_propfind_should_read_requested_props() {
+        let body = r#"<d:propfind xmlns:d="DAV:" xmlns:cs="http://calendarserver.org/ns/">
+            <d:prop><d:resourcetype/><cs:getctag/></d:prop></d:propfind>"#;
+        assert_eq!(
+            PropRequest::parse_propfind(body),
+            Ok(PropRequest::Props(vec![
+                PropName::new(DAV_NS, "resourcetype"),
+                PropName::new(xml::CALSERVER_NS, "getctag"),
+            ]))
+        );
+        assert_eq!(PropRequest::parse_propfind(""), Ok(PropRequest::AllProp));
+        assert_eq!(
+            PropRequest::parse_propfind(r#"<propfind xmlns="DAV:"><allprop/></propfind>"#),
+            Ok(PropRequest::AllProp)
+        );
+        assert!(PropRequest::parse_propfind(r#"<prop xmlns="DAV:"/>"#).is_err());
+    }
+
+    #[test]
+    fn parse_report_should_recognise_supported_reports() {
+        let query = r#"<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+            <d:prop><d:getetag/></d:prop>
+            <c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="vtodo"/></c:comp-filter></c:filter>
+            </c:calendar-query>"#;
+        assert_eq!(
+            Report::parse(query),
+            Ok(Report::CalendarQuery {
+                props: PropRequest::Props(vec![PropName::new(DAV_NS, "getetag")]),
+                component: Some("VTODO".to_string()),
+            })
+        );
+
+        let multiget = r#"<c:calendar-multiget xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+            <d:prop><c:calendar-data/></d:prop><d:href>/a.ics</d:href><d:href> /b.ics </d:href>
+            </c:calendar-multiget>"#;
+        match Report::parse(multiget) {
+            Ok(Report::CalendarMultiget { hrefs, .. }) => assert_eq!(hrefs, vec!["/a.ics", "/b.ics"]),
+            other => panic!("unexpected report: {other:?}"),
+        }
+
+        let sync = r#"<d:sync-collection xmlns:d="DAV:"><d:sync-token/><d:sync-level>1</d:sync-level>
+            <d:prop><d:getetag/></d:prop></d:sync-collection>"#;
+        assert!(matches!(Report::parse(sync), Ok(Report::SyncCollection { sync_token: None, .. })));
+
+        assert_eq!(
+            Report::parse(r#"<d:expand-property xmlns:d="DAV:"/>"#),
+            Ok(Report::Unsupported("expand-property".to_string()))
+        );
+    }
+}
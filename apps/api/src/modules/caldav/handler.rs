@@ -0,0 +1,544 @@
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, FromRequestParts, Path, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+    routing::any,
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::error::ApiError;
+use crate::modules::app_password::service::{self as app_password_service, AppPasswordError};
+use crate::modules::calendar::ics;
+use crate::modules::calendar::service as calendar_service;
+use crate::modules::history::dto::AuditContext;
+
+use super::dto::{PropRequest, PutResource, Report, WritePreconditions};
+use super::service::{self, DavError};
+
+/// Realm pada challenge `WWW-Authenticate`.
+const AUTH_CHALLENGE: &str = "Basic realm=\"todolist\", charset=\"UTF-8\"";
+const MULTISTATUS_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// Route CalDAV tanpa prefix resource (path lengkap di bawah `/api`), dengan atau tanpa `/` di akhir:
+/// - `/caldav/` principal user (`OPTIONS`, `PROPFIND`)
+/// - `/caldav/calendars/` calendar home berisi seluruh project yang bisa diakses (`OPTIONS`, `PROPFIND`)
+/// - `/caldav/calendars/{project_id}/` calendar satu project (`OPTIONS`, `PROPFIND`, `REPORT`)
+/// - `/caldav/calendars/{project_id}/{name}.ics` satu todo (`OPTIONS`, `PROPFIND`, `GET`, `PUT`, `DELETE`)
+///
+/// Seluruh route diautentikasi dengan app password, bukan header `X-User-Id`.
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/caldav", any(principal))
+        .route("/caldav/", any(principal))
+        .route("/caldav/calendars", any(home))
+        .route("/caldav/calendars/", any(home))
+        .route("/caldav/calendars/{project_id}", any(calendar))
+        .route("/caldav/calendars/{project_id}/", any(calendar))
+        .route("/caldav/calendars/{project_id}/{file}", any(resource))
+        .layer(DefaultBodyLimit::max(calendar_service::MAX_IMPORT_BYTES))
+}
+
+/// User CalDAV dari header `Authorization` (`Basic` username + app password, atau `Bearer`).
+/// Gagal dijawab `401` beserta challenge `Basic` agar client menampilkan form login.
+pub struct DavUser(pub Uuid);
+
+impl FromRequestParts<AppState> for DavUser {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let authorization = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        match app_password_service::authenticate(&state.db, authorization).await {
+            Ok(user_id) => Ok(Self(user_id)),
+            Err(AppPasswordError::Database(err)) => {
+                error!(error = %err, "caldav authentication lookup failed");
+                Err(ApiError::internal("unexpected database error").into_response())
+            }
+            Err(_) => {
+                warn!("caldav authentication failed");
+                let mut response = ApiError::unauthorized("valid app password credentials are required").into_response();
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static(AUTH_CHALLENGE));
+                Err(response)
+            }
+        }
+    }
+}
+
+/// `/caldav/`
+/// `PROPFIND` → `207 Multi-Status` berisi `current-user-principal` dan `calendar-home-set`.
+pub async fn principal(
+    State(state): State<AppState>,
+    DavUser(user_id): DavUser,
+    method: Method,
+    body: String,
+) -> Result<Response, Response> {
+    match method.as_str() {
+        "OPTIONS" => Ok(options_response("OPTIONS, PROPFIND")),
+        "PROPFIND" => {
+            let request = parse_propfind(&body).map_err(IntoResponse::into_response)?;
+            let body = service::propfind_principal(&state.db, user_id, &request)
+                .await
+                .map_err(map_dav_error)?;
+            Ok(multistatus_response(body))
+        }
+        _ => Err(method_not_allowed("OPTIONS, PROPFIND")),
+    }
+}
+
+/// `/caldav/calendars/`
+/// `PROPFIND` (`Depth: 1`) → `207 Multi-Status` berisi satu calendar per project.
+pub async fn home(
+    State(state): State<AppState>,
+    DavUser(user_id): DavUser,
+    method: Method,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Response, Response> {
+    match method.as_str() {
+        "OPTIONS" => Ok(options_response("OPTIONS, PROPFIND")),
+        "PROPFIND" => {
+            let request = parse_propfind(&body).map_err(IntoResponse::into_response)?;
+            let body = service::propfind_home(&state.db, user_id, depth_one(&headers), &request)
+                .await
+                .map_err(map_dav_error)?;
+            info!(user_id = %user_id, "caldav calendars listed");
+            Ok(multistatus_response(body))
+        }
+        _ => Err(method_not_allowed("OPTIONS, PROPFIND")),
+    }
+}
+
+/// `/caldav/calendars/{project_id}/`
+/// `PROPFIND` → properti calendar (dan todo bila `Depth: 1`); `REPORT` → `calendar-query`,
+/// `calendar-multiget`, atau `sync-collection`.
+pub async fn calendar(
+    State(state): State<AppState>,
+    DavUser(user_id): DavUser,
+    Path(project_id): Path<Uuid>,
+    method: Method,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Response, Response> {
+    const ALLOW: &str = "OPTIONS, PROPFIND, REPORT";
+    match method.as_str() {
+        "OPTIONS" => Ok(options_response(ALLOW)),
+        "PROPFIND" => {
+            let request = parse_propfind(&body).map_err(IntoResponse::into_response)?;
+            let body = service::propfind_calendar(&state.db, user_id, project_id, depth_one(&headers), &request)
+                .await
+                .map_err(map_dav_error)?;
+            Ok(multistatus_response(body))
+        }
+        "REPORT" => {
+            let report = Report::parse(&body).map_err(|reason| invalid_body("REPORT", reason).into_response())?;
+            let body = service::report(&state.db, user_id, project_id, report)
+                .await
+                .map_err(map_dav_error)?;
+            info!(project_id = %project_id, "caldav report served");
+            Ok(multistatus_response(body))
+        }
+        _ => Err(method_not_allowed(ALLOW)),
+    }
+}
+
+/// `/caldav/calendars/{project_id}/{name}.ics`
+///
+/// - `GET` → `200 OK` `text/calendar` berisi satu `VTODO` beserta `ETag`
+/// - `PUT` → `201 Created` (todo baru) atau `204 No Content`, beserta `ETag`; `If-Match` dan
+///   `If-None-Match: *` dihormati (`412` bila tidak terpenuhi)
+/// - `DELETE` → `204 No Content`
+pub async fn resource(
+    State(state): State<AppState>,
+    DavUser(user_id): DavUser,
+    Path((project_id, file)): Path<(Uuid, String)>,
+    method: Method,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Response, Response> {
+    const ALLOW: &str = "OPTIONS, PROPFIND, GET, HEAD, PUT, DELETE";
+    let name = file.strip_suffix(".ics").unwrap_or(&file).to_string();
+    match method.as_str() {
+        "OPTIONS" => Ok(options_response(ALLOW)),
+        "PROPFIND" => {
+            let request = parse_propfind(&body).map_err(IntoResponse::into_response)?;
+            let body = service::propfind_resource(&state.db, user_id, project_id, &name, &request)
+                .await
+                .map_err(map_dav_error)?;
+            Ok(multistatus_response(body))
+        }
+        "GET" | "HEAD" => {
+            let todo = service::get_resource(&state.db, user_id, project_id, &name)
+                .await
+                .map_err(map_dav_error)?;
+            Ok((
+                [
+                    (header::CONTENT_TYPE, ics::CONTENT_TYPE.to_string()),
+                    (header::ETAG, service::etag(&todo)),
+                    (header::LAST_MODIFIED, service::http_date(todo.updated_at)),
+                ],
+                ics::render_resource(&todo),
+            )
+                .into_response())
+        }
+        "PUT" => {
+            let request = PutResource {
+                name,
+                body,
+                preconditions: preconditions(&headers),
+            };
+            let audit = AuditContext::new(user_id, &state.history);
+            let (created, todo) =
+                service::put_resource(&state.db, user_id, project_id, request, &audit, &state.dependencies)
+                    .await
+                    .map_err(map_dav_error)?;
+            info!(todo_id = %todo.id, created, "caldav resource stored");
+            let status = if created { StatusCode::CREATED } else { StatusCode::NO_CONTENT };
+            Ok((status, [(header::ETAG, service::etag(&todo))]).into_response())
+        }
+        "DELETE" => {
+            let todo = service::get_resource(&state.db, user_id, project_id, &name)
+                .await
+                .map_err(map_dav_error)?;
+            let audit = AuditContext::new(user_id, &state.history);
+            service::delete_resource(&state.db, user_id, &todo, &preconditions(&headers), &audit)
+                .await
+                .map_err(map_dav_error)?;
+            info!(todo_id = %todo.id, "caldav resource deleted");
+            Ok(StatusCode::NO_CONTENT.into_response())
+        }
+        _ => Err(method_not_allowed(ALLOW)),
+    }
+}
+
+// `Depth: 0` hanya resource itu sendiri; `1` dan `infinity` (bawaan PROPFIND) ikut mendaftar anggota.
+fn depth_one(headers: &HeaderMap) -> bool {
+    headers
+        .get("depth")
+        .and_then(|value| value.to_str().ok())
+        .is_none_or(|depth| depth.trim() != "0")
+}
+
+fn preconditions(headers: &HeaderMap) -> WritePreconditions {
+    let read = |name| {
+        headers
+            .get(name)
+            .and_then(|value: &HeaderValue| value.to_str().ok())
+            .map(str::to_string)
+    };
+    WritePreconditions {
+        if_match: read(header::IF_MATCH),
+        if_none_match: read(header::IF_NONE_MATCH),
+    }
+}
+
+fn parse_propfind(body: &str) -> Result<PropRequest, ApiError> {
+    PropRequest::parse_propfind(body).map_err(|reason| invalid_body("PROPFIND", reason))
+}
+
+fn invalid_body(method: &str, reason: String) -> ApiError {
+    warn!(reason = %reason, "invalid caldav {method} body");
+    ApiError::bad_request(format!("invalid {method} body: {reason}"))
+}
+
+fn options_response(allow: &'static str) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::ALLOW, allow),
+            (header::HeaderName::from_static("dav"), service::DAV_CAPABILITIES),
+        ],
+    )
+        .into_response()
+}
+
+fn method_not_allowed(allow: &'static str) -> Response {
+    (StatusCode::METHOD_NOT_ALLOWED, [(header::ALLOW, allow)]).into_response()
+}
+
+fn multistatus_response(body: String) -> Response {
+    (
+        StatusCode::MULTI_STATUS,
+        [(header::CONTENT_TYPE, MULTISTATUS_CONTENT_TYPE)],
+        body,
+    )
+        .into_response()
+}
+
+// Precondition WebDAV dijawab dengan body `DAV:error` agar client tahu harus sinkron ulang.
+fn precondition_response(condition: &str) -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        [(header::CONTENT_TYPE, MULTISTATUS_CONTENT_TYPE)],
+        service::render_error(condition),
+    )
+        .into_response()
+}
+
+/// Mapping error domain CalDAV ke response; precondition WebDAV memakai body XML,
+/// selebihnya `ApiError` agar response error konsisten.
+fn map_dav_error(err: DavError) -> Response {
+    match err {
+        DavError::Validation(message) => {
+            warn!(reason = %message, "caldav validation failed");
+            ApiError::bad_request(message).into_response()
+        }
+        DavError::NotFound => {
+            warn!("caldav resource not found");
+            ApiError::not_found("calendar resource not found").into_response()
+        }
+        DavError::Forbidden => {
+            warn!("caldav action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this calendar").into_response()
+        }
+        DavError::PreconditionFailed => {
+            warn!("caldav write precondition failed");
+            ApiError::precondition_failed("resource etag does not match").into_response()
+        }
+        DavError::Conflict(message) => {
+            warn!(reason = %message, "caldav write conflicted");
+            ApiError::conflict(message).into_response()
+        }
+        DavError::InvalidSyncToken => {
+            warn!("caldav sync token rejected");
+            precondition_response("<d:valid-sync-token/>")
+        }
+        DavError::UnsupportedComponent => {
+            warn!("caldav resource without VTODO rejected");
+            precondition_response("<c:supported-calendar-component/>")
+        }
+        DavError::UnsupportedReport(name) => {
+            warn!(report = %name, "caldav report not supported");
+            precondition_response("<d:supported-report/>")
+        }
+        DavError::Database(db_err) => {
+            error!(error = %db_err, "caldav database operation failed");
+            ApiError::internal("unexpected database error").into_response()
+        }
+    }
+}
+
+// Modul tests untuk integrasi end-to-end CalDAV dengan autentikasi app password.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::modules::app_password::handler as app_password_handler;
+    use crate::modules::project::handler as project_handler;
+    use crate::modules::todo::repository as todo_repository;
+
+    use super::*;
+
+    // Router dipasang di bawah `/api` agar href di response bisa langsung dipakai ulang.
+    async fn build_test_app() -> (Router, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let api = Router::new()
+            .merge(routes())
+            .nest("/app-passwords", app_password_handler::routes())
+            .nest("/projects", project_handler::routes());
+        let app = Router::new()
+            .nest("/api", api)
+            .with_state(AppState::for_tests(db, user_id));
+        (app, user_id)
+    }
+
+    async fn send_json(app: &Router, user_id: Uuid, uri: &str, body: Value) -> Value {
+        let request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header(USER_ID_HEADER, user_id.to_string())
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&bytes).expect("body must be valid json")
+    }
+
+    async fn dav(
+        app: &Router,
+        authorization: Option<&str>,
+        method: &str,
+        uri: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> (StatusCode, HeaderMap, String) {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(authorization) = authorization {
+            builder = builder.header(header::AUTHORIZATION, authorization);
+        }
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        let request = builder.body(Body::from(body.to_string())).expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        (status, headers, String::from_utf8(bytes.to_vec()).expect("body must be utf-8"))
+    }
+
+    fn etag_of(headers: &HeaderMap) -> String {
+        headers
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .expect("etag header")
+            .to_string()
+    }
+
+    fn vtodo(uid: &str, summary: &str) -> String {
+        format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Test//EN\r\nBEGIN:VTODO\r\nUID:{uid}\r\n\
+             SUMMARY:{summary}\r\nDUE:20260301T020000Z\r\nEND:VTODO\r\nEND:VCALENDAR\r\n"
+        )
+    }
+
+    fn sync_report(token: &str) -> String {
+        format!(
+            r#"<d:sync-collection xmlns:d="DAV:"><d:sync-token>{token}</d:sync-token><d:sync-level>1</d:sync-level><d:prop><d:getetag/></d:prop></d:sync-collection>"#
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn caldav_should_sync_todos_of_a_project_with_app_password_auth() {
+        let (app, user_id) = build_test_app().await;
+        let project = send_json(&app, user_id, "/api/projects", json!({ "name": "Belanja" })).await;
+        let project_id = project["data"]["id"].as_str().expect("project id").to_string();
+        let issued = send_json(&app, user_id, "/api/app-passwords", json!({ "name": "Ponsel" })).await;
+        let password = issued["data"]["password"].as_str().expect("app password");
+        let basic = format!("Basic {}", STANDARD.encode(format!("{user_id}:{password}")));
+        let auth = Some(basic.as_str());
+
+        let (status, headers, _) = dav(&app, None, "PROPFIND", "/api/caldav/", &[], "").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(headers.contains_key(header::WWW_AUTHENTICATE));
+        let wrong = format!("Basic {}", STANDARD.encode(format!("{}:{password}", Uuid::new_v4())));
+        let (status, _, _) = dav(&app, Some(&wrong), "PROPFIND", "/api/caldav/", &[], "").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, headers, _) = dav(&app, auth, "OPTIONS", "/api/caldav/", &[], "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers.get("dav").and_then(|value| value.to_str().ok()), Some("1, 3, calendar-access"));
+        let (status, _, body) = dav(&app, auth, "PROPFIND", "/api/caldav/", &[("depth", "0")], "").await;
+        assert_eq!(status, StatusCode::MULTI_STATUS);
+        assert!(body.contains("<c:calendar-home-set><d:href>/api/caldav/calendars/</d:href></c:calendar-home-set>"));
+
+        let calendar_href = format!("/api/caldav/calendars/{project_id}/");
+        let (status, _, body) = dav(&app, auth, "PROPFIND", "/api/caldav/calendars/", &[("depth", "1")], "").await;
+        assert_eq!(status, StatusCode::MULTI_STATUS);
+        assert!(body.contains(&format!("<d:href>{calendar_href}</d:href>")));
+        assert!(body.contains("<d:displayname>Belanja</d:displayname>"));
+
+        let uid = format!("{}@example.com", Uuid::new_v4());
+        let href = format!("{calendar_href}{uid}.ics");
+        let create = [("if-none-match", "*")];
+        let (status, headers, _) = dav(&app, auth, "PUT", &href, &create, &vtodo(&uid, "Beli susu")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let first_etag = etag_of(&headers);
+        let (status, _, _) = dav(&app, auth, "PUT", &href, &create, &vtodo(&uid, "Beli susu")).await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+        let other = format!("{calendar_href}other.ics");
+        let (status, _, _) = dav(&app, auth, "PUT", &other, &[], &vtodo(&uid, "Beli susu")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, headers, body) = dav(&app, auth, "GET", &href, &[], "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(etag_of(&headers), first_etag);
+        assert!(body.contains(&format!("UID:{uid}\r\n")));
+        assert!(body.contains("SUMMARY:Beli susu\r\n"));
+        assert!(!body.contains("METHOD:"));
+
+        let (status, _, body) = dav(&app, auth, "REPORT", &calendar_href, &[], &sync_report("")).await;
+        assert_eq!(status, StatusCode::MULTI_STATUS);
+        assert!(body.contains(&format!("<d:href>{href}</d:href>")));
+        let token = body
+            .split("<d:sync-token>")
+            .nth(1)
+            .and_then(|rest| rest.split("</d:sync-token>").next())
+            .expect("sync token")
+            .to_string();
+
+        let first_match = [("if-match", first_etag.as_str())];
+        let (status, headers, _) = dav(&app, auth, "PUT", &href, &first_match, &vtodo(&uid, "Beli susu murni")).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let second_etag = etag_of(&headers);
+        assert_ne!(second_etag, first_etag);
+        let (status, _, _) = dav(&app, auth, "DELETE", &href, &first_match, "").await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+        let query = r#"<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav"><d:prop><c:calendar-data/></d:prop><c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VTODO"/></c:comp-filter></c:filter></c:calendar-query>"#;
+        let (_, _, body) = dav(&app, auth, "REPORT", &calendar_href, &[("depth", "1")], query).await;
+        assert!(body.contains("SUMMARY:Beli susu murni"));
+
+        let current = [("if-match", second_etag.as_str())];
+        let (status, _, _) = dav(&app, auth, "DELETE", &href, &current, "").await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _, _) = dav(&app, auth, "GET", &href, &[], "").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _, body) = dav(&app, auth, "REPORT", &calendar_href, &[], &sync_report(&token)).await;
+        assert_eq!(status, StatusCode::MULTI_STATUS);
+        assert!(body.contains(&format!("<d:href>{href}</d:href><d:status>HTTP/1.1 404 Not Found</d:status>")));
+        let (status, _, body) = dav(&app, auth, "REPORT", &calendar_href, &[], &sync_report("bogus")).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body.contains("<d:valid-sync-token/>"));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn concurrent_puts_with_the_same_etag_should_not_both_succeed() {
+        let (app, user_id) = build_test_app().await;
+        let project = send_json(&app, user_id, "/api/projects", json!({ "name": "Kantor" })).await;
+        let project_id = project["data"]["id"].as_str().expect("project id").to_string();
+        let issued = send_json(&app, user_id, "/api/app-passwords", json!({ "name": "Laptop" })).await;
+        let password = issued["data"]["password"].as_str().expect("app password");
+        let basic = format!("Basic {}", STANDARD.encode(format!("{user_id}:{password}")));
+        let auth = Some(basic.as_str());
+
+        let uid = format!("{}@example.com", Uuid::new_v4());
+        let href = format!("/api/caldav/calendars/{project_id}/{uid}.ics");
+        let (status, headers, _) = dav(&app, auth, "PUT", &href, &[], &vtodo(&uid, "Rapat")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let etag = etag_of(&headers);
+        let if_match = [("if-match", etag.as_str())];
+
+        let (morning, afternoon) = (vtodo(&uid, "Rapat pagi"), vtodo(&uid, "Rapat sore"));
+        let (first, second) = tokio::join!(
+            dav(&app, auth, "PUT", &href, &if_match, &morning),
+            dav(&app, auth, "PUT", &href, &if_match, &afternoon),
+        );
+        let mut statuses = [first.0, second.0];
+        statuses.sort();
+        assert_eq!(statuses, [StatusCode::NO_CONTENT, StatusCode::PRECONDITION_FAILED]);
+    }
+}
@@ -0,0 +1,6 @@
+//! Modul CalDAV: server minimal dengan setiap project sebagai calendar collection berisi todo `VTODO`.
+
+pub mod dto;
+pub mod handler;
+pub mod service;
+pub mod xml;
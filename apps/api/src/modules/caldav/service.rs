@@ -0,0 +1,717 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::config::dependency::DependencySettings;
+use crate::modules::calendar::dto::ImportedTodo;
+use crate::modules::calendar::ics;
+use crate::modules::calendar::service::{self as calendar_service, CalendarError};
+use crate::modules::history::dto::AuditContext;
+use crate::modules::project::repository as project_repository;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::sync::repository as sync_repository;
+use crate::modules::todo::dto::{CreateTodoRequest, UpdateTodoRequest};
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service::{self as todo_service, TodoError};
+use crate::modules::user::repository as user_repository;
+
+use super::dto::{DavCalendar, DavResponse, PropName, PropRequest, PutResource, Report, WritePreconditions};
+use super::xml::{self, CALDAV_NS, CALSERVER_NS, DAV_NS};
+
+/// Prefix URL seluruh resource CalDAV; sekaligus URL principal user.
+pub const DAV_ROOT: &str = "/api/caldav";
+/// Nilai header `DAV` pada response `OPTIONS`.
+pub const DAV_CAPABILITIES: &str = "1, 3, calendar-access";
+/// Content type calendar object resource.
+pub const RESOURCE_CONTENT_TYPE: &str = "text/calendar; charset=utf-8; component=VTODO";
+const SYNC_TOKEN_PREFIX: &str = "urn:todolist:sync:";
+
+/// Error domain CalDAV yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum DavError {
+    Validation(String),
+    NotFound,
+    Forbidden,
+    /// `If-Match`/`If-None-Match` tidak terpenuhi.
+    PreconditionFailed,
+    Conflict(String),
+    /// Sync token bukan token yang pernah diterbitkan server (`DAV:valid-sync-token`).
+    InvalidSyncToken,
+    /// Resource yang dikirim tidak berisi `VTODO` (`CALDAV:supported-calendar-component`).
+    UnsupportedComponent,
+    /// Jenis `REPORT` yang tidak didukung; berisi nama elemen root.
+    UnsupportedReport(String),
+    Database(DbErr),
+}
+
+impl From<DbErr> for DavError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for DavError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<TodoError> for DavError {
+    fn from(value: TodoError) -> Self {
+        match value {
+            TodoError::Validation(message) => Self::Validation(message),
+            TodoError::NotFound => Self::NotFound,
+            TodoError::Forbidden => Self::Forbidden,
+            TodoError::Blocked(_) => Self::Conflict("todo is blocked by unfinished dependencies".to_string()),
+            TodoError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<CalendarError> for DavError {
+    fn from(value: CalendarError) -> Self {
+        match value {
+            CalendarError::Validation(message) => Self::Validation(message),
+            CalendarError::NotFound => Self::NotFound,
+            CalendarError::Forbidden => Self::Forbidden,
+            CalendarError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+// Resource DAV yang propertinya dirender ke `multistatus`.
+enum Resource<'a> {
+    Principal { email: &'a str },
+    Home,
+    Calendar {
+        calendar: &'a DavCalendar,
+        sync_token: &'a str,
+    },
+    Todo(&'a Todo),
+}
+
+/// `PROPFIND` pada principal user (`/caldav/`).
+pub async fn propfind_principal(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: &PropRequest,
+) -> Result<String, DavError> {
+    let user = user_repository::find_user_by_id(conn, user_id)
+        .await?
+        .ok_or(DavError::NotFound)?;
+    let resource = Resource::Principal { email: &user.email };
+    Ok(render_multistatus(&[propstat(principal_href(), &resource, request)], None))
+}
+
+/// `PROPFIND` pada calendar home (`/caldav/calendars/`); `depth_one` ikut mendaftar seluruh
+/// project yang bisa diakses user, lintas workspace.
+pub async fn propfind_home(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    depth_one: bool,
+    request: &PropRequest,
+) -> Result<String, DavError> {
+    let mut responses = vec![propstat(home_href(), &Resource::Home, request)];
+    if depth_one {
+        let calendars = list_calendars(conn, user_id).await?;
+        let sync_token = current_sync_token(conn).await?;
+        for calendar in &calendars {
+            let resource = Resource::Calendar {
+                calendar,
+                sync_token: &sync_token,
+            };
+            responses.push(propstat(calendar_href(calendar.project.id), &resource, request));
+        }
+    }
+    Ok(render_multistatus(&responses, None))
+}
+
+/// `PROPFIND` pada calendar collection satu project; `depth_one` ikut mendaftar todo-nya.
+pub async fn propfind_calendar(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    depth_one: bool,
+    request: &PropRequest,
+) -> Result<String, DavError> {
+    let calendar = find_calendar(conn, user_id, project_id).await?;
+    let sync_token = current_sync_token(conn).await?;
+    let resource = Resource::Calendar {
+        calendar: &calendar,
+        sync_token: &sync_token,
+    };
+    let mut responses = vec![propstat(calendar_href(project_id), &resource, request)];
+    if depth_one {
+        let todos = sync_repository::list_changed_project_todos(conn, project_id, None).await?;
+        responses.extend(todo_responses(project_id, &todos, request));
+    }
+    Ok(render_multistatus(&responses, None))
+}
+
+/// `PROPFIND` pada satu todo.
+pub async fn propfind_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    name: &str,
+    request: &PropRequest,
+) -> Result<String, DavError> {
+    let todo = get_resource(conn, user_id, project_id, name).await?;
+    let responses = todo_responses(project_id, std::slice::from_ref(&todo), request);
+    Ok(render_multistatus(&responses, None))
+}
+
+/// `REPORT` pada calendar collection.
+///
+/// - `calendar-query`: seluruh todo project; kosong bila filter meminta komponen selain `VTODO`
+///   (filter properti dan rentang waktu tidak dievaluasi).
+/// - `calendar-multiget`: todo sesuai daftar href; href yang tidak dikenal dijawab `404`.
+/// - `sync-collection`: todo yang berubah dan href todo yang terhapus sejak sync token,
+///   beserta token berikutnya; tanpa token berarti seluruh todo.
+pub async fn report(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    report: Report,
+) -> Result<String, DavError> {
+    find_calendar(conn, user_id, project_id).await?;
+    match report {
+        Report::CalendarQuery { props, component } => {
+            let todos = if component.as_deref().is_none_or(|component| component == "VTODO") {
+                sync_repository::list_changed_project_todos(conn, project_id, None).await?
+            } else {
+                Vec::new()
+            };
+            Ok(render_multistatus(&todo_responses(project_id, &todos, &props), None))
+        }
+        Report::CalendarMultiget { props, hrefs } => {
+            let mut responses = Vec::with_capacity(hrefs.len());
+            for href in hrefs {
+                let todo = match resource_name_from_href(project_id, &href) {
+                    Some(name) => todo_repository::find_todo_by_resource_name(conn, project_id, &name).await?,
+                    None => None,
+                };
+                responses.push(match todo {
+                    Some(todo) => propstat(href, &Resource::Todo(&todo), &props),
+                    None => DavResponse::Missing { href },
+                });
+            }
+            Ok(render_multistatus(&responses, None))
+        }
+        Report::SyncCollection { props, sync_token } => {
+            let since = sync_token.as_deref().map(parse_sync_token).transpose()?;
+            let watermark = sync_repository::current_watermark(conn).await?;
+            if since.is_some_and(|since| since > watermark) {
+                return Err(DavError::InvalidSyncToken);
+            }
+            // Resource yang terhapus ditulis lebih dulu, agar UID yang dibuat ulang tetap ada di client.
+            let mut responses: Vec<DavResponse> = match since {
+                Some(since) => sync_repository::list_project_tombstones(conn, project_id, since)
+                    .await?
+                    .into_iter()
+                    .map(|tombstone| {
+                        let name = tombstone.external_uid.unwrap_or_else(|| tombstone.todo_id.to_string());
+                        DavResponse::Missing {
+                            href: resource_href(project_id, &name),
+                        }
+                    })
+                    .collect(),
+                None => Vec::new(),
+            };
+            let todos = sync_repository::list_changed_project_todos(conn, project_id, since).await?;
+            responses.extend(todo_responses(project_id, &todos, &props));
+            Ok(render_multistatus(&responses, Some(&sync_token_for(watermark))))
+        }
+        Report::Unsupported(name) => Err(DavError::UnsupportedReport(name)),
+    }
+}
+
+/// Todo di calendar collection berdasarkan nama resource (tanpa akhiran `.ics`).
+pub async fn get_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    name: &str,
+) -> Result<Todo, DavError> {
+    find_calendar(conn, user_id, project_id).await?;
+    todo_repository::find_todo_by_resource_name(conn, project_id, name)
+        .await?
+        .ok_or(DavError::NotFound)
+}
+
+/// Membuat atau mengganti todo dari satu calendar object resource berisi tepat satu `VTODO`.
+///
+/// Resource baru harus bernama sama dengan UID-nya dan UID tidak boleh sudah dipakai todo lain
+/// milik user; todo dibuat di workspace project lewat jalur create biasa. Resource yang sudah ada
+/// diperbarui lewat jalur update biasa dan UID-nya tidak boleh berubah. Precondition `If-Match`
+/// dicek terhadap baris todo yang dikunci di transaksi update, sehingga dua `PUT` dengan ETag
+/// yang sama tidak bisa sama-sama berhasil. Mengembalikan `true` bila todo baru dibuat.
+pub async fn put_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    request: PutResource,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
+) -> Result<(bool, Todo), DavError> {
+    let calendar = find_calendar(conn, user_id, project_id).await?;
+    let imported = parse_resource(&request.body)?;
+    let name = request.name.as_str();
+    let uid = imported
+        .uid
+        .clone()
+        .ok_or_else(|| DavError::Validation("UID is required".to_string()))?;
+    let txn = conn.begin().await?;
+    let existing = match todo_repository::find_todo_by_resource_name(&txn, project_id, name).await? {
+        Some(found) => todo_repository::find_todo_for_update(&txn, found.id).await?,
+        None => None,
+    };
+    check_preconditions(existing.as_ref(), &request.preconditions)?;
+
+    if let Some(existing) = existing {
+        if ics::resource_uid(&existing) != uid {
+            return Err(DavError::Conflict("UID of an existing resource cannot change".to_string()));
+        }
+        // Tanpa `DESCRIPTION` berarti deskripsi dikosongkan, berbeda dari impor.
+        let desc_unchanged = imported.desc.is_some() || existing.desc.as_deref().is_none_or(str::is_empty);
+        if calendar_service::is_unchanged(&existing, &imported) && desc_unchanged {
+            return Ok((false, existing));
+        }
+        let request = UpdateTodoRequest {
+            title: Some(imported.title),
+            desc: Some(imported.desc.unwrap_or_default()),
+            iscompleted: Some(imported.completed),
+            assignee_id: None,
+            estimate: None,
+            due_at: Some(imported.due_at),
+        };
+        let updated = todo_service::update_todo(&txn, user_id, existing.id, request, audit, dependencies).await?;
+        txn.commit().await?;
+        return Ok((false, updated));
+    }
+    txn.commit().await?;
+
+    if name != uid {
+        return Err(DavError::Validation("resource name must match the VTODO UID".to_string()));
+    }
+    if todo_repository::find_todo_by_external_uid(conn, user_id, calendar.project.workspace_id, &uid).await?.is_some() {
+        return Err(DavError::Conflict("UID is already used by another todo".to_string()));
+    }
+    let request = CreateTodoRequest {
+        title: imported.title,
+        desc: imported.desc,
+        project_id: Some(project_id),
+        assignee_id: None,
+        estimate: None,
+        due_at: imported.due_at,
+    };
+    let mut new_todo = todo_service::prepare_new_todo(conn, user_id, calendar.project.workspace_id, request).await?;
+    new_todo.external_uid = Some(uid);
+    if imported.completed {
+        new_todo.completed_at = Some(imported.completed_at.unwrap_or_else(|| Utc::now().fixed_offset()));
+    }
+    let todo = todo_service::insert_todo(conn, user_id, new_todo, audit).await?;
+    Ok((true, todo))
+}
+
+/// Menghapus todo hasil `get_resource` setelah precondition `If-Match` dicek terhadap baris
+/// todo yang dikunci di transaksi penghapusan.
+pub async fn delete_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo: &Todo,
+    preconditions: &WritePreconditions,
+    audit: &AuditContext,
+) -> Result<(), DavError> {
+    let txn = conn.begin().await?;
+    let current = todo_repository::find_todo_for_update(&txn, todo.id).await?;
+    check_preconditions(current.as_ref(), preconditions)?;
+    todo_service::delete_todo(&txn, user_id, todo.id, audit).await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+/// Seluruh project yang bisa diakses user sebagai calendar collection.
+pub async fn list_calendars(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<DavCalendar>, DavError> {
+    let projects = project_repository::list_all_accessible_projects(conn, user_id).await?;
+    let mut calendars = Vec::with_capacity(projects.len());
+    for project in projects {
+        if let Some(role) = sharing_service::project_role(conn, user_id, &project).await? {
+            calendars.push(DavCalendar { project, role });
+        }
+    }
+    Ok(calendars)
+}
+
+/// ETag todo, berubah setiap kali todo diperbarui.
+pub fn etag(todo: &Todo) -> String {
+    format!("\"{}\"", todo.updated_at.timestamp_micros())
+}
+
+/// Tanggal HTTP (`Last-Modified`/`getlastmodified`).
+pub fn http_date(at: DateTime<FixedOffset>) -> String {
+    at.with_timezone(&Utc).format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Sync token yang diterbitkan untuk watermark sinkronisasi.
+pub fn sync_token_for(watermark: i64) -> String {
+    format!("{SYNC_TOKEN_PREFIX}{watermark}")
+}
+
+/// Watermark dari sync token yang diterbitkan `sync_token_for`.
+pub fn parse_sync_token(raw: &str) -> Result<i64, DavError> {
+    raw.trim()
+        .strip_prefix(SYNC_TOKEN_PREFIX)
+        .and_then(|watermark| watermark.parse::<i64>().ok())
+        .filter(|watermark| *watermark >= 0)
+        .ok_or(DavError::InvalidSyncToken)
+}
+
+/// Href calendar collection satu project.
+pub fn calendar_href(project_id: Uuid) -> String {
+    format!("{DAV_ROOT}/calendars/{project_id}/")
+}
+
+/// Href resource todo dari namanya (UID eksternal atau id todo).
+pub fn resource_href(project_id: Uuid, name: &str) -> String {
+    format!("{}{}.ics", calendar_href(project_id), percent_encode(name))
+}
+
+/// Nama resource dari href `calendar-multiget`, baik path maupun URL lengkap; `None` bila href
+/// bukan resource di calendar collection tersebut.
+pub fn resource_name_from_href(project_id: Uuid, href: &str) -> Option<String> {
+    let prefix = calendar_href(project_id);
+    let start = href.find(&prefix)?;
+    let encoded = href[start + prefix.len()..].strip_suffix(".ics")?;
+    if encoded.is_empty() || encoded.contains('/') {
+        return None;
+    }
+    percent_decode(encoded)
+}
+
+/// Body `DAV:error` berisi satu elemen precondition, mis. `<d:valid-sync-token/>`.
+pub fn render_error(condition: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><d:error xmlns:d="{DAV_NS}" xmlns:c="{CALDAV_NS}">{condition}</d:error>"#
+    )
+}
+
+/// Body `DAV:multistatus`; `sync_token` diisi untuk `sync-collection`.
+pub fn render_multistatus(responses: &[DavResponse], sync_token: Option<&str>) -> String {
+    let mut out = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><d:multistatus xmlns:d="{DAV_NS}" xmlns:c="{CALDAV_NS}" xmlns:cs="{CALSERVER_NS}">"#
+    );
+    for response in responses {
+        out.push_str("<d:response>");
+        match response {
+            DavResponse::Propstat { href, found, missing } => {
+                out.push_str(&format!("<d:href>{}</d:href>", xml::escape(href)));
+                if !found.is_empty() || missing.is_empty() {
+                    out.push_str("<d:propstat><d:prop>");
+                    for (prop, value) in found {
+                        out.push_str(&render_prop(prop, value));
+                    }
+                    out.push_str("</d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat>");
+                }
+                if !missing.is_empty() {
+                    out.push_str("<d:propstat><d:prop>");
+                    for prop in missing {
+                        out.push_str(&render_prop(prop, ""));
+                    }
+                    out.push_str("</d:prop><d:status>HTTP/1.1 404 Not Found</d:status></d:propstat>");
+                }
+            }
+            DavResponse::Missing { href } => {
+                out.push_str(&format!(
+                    "<d:href>{}</d:href><d:status>HTTP/1.1 404 Not Found</d:status>",
+                    xml::escape(href)
+                ));
+            }
+        }
+        out.push_str("</d:response>");
+    }
+    if let Some(sync_token) = sync_token {
+        out.push_str(&format!("<d:sync-token>{}</d:sync-token>", xml::escape(sync_token)));
+    }
+    out.push_str("</d:multistatus>");
+    out
+}
+
+// Project yang bisa dibaca user sebagai calendar collection.
+async fn find_calendar(conn: &DatabaseConnection, user_id: Uuid, project_id: Uuid) -> Result<DavCalendar, DavError> {
+    let (project, role) = sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectRead).await?;
+    Ok(DavCalendar { project, role })
+}
+
+// Token saat ini juga dipakai sebagai `getctag`: berubah tiap ada transaksi baru, sehingga client
+// yang melihat ctag berbeda cukup menjalankan `sync-collection` untuk mengambil selisihnya.
+async fn current_sync_token(conn: &DatabaseConnection) -> Result<String, DavError> {
+    Ok(sync_token_for(sync_repository::current_watermark(conn).await?))
+}
+
+// Body PUT harus berisi tepat satu `VTODO`; komponen lain tidak bisa disimpan.
+fn parse_resource(body: &str) -> Result<ImportedTodo, DavError> {
+    let roots = ics::parse_calendar(body).map_err(DavError::Validation)?;
+    let (vtodos, _) = calendar_service::collect_vtodos(&roots)?;
+    match vtodos.as_slice() {
+        [] => Err(DavError::UnsupportedComponent),
+        [vtodo] => calendar_service::map_vtodo(vtodo).map_err(DavError::Validation),
+        _ => Err(DavError::Validation(
+            "calendar resource must contain exactly one VTODO".to_string(),
+        )),
+    }
+}
+
+// `If-Match` harus cocok dengan resource yang ada; `If-None-Match` tidak boleh cocok.
+fn check_preconditions(existing: Option<&Todo>, preconditions: &WritePreconditions) -> Result<(), DavError> {
+    let matches = |header: &str| {
+        existing.is_some_and(|todo| {
+            let current = etag(todo);
+            header.trim() == "*" || header.split(',').any(|tag| tag.trim() == current)
+        })
+    };
+    if preconditions.if_match.as_deref().is_some_and(|header| !matches(header))
+        || preconditions.if_none_match.as_deref().is_some_and(matches)
+    {
+        return Err(DavError::PreconditionFailed);
+    }
+    Ok(())
+}
+
+fn todo_responses(project_id: Uuid, todos: &[Todo], request: &PropRequest) -> Vec<DavResponse> {
+    todos
+        .iter()
+        .map(|todo| propstat(resource_href(project_id, &ics::resource_uid(todo)), &Resource::Todo(todo), request))
+        .collect()
+}
+
+fn propstat(href: String, resource: &Resource, request: &PropRequest) -> DavResponse {
+    let requested = match request {
+        PropRequest::AllProp => default_props(resource),
+        PropRequest::Props(props) => props.clone(),
+    };
+    let mut found = Vec::new();
+    let mut missing = Vec::new();
+    for prop in requested {
+        match property_value(resource, &prop) {
+            Some(value) => found.push((prop, value)),
+            None => missing.push(prop),
+        }
+    }
+    DavResponse::Propstat { href, found, missing }
+}
+
+// Properti untuk `allprop`; `calendar-data` hanya dikirim bila diminta eksplisit.
+fn default_props(resource: &Resource) -> Vec<PropName> {
+    let names: &[(&str, &str)] = match resource {
+        Resource::Principal { .. } => &[
+            (DAV_NS, "resourcetype"),
+            (DAV_NS, "displayname"),
+            (DAV_NS, "current-user-principal"),
+            (DAV_NS, "principal-URL"),
+            (CALDAV_NS, "calendar-home-set"),
+        ],
+        Resource::Home => &[
+            (DAV_NS, "resourcetype"),
+            (DAV_NS, "displayname"),
+            (DAV_NS, "current-user-principal"),
+        ],
+        Resource::Calendar { .. } => &[
+            (DAV_NS, "resourcetype"),
+            (DAV_NS, "displayname"),
+            (CALSERVER_NS, "getctag"),
+            (DAV_NS, "sync-token"),
+            (CALDAV_NS, "supported-calendar-component-set"),
+            (DAV_NS, "current-user-privilege-set"),
+        ],
+        Resource::Todo(_) => &[
+            (DAV_NS, "resourcetype"),
+            (DAV_NS, "getetag"),
+            (DAV_NS, "getcontenttype"),
+            (DAV_NS, "getlastmodified"),
+        ],
+    };
+    names
+        .iter()
+        .map(|(namespace, name)| PropName::new(namespace, name))
+        .collect()
+}
+
+// Nilai properti dalam bentuk XML (prefix `d:`/`c:`/`cs:`); `None` bila tidak dikenal.
+fn property_value(resource: &Resource, prop: &PropName) -> Option<String> {
+    let value = match (prop.namespace.as_str(), prop.name.as_str(), resource) {
+        (DAV_NS, "current-user-principal", _) => href_value(&principal_href()),
+        (DAV_NS, "resourcetype", Resource::Principal { .. }) => "<d:collection/><d:principal/>".to_string(),
+        (DAV_NS, "resourcetype", Resource::Home) => "<d:collection/>".to_string(),
+        (DAV_NS, "resourcetype", Resource::Calendar { .. }) => "<d:collection/><c:calendar/>".to_string(),
+        (DAV_NS, "resourcetype", Resource::Todo(_)) => String::new(),
+        (DAV_NS, "displayname", Resource::Principal { email }) => xml::escape(email),
+        (DAV_NS, "displayname", Resource::Home) => "Calendars".to_string(),
+        (DAV_NS, "displayname", Resource::Calendar { calendar, .. }) => xml::escape(&calendar.project.name),
+        (DAV_NS, "principal-URL", Resource::Principal { .. }) => href_value(&principal_href()),
+        (CALDAV_NS, "calendar-home-set", Resource::Principal { .. }) => href_value(&home_href()),
+        (CALDAV_NS, "calendar-user-address-set", Resource::Principal { email }) => {
+            href_value(&format!("mailto:{email}"))
+        }
+        (CALDAV_NS, "supported-calendar-component-set", Resource::Calendar { .. }) => {
+            r#"<c:comp name="VTODO"/>"#.to_string()
+        }
+        (CALSERVER_NS, "getctag", Resource::Calendar { sync_token, .. })
+        | (DAV_NS, "sync-token", Resource::Calendar { sync_token, .. }) => xml::escape(sync_token),
+        (DAV_NS, "supported-report-set", Resource::Calendar { .. }) => [
+            "<c:calendar-query/>",
+            "<c:calendar-multiget/>",
+            "<d:sync-collection/>",
+        ]
+        .iter()
+        .map(|report| format!("<d:supported-report><d:report>{report}</d:report></d:supported-report>"))
+        .collect(),
+        (DAV_NS, "current-user-privilege-set", Resource::Calendar { calendar, .. }) => {
+            let privileges: &[&str] = if calendar.writable() {
+                &["read", "write", "write-content", "bind", "unbind"]
+            } else {
+                &["read"]
+            };
+            privileges
+                .iter()
+                .map(|privilege| format!("<d:privilege><d:{privilege}/></d:privilege>"))
+                .collect()
+        }
+        (DAV_NS, "getetag", Resource::Todo(todo)) => xml::escape(&etag(todo)),
+        (DAV_NS, "getcontenttype", Resource::Todo(_)) => RESOURCE_CONTENT_TYPE.to_string(),
+        (DAV_NS, "getlastmodified", Resource::Todo(todo)) => http_date(todo.updated_at),
+        (CALDAV_NS, "calendar-data", Resource::Todo(todo)) => xml::escape(&ics::render_resource(todo)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+// Elemen properti dengan prefix yang dideklarasikan di root; namespace lain dideklarasikan inline.
+fn render_prop(prop: &PropName, value: &str) -> String {
+    let prefix = match prop.namespace.as_str() {
+        DAV_NS => Some("d"),
+        CALDAV_NS => Some("c"),
+        CALSERVER_NS => Some("cs"),
+        _ => None,
+    };
+    let (open, close) = match prefix {
+        Some(prefix) => (format!("{prefix}:{}", prop.name), format!("{prefix}:{}", prop.name)),
+        None => (
+            format!(r#"{} xmlns="{}""#, prop.name, xml::escape(&prop.namespace)),
+            prop.name.clone(),
+        ),
+    };
+    if value.is_empty() {
+        format!("<{open}/>")
+    } else {
+        format!("<{open}>{value}</{close}>")
+    }
+}
+
+fn href_value(href: &str) -> String {
+    format!("<d:href>{}</d:href>", xml::escape(href))
+}
+
+fn principal_href() -> String {
+    format!("{DAV_ROOT}/")
+}
+
+fn home_href() -> String {
+    format!("{DAV_ROOT}/calendars/")
+}
+
+// Percent-encoding segmen path; karakter unreserved RFC 3986 dan `@` dibiarkan.
+fn percent_encode(raw: &str) -> String {
+    let mut encoded = String::with_capacity(raw.len());
+    for byte in raw.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'@') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn percent_decode(raw: &str) -> Option<String> {
+    let bytes = raw.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = raw.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+// Modul tests untuk sync token, href, dan render multistatus CalDAV.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_token_should_round_trip_issued_tokens_only() {
+        assert_eq!(parse_sync_token(&sync_token_for(1024)).ok(), Some(1024));
+        assert!(matches!(parse_sync_token("1024"), Err(DavError::InvalidSyncToken)));
+        assert!(matches!(parse_sync_token("urn:todolist:sync:-1"), Err(DavError::InvalidSyncToken)));
+        assert!(matches!(parse_sync_token("urn:todolist:sync:abc"), Err(DavError::InvalidSyncToken)));
+    }
+
+    #[test]
+    fn resource_href_should_round_trip_through_multiget_hrefs() {
+        let project_id = Uuid::new_v4();
+        let href = resource_href(project_id, "a b/c@example.com");
+        assert_eq!(
+            href,
+            format!("/api/caldav/calendars/{project_id}/a%20b%2Fc@example.com.ics")
+        );
+        assert_eq!(
+            resource_name_from_href(project_id, &href).as_deref(),
+            Some("a b/c@example.com")
+        );
+        assert_eq!(
+            resource_name_from_href(project_id, &format!("https://dav.example.com{href}")).as_deref(),
+            Some("a b/c@example.com")
+        );
+        assert_eq!(resource_name_from_href(Uuid::new_v4(), &href), None);
+        assert_eq!(resource_name_from_href(project_id, &calendar_href(project_id)), None);
+        assert_eq!(
+            resource_name_from_href(project_id, &format!("{}x/y.ics", calendar_href(project_id))),
+            None
+        );
+    }
+
+    #[test]
+    fn render_multistatus_should_split_found_and_missing_props() {
+        let responses = [
+            DavResponse::Propstat {
+                href: "/a&b.ics".to_string(),
+                found: vec![(PropName::new(DAV_NS, "getetag"), "\"1\"".to_string())],
+                missing: vec![PropName::new("urn:x", "color")],
+            },
+            DavResponse::Missing {
+                href: "/gone.ics".to_string(),
+            },
+        ];
+        let body = render_multistatus(&responses, Some("urn:todolist:sync:7"));
+        let parsed = xml::parse(&body).expect("multistatus must be well-formed");
+        assert!(parsed.is(DAV_NS, "multistatus"));
+        assert!(body.contains("<d:href>/a&amp;b.ics</d:href>"));
+        assert!(body.contains("<d:getetag>\"1\"</d:getetag></d:prop><d:status>HTTP/1.1 200 OK"));
+        assert!(body.contains(r#"<color xmlns="urn:x"/></d:prop><d:status>HTTP/1.1 404 Not Found"#));
+        assert!(body.contains("<d:href>/gone.ics</d:href><d:status>HTTP/1.1 404 Not Found</d:status>"));
+        assert!(body.ends_with("<d:sync-token>urn:todolist:sync:7</d:sync-token></d:multistatus>"));
+    }
+}
@@ -0,0 +1,305 @@
+//! Pembaca XML minimal untuk body request WebDAV/CalDAV beserta helper escape output.
+//!
+//! Hanya mendukung yang dipakai client CalDAV: elemen, atribut, namespace (`xmlns`/`xmlns:p`),
+//! teks, entity bawaan XML, CDATA, komentar, dan deklarasi `<?xml ...?>`. `DOCTYPE` ditolak
+//! agar tidak ada ekspansi entity.
+
+use std::collections::HashMap;
+
+/// Namespace WebDAV (RFC 4918).
+pub const DAV_NS: &str = "DAV:";
+/// Namespace CalDAV (RFC 4791).
+pub const CALDAV_NS: &str = "urn:ietf:params:xml:ns:caldav";
+/// Namespace ekstensi Apple Calendar Server (`getctag`).
+pub const CALSERVER_NS: &str = "http://calendarserver.org/ns/";
+
+/// Elemen XML dengan nama yang sudah di-resolve ke namespace-nya.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub namespace: String,
+    pub name: String,
+    /// Atribut selain deklarasi namespace, memakai nama lokal.
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    /// Gabungan teks langsung di dalam elemen (belum di-trim).
+    pub text: String,
+}
+
+impl Element {
+    /// `true` bila elemen bernama `name` di namespace `namespace`.
+    pub fn is(&self, namespace: &str, name: &str) -> bool {
+        self.namespace == namespace && self.name == name
+    }
+
+    /// Anak pertama dengan nama tersebut.
+    pub fn child(&self, namespace: &str, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.is(namespace, name))
+    }
+
+    /// Nilai atribut berdasarkan nama lokal.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Mem-parse dokumen XML menjadi elemen root.
+pub fn parse(input: &str) -> Result<Element, String> {
+    let mut stack: Vec<(Element, String, HashMap<String, String>)> = Vec::new();
+    let mut root = None;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            append_text(&mut stack, rest)?;
+            break;
+        };
+        append_text(&mut stack, &rest[..start])?;
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("<?") {
+            rest = skip_past(after, "?>")?;
+        } else if let Some(after) = rest.strip_prefix("<!--") {
+            rest = skip_past(after, "-->")?;
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").ok_or_else(|| "unterminated CDATA section".to_string())?;
+            match stack.last_mut() {
+                Some((element, _, _)) => element.text.push_str(&after[..end]),
+                None => return Err("text outside of root element".to_string()),
+            }
+            rest = &after[end + 3..];
+        } else if rest.starts_with("<!") {
+            return Err("DOCTYPE and declarations are not supported".to_string());
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').ok_or_else(|| "unterminated end tag".to_string())?;
+            let qname = after[..end].trim();
+            let (element, open_qname, _) = stack.pop().ok_or_else(|| format!("unexpected end tag </{qname}>"))?;
+            if open_qname != qname {
+                return Err(format!("expected </{open_qname}>, found </{qname}>"));
+            }
+            close_element(&mut stack, &mut root, element)?;
+            rest = &after[end + 1..];
+        } else {
+            let end = tag_end(rest).ok_or_else(|| "unterminated start tag".to_string())?;
+            let inner = &rest[1..end];
+            let (inner, self_closing) = match inner.strip_suffix('/') {
+                Some(inner) => (inner, true),
+                None => (inner, false),
+            };
+            let scope = stack.last().map(|(_, _, scope)| scope.clone()).unwrap_or_default();
+            let (element, qname, scope) = open_element(inner, scope)?;
+            if self_closing {
+                close_element(&mut stack, &mut root, element)?;
+            } else {
+                stack.push((element, qname, scope));
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    if let Some((_, qname, _)) = stack.last() {
+        return Err(format!("missing end tag </{qname}>"));
+    }
+    root.ok_or_else(|| "document has no root element".to_string())
+}
+
+/// Escape teks/atribut untuk output XML.
+pub fn escape(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+// Teks di luar root hanya boleh whitespace.
+fn append_text(stack: &mut [(Element, String, HashMap<String, String>)], raw: &str) -> Result<(), String> {
+    match stack.last_mut() {
+        Some((element, _, _)) => {
+            element.text.push_str(&decode_entities(raw)?);
+            Ok(())
+        }
+        None if raw.trim().is_empty() => Ok(()),
+        None => Err("text outside of root element".to_string()),
+    }
+}
+
+fn skip_past<'a>(input: &'a str, marker: &str) -> Result<&'a str, String> {
+    let end = input.find(marker).ok_or_else(|| format!("missing {marker}"))?;
+    Ok(&input[end + marker.len()..])
+}
+
+// Posisi `>` penutup tag, mengabaikan `>` di dalam nilai atribut.
+fn tag_end(input: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, ch) in input.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if open == ch => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+// Membaca nama dan atribut start tag, lalu me-resolve namespace dengan scope induk.
+fn open_element(
+    inner: &str,
+    mut scope: HashMap<String, String>,
+) -> Result<(Element, String, HashMap<String, String>), String> {
+    let inner = inner.trim();
+    let name_end = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let qname = inner[..name_end].to_string();
+    if qname.is_empty() {
+        return Err("missing element name".to_string());
+    }
+
+    let mut raw_attributes = Vec::new();
+    let mut rest = inner[name_end..].trim_start();
+    while !rest.is_empty() {
+        let eq = rest.find('=').ok_or_else(|| format!("attribute without value in <{qname}>"))?;
+        let key = rest[..eq].trim().to_string();
+        let value_part = rest[eq + 1..].trim_start();
+        let quote = value_part
+            .chars()
+            .next()
+            .filter(|ch| *ch == '"' || *ch == '\'')
+            .ok_or_else(|| format!("attribute {key} must be quoted"))?;
+        let close = value_part[1..]
+            .find(quote)
+            .ok_or_else(|| format!("unterminated attribute {key}"))?;
+        let value = decode_entities(&value_part[1..1 + close])?;
+        rest = value_part[close + 2..].trim_start();
+        if key == "xmlns" {
+            scope.insert(String::new(), value);
+        } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+            scope.insert(prefix.to_string(), value);
+        } else {
+            raw_attributes.push((key, value));
+        }
+    }
+
+    let (prefix, local) = match qname.split_once(':') {
+        Some((prefix, local)) => (prefix, local),
+        None => ("", qname.as_str()),
+    };
+    let namespace = match scope.get(prefix) {
+        Some(namespace) => namespace.clone(),
+        None if prefix.is_empty() => String::new(),
+        None => return Err(format!("undeclared namespace prefix {prefix}")),
+    };
+    let attributes = raw_attributes
+        .into_iter()
+        .map(|(key, value)| match key.split_once(':') {
+            Some((_, local)) => (local.to_string(), value),
+            None => (key, value),
+        })
+        .collect();
+    let element = Element {
+        namespace,
+        name: local.to_string(),
+        attributes,
+        ..Element::default()
+    };
+    Ok((element, qname, scope))
+}
+
+fn close_element(
+    stack: &mut [(Element, String, HashMap<String, String>)],
+    root: &mut Option<Element>,
+    element: Element,
+) -> Result<(), String> {
+    match stack.last_mut() {
+        Some((parent, _, _)) => parent.children.push(element),
+        None if root.is_none() => *root = Some(element),
+        None => return Err("document has more than one root element".to_string()),
+    }
+    Ok(())
+}
+
+fn decode_entities(raw: &str) -> Result<String, String> {
+    if !raw.contains('&') {
+        return Ok(raw.to_string());
+    }
+    let mut decoded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| "unterminated entity".to_string())?;
+        let entity = &rest[start + 1..start + end];
+        let ch = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+                    .ok_or_else(|| format!("unknown entity &{entity};"))?
+            }
+        };
+        decoded.push(ch);
+        rest = &rest[start + end + 1..];
+    }
+    decoded.push_str(rest);
+    Ok(decoded)
+}
+
+// Modul tests untuk pembaca XML.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_should_resolve_namespaces_and_text() {
+        let doc = parse(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <!-- propfind -->
+            <d:propfind xmlns:d="DAV:" xmlns="urn:ietf:params:xml:ns:caldav">
+              <d:prop><d:getetag/><calendar-data/></d:prop>
+              <d:href>/a&amp;b</d:href>
+              <comp-filter name='VTODO' test="a>b"><![CDATA[<raw>]]></comp-filter>
+            </d:propfind>"#,
+        )
+        .expect("document must parse");
+
+        assert!(doc.is(DAV_NS, "propfind"));
+        let prop = doc.child(DAV_NS, "prop").expect("prop");
+        assert!(prop.children[0].is(DAV_NS, "getetag"));
+        assert!(prop.children[1].is(CALDAV_NS, "calendar-data"));
+        assert_eq!(doc.child(DAV_NS, "href").map(|href| href.text.as_str()), Some("/a&b"));
+        let filter = doc.child(CALDAV_NS, "comp-filter").expect("comp-filter");
+        assert_eq!(filter.attribute("name"), Some("VTODO"));
+        assert_eq!(filter.attribute("test"), Some("a>b"));
+        assert_eq!(filter.text, "<raw>");
+    }
+
+    #[test]
+    fn parse_should_reject_malformed_documents() {
+        assert!(parse("<a><b></a>").is_err());
+        assert!(parse("<a>").is_err());
+        assert!(parse("<x:a/>").is_err());
+        assert!(parse("<a/><b/>").is_err());
+        assert!(parse("<!DOCTYPE a [<!ENTITY x \"y\">]><a>&x;</a>").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn escape_should_escape_markup() {
+        assert_eq!(escape("<a href=\"x\">&'</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&apos;&lt;/a&gt;");
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity;
+
+/// Payload pembuatan feed langganan kalender.
+#[derive(Debug, Deserialize)]
+pub struct CreateFeedRequest {
+    /// Nama feed yang tampil di aplikasi kalender (`X-WR-CALNAME`).
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Filter project opsional; project harus berada di workspace aktif.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+}
+
+/// Query `POST /import/ics`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportQuery {
+    /// Project tujuan todo baru; todo yang sudah ada tetap di project-nya.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+}
+
+/// Hasil pemetaan satu `VTODO` ke field todo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTodo {
+    pub uid: Option<String>,
+    pub title: String,
+    /// `None` bila `VTODO` tanpa `DESCRIPTION`; deskripsi todo lama tidak diubah.
+    pub desc: Option<String>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub completed: bool,
+    /// Nilai `COMPLETED` bila ada; todo baru tanpa nilai ini memakai waktu impor.
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    /// Properti/komponen yang diabaikan atau dibaca dengan asumsi.
+    pub warnings: Vec<String>,
+}
+
+/// Nasib satu `VTODO` dalam impor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    Created,
+    Updated,
+    /// UID sudah pernah diimpor dan isinya sama; tidak ada perubahan ditulis.
+    Unchanged,
+    Skipped,
+}
+
+/// Hasil per `VTODO`, urut sesuai posisi di file.
+#[derive(Debug, Serialize)]
+pub struct ImportItemResult {
+    /// Posisi `VTODO` di file, mulai dari 0.
+    pub index: usize,
+    pub uid: Option<String>,
+    pub status: ImportStatus,
+    pub todo_id: Option<Uuid>,
+    pub warnings: Vec<String>,
+    /// Alasan `skipped`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Ringkasan impor iCalendar.
+#[derive(Debug, Serialize)]
+pub struct ImportResponse {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    /// Peringatan tingkat file, mis. komponen selain `VTODO` yang diabaikan.
+    pub warnings: Vec<String>,
+    pub items: Vec<ImportItemResult>,
+}
+
+impl ImportResponse {
+    /// Menyusun ringkasan dari hasil per item.
+    pub fn new(warnings: Vec<String>, items: Vec<ImportItemResult>) -> Self {
+        let count = |status: ImportStatus| items.iter().filter(|item| item.status == status).count();
+        Self {
+            created: count(ImportStatus::Created),
+            updated: count(ImportStatus::Updated),
+            unchanged: count(ImportStatus::Unchanged),
+            skipped: count(ImportStatus::Skipped),
+            warnings,
+            items,
+        }
+    }
+}
+
+/// Feed baru beserta token aslinya (hanya tersedia saat dibuat).
+#[derive(Debug)]
+pub struct IssuedFeed {
+    pub feed: entity::Model,
+    pub token: String,
+}
+
+/// Bentuk data feed kalender yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct FeedResponse {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    /// Hanya dikirim sekali pada response pembuatan feed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Path langganan relatif terhadap host API; hanya dikirim bersama `token`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<IssuedFeed> for FeedResponse {
+    /// Response pembuatan feed, satu-satunya yang menyertakan token.
+    fn from(value: IssuedFeed) -> Self {
+        Self {
+            url: Some(format!("/api/calendar/subscribe/{}.ics", value.token)),
+            token: Some(value.token),
+            ..Self::from(value.feed)
+        }
+    }
+}
+
+impl From<entity::Model> for FeedResponse {
+    /// Mapping model database ke response HTTP tanpa token.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            workspace_id: value.workspace_id,
+            project_id: value.project_id,
+            token: None,
+            url: None,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
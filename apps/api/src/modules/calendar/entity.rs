@@ -0,0 +1,29 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `calendar_feeds`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "calendar_feeds")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Pemilik feed; isi feed mengikuti akses user ini saat feed dibaca.
+    pub user_id: Uuid,
+    /// Workspace yang diekspor; `None` untuk ruang pribadi.
+    pub workspace_id: Option<Uuid>,
+    /// Filter project opsional.
+    pub project_id: Option<Uuid>,
+    pub name: Option<String>,
+    /// Hash SHA-256 (hex) token; token asli hanya dikembalikan saat feed dibuat.
+    pub token_hash: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    /// Feed yang dicabut tidak bisa dibaca lagi.
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,489 @@
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Path, Query, State, multipart::MultipartError},
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::todo::dto::TodoQuery;
+use crate::modules::todo::service as todo_service;
+
+use super::dto::{CreateFeedRequest, FeedResponse, ImportQuery, ImportResponse};
+use super::ics;
+use super::service::{self, CalendarError};
+
+/// Toleransi ukuran header/boundary multipart di atas batas ukuran file impor.
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// Route tanpa prefix resource:
+/// - `GET /todos.ics` ekspor todo workspace aktif ke iCalendar
+/// - `POST /import/ics` impor `VTODO` dari file `.ics` (multipart, field `file`)
+pub fn user_routes() -> Router<AppState> {
+    Router::new()
+        .route("/todos.ics", get(export_todos))
+        .route(
+            "/import/ics",
+            post(import_ics).layer(DefaultBodyLimit::max(service::MAX_IMPORT_BYTES + MULTIPART_OVERHEAD_BYTES)),
+        )
+}
+
+/// Registrasi route feed kalender, di-mount di bawah prefix `/calendar`.
+///
+/// - `POST /feeds` buat URL langganan (token hanya dikembalikan di sini)
+/// - `GET /feeds` daftar feed aktif milik user saat ini
+/// - `DELETE /feeds/{id}` cabut feed
+/// - `GET /subscribe/{token}.ics` isi feed; cukup token, tanpa header user
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/feeds", post(create_feed).get(list_feeds))
+        .route("/feeds/{id}", delete(revoke_feed))
+        .route("/subscribe/{file}", get(read_feed))
+}
+
+/// GET `/todos.ics`
+/// Query: `project_id` dan `assignee` opsional seperti `GET /todos`.
+/// Output: `200 OK` `text/calendar` berisi satu `VTODO` per todo.
+pub async fn export_todos(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<TodoQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let filter = todo_service::parse_filter(&query, user.id, user.workspace_id)
+        .map_err(|err| map_calendar_error(err.into()))?;
+    let body = service::export_calendar(&state.db, user.id, filter)
+        .await
+        .map_err(map_calendar_error)?;
+    info!(user_id = %user.id, "todos exported to icalendar");
+    Ok(calendar_response(body))
+}
+
+/// POST `/import/ics`
+///
+/// Input: `multipart/form-data` dengan field `file` (UTF-8), query `project_id` opsional.
+/// Output: `200 OK` + ringkasan dan hasil per `VTODO`; `400` bila file tidak bisa di-parse.
+pub async fn import_ics(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<ImportQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<SuccessResponse<ImportResponse>>, ApiError> {
+    let invalid_multipart = |err: MultipartError| {
+        if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            import_too_large()
+        } else {
+            ApiError::bad_request(format!("invalid multipart body: {err}"))
+        }
+    };
+    let field = loop {
+        match multipart.next_field().await.map_err(invalid_multipart)? {
+            Some(field) if field.name() == Some("file") => break field,
+            Some(_) => continue,
+            None => return Err(ApiError::bad_request("multipart field file is required")),
+        }
+    };
+    let bytes = field.bytes().await.map_err(invalid_multipart)?;
+    if bytes.len() > service::MAX_IMPORT_BYTES {
+        return Err(import_too_large());
+    }
+    let input = std::str::from_utf8(&bytes).map_err(|_| ApiError::bad_request("file must be UTF-8 text"))?;
+
+    let audit = AuditContext::new(user.id, &state.history);
+    let result = service::import_calendar(
+        &state.db,
+        user.id,
+        user.workspace_id,
+        query.project_id,
+        input,
+        &audit,
+        &state.dependencies,
+    )
+    .await
+    .map_err(map_calendar_error)?;
+    info!(
+        created = result.created,
+        updated = result.updated,
+        unchanged = result.unchanged,
+        skipped = result.skipped,
+        "icalendar imported"
+    );
+    Ok(Json(SuccessResponse { data: result }))
+}
+
+/// POST `/calendar/feeds`
+///
+/// Input: `CreateFeedRequest`.
+/// Output: `201 Created` + `SuccessResponse<FeedResponse>` beserta `token` dan `url`.
+pub async fn create_feed(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateFeedRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<FeedResponse>>), ApiError> {
+    let issued = service::create_feed(&state.db, user.id, user.workspace_id, payload)
+        .await
+        .map_err(map_calendar_error)?;
+    info!(feed_id = %issued.feed.id, "calendar feed created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: issued.into() })))
+}
+
+/// GET `/calendar/feeds`
+/// Output: `200 OK` + daftar feed aktif milik user saat ini (tanpa token).
+pub async fn list_feeds(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<FeedResponse>>>, ApiError> {
+    let feeds = service::list_feeds(&state.db, user.id)
+        .await
+        .map_err(map_calendar_error)?;
+    info!(count = feeds.len(), "calendar feeds listed");
+    Ok(Json(SuccessResponse {
+        data: feeds.into_iter().map(FeedResponse::from).collect(),
+    }))
+}
+
+/// DELETE `/calendar/feeds/{id}`
+/// Output: `204 No Content`, `404` bila feed tidak ada atau sudah dicabut.
+pub async fn revoke_feed(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(feed_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    service::revoke_feed(&state.db, user.id, feed_id)
+        .await
+        .map_err(map_calendar_error)?;
+    info!(feed_id = %feed_id, "calendar feed revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET `/calendar/subscribe/{token}.ics`
+/// Akhiran `.ics` opsional; sebagian aplikasi kalender mensyaratkannya.
+/// Output: `200 OK` `text/calendar`, `404` bila token tidak dikenal atau sudah dicabut.
+pub async fn read_feed(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let token = file.strip_suffix(".ics").unwrap_or(&file);
+    let (feed, body) = service::render_feed(&state.db, token)
+        .await
+        .map_err(map_calendar_error)?;
+    info!(feed_id = %feed.id, "calendar feed served");
+    Ok(calendar_response(body))
+}
+
+// Batas body multipart sedikit di atas batas file, jadi ukuran file tetap dicek setelah dibaca.
+fn import_too_large() -> ApiError {
+    ApiError::payload_too_large(format!(
+        "file must be at most {} bytes",
+        service::MAX_IMPORT_BYTES
+    ))
+}
+
+// Header response iCalendar; aplikasi kalender diminta selalu memvalidasi ulang.
+fn calendar_response(body: String) -> impl IntoResponse {
+    (
+        [
+            (header::CONTENT_TYPE, ics::CONTENT_TYPE),
+            (header::CONTENT_DISPOSITION, "inline; filename=\"todos.ics\""),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        body,
+    )
+}
+
+/// Mapping error domain kalender ke `ApiError` agar response error konsisten.
+fn map_calendar_error(err: CalendarError) -> ApiError {
+    match err {
+        CalendarError::Validation(message) => {
+            warn!(reason = %message, "calendar validation failed");
+            ApiError::bad_request(message)
+        }
+        CalendarError::NotFound => {
+            warn!("calendar feed not found");
+            ApiError::not_found("calendar feed not found")
+        }
+        CalendarError::Forbidden => {
+            warn!("calendar action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this project")
+        }
+        CalendarError::Database(db_err) => {
+            error!(error = %db_err, "calendar database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests untuk integrasi end-to-end ekspor dan feed kalender.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::common::current_user::{USER_ID_HEADER, WORKSPACE_ID_HEADER};
+    use crate::modules::project::handler as project_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+    use crate::modules::workspace::handler as workspace_handler;
+
+    use super::*;
+
+    // User acak per test agar isi kalender tidak tercampur data test lain.
+    async fn build_test_app() -> (Router, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let app = Router::new()
+            .merge(user_routes())
+            .nest("/calendar", routes())
+            .nest("/todos", todo_handler::routes())
+            .nest("/projects", project_handler::routes())
+            .nest("/workspaces", workspace_handler::routes())
+            .with_state(AppState::for_tests(db, user_id));
+        (app, user_id)
+    }
+
+    async fn send(app: &Router, as_user: Option<Uuid>, method: &str, uri: String, body: Option<Value>) -> (StatusCode, String) {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(user_id) = as_user {
+            builder = builder.header(USER_ID_HEADER, user_id.to_string());
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        (status, String::from_utf8(bytes.to_vec()).expect("body must be utf-8"))
+    }
+
+    async fn import(app: &Router, user_id: Uuid, workspace_id: Option<&str>, ics: &str) -> (StatusCode, Value) {
+        const BOUNDARY: &str = "ics-boundary";
+        let body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"todos.ics\"\r\n\
+             Content-Type: text/calendar\r\n\r\n{ics}\r\n--{BOUNDARY}--\r\n"
+        );
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/import/ics")
+            .header(USER_ID_HEADER, user_id.to_string())
+            .header("content-type", format!("multipart/form-data; boundary={BOUNDARY}"));
+        if let Some(workspace_id) = workspace_id {
+            builder = builder.header(WORKSPACE_ID_HEADER, workspace_id);
+        }
+        let request = builder.body(Body::from(body)).expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        (status, serde_json::from_slice(&bytes).expect("body must be valid json"))
+    }
+
+    fn json_body(raw: &str) -> Value {
+        serde_json::from_str(raw).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn calendar_export_and_feed_should_honour_filters_and_revocation() {
+        let (app, user_id) = build_test_app().await;
+        let (_, project) = send(&app, Some(user_id), "POST", "/projects".to_string(), Some(json!({ "name": "Kalender" }))).await;
+        let project_id = json_body(&project)["data"]["id"].as_str().expect("project id").to_string();
+        let (status, _) = send(
+            &app,
+            Some(user_id),
+            "POST",
+            "/todos".to_string(),
+            Some(json!({ "title": "Bayar listrik", "project_id": project_id, "due_at": "2026-03-01T09:00:00+07:00" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        send(&app, Some(user_id), "POST", "/todos".to_string(), Some(json!({ "title": "Di luar project" }))).await;
+
+        let (status, ics) = send(&app, Some(user_id), "GET", "/todos.ics".to_string(), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(ics.contains("DUE:20260301T020000Z\r\n"));
+
+        let (status, body) = send(
+            &app,
+            Some(user_id),
+            "POST",
+            "/calendar/feeds".to_string(),
+            Some(json!({ "name": "Tagihan", "project_id": project_id })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let feed = json_body(&body)["data"].clone();
+        let url = feed["url"].as_str().expect("feed url").trim_start_matches("/api").to_string();
+
+        // Feed dibaca tanpa header user dan hanya memuat todo di project filter.
+        let (status, ics) = send(&app, None, "GET", url.clone(), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(ics.contains("X-WR-CALNAME:Tagihan\r\n"));
+        assert!(ics.contains("SUMMARY:Bayar listrik\r\n"));
+        assert!(!ics.contains("Di luar project"));
+
+        let (_, body) = send(&app, Some(user_id), "GET", "/calendar/feeds".to_string(), None).await;
+        let listed = json_body(&body)["data"].clone();
+        assert_eq!(listed.as_array().map(Vec::len), Some(1));
+        assert!(listed[0].get("token").is_none());
+        assert!(!listed[0]["last_used_at"].is_null());
+
+        let feed_id = feed["id"].as_str().expect("feed id");
+        let (status, _) = send(&app, Some(user_id), "DELETE", format!("/calendar/feeds/{feed_id}"), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, None, "GET", url, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, None, "GET", "/calendar/subscribe/unknown.ics".to_string(), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn revoked_feed_token_should_return_404_after_serving_200() {
+        let (app, user_id) = build_test_app().await;
+        send(&app, Some(user_id), "POST", "/todos".to_string(), Some(json!({ "title": "Perpanjang domain" }))).await;
+        let mut urls = Vec::new();
+        let mut ids = Vec::new();
+        for name in ["Ponsel", "Laptop"] {
+            let (status, body) = send(
+                &app,
+                Some(user_id),
+                "POST",
+                "/calendar/feeds".to_string(),
+                Some(json!({ "name": name })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::CREATED);
+            let feed = json_body(&body)["data"].clone();
+            urls.push(feed["url"].as_str().expect("feed url").trim_start_matches("/api").to_string());
+            ids.push(feed["id"].as_str().expect("feed id").to_string());
+        }
+        for url in &urls {
+            let (status, ics) = send(&app, None, "GET", url.clone(), None).await;
+            assert_eq!(status, StatusCode::OK);
+            assert!(ics.contains("SUMMARY:Perpanjang domain\r\n"));
+        }
+
+        // User lain tidak bisa mencabut feed yang bukan miliknya.
+        let stranger = Uuid::new_v4();
+        let db = Database::connect(env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests"))
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, stranger)
+            .await
+            .expect("user seed must succeed");
+        let (status, _) = send(&app, Some(stranger), "DELETE", format!("/calendar/feeds/{}", ids[0]), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, None, "GET", urls[0].clone(), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send(&app, Some(user_id), "DELETE", format!("/calendar/feeds/{}", ids[0]), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        for _ in 0..2 {
+            let (status, body) = send(&app, None, "GET", urls[0].clone(), None).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert!(!body.contains("BEGIN:VCALENDAR"));
+        }
+        let (status, _) = send(&app, None, "GET", urls[1].clone(), None).await;
+        assert_eq!(status, StatusCode::OK, "revoking one feed must not affect another");
+        let (status, _) = send(&app, Some(user_id), "DELETE", format!("/calendar/feeds/{}", ids[0]), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn import_ics_should_create_then_update_by_uid_without_duplicates() {
+        let (app, user_id) = build_test_app().await;
+        let uid = format!("{}@example.com", Uuid::new_v4());
+        let file = |status: &str| {
+            format!(
+                "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:{uid}\r\nSUMMARY:Bayar listrik\r\n\
+                 DUE:20260301T020000Z\r\nSTATUS:{status}\r\nPRIORITY:1\r\nEND:VTODO\r\n\
+                 BEGIN:VTODO\r\nDESCRIPTION:tanpa judul\r\nEND:VTODO\r\n\
+                 BEGIN:VEVENT\r\nSUMMARY:Rapat\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n"
+            )
+        };
+
+        let (status, body) = import(&app, user_id, None, &file("NEEDS-ACTION")).await;
+        assert_eq!(status, StatusCode::OK);
+        let data = &body["data"];
+        assert_eq!((data["created"].as_u64(), data["skipped"].as_u64()), (Some(1), Some(1)));
+        assert_eq!(data["warnings"][0], "1 VEVENT component(s) ignored");
+        assert_eq!(data["items"][0]["warnings"][0], "unsupported properties ignored: PRIORITY");
+        assert_eq!(data["items"][1]["error"], "SUMMARY is required");
+        let todo_id = data["items"][0]["todo_id"].clone();
+
+        let (_, body) = import(&app, user_id, None, &file("NEEDS-ACTION")).await;
+        assert_eq!(body["data"]["items"][0]["status"], "unchanged");
+        let (_, body) = import(&app, user_id, None, &file("COMPLETED")).await;
+        assert_eq!(body["data"]["items"][0]["status"], "updated");
+        assert_eq!(body["data"]["items"][0]["todo_id"], todo_id);
+
+        let (_, body) = send(&app, Some(user_id), "GET", "/todos".to_string(), None).await;
+        let todos = json_body(&body)["data"].clone();
+        assert_eq!(todos.as_array().map(Vec::len), Some(1));
+        assert_eq!(todos[0]["iscompleted"], true);
+        assert_eq!(todos[0]["due_at"], "2026-03-01T02:00:00Z");
+
+        let (status, _) = import(&app, user_id, None, "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\n").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn import_ics_should_dedupe_uid_per_workspace() {
+        let (app, user_id) = build_test_app().await;
+        let uid = format!("{}@example.com", Uuid::new_v4());
+        let file = format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:{uid}\r\nSUMMARY:Laporan bulanan\r\n\
+             END:VTODO\r\nEND:VCALENDAR\r\n"
+        );
+        let mut workspace_ids = Vec::new();
+        for name in ["Kantor", "Rumah"] {
+            let (status, body) =
+                send(&app, Some(user_id), "POST", "/workspaces".to_string(), Some(json!({ "name": name }))).await;
+            assert_eq!(status, StatusCode::CREATED);
+            workspace_ids.push(json_body(&body)["data"]["id"].as_str().expect("workspace id").to_string());
+        }
+
+        let mut todo_ids = Vec::new();
+        for workspace_id in [None, Some(workspace_ids[0].as_str()), Some(workspace_ids[1].as_str())] {
+            let (status, body) = import(&app, user_id, workspace_id, &file).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["data"]["items"][0]["status"], "created");
+            todo_ids.push(body["data"]["items"][0]["todo_id"].clone());
+
+            let (_, body) = import(&app, user_id, workspace_id, &file).await;
+            assert_eq!(body["data"]["items"][0]["status"], "unchanged");
+            assert_eq!(body["data"]["items"][0]["todo_id"], todo_ids[todo_ids.len() - 1]);
+        }
+        todo_ids.dedup();
+        assert_eq!(todo_ids.len(), 3);
+    }
+}
@@ -0,0 +1,414 @@
+//! Penulis dan parser iCalendar (RFC 5545) untuk todo dalam bentuk komponen `VTODO`.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+use crate::modules::todo::entity::Model as Todo;
+
+/// Identitas produk pada properti `PRODID`.
+pub const PRODID: &str = "-//Todolist//Todo API//EN";
+/// Content type response iCalendar.
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+/// Batas panjang satu baris konten sebelum dilipat, dalam octet.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Membentuk satu `VCALENDAR` berisi `VTODO` untuk setiap todo.
+/// `name` opsional diisi ke `X-WR-CALNAME` agar aplikasi kalender menampilkan nama yang ramah.
+pub fn render_calendar(name: Option<&str>, todos: &[Todo]) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    if let Some(name) = name {
+        push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(name)));
+    }
+    for todo in todos {
+        for line in vtodo_lines(todo) {
+            push_line(&mut out, &line);
+        }
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Satu calendar object resource CalDAV: `VCALENDAR` berisi satu `VTODO`, tanpa `METHOD`
+/// (RFC 4791 melarang `METHOD` di resource yang disimpan server).
+pub fn render_resource(todo: &Todo) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    for line in vtodo_lines(todo) {
+        push_line(&mut out, &line);
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Baris konten (belum dilipat) satu komponen `VTODO`.
+///
+/// `UID` memakai UID asal untuk todo hasil impor/CalDAV, selain itu id todo.
+/// `DTSTAMP` memakai `updated_at` agar output stabil untuk data yang sama.
+/// Todo belum punya prioritas maupun pengulangan, jadi `PRIORITY` dan `RRULE` tidak pernah ditulis.
+pub fn vtodo_lines(todo: &Todo) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", escape_text(&resource_uid(todo))),
+        format!("DTSTAMP:{}", format_utc(todo.updated_at)),
+        format!("CREATED:{}", format_utc(todo.created_at)),
+        format!("LAST-MODIFIED:{}", format_utc(todo.updated_at)),
+        format!("SUMMARY:{}", escape_text(&todo.title)),
+    ];
+    if let Some(desc) = todo.desc.as_deref().filter(|desc| !desc.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape_text(desc)));
+    }
+    if let Some(due_at) = todo.due_at {
+        lines.push(format!("DUE:{}", format_utc(due_at)));
+    }
+    if todo.iscompleted {
+        lines.push("STATUS:COMPLETED".to_string());
+        lines.push("PERCENT-COMPLETE:100".to_string());
+        if let Some(completed_at) = todo.completed_at {
+            lines.push(format!("COMPLETED:{}", format_utc(completed_at)));
+        }
+    } else {
+        lines.push("STATUS:NEEDS-ACTION".to_string());
+    }
+    lines.push("END:VTODO".to_string());
+    lines
+}
+
+/// UID iCalendar todo: UID eksternal bila ada, selain itu id todo.
+pub fn resource_uid(todo: &Todo) -> String {
+    todo.external_uid.clone().unwrap_or_else(|| todo.id.to_string())
+}
+
+/// Escape nilai bertipe TEXT: backslash, titik koma, koma, dan baris baru.
+pub fn escape_text(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Format DATE-TIME UTC, mis. `20260218T120000Z`.
+pub fn format_utc(at: DateTime<FixedOffset>) -> String {
+    at.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Satu properti (content line) hasil parse: nama, parameter, dan nilai mentah (belum di-unescape).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    /// Nama properti dalam huruf besar, mis. `DUE`.
+    pub name: String,
+    /// Parameter `NAMA=nilai`; nama huruf besar, tanda kutip nilai sudah dilepas.
+    pub params: Vec<(String, String)>,
+    pub value: String,
+}
+
+impl Property {
+    /// Nilai parameter pertama dengan nama tersebut.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Komponen iCalendar (`VCALENDAR`, `VTODO`, `VALARM`, ...) beserta properti dan sub-komponennya.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Component {
+    /// Nama komponen dalam huruf besar.
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    /// Properti pertama dengan nama tersebut.
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|property| property.name == name)
+    }
+}
+
+/// Mem-parse isi file `.ics` menjadi daftar komponen tingkat atas (biasanya satu `VCALENDAR`).
+/// Baris terlipat digabung lebih dulu; CRLF maupun LF diterima.
+/// Error berisi nomor baris agar mudah ditelusuri pengguna.
+pub fn parse_calendar(input: &str) -> Result<Vec<Component>, String> {
+    let mut roots = Vec::new();
+    let mut stack: Vec<Component> = Vec::new();
+    for (line_no, line) in unfold_lines(input) {
+        let property = parse_property(&line).map_err(|reason| format!("line {line_no}: {reason}"))?;
+        match property.name.as_str() {
+            "BEGIN" => stack.push(Component {
+                name: property.value.trim().to_ascii_uppercase(),
+                ..Component::default()
+            }),
+            "END" => {
+                let name = property.value.trim().to_ascii_uppercase();
+                let component = match stack.pop() {
+                    Some(component) if component.name == name => component,
+                    Some(component) => {
+                        return Err(format!("line {line_no}: expected END:{}, found END:{name}", component.name));
+                    }
+                    None => return Err(format!("line {line_no}: END:{name} without matching BEGIN")),
+                };
+                match stack.last_mut() {
+                    Some(parent) => parent.components.push(component),
+                    None => roots.push(component),
+                }
+            }
+            _ => match stack.last_mut() {
+                Some(component) => component.properties.push(property),
+                None => return Err(format!("line {line_no}: property {} outside of a component", property.name)),
+            },
+        }
+    }
+    if let Some(component) = stack.last() {
+        return Err(format!("missing END:{}", component.name));
+    }
+    if roots.is_empty() {
+        return Err("file does not contain any calendar component".to_string());
+    }
+    Ok(roots)
+}
+
+/// Kebalikan `escape_text`: `\n`/`\N` menjadi baris baru, `\;` `\,` `\\` menjadi karakter aslinya.
+pub fn unescape_text(raw: &str) -> String {
+    let mut unescaped = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Nilai DATE atau DATE-TIME sebuah properti.
+///
+/// - `20260301T020000Z` dibaca sebagai UTC.
+/// - `20260301` (atau `VALUE=DATE`) dibaca sebagai pukul 00:00 UTC.
+/// - waktu lokal (`TZID=...` atau tanpa zona) dibaca sebagai UTC karena server tidak memuat
+///   database zona waktu; kasus ini mengembalikan catatan peringatan di elemen kedua.
+pub fn parse_date_time(property: &Property) -> Result<(DateTime<FixedOffset>, Option<String>), String> {
+    let value = property.value.trim();
+    let invalid = || format!("{} has invalid date-time value {value:?}", property.name);
+    if property.param("VALUE") == Some("DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| invalid())?;
+        let at = date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?;
+        return Ok((at.and_utc().fixed_offset(), None));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let at = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+        return Ok((at.and_utc().fixed_offset(), None));
+    }
+    let at = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+    let note = match property.param("TZID") {
+        Some(tzid) => format!("{} TZID {tzid} is not supported; time was read as UTC", property.name),
+        None => format!("{} has no time zone; time was read as UTC", property.name),
+    };
+    Ok((at.and_utc().fixed_offset(), Some(note)))
+}
+
+// Menggabungkan baris terlipat (diawali spasi/tab) dan membuang baris kosong.
+// Mengembalikan nomor baris fisik awal setiap baris logis.
+fn unfold_lines(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, raw) in input.split('\n').enumerate() {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(continuation) = raw.strip_prefix([' ', '\t'])
+            && let Some((_, last)) = lines.last_mut()
+        {
+            last.push_str(continuation);
+            continue;
+        }
+        if !raw.trim().is_empty() {
+            lines.push((index + 1, raw.to_string()));
+        }
+    }
+    lines
+}
+
+// Memecah satu baris logis `NAMA;PARAM=nilai:VALUE`; `:` dan `;` di dalam tanda kutip diabaikan.
+fn parse_property(line: &str) -> Result<Property, String> {
+    let mut in_quotes = false;
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut value_start = None;
+    for (index, ch) in line.char_indices() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                segments.push(&line[start..index]);
+                start = index + 1;
+            }
+            ':' if !in_quotes => {
+                segments.push(&line[start..index]);
+                value_start = Some(index + 1);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let value_start = value_start.ok_or_else(|| "missing ':' between name and value".to_string())?;
+    let mut segments = segments.into_iter();
+    let name = segments.next().unwrap_or_default().trim().to_ascii_uppercase();
+    if name.is_empty() {
+        return Err("missing property name".to_string());
+    }
+    let params = segments
+        .map(|param| {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("parameter {param:?} must be NAME=value"))?;
+            Ok((key.trim().to_ascii_uppercase(), value.trim_matches('"').to_string()))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(Property {
+        name,
+        params,
+        value: line[value_start..].to_string(),
+    })
+}
+
+// Menulis satu baris konten dengan CRLF, dilipat per 75 octet tanpa memotong karakter UTF-8.
+fn push_line(out: &mut String, line: &str) {
+    let mut budget = MAX_LINE_OCTETS;
+    let mut used = 0;
+    for ch in line.chars() {
+        if used + ch.len_utf8() > budget {
+            out.push_str("\r\n ");
+            // Spasi awal baris lanjutan ikut dihitung.
+            budget = MAX_LINE_OCTETS - 1;
+            used = 0;
+        }
+        out.push(ch);
+        used += ch.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+// Modul tests untuk format iCalendar.
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn todo(title: &str) -> Todo {
+        let at = Utc.with_ymd_and_hms(2026, 2, 18, 12, 0, 0).single().expect("valid timestamp").fixed_offset();
+        Todo {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            workspace_id: None,
+            project_id: None,
+            assignee_id: None,
+            title: title.to_string(),
+            desc: Some("line one\nline; two, three".to_string()),
+            iscompleted: false,
+            completed_at: None,
+            due_at: Some(at + chrono::Duration::days(1)),
+            estimate_value: None,
+            estimate_unit: None,
+            external_uid: None,
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    #[test]
+    fn escape_text_should_escape_reserved_characters() {
+        assert_eq!(escape_text("a\\b;c,d\r\ne"), "a\\\\b\\;c\\,d\\ne");
+    }
+
+    #[test]
+    fn render_calendar_should_write_vtodo_with_crlf() {
+        let mut done = todo("Done");
+        done.iscompleted = true;
+        done.completed_at = Some(done.updated_at);
+        let ics = render_calendar(Some("Todos"), &[todo("Open"), done]);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:Todos\r\n"));
+        assert!(ics.contains("UID:00000000-0000-0000-0000-000000000000\r\n"));
+        let mut imported = todo("Imported");
+        imported.external_uid = Some("abc@example.com".to_string());
+        assert!(render_calendar(None, &[imported]).contains("UID:abc@example.com\r\n"));
+        assert!(ics.contains("DUE:20260219T120000Z\r\n"));
+        assert!(ics.contains("DESCRIPTION:line one\\nline\\; two\\, three\r\n"));
+        assert!(ics.contains("STATUS:NEEDS-ACTION\r\n"));
+        assert!(ics.contains("STATUS:COMPLETED\r\nPERCENT-COMPLETE:100\r\nCOMPLETED:20260218T120000Z\r\n"));
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(!ics.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn push_line_should_fold_long_lines_on_char_boundaries() {
+        let mut out = String::new();
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        push_line(&mut out, &line);
+        let physical: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(physical.len() > 1);
+        assert!(physical.iter().all(|part| part.len() <= MAX_LINE_OCTETS));
+        assert!(physical[1..].iter().all(|part| part.starts_with(' ')));
+        let unfolded: String = physical.iter().enumerate().map(|(i, part)| if i == 0 { *part } else { &part[1..] }).collect();
+        assert_eq!(unfolded, line);
+    }
+
+    #[test]
+    fn parse_calendar_should_round_trip_rendered_output() {
+        let mut long = todo(&"Judul panjang ".repeat(10));
+        long.desc = Some("baris; satu,\ndua \\ tiga".to_string());
+        let roots = parse_calendar(&render_calendar(Some("Todos"), &[long.clone()])).expect("output must parse");
+
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "VCALENDAR");
+        let vtodo = &roots[0].components[0];
+        assert_eq!(vtodo.name, "VTODO");
+        let summary = vtodo.property("SUMMARY").expect("summary");
+        assert_eq!(unescape_text(&summary.value), long.title);
+        let desc = vtodo.property("DESCRIPTION").expect("description");
+        assert_eq!(unescape_text(&desc.value), "baris; satu,\ndua \\ tiga");
+        let (due_at, note) = parse_date_time(vtodo.property("DUE").expect("due")).expect("due must parse");
+        assert_eq!(Some(due_at), long.due_at);
+        assert_eq!(note, None);
+    }
+
+    #[test]
+    fn parse_calendar_should_read_params_and_reject_broken_structure() {
+        let input = "BEGIN:VCALENDAR\nBEGIN:VTODO\nDUE;TZID=\"Asia/Jakarta\":20260301T090000\nSUMMARY;LANGUAGE=id:Rapat\nEND:VTODO\nEND:VCALENDAR\n";
+        let roots = parse_calendar(input).expect("input must parse");
+        let due = roots[0].components[0].property("DUE").expect("due");
+        assert_eq!(due.param("TZID"), Some("Asia/Jakarta"));
+        let (due_at, note) = parse_date_time(due).expect("due must parse");
+        assert_eq!(format_utc(due_at), "20260301T090000Z");
+        assert!(note.is_some());
+
+        let date = Property { name: "DUE".to_string(), params: vec![("VALUE".to_string(), "DATE".to_string())], value: "20260301".to_string() };
+        assert_eq!(parse_date_time(&date).map(|(at, _)| format_utc(at)), Ok("20260301T000000Z".to_string()));
+
+        assert!(parse_calendar("BEGIN:VCALENDAR\nBEGIN:VTODO\nEND:VCALENDAR\n").is_err());
+        assert!(parse_calendar("BEGIN:VCALENDAR\nSUMMARY\nEND:VCALENDAR\n").is_err());
+        assert!(parse_calendar("SUMMARY:x\n").is_err());
+        assert!(parse_calendar("").is_err());
+    }
+}
@@ -0,0 +1,8 @@
+//! Modul kalender: ekspor todo ke iCalendar (VTODO) dan feed langganan bertoken rahasia.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod ics;
+pub mod repository;
+pub mod service;
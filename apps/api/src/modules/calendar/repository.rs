@@ -0,0 +1,73 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan feed baru; hanya hash token yang disimpan.
+pub async fn create_feed(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    project_id: Option<Uuid>,
+    name: Option<String>,
+    token_hash: String,
+) -> Result<Model, DbErr> {
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        workspace_id: Set(workspace_id),
+        project_id: Set(project_id),
+        name: Set(name),
+        token_hash: Set(token_hash),
+        created_at: Set(Utc::now().fixed_offset()),
+        last_used_at: Set(None),
+        revoked_at: Set(None),
+    };
+    active.insert(conn).await
+}
+
+/// Feed aktif (belum dicabut) milik user, urut waktu dibuat.
+pub async fn list_active_feeds(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::RevokedAt.is_null())
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Feed aktif milik user berdasarkan id.
+pub async fn find_active_feed(conn: &DatabaseConnection, user_id: Uuid, feed_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(feed_id))
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::RevokedAt.is_null())
+        .one(conn)
+        .await
+}
+
+/// Feed aktif berdasarkan hash token.
+pub async fn find_active_feed_by_token_hash(conn: &DatabaseConnection, token_hash: &str) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TokenHash.eq(token_hash))
+        .filter(Column::RevokedAt.is_null())
+        .one(conn)
+        .await
+}
+
+/// Menandai feed dicabut.
+pub async fn revoke_feed(conn: &DatabaseConnection, feed: Model, now: DateTime<FixedOffset>) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = feed.into();
+    active.revoked_at = Set(Some(now));
+    active.update(conn).await
+}
+
+/// Mencatat waktu terakhir feed dibaca aplikasi kalender.
+pub async fn touch_feed(conn: &DatabaseConnection, feed: Model, now: DateTime<FixedOffset>) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = feed.into();
+    active.last_used_at = Set(Some(now));
+    active.update(conn).await
+}
@@ -0,0 +1,527 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, DbErr};
+use sha2::{Digest, Sha256};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::config::dependency::DependencySettings;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::dto::{CreateTodoRequest, TodoFilter, UpdateTodoRequest};
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service::{self as todo_service, TodoError};
+
+use super::dto::{CreateFeedRequest, ImportItemResult, ImportResponse, ImportStatus, ImportedTodo, IssuedFeed};
+use super::entity::Model;
+use super::ics::{self, Component};
+use super::repository;
+
+/// Nama kalender bawaan bila feed tidak diberi nama.
+pub const DEFAULT_CALENDAR_NAME: &str = "Todos";
+const MAX_FEED_NAME_LEN: usize = 100;
+/// Batas ukuran file `.ics` yang diimpor.
+pub const MAX_IMPORT_BYTES: usize = 1024 * 1024;
+/// Batas jumlah `VTODO` dalam satu impor.
+pub const MAX_IMPORT_ITEMS: usize = 500;
+/// Sama dengan panjang kolom `todos.external_uid`.
+const MAX_UID_LEN: usize = 255;
+// Properti `VTODO` yang dipetakan, atau metadata yang memang tidak perlu disimpan.
+const SUPPORTED_PROPERTIES: &[&str] = &[
+    "UID",
+    "SUMMARY",
+    "DESCRIPTION",
+    "DUE",
+    "STATUS",
+    "COMPLETED",
+    "PERCENT-COMPLETE",
+    "DTSTAMP",
+    "CREATED",
+    "LAST-MODIFIED",
+    "SEQUENCE",
+];
+
+/// Error domain kalender yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum CalendarError {
+    Validation(String),
+    /// Feed tidak ada, dicabut, atau milik user lain.
+    NotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for CalendarError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for CalendarError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<TodoError> for CalendarError {
+    fn from(value: TodoError) -> Self {
+        match value {
+            TodoError::Validation(message) => Self::Validation(message),
+            TodoError::NotFound => Self::NotFound,
+            TodoError::Forbidden | TodoError::Blocked(_) => Self::Forbidden,
+            TodoError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// iCalendar todo yang bisa diakses user sesuai filter (workspace aktif, project, assignee).
+pub async fn export_calendar(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    filter: TodoFilter,
+) -> Result<String, CalendarError> {
+    let todos = todo_service::list_todos(conn, user_id, filter).await?;
+    Ok(ics::render_calendar(Some(DEFAULT_CALENDAR_NAME), &todos))
+}
+
+/// Membuat feed langganan untuk workspace aktif, opsional dibatasi satu project yang bisa dibaca user.
+/// Token asli hanya dikembalikan di sini; database menyimpan hash-nya.
+pub async fn create_feed(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    request: CreateFeedRequest,
+) -> Result<IssuedFeed, CalendarError> {
+    let name = normalize_name(request.name)?;
+    if let Some(project_id) = request.project_id {
+        ensure_workspace_project(conn, user_id, workspace_id, project_id, Permission::ProjectRead).await?;
+    }
+    let token = generate_token();
+    let feed = repository::create_feed(conn, user_id, workspace_id, request.project_id, name, hash_token(&token)).await?;
+    Ok(IssuedFeed { feed, token })
+}
+
+/// Feed aktif milik user.
+pub async fn list_feeds(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, CalendarError> {
+    repository::list_active_feeds(conn, user_id)
+        .await
+        .map_err(CalendarError::from)
+}
+
+/// Mencabut feed milik user; URL lamanya langsung berhenti berfungsi.
+pub async fn revoke_feed(conn: &DatabaseConnection, user_id: Uuid, feed_id: Uuid) -> Result<(), CalendarError> {
+    let feed = repository::find_active_feed(conn, user_id, feed_id)
+        .await?
+        .ok_or(CalendarError::NotFound)?;
+    repository::revoke_feed(conn, feed, Utc::now().fixed_offset()).await?;
+    Ok(())
+}
+
+/// iCalendar untuk URL langganan bertoken. Isi feed mengikuti akses pemilik feed saat ini,
+/// sehingga todo yang sudah tidak bisa diakses pemilik ikut hilang dari feed.
+pub async fn render_feed(conn: &DatabaseConnection, token: &str) -> Result<(Model, String), CalendarError> {
+    let feed = repository::find_active_feed_by_token_hash(conn, &hash_token(token))
+        .await?
+        .ok_or(CalendarError::NotFound)?;
+    let filter = TodoFilter {
+        workspace_id: feed.workspace_id,
+        project_id: feed.project_id,
+        assignee: None,
+    };
+    let todos = todo_service::list_todos(conn, feed.user_id, filter).await?;
+    let name = feed.name.clone().unwrap_or_else(|| DEFAULT_CALENDAR_NAME.to_string());
+    let body = ics::render_calendar(Some(&name), &todos);
+    let feed = repository::touch_feed(conn, feed, Utc::now().fixed_offset()).await?;
+    Ok((feed, body))
+}
+
+/// Mengimpor `VTODO` dari isi file `.ics` ke workspace aktif, opsional ke satu project.
+/// `VTODO` dengan UID yang pernah diimpor user memperbarui todo tersebut, bukan menduplikasi.
+/// Kegagalan satu item dicatat di hasilnya tanpa membatalkan item lain; error hanya untuk file
+/// yang tidak bisa di-parse, terlalu banyak item, atau project tujuan yang tidak valid.
+pub async fn import_calendar(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    project_id: Option<Uuid>,
+    input: &str,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
+) -> Result<ImportResponse, CalendarError> {
+    let roots = ics::parse_calendar(input).map_err(CalendarError::Validation)?;
+    let (vtodos, warnings) = collect_vtodos(&roots)?;
+    if vtodos.len() > MAX_IMPORT_ITEMS {
+        return Err(CalendarError::Validation(format!(
+            "file must contain at most {MAX_IMPORT_ITEMS} VTODO components"
+        )));
+    }
+    if let Some(project_id) = project_id {
+        ensure_workspace_project(conn, user_id, workspace_id, project_id, Permission::ProjectWrite).await?;
+    }
+
+    let mut items = Vec::with_capacity(vtodos.len());
+    for (index, vtodo) in vtodos.into_iter().enumerate() {
+        let imported = match map_vtodo(vtodo) {
+            Ok(imported) => imported,
+            Err(reason) => {
+                items.push(ImportItemResult {
+                    index,
+                    uid: vtodo.property("UID").map(|uid| uid.value.trim().to_string()),
+                    status: ImportStatus::Skipped,
+                    todo_id: None,
+                    warnings: Vec::new(),
+                    error: Some(reason),
+                });
+                continue;
+            }
+        };
+        let uid = imported.uid.clone();
+        let warnings = imported.warnings.clone();
+        let item = match apply_import(conn, user_id, workspace_id, project_id, imported, audit, dependencies).await {
+            Ok((status, todo_id)) => ImportItemResult {
+                index,
+                uid,
+                status,
+                todo_id: Some(todo_id),
+                warnings,
+                error: None,
+            },
+            Err(err) => ImportItemResult {
+                index,
+                uid,
+                status: ImportStatus::Skipped,
+                todo_id: None,
+                warnings,
+                error: Some(import_error_message(err)),
+            },
+        };
+        items.push(item);
+    }
+    Ok(ImportResponse::new(warnings, items))
+}
+
+/// Seluruh `VTODO` di dalam `VCALENDAR`, beserta peringatan untuk komponen lain yang diabaikan.
+/// `VTIMEZONE` diabaikan tanpa peringatan karena hanya pelengkap waktu lokal.
+pub fn collect_vtodos(roots: &[Component]) -> Result<(Vec<&Component>, Vec<String>), CalendarError> {
+    let mut vtodos = Vec::new();
+    let mut ignored: BTreeMap<&str, usize> = BTreeMap::new();
+    for root in roots {
+        if root.name != "VCALENDAR" {
+            return Err(CalendarError::Validation(format!(
+                "top-level component must be VCALENDAR, found {}",
+                root.name
+            )));
+        }
+        for component in &root.components {
+            match component.name.as_str() {
+                "VTODO" => vtodos.push(component),
+                "VTIMEZONE" => {}
+                other => *ignored.entry(other).or_default() += 1,
+            }
+        }
+    }
+    let warnings = ignored
+        .into_iter()
+        .map(|(name, count)| format!("{count} {name} component(s) ignored"))
+        .collect();
+    Ok((vtodos, warnings))
+}
+
+/// Memetakan satu `VTODO` ke field todo.
+///
+/// - `SUMMARY` wajib dan menjadi `title`; `DESCRIPTION` menjadi `desc`; `DUE` menjadi `due_at`.
+/// - `STATUS:COMPLETED` menandai selesai (`COMPLETED` menjadi `completed_at`); tanpa `STATUS`,
+///   `PERCENT-COMPLETE:100` atau adanya `COMPLETED` juga berarti selesai.
+/// - Properti lain dan sub-komponen (mis. `VALARM`) diabaikan dengan peringatan.
+///
+/// Error berisi alasan item dilewati.
+pub fn map_vtodo(vtodo: &Component) -> Result<ImportedTodo, String> {
+    let mut warnings = Vec::new();
+    let uid = vtodo
+        .property("UID")
+        .map(|uid| uid.value.trim().to_string())
+        .filter(|uid| !uid.is_empty());
+    match &uid {
+        Some(uid) if uid.chars().count() > MAX_UID_LEN => {
+            return Err(format!("UID must be at most {MAX_UID_LEN} characters"));
+        }
+        Some(_) => {}
+        None => warnings.push("UID is missing; importing this file again creates a duplicate".to_string()),
+    }
+
+    let title = vtodo
+        .property("SUMMARY")
+        .map(|summary| ics::unescape_text(&summary.value))
+        .filter(|title| !title.trim().is_empty())
+        .ok_or_else(|| "SUMMARY is required".to_string())?;
+    let title = todo_service::normalize_title(title).map_err(import_error_message)?;
+    let desc = vtodo
+        .property("DESCRIPTION")
+        .map(|desc| ics::unescape_text(&desc.value));
+    let desc = todo_service::normalize_desc(desc).map_err(import_error_message)?;
+
+    let due_at = match vtodo.property("DUE") {
+        Some(due) => {
+            let (due_at, note) = ics::parse_date_time(due)?;
+            warnings.extend(note);
+            Some(due_at)
+        }
+        None => None,
+    };
+    let completed = match vtodo.property("STATUS").map(|status| status.value.trim().to_ascii_uppercase()) {
+        Some(status) if status == "COMPLETED" => true,
+        Some(status) if status == "NEEDS-ACTION" || status == "IN-PROCESS" => false,
+        Some(status) => {
+            warnings.push(format!("STATUS:{status} is not supported; imported as not completed"));
+            false
+        }
+        None => {
+            vtodo
+                .property("PERCENT-COMPLETE")
+                .is_some_and(|percent| percent.value.trim() == "100")
+                || vtodo.property("COMPLETED").is_some()
+        }
+    };
+    let completed_at = match vtodo.property("COMPLETED").filter(|_| completed) {
+        Some(property) => {
+            let (completed_at, note) = ics::parse_date_time(property)?;
+            warnings.extend(note);
+            Some(completed_at)
+        }
+        None => None,
+    };
+
+    let mut unsupported: Vec<&str> = Vec::new();
+    for property in &vtodo.properties {
+        let name = property.name.as_str();
+        if !SUPPORTED_PROPERTIES.contains(&name) && !unsupported.contains(&name) {
+            unsupported.push(name);
+        }
+    }
+    if !unsupported.is_empty() {
+        warnings.push(format!("unsupported properties ignored: {}", unsupported.join(", ")));
+    }
+    let mut components: Vec<&str> = vtodo.components.iter().map(|component| component.name.as_str()).collect();
+    components.dedup();
+    if !components.is_empty() {
+        warnings.push(format!("unsupported components ignored: {}", components.join(", ")));
+    }
+
+    Ok(ImportedTodo {
+        uid,
+        title,
+        desc,
+        due_at,
+        completed,
+        completed_at,
+        warnings,
+    })
+}
+
+/// `true` bila todo hasil impor sebelumnya sudah sama dengan isi `VTODO` sehingga tidak perlu ditulis.
+pub fn is_unchanged(existing: &Todo, imported: &ImportedTodo) -> bool {
+    existing.title == imported.title
+        && imported.desc.as_ref().is_none_or(|desc| existing.desc.as_ref() == Some(desc))
+        && existing.due_at == imported.due_at
+        && existing.iscompleted == imported.completed
+}
+
+// Membuat todo baru, atau memperbarui todo milik user dengan UID yang sama di workspace aktif lewat jalur update biasa
+// (history, outbox, dan aturan dependency tetap berlaku).
+async fn apply_import(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    project_id: Option<Uuid>,
+    imported: ImportedTodo,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
+) -> Result<(ImportStatus, Uuid), TodoError> {
+    let existing = match &imported.uid {
+        Some(uid) => todo_repository::find_todo_by_external_uid(conn, user_id, workspace_id, uid).await?,
+        None => None,
+    };
+    if let Some(existing) = existing {
+        if is_unchanged(&existing, &imported) {
+            return Ok((ImportStatus::Unchanged, existing.id));
+        }
+        let request = UpdateTodoRequest {
+            title: Some(imported.title),
+            desc: imported.desc,
+            iscompleted: Some(imported.completed),
+            assignee_id: None,
+            estimate: None,
+            due_at: Some(imported.due_at),
+        };
+        let updated = todo_service::update_todo(conn, user_id, existing.id, request, audit, dependencies).await?;
+        return Ok((ImportStatus::Updated, updated.id));
+    }
+
+    let request = CreateTodoRequest {
+        title: imported.title,
+        desc: imported.desc,
+        project_id,
+        assignee_id: None,
+        estimate: None,
+        due_at: imported.due_at,
+    };
+    let mut new_todo = todo_service::prepare_new_todo(conn, user_id, workspace_id, request).await?;
+    new_todo.external_uid = imported.uid;
+    if imported.completed {
+        new_todo.completed_at = Some(imported.completed_at.unwrap_or_else(|| Utc::now().fixed_offset()));
+    }
+    let todo = todo_service::insert_todo(conn, user_id, new_todo, audit).await?;
+    Ok((ImportStatus::Created, todo.id))
+}
+
+// Alasan item impor dilewati, dalam bentuk yang aman ditampilkan ke client.
+fn import_error_message(err: TodoError) -> String {
+    match err {
+        TodoError::Validation(message) => message,
+        TodoError::NotFound => "todo not found".to_string(),
+        TodoError::Forbidden => "insufficient permission for this todo".to_string(),
+        TodoError::Blocked(_) => "todo is blocked by unfinished dependencies".to_string(),
+        TodoError::Database(db_err) => {
+            error!(error = %db_err, "calendar import database operation failed");
+            "unexpected database error".to_string()
+        }
+    }
+}
+
+// Project harus bisa diakses dengan `permission` dan berada di workspace aktif.
+async fn ensure_workspace_project(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    project_id: Uuid,
+    permission: Permission,
+) -> Result<(), CalendarError> {
+    let (project, _) = sharing_service::authorize_project(conn, user_id, project_id, permission)
+        .await
+        .map_err(|err| match err {
+            AccessError::NotFound => CalendarError::Validation("project_id does not exist".to_string()),
+            other => other.into(),
+        })?;
+    if project.workspace_id != workspace_id {
+        return Err(CalendarError::Validation(
+            "project_id belongs to a different workspace".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Nama feed di-trim; kosong berarti tanpa nama.
+pub fn normalize_name(raw: Option<String>) -> Result<Option<String>, CalendarError> {
+    let Some(name) = raw.map(|name| name.trim().to_string()).filter(|name| !name.is_empty()) else {
+        return Ok(None);
+    };
+    if name.chars().count() > MAX_FEED_NAME_LEN {
+        return Err(CalendarError::Validation(format!(
+            "name must be at most {MAX_FEED_NAME_LEN} characters"
+        )));
+    }
+    Ok(Some(name))
+}
+
+/// Hash SHA-256 (hex) dari token feed; hanya nilai ini yang disimpan di database.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+// Token acak 256-bit dari dua UUID v4, dipakai sebagai bagian path URL langganan.
+fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+// Modul tests untuk validasi feed kalender.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_name_should_trim_and_limit_length() {
+        assert_eq!(normalize_name(Some("  Kerja ".to_string())).ok(), Some(Some("Kerja".to_string())));
+        assert_eq!(normalize_name(Some("   ".to_string())).ok(), Some(None));
+        assert_eq!(normalize_name(None).ok(), Some(None));
+        assert!(normalize_name(Some("x".repeat(MAX_FEED_NAME_LEN + 1))).is_err());
+    }
+
+    #[test]
+    fn generated_tokens_should_be_unique_and_hashed() {
+        let token = generate_token();
+        assert_eq!(token.len(), 64);
+        assert_ne!(token, generate_token());
+        assert_eq!(hash_token(&token), hash_token(&format!(" {token} ")));
+        assert_ne!(hash_token(&token), token);
+    }
+
+    fn vtodo(lines: &str) -> Component {
+        let input = format!("BEGIN:VCALENDAR\nBEGIN:VTODO\n{lines}END:VTODO\nEND:VCALENDAR\n");
+        let roots = ics::parse_calendar(&input).expect("input must parse");
+        roots[0].components[0].clone()
+    }
+
+    #[test]
+    fn map_vtodo_should_map_fields_and_warn_on_unsupported_properties() {
+        let imported = map_vtodo(&vtodo(
+            "UID:abc@example.com\nSUMMARY: Bayar\\, listrik \nDESCRIPTION:baris\\nkedua\nDUE:20260301T020000Z\n\
+             STATUS:COMPLETED\nCOMPLETED:20260228T100000Z\nPRIORITY:1\nRRULE:FREQ=WEEKLY\nPRIORITY:2\n\
+             BEGIN:VALARM\nACTION:DISPLAY\nEND:VALARM\n",
+        ))
+        .expect("vtodo must map");
+
+        assert_eq!(imported.uid.as_deref(), Some("abc@example.com"));
+        assert_eq!(imported.title, "Bayar, listrik");
+        assert_eq!(imported.desc.as_deref(), Some("baris\nkedua"));
+        assert_eq!(imported.due_at.map(ics::format_utc).as_deref(), Some("20260301T020000Z"));
+        assert!(imported.completed);
+        assert_eq!(imported.completed_at.map(ics::format_utc).as_deref(), Some("20260228T100000Z"));
+        assert_eq!(
+            imported.warnings,
+            vec![
+                "unsupported properties ignored: PRIORITY, RRULE".to_string(),
+                "unsupported components ignored: VALARM".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn map_vtodo_should_skip_invalid_items_and_note_assumptions() {
+        assert_eq!(map_vtodo(&vtodo("UID:x\n")).err().as_deref(), Some("SUMMARY is required"));
+        assert!(map_vtodo(&vtodo(&format!("UID:{}\nSUMMARY:x\n", "u".repeat(MAX_UID_LEN + 1)))).is_err());
+        assert!(map_vtodo(&vtodo("SUMMARY:x\nDUE:besok\n")).is_err());
+
+        let imported = map_vtodo(&vtodo("SUMMARY:x\nDUE;TZID=Asia/Jakarta:20260301T090000\nSTATUS:CANCELLED\n"))
+            .expect("vtodo must map");
+        assert_eq!(imported.uid, None);
+        assert!(!imported.completed);
+        assert_eq!(imported.warnings.len(), 3);
+
+        let imported = map_vtodo(&vtodo("UID:y\nSUMMARY:x\nPERCENT-COMPLETE:100\n")).expect("vtodo must map");
+        assert!(imported.completed);
+        assert_eq!(imported.completed_at, None);
+        assert!(imported.warnings.is_empty());
+    }
+
+    #[test]
+    fn collect_vtodos_should_count_ignored_components() {
+        let roots = ics::parse_calendar(
+            "BEGIN:VCALENDAR\nBEGIN:VTIMEZONE\nEND:VTIMEZONE\nBEGIN:VEVENT\nEND:VEVENT\nBEGIN:VEVENT\nEND:VEVENT\n\
+             BEGIN:VTODO\nSUMMARY:x\nEND:VTODO\nEND:VCALENDAR\n",
+        )
+        .expect("input must parse");
+        let (vtodos, warnings) = collect_vtodos(&roots).expect("calendar must be collected");
+        assert_eq!(vtodos.len(), 1);
+        assert_eq!(warnings, vec!["2 VEVENT component(s) ignored".to_string()]);
+
+        let roots = ics::parse_calendar("BEGIN:VTODO\nSUMMARY:x\nEND:VTODO\n").expect("input must parse");
+        assert!(collect_vtodos(&roots).is_err());
+    }
+}
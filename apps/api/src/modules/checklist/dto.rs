@@ -0,0 +1,68 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity;
+
+/// Payload request untuk menambah item checklist.
+#[derive(Debug, Deserialize)]
+pub struct CreateChecklistItemRequest {
+    pub text: String,
+    #[serde(default)]
+    pub checked: bool,
+    /// Posisi sisip (mulai `0`); tidak dikirim atau melebihi jumlah item berarti di akhir.
+    #[serde(default)]
+    pub position: Option<i32>,
+}
+
+/// Payload request untuk mengubah item checklist.
+#[derive(Debug, Deserialize)]
+pub struct UpdateChecklistItemRequest {
+    /// Teks baru; `None` berarti tidak diubah.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Status centang baru; `None` berarti tidak diubah.
+    #[serde(default)]
+    pub checked: Option<bool>,
+}
+
+/// Payload request untuk mengurutkan ulang checklist.
+#[derive(Debug, Deserialize)]
+pub struct ReorderChecklistRequest {
+    /// Seluruh id item checklist todo dalam urutan baru.
+    pub item_ids: Vec<Uuid>,
+}
+
+/// Ringkasan progres checklist untuk tampilan daftar (mis. 3/5).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ChecklistProgress {
+    pub done: u64,
+    pub total: u64,
+}
+
+/// Bentuk data item checklist yang dikirim kembali ke client.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChecklistItemResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub text: String,
+    pub checked: bool,
+    pub position: i32,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for ChecklistItemResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            text: value.text,
+            checked: value.checked,
+            position: value.position,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
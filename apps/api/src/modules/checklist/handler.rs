@@ -0,0 +1,282 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, patch, put},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{
+    ChecklistItemResponse, CreateChecklistItemRequest, ReorderChecklistRequest, UpdateChecklistItemRequest,
+};
+use super::service::{self, ChecklistError};
+
+/// Registrasi route checklist, di-mount di bawah prefix `/todos`.
+///
+/// - `GET /{id}/checklist` daftar item sesuai urutan
+/// - `POST /{id}/checklist` tambah item
+/// - `PUT /{id}/checklist/order` urutkan ulang seluruh item
+/// - `PATCH /{id}/checklist/{item_id}` ubah teks/centang item
+/// - `DELETE /{id}/checklist/{item_id}` hapus item
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/checklist", get(list_items).post(create_item))
+        .route("/{id}/checklist/order", put(reorder_items))
+        .route("/{id}/checklist/{item_id}", patch(update_item).delete(delete_item))
+}
+
+/// GET `/todos/{id}/checklist`
+/// Output: `200 OK` + daftar item checklist sesuai urutan.
+pub async fn list_items(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<ChecklistItemResponse>>>, ApiError> {
+    let items = service::list_items(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_checklist_error)?;
+    info!(todo_id = %todo_id, count = items.len(), "checklist listed");
+    Ok(Json(SuccessResponse {
+        data: items.into_iter().map(ChecklistItemResponse::from).collect(),
+    }))
+}
+
+/// POST `/todos/{id}/checklist`
+///
+/// Input: `CreateChecklistItemRequest`.
+/// Output: `201 Created` + `SuccessResponse<ChecklistItemResponse>`.
+pub async fn create_item(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<CreateChecklistItemRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<ChecklistItemResponse>>), ApiError> {
+    let item = service::create_item(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_checklist_error)?;
+    info!(todo_id = %todo_id, item_id = %item.id, "checklist item created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: item.into() })))
+}
+
+/// PATCH `/todos/{id}/checklist/{item_id}`
+///
+/// Input: `UpdateChecklistItemRequest`.
+/// Output: `200 OK` + item terbaru.
+pub async fn update_item(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, item_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateChecklistItemRequest>,
+) -> Result<Json<SuccessResponse<ChecklistItemResponse>>, ApiError> {
+    let item = service::update_item(&state.db, user.id, todo_id, item_id, payload)
+        .await
+        .map_err(map_checklist_error)?;
+    info!(todo_id = %todo_id, item_id = %item_id, "checklist item updated");
+    Ok(Json(SuccessResponse { data: item.into() }))
+}
+
+/// DELETE `/todos/{id}/checklist/{item_id}`
+/// Output: `204 No Content`.
+pub async fn delete_item(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_item(&state.db, user.id, todo_id, item_id)
+        .await
+        .map_err(map_checklist_error)?;
+    info!(todo_id = %todo_id, item_id = %item_id, "checklist item deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PUT `/todos/{id}/checklist/order`
+///
+/// Input: `ReorderChecklistRequest`.
+/// Output: `200 OK` + checklist dalam urutan baru.
+pub async fn reorder_items(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<ReorderChecklistRequest>,
+) -> Result<Json<SuccessResponse<Vec<ChecklistItemResponse>>>, ApiError> {
+    let items = service::reorder_items(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_checklist_error)?;
+    info!(todo_id = %todo_id, count = items.len(), "checklist reordered");
+    Ok(Json(SuccessResponse {
+        data: items.into_iter().map(ChecklistItemResponse::from).collect(),
+    }))
+}
+
+/// Mapping error domain checklist ke `ApiError`.
+fn map_checklist_error(err: ChecklistError) -> ApiError {
+    match err {
+        ChecklistError::Validation(message) => {
+            warn!(reason = %message, "checklist validation failed");
+            ApiError::bad_request(message)
+        }
+        ChecklistError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        ChecklistError::NotFound => {
+            warn!("checklist item not found");
+            ApiError::not_found("checklist item not found")
+        }
+        ChecklistError::Forbidden => {
+            warn!("checklist change rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        ChecklistError::Database(db_err) => {
+            error!(error = %db_err, "checklist database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint checklist terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn checklist_flow_should_keep_order_and_report_progress() {
+        let app = build_test_app().await;
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "release" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        let mut ids = Vec::new();
+        for text in ["tag version", "publish notes"] {
+            let created = send(
+                &app,
+                "POST",
+                format!("/todos/{todo_id}/checklist"),
+                Some(json!({ "text": text })),
+            )
+            .await;
+            assert_eq!(created.status(), StatusCode::CREATED);
+            ids.push(read_json(created).await["data"]["id"].as_str().expect("item id").to_string());
+        }
+        let first = read_json(
+            send(
+                &app,
+                "POST",
+                format!("/todos/{todo_id}/checklist"),
+                Some(json!({ "text": "run tests", "position": 0 })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(first["data"]["position"], 0);
+        let first_id = first["data"]["id"].as_str().expect("item id").to_string();
+
+        let checked = read_json(
+            send(
+                &app,
+                "PATCH",
+                format!("/todos/{todo_id}/checklist/{first_id}"),
+                Some(json!({ "checked": true })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(checked["data"]["checked"], true);
+
+        let partial = send(
+            &app,
+            "PUT",
+            format!("/todos/{todo_id}/checklist/order"),
+            Some(json!({ "item_ids": [ids[0]] })),
+        )
+        .await;
+        assert_eq!(partial.status(), StatusCode::BAD_REQUEST);
+        let reordered = read_json(
+            send(
+                &app,
+                "PUT",
+                format!("/todos/{todo_id}/checklist/order"),
+                Some(json!({ "item_ids": [ids[1], first_id, ids[0]] })),
+            )
+            .await,
+        )
+        .await;
+        let texts: Vec<&str> = reordered["data"]
+            .as_array()
+            .expect("list must be an array")
+            .iter()
+            .map(|item| item["text"].as_str().expect("text"))
+            .collect();
+        assert_eq!(texts, vec!["publish notes", "run tests", "tag version"]);
+
+        let deleted = send(&app, "DELETE", format!("/todos/{todo_id}/checklist/{}", ids[1]), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+
+        let detail = read_json(
+            send(&app, "GET", format!("/todos/{todo_id}?include=checklist,checklist_progress"), None).await,
+        )
+        .await;
+        assert_eq!(detail["data"]["checklist_progress"], json!({ "done": 1, "total": 2 }));
+        assert_eq!(detail["data"]["checklist"][0]["text"], "run tests");
+        assert_eq!(detail["data"]["checklist"][0]["position"], 0);
+        assert_eq!(detail["data"]["checklist"][1]["position"], 1);
+    }
+}
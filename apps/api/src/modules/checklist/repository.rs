@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+    sea_query::Expr,
+};
+use uuid::Uuid;
+
+use super::dto::ChecklistProgress;
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+
+/// Menyisipkan item checklist pada `position`; item di posisi tersebut dan sesudahnya digeser.
+pub async fn create_item(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    text: String,
+    checked: bool,
+    position: i32,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+    Entity::update_many()
+        .col_expr(Column::Position, Expr::col(Column::Position).add(1))
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::Position.gte(position))
+        .exec(&txn)
+        .await?;
+    let created = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        todo_id: Set(todo_id),
+        text: Set(text),
+        checked: Set(checked),
+        position: Set(position),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&txn)
+    .await?;
+    txn.commit().await?;
+    Ok(created)
+}
+
+/// Mengambil checklist satu todo sesuai urutan.
+pub async fn list_items<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::Position, Order::Asc)
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil checklist banyak todo sekaligus, dikelompokkan per todo sesuai urutan.
+pub async fn list_items_by_todo_ids(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Model>>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let items = Entity::find()
+        .filter(Column::TodoId.is_in(todo_ids.iter().copied()))
+        .order_by(Column::Position, Order::Asc)
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await?;
+    let mut grouped: HashMap<Uuid, Vec<Model>> = HashMap::new();
+    for item in items {
+        grouped.entry(item.todo_id).or_default().push(item);
+    }
+    Ok(grouped)
+}
+
+/// Menghitung progres (item tercentang dari seluruh item) untuk banyak todo sekaligus.
+/// Todo tanpa checklist tidak muncul di map.
+pub async fn count_progress_by_todo_ids(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, ChecklistProgress>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows: Vec<(Uuid, i64, i64)> = Entity::find()
+        .select_only()
+        .column(Column::TodoId)
+        .column_as(Column::Id.count(), "total")
+        .column_as(Expr::cust("COUNT(*) FILTER (WHERE checked)"), "done")
+        .filter(Column::TodoId.is_in(todo_ids.iter().copied()))
+        .group_by(Column::TodoId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(todo_id, total, done)| {
+            let progress = ChecklistProgress {
+                done: u64::try_from(done).unwrap_or_default(),
+                total: u64::try_from(total).unwrap_or_default(),
+            };
+            (todo_id, progress)
+        })
+        .collect())
+}
+
+/// Jumlah item checklist satu todo.
+pub async fn count_items(conn: &DatabaseConnection, todo_id: Uuid) -> Result<u64, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .count(conn)
+        .await
+}
+
+/// Mengambil satu item checklist dalam scope todo tertentu.
+pub async fn find_item(conn: &DatabaseConnection, todo_id: Uuid, item_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::Id.eq(item_id))
+        .one(conn)
+        .await
+}
+
+/// Mengubah teks dan/atau status centang item.
+pub async fn update_item(
+    conn: &DatabaseConnection,
+    existing: Model,
+    text: Option<String>,
+    checked: Option<bool>,
+) -> Result<Model, DbErr> {
+    let mut active: entity::ActiveModel = existing.into();
+    if let Some(text) = text {
+        active.text = Set(text);
+    }
+    if let Some(checked) = checked {
+        active.checked = Set(checked);
+    }
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus item lalu merapatkan posisi item sesudahnya.
+pub async fn delete_item(conn: &DatabaseConnection, existing: Model) -> Result<(), DbErr> {
+    let txn = conn.begin().await?;
+    Entity::delete_by_id(existing.id).exec(&txn).await?;
+    Entity::update_many()
+        .col_expr(Column::Position, Expr::col(Column::Position).sub(1))
+        .filter(Column::TodoId.eq(existing.todo_id))
+        .filter(Column::Position.gt(existing.position))
+        .exec(&txn)
+        .await?;
+    txn.commit().await
+}
+
+/// Menyimpan urutan baru: item ke-`i` pada `item_ids` mendapat posisi `i`.
+/// Mengembalikan checklist sesudah diurutkan.
+pub async fn reorder_items(conn: &DatabaseConnection, todo_id: Uuid, item_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    let txn = conn.begin().await?;
+    for (position, item_id) in item_ids.iter().enumerate() {
+        Entity::update_many()
+            .col_expr(Column::Position, Expr::value(i32::try_from(position).unwrap_or(i32::MAX)))
+            .filter(Column::TodoId.eq(todo_id))
+            .filter(Column::Id.eq(*item_id))
+            .exec(&txn)
+            .await?;
+    }
+    let items = list_items(&txn, todo_id).await?;
+    txn.commit().await?;
+    Ok(items)
+}
+
+/// Menambahkan beberapa item (belum tercentang) ke todo yang checklist-nya masih kosong,
+/// dengan posisi sesuai urutan `texts`. Dipakai di dalam transaksi pemanggil.
+pub async fn insert_items<C: ConnectionTrait>(conn: &C, todo_id: Uuid, texts: Vec<String>) -> Result<Vec<Model>, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let mut items = Vec::with_capacity(texts.len());
+    for (position, text) in texts.into_iter().enumerate() {
+        let item = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            todo_id: Set(todo_id),
+            text: Set(text),
+            checked: Set(false),
+            position: Set(i32::try_from(position).unwrap_or(i32::MAX)),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(conn)
+        .await?;
+        items.push(item);
+    }
+    Ok(items)
+}
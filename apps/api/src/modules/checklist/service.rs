@@ -0,0 +1,222 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::sharing::service as sharing_service;
+
+use super::dto::{
+    ChecklistProgress, CreateChecklistItemRequest, ReorderChecklistRequest, UpdateChecklistItemRequest,
+};
+use super::entity::Model;
+use super::repository;
+
+const MAX_TEXT_LEN: usize = 500;
+/// Batas jumlah item per todo; kebutuhan yang lebih besar sebaiknya memakai todo terpisah.
+const MAX_ITEMS_PER_TODO: u64 = 200;
+
+/// Error domain checklist yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum ChecklistError {
+    Validation(String),
+    TodoNotFound,
+    NotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for ChecklistError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for ChecklistError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Mengambil checklist todo sesuai urutan; butuh `todo.read`.
+pub async fn list_items(conn: &DatabaseConnection, user_id: Uuid, todo_id: Uuid) -> Result<Vec<Model>, ChecklistError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    repository::list_items(conn, todo_id)
+        .await
+        .map_err(ChecklistError::from)
+}
+
+/// Menambah item checklist; butuh `todo.write`.
+pub async fn create_item(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    request: CreateChecklistItemRequest,
+) -> Result<Model, ChecklistError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
+    let text = normalize_text(request.text)?;
+    let count = repository::count_items(conn, todo_id).await?;
+    if count >= MAX_ITEMS_PER_TODO {
+        return Err(ChecklistError::Validation(format!(
+            "checklist must have at most {MAX_ITEMS_PER_TODO} items"
+        )));
+    }
+    let position = insert_position(request.position, count)?;
+    repository::create_item(conn, todo_id, text, request.checked, position)
+        .await
+        .map_err(ChecklistError::from)
+}
+
+/// Mengubah teks dan/atau status centang item; butuh `todo.write`.
+pub async fn update_item(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    item_id: Uuid,
+    request: UpdateChecklistItemRequest,
+) -> Result<Model, ChecklistError> {
+    let existing = find_writable_item(conn, user_id, todo_id, item_id).await?;
+    let text = match request.text {
+        Some(text) => Some(normalize_text(text)?),
+        None => None,
+    };
+    repository::update_item(conn, existing, text, request.checked)
+        .await
+        .map_err(ChecklistError::from)
+}
+
+/// Menghapus item checklist; butuh `todo.write`.
+pub async fn delete_item(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    item_id: Uuid,
+) -> Result<(), ChecklistError> {
+    let existing = find_writable_item(conn, user_id, todo_id, item_id).await?;
+    repository::delete_item(conn, existing)
+        .await
+        .map_err(ChecklistError::from)
+}
+
+/// Mengurutkan ulang checklist; `item_ids` harus memuat tepat seluruh item todo.
+/// Butuh `todo.write`.
+pub async fn reorder_items(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    request: ReorderChecklistRequest,
+) -> Result<Vec<Model>, ChecklistError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
+    let current = repository::list_items(conn, todo_id).await?;
+    let current_ids: Vec<Uuid> = current.iter().map(|item| item.id).collect();
+    validate_order(&current_ids, &request.item_ids)?;
+    repository::reorder_items(conn, todo_id, &request.item_ids)
+        .await
+        .map_err(ChecklistError::from)
+}
+
+/// Checklist banyak todo sekaligus untuk kebutuhan `?include=checklist`.
+pub async fn load_checklists(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Model>>, DbErr> {
+    repository::list_items_by_todo_ids(conn, todo_ids).await
+}
+
+/// Progres checklist per todo untuk kebutuhan `?include=checklist_progress`.
+pub async fn load_progress(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, ChecklistProgress>, DbErr> {
+    repository::count_progress_by_todo_ids(conn, todo_ids).await
+}
+
+/// Normalisasi dan validasi teks item checklist.
+pub fn normalize_text(raw_text: String) -> Result<String, ChecklistError> {
+    let text = raw_text.trim();
+    if text.is_empty() {
+        return Err(ChecklistError::Validation("text must not be empty".to_string()));
+    }
+    if text.chars().count() > MAX_TEXT_LEN {
+        return Err(ChecklistError::Validation(format!(
+            "text must be at most {MAX_TEXT_LEN} characters"
+        )));
+    }
+    Ok(text.to_string())
+}
+
+/// Posisi sisip item baru: default di akhir, posisi melebihi jumlah item dipotong ke akhir.
+pub fn insert_position(requested: Option<i32>, count: u64) -> Result<i32, ChecklistError> {
+    let end = i32::try_from(count).unwrap_or(i32::MAX);
+    match requested {
+        Some(position) if position < 0 => Err(ChecklistError::Validation(
+            "position must not be negative".to_string(),
+        )),
+        Some(position) => Ok(position.min(end)),
+        None => Ok(end),
+    }
+}
+
+/// Urutan baru harus berisi seluruh item saat ini tepat sekali.
+pub fn validate_order(current_ids: &[Uuid], requested_ids: &[Uuid]) -> Result<(), ChecklistError> {
+    let requested: HashSet<Uuid> = requested_ids.iter().copied().collect();
+    if requested.len() != requested_ids.len() {
+        return Err(ChecklistError::Validation("item_ids must not contain duplicates".to_string()));
+    }
+    let current: HashSet<Uuid> = current_ids.iter().copied().collect();
+    if requested != current {
+        return Err(ChecklistError::Validation(
+            "item_ids must contain every checklist item of the todo".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Mengambil item setelah memastikan pelaku punya `todo.write` pada todo induknya.
+async fn find_writable_item(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    item_id: Uuid,
+) -> Result<Model, ChecklistError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
+    repository::find_item(conn, todo_id, item_id)
+        .await?
+        .ok_or(ChecklistError::NotFound)
+}
+
+// Modul tests untuk unit testing validasi checklist.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_text_should_trim_and_reject_empty() {
+        assert_eq!(normalize_text("  buy milk ".to_string()).ok().as_deref(), Some("buy milk"));
+        assert!(matches!(normalize_text("   ".to_string()), Err(ChecklistError::Validation(_))));
+    }
+
+    #[test]
+    fn insert_position_should_default_to_end_and_clamp() {
+        assert_eq!(insert_position(None, 3).ok(), Some(3));
+        assert_eq!(insert_position(Some(1), 3).ok(), Some(1));
+        assert_eq!(insert_position(Some(10), 3).ok(), Some(3));
+        assert!(matches!(insert_position(Some(-1), 3), Err(ChecklistError::Validation(_))));
+    }
+
+    #[test]
+    fn validate_order_should_require_exact_permutation() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        assert!(validate_order(&[a, b, c], &[c, a, b]).is_ok());
+        assert!(matches!(validate_order(&[a, b, c], &[a, b]), Err(ChecklistError::Validation(_))));
+        assert!(matches!(validate_order(&[a, b], &[a, a]), Err(ChecklistError::Validation(_))));
+        assert!(matches!(
+            validate_order(&[a, b], &[a, b, Uuid::new_v4()]),
+            Err(ChecklistError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Versi skema pesan WebSocket; setiap pesan dua arah membawa field `v`.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Pesan dari client (`{"v": 1, "type": "...", ...}`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Mulai melihat project: menerima presence, lock, dan perubahan todo-nya.
+    Subscribe { project_id: Uuid },
+    Unsubscribe { project_id: Uuid },
+    /// Mengambil atau memperpanjang soft lock pada todo.
+    Lock { todo_id: Uuid },
+    Unlock { todo_id: Uuid },
+}
+
+/// Pesan ke client; dibungkus `ServerEnvelope` agar selalu membawa `v`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Dikirim sekali setelah koneksi dibuka.
+    Welcome { user_id: Uuid, lock_ttl_secs: u64 },
+    /// Balasan `subscribe` beserta kondisi project saat ini.
+    Subscribed {
+        project_id: Uuid,
+        viewers: Vec<Uuid>,
+        locks: Vec<LockView>,
+    },
+    Unsubscribed { project_id: Uuid },
+    /// Daftar user yang sedang melihat project berubah.
+    Presence { project_id: Uuid, viewers: Vec<Uuid> },
+    /// Daftar soft lock aktif di project berubah.
+    Locks { project_id: Uuid, locks: Vec<LockView> },
+    LockAcquired { lock: LockView },
+    /// Todo sedang dikunci user lain sampai `lock.expires_at`.
+    LockDenied { todo_id: Uuid, lock: LockView },
+    LockReleased { todo_id: Uuid },
+    /// Perubahan todo di project yang di-subscribe; `data` sama dengan stream SSE.
+    TodoChanged {
+        project_id: Uuid,
+        event: String,
+        sequence: i64,
+        data: Value,
+    },
+    /// Ada pesan yang mungkin terlewat; client sebaiknya memuat ulang data project.
+    Resync,
+    Error { code: ErrorCode, message: String },
+}
+
+/// Amplop pesan ke client.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ServerEnvelope {
+    pub v: u32,
+    #[serde(flatten)]
+    pub message: ServerMessage,
+}
+
+impl From<ServerMessage> for ServerEnvelope {
+    fn from(message: ServerMessage) -> Self {
+        Self {
+            v: PROTOCOL_VERSION,
+            message,
+        }
+    }
+}
+
+/// Kode error pada pesan `error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    InvalidMessage,
+    UnsupportedVersion,
+    NotFound,
+    Forbidden,
+    /// Soft lock hanya berlaku untuk todo di dalam project.
+    NotInProject,
+    Internal,
+}
+
+/// Soft lock yang dikirim ke client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LockView {
+    pub todo_id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub expires_at: DateTime<FixedOffset>,
+}
@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use axum::{
+    Router,
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::get,
+};
+use bytes::Bytes;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::modules::event_stream::hub::HubMessage;
+
+use super::dto::{ServerEnvelope, ServerMessage};
+use super::service::{CollabError, Session};
+
+/// Jeda ping server agar proxy tidak menutup koneksi yang sepi.
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Registrasi route kolaborasi, di-mount di bawah prefix `/collab`.
+///
+/// - `GET /ws` upgrade ke WebSocket kolaborasi (skema pesan versi 1)
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/ws", get(collab_socket))
+}
+
+/// GET `/collab/ws`
+/// User pelaku diambil dengan extractor yang sama dengan REST (`X-User-Id`, `X-Workspace-Id`).
+/// Output: `101 Switching Protocols`, lalu pesan JSON `{"v": 1, "type": ...}` dua arah.
+pub async fn collab_socket(ws: WebSocketUpgrade, State(state): State<AppState>, user: CurrentUser) -> Response {
+    info!(user_id = %user.id, "collaboration socket opened");
+    ws.on_upgrade(move |socket| run_socket(socket, state, user.id))
+}
+
+// Loop satu koneksi: pesan client, perubahan presence/lock, dan event todo dari hub SSE.
+async fn run_socket(mut socket: WebSocket, state: AppState, user_id: Uuid) {
+    let mut session = Session::new(state.db.clone(), state.collab.clone(), user_id);
+    let mut project_updates = state.collab.subscribe();
+    let mut todo_events = state.todo_events.subscribe();
+    let mut ping = tokio::time::interval(PING_INTERVAL);
+    ping.tick().await;
+
+    if send(&mut socket, session.welcome()).await.is_ok() {
+        loop {
+            let outgoing = tokio::select! {
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Text(text))) => Some(session.handle_text(text.as_str()).await),
+                    Some(Ok(Message::Binary(_))) => {
+                        Some(CollabError::InvalidMessage("binary frames are not supported".to_string()).into_message())
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => None,
+                    Some(Err(err)) => {
+                        warn!(error = %err, user_id = %user_id, "collaboration socket failed");
+                        break;
+                    }
+                },
+                update = project_updates.recv() => match update {
+                    Ok(update) => session.project_update(update),
+                    Err(RecvError::Lagged(_)) => Some(ServerMessage::Resync),
+                    Err(RecvError::Closed) => break,
+                },
+                event = todo_events.recv() => match event {
+                    Ok(HubMessage::Event(event)) => session.todo_change(&event).await.unwrap_or_else(|err| {
+                        error!(error = %err, "failed to check todo event access");
+                        Some(ServerMessage::Resync)
+                    }),
+                    Ok(HubMessage::Resync) | Err(RecvError::Lagged(_)) => Some(ServerMessage::Resync),
+                    Err(RecvError::Closed) => break,
+                },
+                _ = ping.tick() => {
+                    if socket.send(Message::Ping(Bytes::new())).await.is_err() {
+                        break;
+                    }
+                    None
+                }
+            };
+            if let Some(message) = outgoing
+                && send(&mut socket, message).await.is_err()
+            {
+                break;
+            }
+        }
+    }
+    session.close();
+    info!(user_id = %user_id, "collaboration socket closed");
+}
+
+async fn send(socket: &mut WebSocket, message: ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(&ServerEnvelope::from(message)).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text.into())).await
+}
+
+// Modul tests integrasi WebSocket kolaborasi terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use futures_util::{SinkExt, StreamExt};
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::tungstenite::{Message as WsMessage, client::IntoClientRequest};
+    use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::modules::event_stream::service as event_stream_service;
+    use crate::modules::project::handler as project_handler;
+    use crate::modules::sharing::handler as sharing_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    struct TestServer {
+        base: String,
+        http: reqwest::Client,
+    }
+
+    impl TestServer {
+        async fn rest(&self, as_user: Option<Uuid>, method: reqwest::Method, path: &str, body: Value) -> Value {
+            let mut request = self
+                .http
+                .request(method, format!("http://{}{path}", self.base))
+                .header("content-type", "application/json")
+                .body(body.to_string());
+            if let Some(user_id) = as_user {
+                request = request.header(USER_ID_HEADER, user_id.to_string());
+            }
+            let response = request.send().await.expect("request must succeed");
+            assert!(response.status().is_success(), "unexpected status {}", response.status());
+            let body = response.bytes().await.expect("body must be readable");
+            serde_json::from_slice(&body).expect("body must be json")
+        }
+
+        async fn connect(&self, user_id: Uuid) -> Client {
+            let mut request = format!("ws://{}/collab/ws", self.base)
+                .into_client_request()
+                .expect("request should be valid");
+            request
+                .headers_mut()
+                .insert(USER_ID_HEADER, user_id.to_string().parse().expect("header value"));
+            let (mut client, _) = connect_async(request).await.expect("socket must connect");
+            let welcome = next_matching(&mut client, |message| message["type"] == "welcome").await;
+            assert_eq!(welcome["v"], 1);
+            assert_eq!(welcome["user_id"], user_id.to_string());
+            client
+        }
+    }
+
+    // Server sungguhan di port acak, karena upgrade WebSocket butuh koneksi TCP.
+    async fn spawn_server() -> (TestServer, Uuid, Uuid, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let users = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        for user_id in users {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let state = AppState::for_tests(db.clone(), users[0]);
+        tokio::spawn(event_stream_service::run_listener(db, state.todo_events.clone()));
+        let app = Router::new()
+            .nest("/collab", routes())
+            .nest("/projects", project_handler::routes().merge(sharing_handler::project_routes()))
+            .nest("/todos", todo_handler::routes())
+            .with_state(state);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("server must bind");
+        let addr = listener.local_addr().expect("server must have an address");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let server = TestServer {
+            base: addr.to_string(),
+            http: reqwest::Client::new(),
+        };
+        (server, users[0], users[1], users[2])
+    }
+
+    async fn send_json(client: &mut Client, message: Value) {
+        client
+            .send(WsMessage::Text(message.to_string().into()))
+            .await
+            .expect("message must be sent");
+    }
+
+    // Pesan berikutnya yang memenuhi `predicate`; pesan lain dilewati.
+    async fn next_matching(client: &mut Client, predicate: impl Fn(&Value) -> bool) -> Value {
+        loop {
+            let frame = tokio::time::timeout(Duration::from_secs(10), client.next())
+                .await
+                .expect("message must arrive in time")
+                .expect("socket must stay open")
+                .expect("frame must be readable");
+            if let WsMessage::Text(text) = frame {
+                let message: Value = serde_json::from_str(text.as_str()).expect("message must be json");
+                if predicate(&message) {
+                    return message;
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn collab_socket_should_share_presence_locks_and_todo_changes() {
+        let (server, owner, peer, stranger) = spawn_server().await;
+        let project = server
+            .rest(None, reqwest::Method::POST, "/projects", json!({ "name": "launch" }))
+            .await;
+        let project_id = project["data"]["id"].as_str().expect("project id").to_string();
+        server
+            .rest(
+                None,
+                reqwest::Method::PUT,
+                &format!("/projects/{project_id}/shares/{peer}"),
+                json!({ "role": "editor" }),
+            )
+            .await;
+        let todo = server
+            .rest(
+                None,
+                reqwest::Method::POST,
+                "/todos",
+                json!({ "title": "draft notes", "project_id": project_id }),
+            )
+            .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id").to_string();
+
+        let mut owner_socket = server.connect(owner).await;
+        send_json(&mut owner_socket, json!({ "v": 1, "type": "subscribe", "project_id": project_id })).await;
+        let subscribed = next_matching(&mut owner_socket, |message| message["type"] == "subscribed").await;
+        assert_eq!(subscribed["viewers"], json!([owner]));
+
+        let mut stranger_socket = server.connect(stranger).await;
+        send_json(&mut stranger_socket, json!({ "v": 1, "type": "subscribe", "project_id": project_id })).await;
+        let denied = next_matching(&mut stranger_socket, |message| message["type"] == "error").await;
+        assert_eq!(denied["code"], "not_found");
+
+        let mut peer_socket = server.connect(peer).await;
+        send_json(&mut peer_socket, json!({ "v": 1, "type": "subscribe", "project_id": project_id })).await;
+        next_matching(&mut peer_socket, |message| message["type"] == "subscribed").await;
+        let presence = next_matching(&mut owner_socket, |message| {
+            message["type"] == "presence" && message["viewers"].as_array().is_some_and(|viewers| viewers.len() == 2)
+        })
+        .await;
+        assert!(presence["viewers"].as_array().expect("viewers").contains(&json!(peer)));
+
+        send_json(&mut peer_socket, json!({ "v": 1, "type": "lock", "todo_id": todo_id })).await;
+        let acquired = next_matching(&mut peer_socket, |message| message["type"] == "lock_acquired").await;
+        assert_eq!(acquired["lock"]["user_id"], peer.to_string());
+        let locks = next_matching(&mut owner_socket, |message| message["type"] == "locks").await;
+        assert_eq!(locks["locks"][0]["todo_id"], todo_id.as_str());
+        send_json(&mut owner_socket, json!({ "v": 1, "type": "lock", "todo_id": todo_id })).await;
+        let lock_denied = next_matching(&mut owner_socket, |message| message["type"] == "lock_denied").await;
+        assert_eq!(lock_denied["lock"]["user_id"], peer.to_string());
+
+        send_json(&mut owner_socket, json!({ "v": 2, "type": "unsubscribe", "project_id": project_id })).await;
+        let unsupported = next_matching(&mut owner_socket, |message| message["type"] == "error").await;
+        assert_eq!(unsupported["code"], "unsupported_version");
+
+        server
+            .rest(
+                None,
+                reqwest::Method::PATCH,
+                &format!("/todos/{todo_id}"),
+                json!({ "title": "final notes" }),
+            )
+            .await;
+        let changed = next_matching(&mut peer_socket, |message| message["type"] == "todo_changed").await;
+        assert_eq!(changed["event"], "todo.updated");
+        assert_eq!(changed["data"]["todo"]["title"], "final notes");
+
+        // Koneksi yang ditutup melepas presence dan lock-nya.
+        peer_socket.close(None).await.expect("socket must close");
+        let left = next_matching(&mut owner_socket, |message| message["type"] == "presence").await;
+        assert_eq!(left["viewers"], json!([owner]));
+        let released = next_matching(&mut owner_socket, |message| message["type"] == "locks").await;
+        assert_eq!(released["locks"], json!([]));
+    }
+}
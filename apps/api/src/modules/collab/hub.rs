@@ -0,0 +1,349 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use chrono::{DateTime, FixedOffset, TimeDelta};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use super::dto::LockView;
+
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Perubahan presence/lock satu project yang disebar ke semua koneksi di proses ini.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectUpdate {
+    Presence { project_id: Uuid, viewers: Vec<Uuid> },
+    Locks { project_id: Uuid, locks: Vec<LockView> },
+}
+
+impl ProjectUpdate {
+    pub fn project_id(&self) -> Uuid {
+        match self {
+            Self::Presence { project_id, .. } | Self::Locks { project_id, .. } => *project_id,
+        }
+    }
+}
+
+/// Permintaan soft lock dari satu koneksi.
+#[derive(Debug, Clone, Copy)]
+pub struct LockRequest {
+    pub todo_id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub connection_id: Uuid,
+}
+
+/// Presence dan soft lock yang disimpan di memori proses. Dibagi antar handler lewat `AppState`.
+#[derive(Debug, Clone)]
+pub struct CollabHub {
+    state: Arc<Mutex<CollabState>>,
+    sender: broadcast::Sender<ProjectUpdate>,
+}
+
+// Viewer per project (koneksi -> user) dan lock per todo.
+#[derive(Debug, Default)]
+struct CollabState {
+    viewers: HashMap<Uuid, HashMap<Uuid, Uuid>>,
+    locks: HashMap<Uuid, HeldLock>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HeldLock {
+    view: LockView,
+    connection_id: Uuid,
+}
+
+impl CollabHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            state: Arc::default(),
+            sender,
+        }
+    }
+
+    /// Berlangganan perubahan presence/lock semua project.
+    pub fn subscribe(&self) -> broadcast::Receiver<ProjectUpdate> {
+        self.sender.subscribe()
+    }
+
+    /// Mencatat koneksi sebagai viewer project lalu mengembalikan viewer dan lock aktif saat ini.
+    pub fn join(
+        &self,
+        project_id: Uuid,
+        connection_id: Uuid,
+        user_id: Uuid,
+        now: DateTime<FixedOffset>,
+    ) -> (Vec<Uuid>, Vec<LockView>) {
+        let (viewers, locks) = {
+            let mut state = self.lock_state();
+            state.viewers.entry(project_id).or_default().insert(connection_id, user_id);
+            (state.viewers_of(project_id), state.locks_of(project_id, now))
+        };
+        self.publish(ProjectUpdate::Presence {
+            project_id,
+            viewers: viewers.clone(),
+        });
+        (viewers, locks)
+    }
+
+    /// Menghapus koneksi dari viewer project.
+    pub fn leave(&self, project_id: Uuid, connection_id: Uuid) {
+        let viewers = {
+            let mut state = self.lock_state();
+            state.remove_viewer(project_id, connection_id)
+        };
+        if let Some(viewers) = viewers {
+            self.publish(ProjectUpdate::Presence { project_id, viewers });
+        }
+    }
+
+    /// Mengambil atau memperpanjang soft lock sampai `now + ttl`. User yang sama boleh
+    /// memperpanjang dari koneksi mana pun; lock user lain yang masih berlaku dikembalikan
+    /// sebagai `Err`.
+    pub fn acquire_lock(
+        &self,
+        request: LockRequest,
+        now: DateTime<FixedOffset>,
+        ttl: TimeDelta,
+    ) -> Result<LockView, LockView> {
+        let (view, locks) = {
+            let mut state = self.lock_state();
+            if let Some(held) = state.locks.get(&request.todo_id)
+                && held.view.user_id != request.user_id
+                && held.view.expires_at > now
+            {
+                return Err(held.view);
+            }
+            let view = LockView {
+                todo_id: request.todo_id,
+                project_id: request.project_id,
+                user_id: request.user_id,
+                expires_at: now + ttl,
+            };
+            state.locks.insert(
+                request.todo_id,
+                HeldLock {
+                    view,
+                    connection_id: request.connection_id,
+                },
+            );
+            (view, state.locks_of(request.project_id, now))
+        };
+        self.publish(ProjectUpdate::Locks {
+            project_id: request.project_id,
+            locks,
+        });
+        Ok(view)
+    }
+
+    /// Melepas lock milik `user_id`; `false` bila todo tidak sedang dikunci user tersebut.
+    pub fn release_lock(&self, todo_id: Uuid, user_id: Uuid, now: DateTime<FixedOffset>) -> bool {
+        let update = {
+            let mut state = self.lock_state();
+            match state.locks.get(&todo_id) {
+                Some(held) if held.view.user_id == user_id => {
+                    let project_id = held.view.project_id;
+                    state.locks.remove(&todo_id);
+                    Some(ProjectUpdate::Locks {
+                        project_id,
+                        locks: state.locks_of(project_id, now),
+                    })
+                }
+                _ => None,
+            }
+        };
+        let released = update.is_some();
+        if let Some(update) = update {
+            self.publish(update);
+        }
+        released
+    }
+
+    /// Membuang lock yang sudah kedaluwarsa lalu memberi tahu project terkait.
+    /// Mengembalikan jumlah lock yang dibuang.
+    pub fn expire_locks(&self, now: DateTime<FixedOffset>) -> usize {
+        let (expired_count, updates) = {
+            let mut state = self.lock_state();
+            let expired: Vec<(Uuid, Uuid)> = state
+                .locks
+                .values()
+                .filter(|held| held.view.expires_at <= now)
+                .map(|held| (held.view.todo_id, held.view.project_id))
+                .collect();
+            for (todo_id, _) in &expired {
+                state.locks.remove(todo_id);
+            }
+            let projects: BTreeSet<Uuid> = expired.iter().map(|(_, project_id)| *project_id).collect();
+            let updates: Vec<ProjectUpdate> = projects
+                .into_iter()
+                .map(|project_id| ProjectUpdate::Locks {
+                    project_id,
+                    locks: state.locks_of(project_id, now),
+                })
+                .collect();
+            (expired.len(), updates)
+        };
+        for update in updates {
+            self.publish(update);
+        }
+        expired_count
+    }
+
+    /// Membersihkan presence dan lock milik koneksi yang ditutup.
+    pub fn disconnect(&self, connection_id: Uuid, now: DateTime<FixedOffset>) {
+        let updates = {
+            let mut state = self.lock_state();
+            let projects: Vec<Uuid> = state
+                .viewers
+                .iter()
+                .filter(|(_, viewers)| viewers.contains_key(&connection_id))
+                .map(|(project_id, _)| *project_id)
+                .collect();
+            let mut updates: Vec<ProjectUpdate> = projects
+                .into_iter()
+                .filter_map(|project_id| {
+                    state
+                        .remove_viewer(project_id, connection_id)
+                        .map(|viewers| ProjectUpdate::Presence { project_id, viewers })
+                })
+                .collect();
+            let released: BTreeSet<Uuid> = state
+                .locks
+                .values()
+                .filter(|held| held.connection_id == connection_id)
+                .map(|held| held.view.project_id)
+                .collect();
+            state.locks.retain(|_, held| held.connection_id != connection_id);
+            updates.extend(released.into_iter().map(|project_id| ProjectUpdate::Locks {
+                project_id,
+                locks: state.locks_of(project_id, now),
+            }));
+            updates
+        };
+        for update in updates {
+            self.publish(update);
+        }
+    }
+
+    // Tidak ada invariant yang bisa rusak di tengah jalan, jadi state tetap dipakai walau poisoned.
+    fn lock_state(&self) -> MutexGuard<'_, CollabState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Diabaikan bila belum ada koneksi yang berlangganan.
+    fn publish(&self, update: ProjectUpdate) {
+        let _ = self.sender.send(update);
+    }
+}
+
+impl Default for CollabHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollabState {
+    // User unik yang sedang melihat project, terurut.
+    fn viewers_of(&self, project_id: Uuid) -> Vec<Uuid> {
+        let users: BTreeSet<Uuid> = self
+            .viewers
+            .get(&project_id)
+            .map(|viewers| viewers.values().copied().collect())
+            .unwrap_or_default();
+        users.into_iter().collect()
+    }
+
+    // Lock yang masih berlaku di project, urut waktu kedaluwarsa.
+    fn locks_of(&self, project_id: Uuid, now: DateTime<FixedOffset>) -> Vec<LockView> {
+        let mut locks: Vec<LockView> = self
+            .locks
+            .values()
+            .filter(|held| held.view.project_id == project_id && held.view.expires_at > now)
+            .map(|held| held.view)
+            .collect();
+        locks.sort_by_key(|lock| (lock.expires_at, lock.todo_id));
+        locks
+    }
+
+    // Viewer terbaru bila koneksi memang terdaftar di project.
+    fn remove_viewer(&mut self, project_id: Uuid, connection_id: Uuid) -> Option<Vec<Uuid>> {
+        let viewers = self.viewers.get_mut(&project_id)?;
+        viewers.remove(&connection_id)?;
+        if viewers.is_empty() {
+            self.viewers.remove(&project_id);
+        }
+        Some(self.viewers_of(project_id))
+    }
+}
+
+// Modul tests unit untuk presence dan soft lock di memori.
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn at(second: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2026, 2, 20, 9, 0, second).unwrap().fixed_offset()
+    }
+
+    #[test]
+    fn presence_should_count_users_once_and_clear_on_disconnect() {
+        let hub = CollabHub::new();
+        let mut updates = hub.subscribe();
+        let project_id = Uuid::new_v4();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (first_tab, second_tab, bob_tab) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        hub.join(project_id, first_tab, alice, at(0));
+        hub.join(project_id, second_tab, alice, at(0));
+        let (viewers, _) = hub.join(project_id, bob_tab, bob, at(0));
+        let mut expected = vec![alice, bob];
+        expected.sort();
+        assert_eq!(viewers, expected);
+
+        hub.disconnect(first_tab, at(1));
+        hub.leave(project_id, bob_tab);
+        let mut last = None;
+        while let Ok(update) = updates.try_recv() {
+            last = Some(update);
+        }
+        assert_eq!(
+            last,
+            Some(ProjectUpdate::Presence {
+                project_id,
+                viewers: vec![alice]
+            })
+        );
+    }
+
+    #[test]
+    fn soft_lock_should_block_other_users_until_expiry_or_release() {
+        let hub = CollabHub::new();
+        let project_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let request = |user_id| LockRequest {
+            todo_id,
+            project_id,
+            user_id,
+            connection_id: Uuid::new_v4(),
+        };
+        let ttl = TimeDelta::seconds(30);
+
+        let held = hub.acquire_lock(request(alice), at(0), ttl).expect("free todo must lock");
+        assert_eq!(held.expires_at, at(30));
+        let denied = hub.acquire_lock(request(bob), at(10), ttl).expect_err("held lock must block");
+        assert_eq!(denied.user_id, alice);
+        let refreshed = hub.acquire_lock(request(alice), at(20), ttl).expect("owner may refresh");
+        assert_eq!(refreshed.expires_at, at(50));
+        assert!(!hub.release_lock(todo_id, bob, at(21)));
+
+        assert_eq!(hub.expire_locks(at(49)), 0);
+        assert_eq!(hub.expire_locks(at(50)), 1);
+        hub.acquire_lock(request(bob), at(51), ttl).expect("expired lock must be free");
+        assert!(hub.release_lock(todo_id, bob, at(52)));
+        hub.acquire_lock(request(alice), at(53), ttl).expect("released lock must be free");
+    }
+}
@@ -0,0 +1,6 @@
+//! Modul kolaborasi real-time via WebSocket: perubahan todo per project, presence, dan soft lock.
+
+pub mod dto;
+pub mod handler;
+pub mod hub;
+pub mod service;
@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde_json::Value;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::event_stream::service as event_stream_service;
+use crate::modules::outbox::dto::event_snapshot;
+use crate::modules::outbox::entity::Model as OutboxEvent;
+use crate::modules::sharing::service as sharing_service;
+
+use super::dto::{ClientMessage, ErrorCode, PROTOCOL_VERSION, ServerMessage};
+use super::hub::{CollabHub, LockRequest, ProjectUpdate};
+
+/// Masa berlaku soft lock; client memperpanjang dengan mengirim `lock` lagi sebelum habis.
+pub const LOCK_TTL_SECS: u64 = 30;
+const SWEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Error pemrosesan pesan client yang dikirim balik sebagai pesan `error`.
+#[derive(Debug)]
+pub enum CollabError {
+    InvalidMessage(String),
+    UnsupportedVersion(String),
+    NotFound,
+    Forbidden,
+    NotInProject,
+    Database(DbErr),
+}
+
+impl From<DbErr> for CollabError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for CollabError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl CollabError {
+    /// Pesan `error` untuk client; error database hanya dicatat di log.
+    pub fn into_message(self) -> ServerMessage {
+        let (code, message) = match self {
+            Self::InvalidMessage(message) => (ErrorCode::InvalidMessage, message),
+            Self::UnsupportedVersion(message) => (ErrorCode::UnsupportedVersion, message),
+            Self::NotFound => (ErrorCode::NotFound, "project or todo not found".to_string()),
+            Self::Forbidden => (ErrorCode::Forbidden, "insufficient permission".to_string()),
+            Self::NotInProject => (ErrorCode::NotInProject, "only todos in a project can be locked".to_string()),
+            Self::Database(err) => {
+                error!(error = %err, "collaboration database operation failed");
+                (ErrorCode::Internal, "unexpected database error".to_string())
+            }
+        };
+        ServerMessage::Error { code, message }
+    }
+}
+
+/// Loop pembersih soft lock kedaluwarsa untuk dijalankan lewat `tokio::spawn` saat startup.
+pub async fn run_lock_sweeper(hub: CollabHub) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        let expired = hub.expire_locks(Utc::now().fixed_offset());
+        if expired > 0 {
+            info!(expired, "expired soft locks released");
+        }
+    }
+}
+
+/// Validasi versi lalu parsing pesan client.
+pub fn parse_client_message(raw: &str) -> Result<ClientMessage, CollabError> {
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|_| CollabError::InvalidMessage("message must be a json object".to_string()))?;
+    match value.get("v").and_then(Value::as_u64) {
+        Some(version) if version == u64::from(PROTOCOL_VERSION) => {}
+        Some(version) => {
+            return Err(CollabError::UnsupportedVersion(format!(
+                "protocol version {version} is not supported, use {PROTOCOL_VERSION}"
+            )));
+        }
+        None => return Err(CollabError::InvalidMessage("field `v` is required".to_string())),
+    }
+    serde_json::from_value(value).map_err(|err| CollabError::InvalidMessage(format!("invalid message: {err}")))
+}
+
+/// State satu koneksi WebSocket: user pelaku dan project yang sedang dilihat.
+pub struct Session {
+    conn: DatabaseConnection,
+    hub: CollabHub,
+    user_id: Uuid,
+    connection_id: Uuid,
+    projects: HashSet<Uuid>,
+}
+
+impl Session {
+    pub fn new(conn: DatabaseConnection, hub: CollabHub, user_id: Uuid) -> Self {
+        Self {
+            conn,
+            hub,
+            user_id,
+            connection_id: Uuid::new_v4(),
+            projects: HashSet::new(),
+        }
+    }
+
+    /// Pesan pertama setelah koneksi dibuka.
+    pub fn welcome(&self) -> ServerMessage {
+        ServerMessage::Welcome {
+            user_id: self.user_id,
+            lock_ttl_secs: LOCK_TTL_SECS,
+        }
+    }
+
+    /// Memproses satu frame teks dari client dan mengembalikan balasannya.
+    pub async fn handle_text(&mut self, raw: &str) -> ServerMessage {
+        let result = match parse_client_message(raw) {
+            Ok(message) => self.handle(message).await,
+            Err(err) => Err(err),
+        };
+        result.unwrap_or_else(|err| {
+            warn!(user_id = %self.user_id, error = ?err, "collaboration message rejected");
+            err.into_message()
+        })
+    }
+
+    /// Perubahan presence/lock untuk client bila project-nya sedang dilihat koneksi ini.
+    pub fn project_update(&self, update: ProjectUpdate) -> Option<ServerMessage> {
+        if !self.projects.contains(&update.project_id()) {
+            return None;
+        }
+        Some(match update {
+            ProjectUpdate::Presence { project_id, viewers } => ServerMessage::Presence { project_id, viewers },
+            ProjectUpdate::Locks { project_id, locks } => ServerMessage::Locks { project_id, locks },
+        })
+    }
+
+    /// Pesan `todo_changed` bila todo berada di project yang sedang dilihat dan masih bisa diakses.
+    pub async fn todo_change(&self, event: &OutboxEvent) -> Result<Option<ServerMessage>, DbErr> {
+        // Project dicek dulu dari payload agar event project lain tidak memicu query akses.
+        let Some(project_id) = event_snapshot(&event.payload)
+            .and_then(|snapshot| snapshot.project_id)
+            .filter(|project_id| self.projects.contains(project_id))
+        else {
+            return Ok(None);
+        };
+        let Some(data) = event_stream_service::accessible_event(&self.conn, self.user_id, event).await? else {
+            return Ok(None);
+        };
+        Ok(Some(ServerMessage::TodoChanged {
+            project_id,
+            event: event.event_type.clone(),
+            sequence: event.sequence,
+            data,
+        }))
+    }
+
+    /// Melepas presence dan lock milik koneksi ini; dipanggil saat koneksi ditutup.
+    pub fn close(&self) {
+        self.hub.disconnect(self.connection_id, Utc::now().fixed_offset());
+    }
+
+    async fn handle(&mut self, message: ClientMessage) -> Result<ServerMessage, CollabError> {
+        let now = Utc::now().fixed_offset();
+        match message {
+            ClientMessage::Subscribe { project_id } => {
+                sharing_service::authorize_project(&self.conn, self.user_id, project_id, Permission::ProjectRead)
+                    .await?;
+                self.projects.insert(project_id);
+                let (viewers, locks) = self.hub.join(project_id, self.connection_id, self.user_id, now);
+                Ok(ServerMessage::Subscribed {
+                    project_id,
+                    viewers,
+                    locks,
+                })
+            }
+            ClientMessage::Unsubscribe { project_id } => {
+                if self.projects.remove(&project_id) {
+                    self.hub.leave(project_id, self.connection_id);
+                }
+                Ok(ServerMessage::Unsubscribed { project_id })
+            }
+            ClientMessage::Lock { todo_id } => {
+                let todo =
+                    sharing_service::authorize_todo(&self.conn, self.user_id, todo_id, Permission::TodoWrite).await?;
+                let project_id = todo.project_id.ok_or(CollabError::NotInProject)?;
+                let request = LockRequest {
+                    todo_id,
+                    project_id,
+                    user_id: self.user_id,
+                    connection_id: self.connection_id,
+                };
+                let ttl = TimeDelta::seconds(LOCK_TTL_SECS as i64);
+                Ok(match self.hub.acquire_lock(request, now, ttl) {
+                    Ok(lock) => ServerMessage::LockAcquired { lock },
+                    Err(lock) => ServerMessage::LockDenied { todo_id, lock },
+                })
+            }
+            ClientMessage::Unlock { todo_id } => {
+                self.hub.release_lock(todo_id, self.user_id, now);
+                Ok(ServerMessage::LockReleased { todo_id })
+            }
+        }
+    }
+}
+
+// Modul tests unit untuk parsing pesan versi protokol.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_client_message_should_require_supported_version() {
+        let project_id = Uuid::new_v4();
+        let raw = format!(r#"{{"v":1,"type":"subscribe","project_id":"{project_id}"}}"#);
+        assert_eq!(
+            parse_client_message(&raw).unwrap(),
+            ClientMessage::Subscribe { project_id }
+        );
+
+        let future = format!(r#"{{"v":2,"type":"subscribe","project_id":"{project_id}"}}"#);
+        assert!(matches!(parse_client_message(&future), Err(CollabError::UnsupportedVersion(_))));
+        for raw in [
+            r#"{"type":"unlock","todo_id":"00000000-0000-0000-0000-000000000001"}"#,
+            r#"{"v":1,"type":"shout"}"#,
+            "not json",
+        ] {
+            assert!(matches!(parse_client_message(raw), Err(CollabError::InvalidMessage(_))));
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity;
+
+/// Payload request untuk membuat komentar baru.
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentRequest {
+    pub body: String,
+}
+
+/// Payload request untuk mengubah isi komentar.
+#[derive(Debug, Deserialize)]
+pub struct UpdateCommentRequest {
+    pub body: String,
+}
+
+/// Bentuk data komentar yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for CommentResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            author_id: value.author_id,
+            body: value.body,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, patch},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{CommentResponse, CreateCommentRequest, UpdateCommentRequest};
+use super::service::{self, CommentError};
+
+/// Registrasi route komentar, di-mount di bawah prefix `/todos`.
+///
+/// - `POST /{id}/comments` buat komentar
+/// - `GET /{id}/comments` daftar komentar
+/// - `PATCH /{id}/comments/{comment_id}` ubah komentar (penulis saja)
+/// - `DELETE /{id}/comments/{comment_id}` hapus komentar (penulis saja)
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/comments", get(list_comments).post(create_comment))
+        .route(
+            "/{id}/comments/{comment_id}",
+            patch(update_comment).delete(delete_comment),
+        )
+}
+
+/// POST `/todos/{id}/comments`
+///
+/// Input: `CreateCommentRequest`.
+/// Output: `201 Created` + `SuccessResponse<CommentResponse>`.
+pub async fn create_comment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<CreateCommentRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<CommentResponse>>), ApiError> {
+    let comment = service::create_comment(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_comment_error)?;
+    info!(todo_id = %todo_id, comment_id = %comment.id, "comment created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: comment.into() })))
+}
+
+/// GET `/todos/{id}/comments`
+/// Output: `200 OK` + daftar komentar urut terlama.
+pub async fn list_comments(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<CommentResponse>>>, ApiError> {
+    let comments = service::list_comments(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_comment_error)?;
+    info!(todo_id = %todo_id, count = comments.len(), "comments listed");
+    let data = comments.into_iter().map(CommentResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// PATCH `/todos/{id}/comments/{comment_id}`
+/// Input: `UpdateCommentRequest`.
+/// Output: `200 OK` dengan komentar terbaru, `403` bila bukan penulis.
+pub async fn update_comment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateCommentRequest>,
+) -> Result<Json<SuccessResponse<CommentResponse>>, ApiError> {
+    let comment = service::update_comment(&state.db, user.id, todo_id, comment_id, payload)
+        .await
+        .map_err(map_comment_error)?;
+    info!(todo_id = %todo_id, comment_id = %comment_id, "comment updated");
+    Ok(Json(SuccessResponse { data: comment.into() }))
+}
+
+/// DELETE `/todos/{id}/comments/{comment_id}`
+/// Output: `204 No Content`, `403` bila bukan penulis.
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_comment(&state.db, user.id, todo_id, comment_id)
+        .await
+        .map_err(map_comment_error)?;
+    info!(todo_id = %todo_id, comment_id = %comment_id, "comment deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain komentar ke `ApiError`.
+fn map_comment_error(err: CommentError) -> ApiError {
+    match err {
+        CommentError::Validation(message) => {
+            warn!(reason = %message, "comment validation failed");
+            ApiError::bad_request(message)
+        }
+        CommentError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        CommentError::NotFound => {
+            warn!("comment not found");
+            ApiError::not_found("comment not found")
+        }
+        CommentError::TodoForbidden => {
+            warn!("comment write rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        CommentError::Forbidden => {
+            warn!("comment modification by non-author rejected");
+            ApiError::forbidden("only the comment author can modify this comment")
+        }
+        CommentError::Database(db_err) => {
+            error!(error = %db_err, "comment database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint komentar terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn comment_thread_flow_should_succeed() {
+        let app = build_test_app().await;
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "discuss me" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        let created = send(
+            &app,
+            "POST",
+            format!("/todos/{todo_id}/comments"),
+            Some(json!({ "body": "  looks **good**  " })),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let created = read_json(created).await;
+        assert_eq!(created["data"]["body"], "looks **good**");
+        let comment_id = created["data"]["id"].as_str().expect("comment id must exist").to_string();
+
+        let detail = read_json(
+            send(&app, "GET", format!("/todos/{todo_id}?include=comment_count"), None).await,
+        )
+        .await;
+        assert_eq!(detail["data"]["comment_count"], 1);
+
+        let edited = send(
+            &app,
+            "PATCH",
+            format!("/todos/{todo_id}/comments/{comment_id}"),
+            Some(json!({ "body": "edited" })),
+        )
+        .await;
+        assert_eq!(edited.status(), StatusCode::OK);
+
+        let empty = send(
+            &app,
+            "POST",
+            format!("/todos/{todo_id}/comments"),
+            Some(json!({ "body": "   " })),
+        )
+        .await;
+        assert_eq!(empty.status(), StatusCode::BAD_REQUEST);
+
+        let deleted = send(&app, "DELETE", format!("/todos/{todo_id}/comments/{comment_id}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+
+        let list = read_json(send(&app, "GET", format!("/todos/{todo_id}/comments"), None).await).await;
+        assert_eq!(list["data"], json!([]));
+    }
+}
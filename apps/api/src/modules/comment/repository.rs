@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use uuid::Uuid;
+
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+
+/// Menyimpan komentar baru pada todo.
+pub async fn create_comment(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    author_id: Uuid,
+    body: String,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        todo_id: Set(todo_id),
+        author_id: Set(author_id),
+        body: Set(body),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil seluruh komentar satu todo, urut terlama lebih dulu.
+pub async fn list_comments(conn: &DatabaseConnection, todo_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil satu komentar berdasarkan id dalam scope todo tertentu.
+pub async fn find_comment_by_id(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    comment_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::Id.eq(comment_id))
+        .one(conn)
+        .await
+}
+
+/// Mengganti isi komentar dan memperbarui `updated_at`.
+pub async fn update_comment(
+    conn: &DatabaseConnection,
+    existing: Model,
+    body: String,
+) -> Result<Model, DbErr> {
+    let mut active: entity::ActiveModel = existing.into();
+    active.body = Set(body);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus komentar berdasarkan id.
+pub async fn delete_comment(conn: &DatabaseConnection, comment_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(comment_id).exec(conn).await?;
+    Ok(())
+}
+
+/// Menghitung jumlah komentar untuk banyak todo sekaligus.
+/// Todo tanpa komentar tidak muncul di map (anggap `0`).
+pub async fn count_by_todo_ids(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, u64>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows: Vec<(Uuid, i64)> = Entity::find()
+        .select_only()
+        .column(Column::TodoId)
+        .column_as(Column::Id.count(), "count")
+        .filter(Column::TodoId.is_in(todo_ids.iter().copied()))
+        .group_by(Column::TodoId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(todo_id, count)| (todo_id, u64::try_from(count).unwrap_or_default()))
+        .collect())
+}
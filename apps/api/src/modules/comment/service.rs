@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use sea_orm::{DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::notification::service as notification_service;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::service::MAX_DESC_LEN;
+
+use super::dto::{CreateCommentRequest, UpdateCommentRequest};
+use super::entity::Model;
+use super::repository;
+
+/// Batas panjang komentar disamakan dengan batas `desc` todo.
+const MAX_BODY_LEN: usize = MAX_DESC_LEN;
+
+/// Error domain komentar yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum CommentError {
+    Validation(String),
+    TodoNotFound,
+    NotFound,
+    /// Role user pada todo kurang untuk menulis komentar.
+    TodoForbidden,
+    /// Hanya penulis komentar yang boleh mengubah atau menghapusnya.
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for CommentError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for CommentError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::TodoForbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Menambahkan komentar ke todo; butuh `comment.write`.
+/// Pemilik dan assignee todo (selain penulis) mendapat notifikasi in-app.
+pub async fn create_comment(
+    conn: &DatabaseConnection,
+    author_id: Uuid,
+    todo_id: Uuid,
+    request: CreateCommentRequest,
+) -> Result<Model, CommentError> {
+    let todo = sharing_service::authorize_todo(conn, author_id, todo_id, Permission::CommentWrite).await?;
+    let body = normalize_body(request.body)?;
+    let comment = repository::create_comment(conn, todo_id, author_id, body)
+        .await
+        .map_err(CommentError::from)?;
+    notification_service::publish(conn, notification_service::comment_notifications(&todo, author_id)).await;
+    Ok(comment)
+}
+
+/// Mengambil thread komentar satu todo; butuh `todo.read`.
+pub async fn list_comments(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Vec<Model>, CommentError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    repository::list_comments(conn, todo_id)
+        .await
+        .map_err(CommentError::from)
+}
+
+/// Mengubah isi komentar; hanya boleh dilakukan oleh penulisnya.
+pub async fn update_comment(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    comment_id: Uuid,
+    request: UpdateCommentRequest,
+) -> Result<Model, CommentError> {
+    let existing = find_authored_comment(conn, actor_id, todo_id, comment_id).await?;
+    let body = normalize_body(request.body)?;
+    repository::update_comment(conn, existing, body)
+        .await
+        .map_err(CommentError::from)
+}
+
+/// Menghapus komentar; hanya boleh dilakukan oleh penulisnya.
+pub async fn delete_comment(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    comment_id: Uuid,
+) -> Result<(), CommentError> {
+    let existing = find_authored_comment(conn, actor_id, todo_id, comment_id).await?;
+    repository::delete_comment(conn, existing.id)
+        .await
+        .map_err(CommentError::from)
+}
+
+/// Jumlah komentar per todo untuk kebutuhan `?include=comment_count`.
+pub async fn count_comments(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashMap<Uuid, u64>, DbErr> {
+    repository::count_by_todo_ids(conn, todo_ids).await
+}
+
+/// Normalisasi dan validasi isi komentar markdown.
+pub fn normalize_body(raw_body: String) -> Result<String, CommentError> {
+    let body = raw_body.trim();
+    if body.is_empty() {
+        return Err(CommentError::Validation("body must not be empty".to_string()));
+    }
+    if body.len() > MAX_BODY_LEN {
+        return Err(CommentError::Validation(format!(
+            "body must be at most {MAX_BODY_LEN} characters"
+        )));
+    }
+    Ok(body.to_string())
+}
+
+// Mengambil komentar lalu memastikan pelaku masih bisa melihat todo dan adalah penulisnya.
+async fn find_authored_comment(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    comment_id: Uuid,
+) -> Result<Model, CommentError> {
+    sharing_service::authorize_todo(conn, actor_id, todo_id, Permission::TodoRead).await?;
+    let comment = repository::find_comment_by_id(conn, todo_id, comment_id)
+        .await?
+        .ok_or(CommentError::NotFound)?;
+    if comment.author_id != actor_id {
+        return Err(CommentError::Forbidden);
+    }
+    Ok(comment)
+}
+
+// Modul tests untuk unit testing validasi isi komentar.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_body_should_trim_markdown() {
+        let body = normalize_body("  **done** \n".to_string()).expect("body should be valid");
+        assert_eq!(body, "**done**");
+    }
+
+    #[test]
+    fn normalize_body_should_fail_when_too_long() {
+        let result = normalize_body("a".repeat(MAX_BODY_LEN + 1));
+        assert!(matches!(result, Err(CommentError::Validation(_))));
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::todo::entity::Model as Todo;
+
+use super::entity;
+
+/// Payload request untuk menandai todo diblokir oleh todo lain.
+#[derive(Debug, Deserialize)]
+pub struct CreateDependencyRequest {
+    pub blocked_by_id: Uuid,
+}
+
+/// Bentuk data dependency yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct DependencyResponse {
+    pub todo_id: Uuid,
+    pub blocked_by_id: Uuid,
+    /// Status selesai todo pemblokir; `false` berarti masih memblokir.
+    pub blocker_completed: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl DependencyResponse {
+    /// Mapping model database + todo pemblokir ke response HTTP.
+    pub fn new(value: entity::Model, blocker: &Todo) -> Self {
+        Self {
+            todo_id: value.todo_id,
+            blocked_by_id: value.blocked_by_id,
+            blocker_completed: blocker.iscompleted,
+            created_by: value.created_by,
+            created_at: value.created_at,
+        }
+    }
+}
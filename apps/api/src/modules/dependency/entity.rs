@@ -0,0 +1,21 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `todo_dependencies`.
+/// Satu baris berarti `todo_id` diblokir oleh `blocked_by_id`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "todo_dependencies")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub todo_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub blocked_by_id: Uuid,
+    pub created_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
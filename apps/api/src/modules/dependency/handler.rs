@@ -0,0 +1,302 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{CreateDependencyRequest, DependencyResponse};
+use super::service::{self, DependencyError};
+
+/// Registrasi route dependency, di-mount di bawah prefix `/todos`.
+///
+/// - `POST /{id}/dependencies` tandai todo diblokir oleh todo lain
+/// - `GET /{id}/dependencies` daftar pemblokir todo
+/// - `DELETE /{id}/dependencies/{blocked_by_id}` hapus relasi
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/dependencies", get(list_dependencies).post(create_dependency))
+        .route("/{id}/dependencies/{blocked_by_id}", delete(delete_dependency))
+}
+
+/// POST `/todos/{id}/dependencies`
+///
+/// Input: `CreateDependencyRequest`.
+/// Output: `201 Created` + `SuccessResponse<DependencyResponse>`,
+/// `409` bila relasi sudah ada atau membentuk siklus.
+pub async fn create_dependency(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<CreateDependencyRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<DependencyResponse>>), ApiError> {
+    let (dependency, blocker) = service::add_dependency(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_dependency_error)?;
+    info!(todo_id = %todo_id, blocked_by_id = %blocker.id, "todo dependency created");
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: DependencyResponse::new(dependency, &blocker),
+        }),
+    ))
+}
+
+/// GET `/todos/{id}/dependencies`
+/// Output: `200 OK` + daftar pemblokir urut paling lama dibuat.
+pub async fn list_dependencies(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<DependencyResponse>>>, ApiError> {
+    let dependencies = service::list_dependencies(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_dependency_error)?;
+    info!(todo_id = %todo_id, count = dependencies.len(), "todo dependencies listed");
+    let data = dependencies
+        .into_iter()
+        .map(|(dependency, blocker)| DependencyResponse::new(dependency, &blocker))
+        .collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// DELETE `/todos/{id}/dependencies/{blocked_by_id}`
+/// Output: `204 No Content`, `404` bila relasi tidak ada.
+pub async fn delete_dependency(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, blocked_by_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::remove_dependency(&state.db, user.id, todo_id, blocked_by_id)
+        .await
+        .map_err(map_dependency_error)?;
+    info!(todo_id = %todo_id, blocked_by_id = %blocked_by_id, "todo dependency deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain dependency ke `ApiError`.
+fn map_dependency_error(err: DependencyError) -> ApiError {
+    match err {
+        DependencyError::Validation(message) => {
+            warn!(reason = %message, "dependency validation failed");
+            ApiError::bad_request(message)
+        }
+        DependencyError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        DependencyError::NotFound => {
+            warn!("dependency not found");
+            ApiError::not_found("dependency not found")
+        }
+        DependencyError::Forbidden => {
+            warn!("dependency change rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        DependencyError::Conflict(message) => {
+            warn!(reason = %message, "dependency conflict");
+            ApiError::conflict(message)
+        }
+        DependencyError::Database(db_err) => {
+            error!(error = %db_err, "dependency database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint dependency terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    async fn create_todo(app: &Router, title: &str) -> String {
+        let todo = read_json(send(app, "POST", "/todos".to_string(), Some(json!({ "title": title }))).await).await;
+        todo["data"]["id"].as_str().expect("todo id must exist").to_string()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn dependency_flow_should_block_completion_and_reject_cycles() {
+        let app = build_test_app().await;
+        let first = create_todo(&app, "design").await;
+        let second = create_todo(&app, "build").await;
+        let third = create_todo(&app, "ship").await;
+
+        let created = send(
+            &app,
+            "POST",
+            format!("/todos/{second}/dependencies"),
+            Some(json!({ "blocked_by_id": first })),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        assert_eq!(read_json(created).await["data"]["blocker_completed"], false);
+        send(
+            &app,
+            "POST",
+            format!("/todos/{third}/dependencies"),
+            Some(json!({ "blocked_by_id": second })),
+        )
+        .await;
+
+        let cycle = send(
+            &app,
+            "POST",
+            format!("/todos/{first}/dependencies"),
+            Some(json!({ "blocked_by_id": third })),
+        )
+        .await;
+        assert_eq!(cycle.status(), StatusCode::CONFLICT);
+        let itself = send(
+            &app,
+            "POST",
+            format!("/todos/{first}/dependencies"),
+            Some(json!({ "blocked_by_id": first })),
+        )
+        .await;
+        assert_eq!(itself.status(), StatusCode::BAD_REQUEST);
+
+        let detail = read_json(send(&app, "GET", format!("/todos/{second}"), None).await).await;
+        assert_eq!(detail["data"]["is_blocked"], true);
+
+        let blocked = send(
+            &app,
+            "PATCH",
+            format!("/todos/{second}"),
+            Some(json!({ "iscompleted": true })),
+        )
+        .await;
+        assert_eq!(blocked.status(), StatusCode::CONFLICT);
+        let blocked = read_json(blocked).await;
+        assert_eq!(blocked["error"]["details"]["blocking_ids"], json!([first]));
+
+        send(&app, "PATCH", format!("/todos/{first}"), Some(json!({ "iscompleted": true }))).await;
+        let unblocked = read_json(send(&app, "GET", format!("/todos/{second}"), None).await).await;
+        assert_eq!(unblocked["data"]["is_blocked"], false);
+        let completed = send(
+            &app,
+            "PATCH",
+            format!("/todos/{second}"),
+            Some(json!({ "iscompleted": true })),
+        )
+        .await;
+        assert_eq!(completed.status(), StatusCode::OK);
+
+        let deleted = send(&app, "DELETE", format!("/todos/{third}/dependencies/{second}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+        let list = read_json(send(&app, "GET", format!("/todos/{third}/dependencies"), None).await).await;
+        assert_eq!(list["data"], json!([]));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn concurrent_opposite_dependencies_should_not_both_succeed() {
+        let app = build_test_app().await;
+        for _ in 0..5 {
+            let first = create_todo(&app, "left").await;
+            let second = create_todo(&app, "right").await;
+
+            let (forward, backward) = tokio::join!(
+                send(
+                    &app,
+                    "POST",
+                    format!("/todos/{first}/dependencies"),
+                    Some(json!({ "blocked_by_id": second })),
+                ),
+                send(
+                    &app,
+                    "POST",
+                    format!("/todos/{second}/dependencies"),
+                    Some(json!({ "blocked_by_id": first })),
+                ),
+            );
+            let mut statuses = [forward.status(), backward.status()];
+            statuses.sort();
+            assert_eq!(statuses, [StatusCode::CREATED, StatusCode::CONFLICT]);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn concurrent_reopen_and_complete_should_not_bypass_open_blocker() {
+        let app = build_test_app().await;
+        for _ in 0..5 {
+            let blocker = create_todo(&app, "review").await;
+            let todo = create_todo(&app, "merge").await;
+            send(&app, "PATCH", format!("/todos/{todo}"), Some(json!({ "iscompleted": true }))).await;
+            // Relasi ke todo yang sudah selesai diizinkan; todo baru terblokir setelah dibuka lagi.
+            let created = send(
+                &app,
+                "POST",
+                format!("/todos/{todo}/dependencies"),
+                Some(json!({ "blocked_by_id": blocker })),
+            )
+            .await;
+            assert_eq!(created.status(), StatusCode::CREATED);
+
+            let (reopened, _) = tokio::join!(
+                send(&app, "PATCH", format!("/todos/{todo}"), Some(json!({ "iscompleted": false }))),
+                send(&app, "PATCH", format!("/todos/{todo}"), Some(json!({ "iscompleted": true }))),
+            );
+            assert_eq!(reopened.status(), StatusCode::OK);
+            let detail = read_json(send(&app, "GET", format!("/todos/{todo}"), None).await).await;
+            assert_eq!(detail["data"]["iscompleted"], false);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    QueryFilter, QueryOrder, QuerySelect, Set, Statement,
+    sea_query::{Query, SelectStatement},
+};
+use uuid::Uuid;
+
+use crate::modules::todo::entity as todo;
+
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Mengambil advisory lock transaksi untuk graf dependency satu workspace (`None` = ruang
+/// pribadi), agar cek siklus dan insert relasi tidak balapan dengan penambahan relasi lain.
+/// Lock dilepas otomatis saat transaksi `conn` selesai.
+pub async fn lock_dependency_graph<C: ConnectionTrait>(conn: &C, workspace_id: Option<Uuid>) -> Result<(), DbErr> {
+    let key = format!("todo_dependencies:{}", workspace_id.unwrap_or_else(Uuid::nil));
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))",
+        [key.into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Menyimpan relasi `todo_id` diblokir oleh `blocked_by_id`.
+pub async fn create_dependency<C: ConnectionTrait>(
+    conn: &C,
+    todo_id: Uuid,
+    blocked_by_id: Uuid,
+    created_by: Uuid,
+) -> Result<Model, DbErr> {
+    ActiveModel {
+        todo_id: Set(todo_id),
+        blocked_by_id: Set(blocked_by_id),
+        created_by: Set(created_by),
+        created_at: Set(Utc::now().fixed_offset()),
+    }
+    .insert(conn)
+    .await
+}
+
+/// Mengambil satu relasi dependency.
+pub async fn find_dependency<C: ConnectionTrait>(
+    conn: &C,
+    todo_id: Uuid,
+    blocked_by_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id((todo_id, blocked_by_id)).one(conn).await
+}
+
+/// Mengambil seluruh pemblokir satu todo, urut paling lama dibuat.
+pub async fn list_blockers(conn: &DatabaseConnection, todo_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil relasi dependency milik banyak todo sekaligus (dipakai untuk menelusuri graf).
+pub async fn list_blockers_of<C: ConnectionTrait>(conn: &C, todo_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Entity::find()
+        .filter(Column::TodoId.is_in(todo_ids.iter().copied()))
+        .all(conn)
+        .await
+}
+
+/// Menghapus relasi dependency; `false` bila relasi tidak ada.
+pub async fn delete_dependency(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    blocked_by_id: Uuid,
+) -> Result<bool, DbErr> {
+    let result = Entity::delete_by_id((todo_id, blocked_by_id)).exec(conn).await?;
+    Ok(result.rows_affected > 0)
+}
+
+/// Id pemblokir satu todo yang belum selesai.
+pub async fn find_open_blocker_ids<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Vec<Uuid>, DbErr> {
+    Entity::find()
+        .select_only()
+        .column(Column::BlockedById)
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::BlockedById.in_subquery(open_todo_ids_query()))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .into_tuple()
+        .all(conn)
+        .await
+}
+
+/// Id todo (dari `todo_ids`) yang masih punya pemblokir belum selesai.
+pub async fn find_blocked_todo_ids(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+) -> Result<HashSet<Uuid>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let rows: Vec<Uuid> = Entity::find()
+        .select_only()
+        .column(Column::TodoId)
+        .distinct()
+        .filter(Column::TodoId.is_in(todo_ids.iter().copied()))
+        .filter(Column::BlockedById.in_subquery(open_todo_ids_query()))
+        .into_tuple()
+        .all(conn)
+        .await?;
+    Ok(rows.into_iter().collect())
+}
+
+// Subquery id todo yang belum selesai.
+fn open_todo_ids_query() -> SelectStatement {
+    Query::select()
+        .column(todo::Column::Id)
+        .from(todo::Entity)
+        .and_where(todo::Column::Iscompleted.eq(false))
+        .to_owned()
+}
@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+
+use super::dto::CreateDependencyRequest;
+use super::entity::Model;
+use super::repository;
+
+/// Error domain dependency yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum DependencyError {
+    Validation(String),
+    TodoNotFound,
+    NotFound,
+    Forbidden,
+    /// Relasi sudah ada atau akan membentuk siklus.
+    Conflict(String),
+    Database(DbErr),
+}
+
+impl From<DbErr> for DependencyError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for DependencyError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Menandai `todo_id` diblokir oleh `blocked_by_id`; butuh `todo.write` pada todo yang diblokir
+/// dan `todo.read` pada pemblokir. Keduanya harus berada di workspace yang sama dan relasi baru
+/// tidak boleh membentuk siklus. Cek siklus dan insert berjalan di satu transaksi yang memegang
+/// lock graf workspace, sehingga dua relasi berlawanan yang ditambah bersamaan tidak bisa
+/// sama-sama lolos. Mengembalikan relasi baru beserta todo pemblokirnya.
+pub async fn add_dependency(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    request: CreateDependencyRequest,
+) -> Result<(Model, Todo), DependencyError> {
+    let todo = sharing_service::authorize_todo(conn, actor_id, todo_id, Permission::TodoWrite).await?;
+    if request.blocked_by_id == todo_id {
+        return Err(DependencyError::Validation("todo cannot block itself".to_string()));
+    }
+    let blocker = match sharing_service::authorize_todo(conn, actor_id, request.blocked_by_id, Permission::TodoRead).await
+    {
+        Ok(blocker) => blocker,
+        Err(AccessError::NotFound) => {
+            return Err(DependencyError::Validation("blocked_by_id does not exist".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if blocker.workspace_id != todo.workspace_id {
+        return Err(DependencyError::Validation(
+            "blocked_by_id belongs to a different workspace".to_string(),
+        ));
+    }
+    let txn = conn.begin().await?;
+    repository::lock_dependency_graph(&txn, todo.workspace_id).await?;
+    if repository::find_dependency(&txn, todo_id, blocker.id).await?.is_some() {
+        return Err(DependencyError::Conflict("dependency already exists".to_string()));
+    }
+    let edges = load_reachable_edges(&txn, blocker.id).await?;
+    if creates_cycle(todo_id, blocker.id, &edges) {
+        return Err(DependencyError::Conflict("dependency would create a cycle".to_string()));
+    }
+    let dependency = repository::create_dependency(&txn, todo_id, blocker.id, actor_id).await?;
+    txn.commit().await?;
+    Ok((dependency, blocker))
+}
+
+/// Daftar pemblokir satu todo beserta todo pemblokirnya; butuh `todo.read`.
+pub async fn list_dependencies(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Vec<(Model, Todo)>, DependencyError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    let dependencies = repository::list_blockers(conn, todo_id).await?;
+    let blocker_ids: Vec<Uuid> = dependencies.iter().map(|dependency| dependency.blocked_by_id).collect();
+    let blockers = todo_repository::find_todos_by_ids(conn, &blocker_ids).await?;
+    Ok(dependencies
+        .into_iter()
+        .filter_map(|dependency| {
+            let blocker = blockers.iter().find(|todo| todo.id == dependency.blocked_by_id)?.clone();
+            Some((dependency, blocker))
+        })
+        .collect())
+}
+
+/// Menghapus relasi dependency; butuh `todo.write` pada todo yang diblokir.
+pub async fn remove_dependency(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    blocked_by_id: Uuid,
+) -> Result<(), DependencyError> {
+    sharing_service::authorize_todo(conn, actor_id, todo_id, Permission::TodoWrite).await?;
+    if !repository::delete_dependency(conn, todo_id, blocked_by_id).await? {
+        return Err(DependencyError::NotFound);
+    }
+    Ok(())
+}
+
+/// Mengunci graf dependency workspace di transaksi `conn`, agar cek pemblokir saat menyelesaikan
+/// todo tidak balapan dengan penambahan relasi baru.
+pub async fn lock_graph<C: ConnectionTrait>(conn: &C, workspace_id: Option<Uuid>) -> Result<(), DbErr> {
+    repository::lock_dependency_graph(conn, workspace_id).await
+}
+
+/// Id pemblokir todo yang belum selesai (dipakai aturan penyelesaian todo).
+pub async fn open_blocker_ids<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Vec<Uuid>, DbErr> {
+    repository::find_open_blocker_ids(conn, todo_id).await
+}
+
+/// Id todo yang masih diblokir, untuk mengisi `is_blocked` di response todo.
+pub async fn blocked_todo_ids(conn: &DatabaseConnection, todo_ids: &[Uuid]) -> Result<HashSet<Uuid>, DbErr> {
+    repository::find_blocked_todo_ids(conn, todo_ids).await
+}
+
+/// Apakah menambah relasi `todo_id` diblokir oleh `blocked_by_id` membentuk siklus,
+/// yaitu `todo_id` sudah (tidak langsung) memblokir `blocked_by_id`.
+/// `edges` berisi pasangan `(todo_id, blocked_by_id)` yang sudah ada.
+pub fn creates_cycle(todo_id: Uuid, blocked_by_id: Uuid, edges: &[(Uuid, Uuid)]) -> bool {
+    let mut blockers_of: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (todo, blocker) in edges {
+        blockers_of.entry(*todo).or_default().push(*blocker);
+    }
+    let mut visited = HashSet::new();
+    let mut stack = vec![blocked_by_id];
+    while let Some(current) = stack.pop() {
+        if current == todo_id {
+            return true;
+        }
+        if visited.insert(current)
+            && let Some(next) = blockers_of.get(&current)
+        {
+            stack.extend(next.iter().copied());
+        }
+    }
+    false
+}
+
+// Menelusuri graf "blocked by" mulai dari `start` per level dan mengembalikan seluruh edge
+// yang terjangkau.
+async fn load_reachable_edges<C: ConnectionTrait>(conn: &C, start: Uuid) -> Result<Vec<(Uuid, Uuid)>, DbErr> {
+    let mut edges = Vec::new();
+    let mut visited = HashSet::from([start]);
+    let mut frontier = vec![start];
+    while !frontier.is_empty() {
+        let found = repository::list_blockers_of(conn, &frontier).await?;
+        frontier = Vec::new();
+        for dependency in found {
+            if visited.insert(dependency.blocked_by_id) {
+                frontier.push(dependency.blocked_by_id);
+            }
+            edges.push((dependency.todo_id, dependency.blocked_by_id));
+        }
+    }
+    Ok(edges)
+}
+
+// Modul tests untuk unit testing deteksi siklus.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_cycle_should_detect_direct_and_transitive_cycles() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // b diblokir a, c diblokir b.
+        let edges = [(b, a), (c, b)];
+        assert!(creates_cycle(a, b, &edges));
+        assert!(creates_cycle(a, c, &edges));
+        assert!(!creates_cycle(c, a, &edges));
+    }
+
+    #[test]
+    fn creates_cycle_should_allow_diamonds() {
+        let (a, b, c, d) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // d diblokir b dan c, keduanya diblokir a.
+        let edges = [(b, a), (c, a), (d, b), (d, c)];
+        assert!(!creates_cycle(d, a, &edges));
+        assert!(creates_cycle(a, d, &edges));
+    }
+}
@@ -0,0 +1,26 @@
+use serde_json::Value;
+
+/// Pesan yang dikirim ke client stream, belum dalam format SSE.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamMessage {
+    /// Perubahan todo; `position` menjadi `id` SSE untuk `Last-Event-ID`.
+    Todo {
+        position: i64,
+        event_type: String,
+        data: Value,
+    },
+    /// Terlalu banyak event terlewat untuk diputar ulang; client perlu memuat ulang daftar todo
+    /// lalu melanjutkan dari `position` ini.
+    Reset { position: i64 },
+}
+
+impl StreamMessage {
+    /// Posisi resume stream setelah pesan ini, dipakai sebagai `id` SSE. Posisi berupa watermark
+    /// id transaksi: seluruh event dari transaksi di bawahnya sudah terkirim.
+    pub fn position(&self) -> i64 {
+        match self {
+            Self::Todo { position, .. } | Self::Reset { position } => *position,
+        }
+    }
+}
+
@@ -0,0 +1,295 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    Router,
+    extract::State,
+    http::{HeaderMap, HeaderName, HeaderValue},
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::get,
+};
+use futures_util::StreamExt;
+use tracing::{error, info, warn};
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+
+use super::dto::StreamMessage;
+use super::service::{self, EventStreamError};
+
+/// Header resume standar SSE yang dikirim ulang browser saat tersambung kembali.
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+/// Jeda komentar heartbeat agar proxy tidak menutup koneksi yang sepi.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Registrasi route stream perubahan todo, di-merge ke router `/todos`.
+///
+/// - `GET /events` stream SSE perubahan todo di workspace aktif
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/events", get(stream_todo_events))
+}
+
+/// GET `/todos/events`
+/// Header: `Last-Event-ID` opsional untuk melanjutkan setelah event tertentu.
+/// Output: `200 OK` `text/event-stream`; tiap event ber-`id` posisi resume dan ber-`event`
+/// jenis perubahan (`todo.created`, ..., atau `reset`), diselingi komentar heartbeat.
+pub async fn stream_todo_events(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError> {
+    let last_event_id = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .map(|value| service::parse_last_event_id(value.to_str().unwrap_or_default()))
+        .transpose()
+        .map_err(map_stream_error)?;
+    let messages = service::open_stream(state.db.clone(), &state.todo_events, user.id, user.workspace_id, last_event_id)
+        .await
+        .map_err(map_stream_error)?;
+    info!(user_id = %user.id, last_event_id = ?last_event_id, "todo event stream opened");
+
+    let events = messages.map(|message| Ok::<_, Infallible>(sse_event(message)));
+    let keep_alive = KeepAlive::new().interval(HEARTBEAT_INTERVAL).text("heartbeat");
+    // Nginx dan proxy sejenis tidak boleh menahan stream di buffer.
+    let no_buffering = [(HeaderName::from_static("x-accel-buffering"), HeaderValue::from_static("no"))];
+    Ok((no_buffering, Sse::new(events).keep_alive(keep_alive)))
+}
+
+// Format SSE: `id` = posisi resume, `event` = jenis, `data` = JSON.
+fn sse_event(message: StreamMessage) -> Event {
+    let event = Event::default().id(message.position().to_string());
+    match message {
+        StreamMessage::Todo { event_type, data, .. } => event.event(event_type).data(data.to_string()),
+        StreamMessage::Reset { .. } => event.event("reset").data("{}"),
+    }
+}
+
+fn map_stream_error(err: EventStreamError) -> ApiError {
+    match err {
+        EventStreamError::Validation(message) => {
+            warn!(reason = %message, "todo event stream validation failed");
+            ApiError::bad_request(message)
+        }
+        EventStreamError::Database(db_err) => {
+            error!(error = %db_err, "todo event stream database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi stream SSE terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, BodyDataStream},
+        http::{Request, StatusCode, header},
+    };
+    use sea_orm::{Database, DatabaseConnection, TransactionTrait};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::modules::history::dto::AuditContext;
+    use crate::modules::outbox::dto::TodoEvent;
+    use crate::modules::outbox::service as outbox_service;
+    use crate::modules::todo::dto::NewTodo;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // User acak agar stream tidak menerima event dari data test lain; listener ikut dijalankan.
+    async fn build_test_app() -> (Router, DatabaseConnection, Uuid, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let owner_id = Uuid::new_v4();
+        let stranger_id = Uuid::new_v4();
+        for user_id in [owner_id, stranger_id] {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let state = AppState::for_tests(db.clone(), owner_id);
+        tokio::spawn(service::run_listener(db.clone(), state.todo_events.clone()));
+        let app = Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(state);
+        (app, db, owner_id, stranger_id)
+    }
+
+    async fn send(
+        app: &Router,
+        as_user: Option<Uuid>,
+        method: &str,
+        uri: String,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(user_id) = as_user {
+            builder = builder.header(USER_ID_HEADER, user_id.to_string());
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn open_stream(app: &Router, last_event_id: Option<&str>) -> axum::response::Response {
+        let mut builder = Request::builder().uri("/todos/events");
+        if let Some(last_event_id) = last_event_id {
+            builder = builder.header(LAST_EVENT_ID_HEADER, last_event_id);
+        }
+        let request = builder.body(Body::empty()).expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    // Frame SSE berikutnya, melewati komentar heartbeat.
+    async fn next_frame(body: &mut BodyDataStream, buffer: &mut String) -> String {
+        loop {
+            if let Some(end) = buffer.find("\n\n") {
+                let frame = buffer[..end].to_string();
+                buffer.drain(..end + 2);
+                if frame.lines().all(|line| line.starts_with(':')) {
+                    continue;
+                }
+                return frame;
+            }
+            let chunk = tokio::time::timeout(Duration::from_secs(10), body.next())
+                .await
+                .expect("event must arrive in time")
+                .expect("stream must stay open")
+                .expect("chunk must be readable");
+            buffer.push_str(std::str::from_utf8(&chunk).expect("stream must be utf-8"));
+        }
+    }
+
+    fn field<'a>(frame: &'a str, name: &str) -> &'a str {
+        frame
+            .lines()
+            .find_map(|line| line.strip_prefix(name).and_then(|rest| rest.strip_prefix(':')))
+            .map(str::trim)
+            .expect("frame field must exist")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn stream_should_push_visible_changes_and_resume_from_last_event_id() {
+        let (app, _, _, stranger) = build_test_app().await;
+        let invalid = open_stream(&app, Some("abc")).await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let live = open_stream(&app, None).await;
+        assert_eq!(live.status(), StatusCode::OK);
+        assert_eq!(live.headers()[header::CONTENT_TYPE], "text/event-stream");
+        let mut live_body = live.into_body().into_data_stream();
+        let mut buffer = String::new();
+
+        // Todo user lain tidak boleh ikut terkirim; frame pertama harus milik owner.
+        send(&app, Some(stranger), "POST", "/todos".to_string(), Some(json!({ "title": "not yours" }))).await;
+        let created = send(&app, None, "POST", "/todos".to_string(), Some(json!({ "title": "stream me" }))).await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let frame = next_frame(&mut live_body, &mut buffer).await;
+        assert_eq!(field(&frame, "event"), "todo.created");
+        let data: Value = serde_json::from_str(field(&frame, "data")).expect("data must be json");
+        assert_eq!(data["todo"]["title"], "stream me");
+        let created_id = field(&frame, "id").to_string();
+        let todo_id = data["todo"]["id"].as_str().expect("todo id").to_string();
+
+        send(&app, None, "PATCH", format!("/todos/{todo_id}"), Some(json!({ "iscompleted": true }))).await;
+        let updated = next_frame(&mut live_body, &mut buffer).await;
+        let completed = next_frame(&mut live_body, &mut buffer).await;
+        assert_eq!(field(&updated, "event"), "todo.updated");
+        assert_eq!(field(&completed, "event"), "todo.completed");
+
+        // Client yang tersambung ulang menerima event setelah `Last-Event-ID` dari outbox.
+        let resumed = open_stream(&app, Some(&created_id)).await;
+        let mut resumed_body = resumed.into_body().into_data_stream();
+        let mut resumed_buffer = String::new();
+        let expected = [event_id(&updated), event_id(&completed)];
+        let replayed = collect_event_ids(&mut resumed_body, &mut resumed_buffer, &expected).await;
+        let position = |id: &str| replayed.iter().position(|replayed_id| replayed_id == id);
+        assert!(position(&expected[0]) < position(&expected[1]));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn stream_should_deliver_events_committed_out_of_sequence_order() {
+        let (app, db, owner, _) = build_test_app().await;
+        let live = open_stream(&app, None).await;
+        let mut live_body = live.into_body().into_data_stream();
+        let mut buffer = String::new();
+
+        // Transaksi pertama mengambil `sequence` lebih kecil tetapi ter-commit paling akhir.
+        let audit = AuditContext {
+            actor_id: owner,
+            redact_desc: false,
+            reverts_event_id: None,
+        };
+        let slow = db.begin().await.expect("transaction must start");
+        let new_todo = NewTodo {
+            id: Uuid::new_v4(),
+            user_id: owner,
+            workspace_id: None,
+            project_id: None,
+            assignee_id: None,
+            title: "slow commit".to_string(),
+            desc: None,
+            estimate: None,
+            due_at: None,
+            completed_at: None,
+            external_uid: None,
+        };
+        let slow_todo = todo_repository::create_todo(&slow, new_todo, &audit)
+            .await
+            .expect("todo insert must succeed");
+        outbox_service::record_todo_events(&slow, &[TodoEvent::Created], &slow_todo, owner, None)
+            .await
+            .expect("outbox insert must succeed");
+
+        let fast = send(&app, None, "POST", "/todos".to_string(), Some(json!({ "title": "fast commit" }))).await;
+        assert_eq!(fast.status(), StatusCode::CREATED);
+        let fast_frame = next_frame(&mut live_body, &mut buffer).await;
+        let fast_data: Value = serde_json::from_str(field(&fast_frame, "data")).expect("data must be json");
+        assert_eq!(fast_data["todo"]["title"], "fast commit");
+        let fast_id = field(&fast_frame, "id").to_string();
+
+        slow.commit().await.expect("commit must succeed");
+        let slow_frame = next_frame(&mut live_body, &mut buffer).await;
+        let slow_data: Value = serde_json::from_str(field(&slow_frame, "data")).expect("data must be json");
+        assert_eq!(slow_data["todo"]["title"], "slow commit");
+
+        // Resume dari posisi event yang ter-commit lebih dulu tetap memutar ulang event yang terlambat.
+        let resumed = open_stream(&app, Some(&fast_id)).await;
+        let mut resumed_body = resumed.into_body().into_data_stream();
+        let mut resumed_buffer = String::new();
+        collect_event_ids(&mut resumed_body, &mut resumed_buffer, &[event_id(&slow_frame)]).await;
+    }
+
+    fn event_id(frame: &str) -> String {
+        let data: Value = serde_json::from_str(field(frame, "data")).expect("data must be json");
+        data["event_id"].as_str().expect("event id must exist").to_string()
+    }
+
+    // Membaca frame sampai seluruh `expected` diterima; event lain (mis. duplikat) boleh muncul.
+    async fn collect_event_ids(body: &mut BodyDataStream, buffer: &mut String, expected: &[String]) -> Vec<String> {
+        let mut received = Vec::new();
+        while !expected.iter().all(|id| received.contains(id)) {
+            let frame = next_frame(body, buffer).await;
+            received.push(event_id(&frame));
+        }
+        received
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
+
+use crate::modules::outbox::entity::Model as OutboxEvent;
+
+/// Kapasitas buffer per subscriber; subscriber yang tertinggal lebih jauh mengejar lewat outbox.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Pesan dari listener `LISTEN/NOTIFY` ke setiap stream yang terbuka.
+#[derive(Debug, Clone)]
+pub enum HubMessage {
+    /// Event outbox yang baru ter-commit.
+    Event(Arc<OutboxEvent>),
+    /// Notifikasi mungkin ada yang terlewat (listener baru tersambung atau tersambung ulang);
+    /// stream perlu mengejar dari outbox.
+    Resync,
+}
+
+/// Fan-out event todo di dalam satu proses: satu listener Postgres, banyak stream SSE.
+#[derive(Debug, Clone)]
+pub struct TodoEventHub {
+    sender: broadcast::Sender<HubMessage>,
+}
+
+impl TodoEventHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Berlangganan pesan berikutnya; pesan sebelum pemanggilan tidak ikut diterima.
+    pub fn subscribe(&self) -> broadcast::Receiver<HubMessage> {
+        self.sender.subscribe()
+    }
+
+    /// Meneruskan pesan ke semua subscriber; diabaikan bila belum ada stream yang terbuka.
+    pub fn publish(&self, message: HubMessage) {
+        let _ = self.sender.send(message);
+    }
+}
+
+impl Default for TodoEventHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,6 @@
+//! Modul stream perubahan todo via Server-Sent Events, diumpankan `LISTEN/NOTIFY` dari outbox.
+
+pub mod dto;
+pub mod handler;
+pub mod hub;
+pub mod service;
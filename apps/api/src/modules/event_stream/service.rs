@@ -0,0 +1,372 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{Stream, stream};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
+use serde_json::{Value, json};
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::infrastructure::db::listener::ChannelListener;
+use crate::modules::outbox::dto::{TODO_EVENTS_CHANNEL, TodoEventPayload, event_snapshot};
+use crate::modules::outbox::entity::Model as OutboxEvent;
+use crate::modules::outbox::repository as outbox_repository;
+use crate::modules::sharing::dto::TodoScope;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::sync::repository as sync_repository;
+
+use super::dto::StreamMessage;
+use super::hub::{HubMessage, TodoEventHub};
+
+/// Batas event yang diputar ulang saat resume; lebih dari ini client menerima `reset`.
+pub const MAX_REPLAY_EVENTS: u64 = 500;
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Error domain stream yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum EventStreamError {
+    Validation(String),
+    Database(DbErr),
+}
+
+impl From<DbErr> for EventStreamError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Loop listener `LISTEN todo_events` untuk dijalankan lewat `tokio::spawn` saat startup.
+///
+/// Setiap notifikasi berisi `sequence` event outbox; event dimuat sekali lalu disebar ke semua
+/// stream lewat `hub`. Setelah (re)connect, `Resync` dikirim agar stream mengejar event yang
+/// ter-commit selama listener belum mendengarkan.
+pub async fn run_listener(conn: DatabaseConnection, hub: TodoEventHub) {
+    loop {
+        match ChannelListener::connect(&conn, TODO_EVENTS_CHANNEL).await {
+            Ok(mut listener) => {
+                info!("todo event listener connected");
+                hub.publish(HubMessage::Resync);
+                match forward_notifications(&conn, &hub, &mut listener).await {
+                    Ok(()) => warn!("todo event listener lost its connection"),
+                    Err(err) => error!(error = %err, "todo event listener failed"),
+                }
+            }
+            Err(err) => error!(error = %err, "failed to start todo event listener"),
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Membuka stream perubahan todo yang bisa diakses user di workspace aktif (`None` = ruang pribadi).
+///
+/// Posisi stream berupa watermark id transaksi (`pg_snapshot_xmin`), bukan `sequence`: transaksi
+/// lama bisa ter-commit setelah event dengan `sequence` lebih besar, jadi setiap pembacaan dimulai
+/// lagi dari watermark terakhir dan event yang sudah terkirim dilewati. Tanpa `last_event_id`,
+/// stream dimulai dari event berikutnya. Dengan `last_event_id`, event setelah posisi itu diputar
+/// ulang dari outbox lebih dulu (maksimal `MAX_REPLAY_EVENTS`, selebihnya diganti satu pesan
+/// `Reset`); event yang sudah diterima sebelum putus bisa terkirim lagi. Stream berakhir bila
+/// query database gagal; client cukup tersambung ulang dengan `Last-Event-ID` terakhir.
+pub async fn open_stream(
+    conn: DatabaseConnection,
+    hub: &TodoEventHub,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    last_event_id: Option<i64>,
+) -> Result<impl Stream<Item = StreamMessage> + use<>, EventStreamError> {
+    // Subscribe lebih dulu agar event yang ter-commit selama replay tidak terlewat.
+    let receiver = hub.subscribe();
+    let watermark = sync_repository::current_watermark(&conn).await?;
+    let mut cursor = Cursor {
+        conn,
+        user_id,
+        workspace_id,
+        receiver,
+        watermark,
+        delivered: HashMap::new(),
+        pending: VecDeque::new(),
+    };
+    match last_event_id {
+        Some(last_event_id) if last_event_id < watermark => {
+            cursor.watermark = last_event_id;
+            cursor.catch_up().await?;
+        }
+        // Event yang sudah terlihat saat stream dibuka bukan bagian stream ini.
+        _ => cursor.skip_visible().await?,
+    }
+    Ok(stream::unfold(cursor, |mut cursor| async move {
+        match cursor.next_message().await {
+            Ok(Some(message)) => Some((message, cursor)),
+            Ok(None) => None,
+            Err(err) => {
+                warn!(error = %err, user_id = %cursor.user_id, "todo event stream closed after database error");
+                None
+            }
+        }
+    }))
+}
+
+/// Validasi header `Last-Event-ID`: harus bilangan bulat non-negatif.
+pub fn parse_last_event_id(raw: &str) -> Result<i64, EventStreamError> {
+    raw.trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|position| *position >= 0)
+        .ok_or_else(|| EventStreamError::Validation("Last-Event-ID must be a non-negative integer".to_string()))
+}
+
+// Posisi satu stream: event dari transaksi dengan id < `watermark` sudah diproses, begitu juga
+// event di `delivered` (`sequence` -> `txid`) yang transaksinya di atas watermark.
+struct Cursor {
+    conn: DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    receiver: Receiver<HubMessage>,
+    watermark: i64,
+    delivered: HashMap<i64, i64>,
+    pending: VecDeque<StreamMessage>,
+}
+
+impl Cursor {
+    // Pesan berikutnya untuk client; `None` bila hub ditutup.
+    async fn next_message(&mut self) -> Result<Option<StreamMessage>, DbErr> {
+        loop {
+            if let Some(message) = self.pending.pop_front() {
+                return Ok(Some(message));
+            }
+            match self.receiver.recv().await {
+                Ok(HubMessage::Event(event)) => {
+                    if event.txid < self.watermark || self.delivered.contains_key(&event.sequence) {
+                        continue;
+                    }
+                    // Event di luar workspace stream tidak mungkin terlihat: cukup ditandai tanpa
+                    // query, dan baru mengejar outbox bila tanda seperti ini sudah menumpuk.
+                    if !in_workspace(&event, self.workspace_id) {
+                        self.delivered.insert(event.sequence, event.txid);
+                        if self.delivered.len() as u64 > MAX_REPLAY_EVENTS {
+                            self.catch_up().await?;
+                        }
+                        continue;
+                    }
+                    // Dibaca ulang dari outbox agar event lain yang ter-commit lebih dulu dari
+                    // notifikasinya ikut terkirim dan watermark maju.
+                    self.catch_up().await?;
+                }
+                Ok(HubMessage::Resync) | Err(RecvError::Lagged(_)) => self.catch_up().await?,
+                Err(RecvError::Closed) => return Ok(None),
+            }
+        }
+    }
+
+    // Memutar ulang event setelah posisi stream dari outbox ke antrean pesan, lalu memajukan
+    // watermark. Watermark dibaca sebelum event sehingga event dari transaksi di bawahnya pasti
+    // sudah terlihat pada pembacaan ini.
+    async fn catch_up(&mut self) -> Result<(), DbErr> {
+        let watermark = sync_repository::current_watermark(&self.conn).await?;
+        let limit = MAX_REPLAY_EVENTS + 1 + self.delivered.len() as u64;
+        let events = outbox_repository::list_from_txid(&self.conn, self.watermark, limit).await?;
+        let fresh: Vec<OutboxEvent> = events
+            .into_iter()
+            .filter(|event| !self.delivered.contains_key(&event.sequence))
+            .collect();
+        if fresh.len() as u64 > MAX_REPLAY_EVENTS {
+            self.watermark = watermark;
+            self.skip_visible().await?;
+            self.pending.clear();
+            self.pending.push_back(StreamMessage::Reset { position: watermark });
+            return Ok(());
+        }
+
+        let queued = self.pending.len();
+        for event in fresh {
+            self.delivered.insert(event.sequence, event.txid);
+            if let Some(message) = visible_message(&self.conn, self.user_id, self.workspace_id, self.watermark, &event).await? {
+                self.pending.push_back(message);
+            }
+        }
+        self.advance(watermark);
+        // Pesan terakhir membawa watermark baru: semua event di bawahnya sudah ada di antrean.
+        if self.pending.len() > queued
+            && let Some(StreamMessage::Todo { position, .. }) = self.pending.back_mut()
+        {
+            *position = self.watermark;
+        }
+        Ok(())
+    }
+
+    // Menandai event yang sudah terlihat di atas watermark sebagai terkirim tanpa mengirimnya.
+    async fn skip_visible(&mut self) -> Result<(), DbErr> {
+        self.delivered = outbox_repository::list_positions_from_txid(&self.conn, self.watermark)
+            .await?
+            .into_iter()
+            .collect();
+        Ok(())
+    }
+
+    // Memajukan watermark dan melupakan event di bawahnya; watermark tidak pernah mundur.
+    fn advance(&mut self, watermark: i64) {
+        self.watermark = self.watermark.max(watermark);
+        let floor = self.watermark;
+        self.delivered.retain(|_, txid| *txid >= floor);
+    }
+}
+
+// Meneruskan notifikasi sampai koneksi listener putus (`Ok`) atau gagal (`Err`).
+async fn forward_notifications(
+    conn: &DatabaseConnection,
+    hub: &TodoEventHub,
+    listener: &mut ChannelListener,
+) -> Result<(), DbErr> {
+    while let Some(payload) = listener.recv().await? {
+        let Ok(sequence) = payload.parse::<i64>() else {
+            warn!(payload = %payload, "ignoring malformed todo event notification");
+            continue;
+        };
+        match outbox_repository::find_by_sequence(conn, sequence).await {
+            Ok(Some(event)) => hub.publish(HubMessage::Event(Arc::new(event))),
+            // Sudah dibersihkan; tidak ada yang perlu dikirim.
+            Ok(None) => {}
+            Err(err) => {
+                error!(error = %err, sequence, "failed to load notified todo event");
+                hub.publish(HubMessage::Resync);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Data event todo siap kirim bila todo-nya bisa diakses user; `None` bila tidak.
+/// Akses dicek dari snapshot di payload sehingga event `todo.deleted` tetap bisa dinilai.
+pub async fn accessible_event<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    event: &OutboxEvent,
+) -> Result<Option<Value>, DbErr> {
+    let parsed = serde_json::from_value::<TodoEventPayload>(event.payload.clone())
+        .and_then(|payload| payload.snapshot().map(|snapshot| (payload, snapshot)));
+    let (payload, snapshot) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            warn!(error = %err, event_id = %event.id, "skipping todo event with invalid payload");
+            return Ok(None);
+        }
+    };
+    let scope = TodoScope {
+        todo_id: snapshot.id,
+        owner_id: snapshot.user_id,
+        workspace_id: snapshot.workspace_id,
+        project_id: snapshot.project_id,
+    };
+    if sharing_service::scope_role(conn, user_id, scope).await?.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(json!({
+        "event_id": event.id,
+        "occurred_at": event.created_at,
+        "actor_id": payload.actor_id,
+        "todo": payload.todo,
+    })))
+}
+
+// Pesan untuk event bila todo-nya berada di workspace aktif dan bisa diakses user.
+async fn visible_message<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    position: i64,
+    event: &OutboxEvent,
+) -> Result<Option<StreamMessage>, DbErr> {
+    // Workspace dicek dulu dari payload agar event workspace lain tidak memicu query akses.
+    if !in_workspace(event, workspace_id) {
+        return Ok(None);
+    }
+    let Some(data) = accessible_event(conn, user_id, event).await? else {
+        return Ok(None);
+    };
+    Ok(Some(StreamMessage::Todo {
+        position,
+        event_type: event.event_type.clone(),
+        data,
+    }))
+}
+
+// `true` bila snapshot todo pada event berada di workspace stream (`None` = ruang pribadi).
+// Payload yang tidak valid tidak pernah terlihat oleh stream mana pun.
+fn in_workspace(event: &OutboxEvent, workspace_id: Option<Uuid>) -> bool {
+    event_snapshot(&event.payload).is_some_and(|snapshot| snapshot.workspace_id == workspace_id)
+}
+
+// Modul tests unit untuk validasi header resume stream dan penyaringan event per workspace.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use chrono::Utc;
+    use sea_orm::Database;
+
+    use super::*;
+
+    fn outbox_event(sequence: i64, workspace_id: Option<Uuid>) -> OutboxEvent {
+        let now = Utc::now().fixed_offset();
+        OutboxEvent {
+            id: Uuid::new_v4(),
+            sequence,
+            txid: sequence,
+            event_type: "todo.created".to_string(),
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({
+                "actor_id": Uuid::new_v4(),
+                "todo": { "id": Uuid::new_v4(), "user_id": Uuid::new_v4(), "workspace_id": workspace_id, "title": "t" },
+            }),
+            status: "pending".to_string(),
+            attempts: 0,
+            next_attempt_at: now,
+            handled_by: json!([]),
+            last_error: None,
+            created_at: now,
+            processed_at: None,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn events_outside_stream_workspace_should_not_read_outbox() {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let hub = TodoEventHub::new();
+        let workspace_id = Uuid::new_v4();
+        let mut cursor = Cursor {
+            conn: db.clone(),
+            user_id: Uuid::new_v4(),
+            workspace_id: Some(workspace_id),
+            receiver: hub.subscribe(),
+            watermark: 0,
+            delivered: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+        // Query apa pun setelah pool ditutup gagal, jadi stream yang membaca outbox berhenti dengan error.
+        db.close().await.expect("pool must close");
+
+        hub.publish(HubMessage::Event(Arc::new(outbox_event(1, None))));
+        hub.publish(HubMessage::Event(Arc::new(outbox_event(2, Some(Uuid::new_v4())))));
+        let idle = tokio::time::timeout(Duration::from_millis(200), cursor.next_message()).await;
+        assert!(idle.is_err(), "events of other workspaces must not trigger a catch-up");
+        assert_eq!(cursor.delivered.len(), 2);
+
+        hub.publish(HubMessage::Event(Arc::new(outbox_event(3, Some(workspace_id)))));
+        assert!(cursor.next_message().await.is_err(), "same-workspace event must read the outbox");
+    }
+
+    #[test]
+    fn parse_last_event_id_should_accept_only_non_negative_integers() {
+        assert_eq!(parse_last_event_id("42").unwrap(), 42);
+        assert_eq!(parse_last_event_id(" 0 ").unwrap(), 0);
+        for raw in ["", "-1", "abc", "1.5"] {
+            assert!(matches!(parse_last_event_id(raw), Err(EventStreamError::Validation(_))));
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::config::history::HistorySettings;
+use crate::modules::todo::entity as todo_entity;
+
+use super::entity;
+
+/// Field yang tidak dicatat pada event `updated` karena selalu berubah di setiap mutasi.
+const IGNORED_UPDATE_FIELDS: &[&str] = &["updated_at"];
+
+/// Jenis event yang dicatat untuk setiap mutasi todo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoEventKind {
+    Created,
+    Updated,
+    Completed,
+    /// Assignee todo berubah; dipakai sebagai sumber notifikasi penugasan.
+    Assigned,
+    Deleted,
+}
+
+impl TodoEventKind {
+    /// Nilai string yang disimpan di kolom `event_type`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Completed => "completed",
+            Self::Assigned => "assigned",
+            Self::Deleted => "deleted",
+        }
+    }
+
+    /// Kebalikan dari `as_str`; `None` bila nilai kolom tidak dikenal.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "created" => Some(Self::Created),
+            "updated" => Some(Self::Updated),
+            "completed" => Some(Self::Completed),
+            "assigned" => Some(Self::Assigned),
+            "deleted" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+
+/// Konteks pelaku mutasi yang dibawa dari handler sampai repository.
+#[derive(Debug, Clone, Copy)]
+pub struct AuditContext {
+    pub actor_id: Uuid,
+    /// Bila `true`, nilai `desc` pada diff diganti penanda `{ "redacted": true }`.
+    pub redact_desc: bool,
+    /// Event yang dibatalkan oleh mutasi ini (hanya terisi saat undo).
+    pub reverts_event_id: Option<Uuid>,
+}
+
+impl AuditContext {
+    /// Membentuk konteks audit dari user pelaku dan konfigurasi history.
+    pub fn new(actor_id: Uuid, settings: &HistorySettings) -> Self {
+        Self {
+            actor_id,
+            redact_desc: settings.redact_desc,
+            reverts_event_id: None,
+        }
+    }
+
+    /// Menandai mutasi sebagai kompensasi undo atas `event_id`.
+    pub fn reverting(self, event_id: Uuid) -> Self {
+        Self {
+            reverts_event_id: Some(event_id),
+            ..self
+        }
+    }
+}
+
+/// Event siap simpan hasil perbandingan snapshot todo sebelum dan sesudah mutasi.
+#[derive(Debug, Clone)]
+pub struct NewTodoEvent {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub actor_id: Uuid,
+    pub kind: TodoEventKind,
+    pub changes: Value,
+    pub reverts_event_id: Option<Uuid>,
+}
+
+impl NewTodoEvent {
+    /// Membandingkan snapshot `before`/`after` lalu menyusun diff per field.
+    ///
+    /// - `before = None` berarti todo baru dibuat (`created`),
+    /// - `after = None` berarti todo dihapus (`deleted`),
+    /// - selain itu `updated`, `completed` bila `iscompleted` berubah menjadi `true`,
+    ///   atau `assigned` bila `assignee_id` berubah.
+    ///
+    /// Mengembalikan `None` bila tidak ada field yang berubah atau kedua snapshot kosong.
+    pub fn from_snapshots(
+        audit: &AuditContext,
+        before: Option<&todo_entity::Model>,
+        after: Option<&todo_entity::Model>,
+    ) -> Option<Self> {
+        let (todo_id, user_id, kind) = match (before, after) {
+            (None, Some(new)) => (new.id, new.user_id, TodoEventKind::Created),
+            (Some(old), None) => (old.id, old.user_id, TodoEventKind::Deleted),
+            (Some(old), Some(new)) if !old.iscompleted && new.iscompleted => {
+                (new.id, new.user_id, TodoEventKind::Completed)
+            }
+            (Some(old), Some(new)) if old.assignee_id != new.assignee_id => {
+                (new.id, new.user_id, TodoEventKind::Assigned)
+            }
+            (Some(_), Some(new)) => (new.id, new.user_id, TodoEventKind::Updated),
+            (None, None) => return None,
+        };
+
+        let old_fields = snapshot_fields(before);
+        let new_fields = snapshot_fields(after);
+        let mut changes = Map::new();
+        for key in old_fields.keys().chain(new_fields.keys()) {
+            if changes.contains_key(key) {
+                continue;
+            }
+            if before.is_some() && after.is_some() && IGNORED_UPDATE_FIELDS.contains(&key.as_str()) {
+                continue;
+            }
+            let old = old_fields.get(key).cloned().unwrap_or(Value::Null);
+            let new = new_fields.get(key).cloned().unwrap_or(Value::Null);
+            if old == new {
+                continue;
+            }
+            let (old, new) = if audit.redact_desc && key == "desc" {
+                (redact(old), redact(new))
+            } else {
+                (old, new)
+            };
+            changes.insert(key.clone(), json!({ "old": old, "new": new }));
+        }
+
+        if changes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            todo_id,
+            user_id,
+            actor_id: audit.actor_id,
+            kind,
+            changes: Value::Object(changes),
+            reverts_event_id: audit.reverts_event_id,
+        })
+    }
+}
+
+/// Bentuk event history yang dikirim ke client.
+#[derive(Debug, Serialize)]
+pub struct TodoEventResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub actor_id: Uuid,
+    pub event_type: String,
+    pub changes: Value,
+    pub created_at: DateTime<FixedOffset>,
+    pub reverts_event_id: Option<Uuid>,
+    pub undone_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<entity::Model> for TodoEventResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            actor_id: value.actor_id,
+            event_type: value.event_type,
+            changes: value.changes,
+            created_at: value.created_at,
+            reverts_event_id: value.reverts_event_id,
+            undone_at: value.undone_at,
+        }
+    }
+}
+
+// Serialisasi snapshot todo menjadi map field -> nilai JSON.
+fn snapshot_fields(model: Option<&todo_entity::Model>) -> Map<String, Value> {
+    match model.map(serde_json::to_value) {
+        Some(Ok(Value::Object(fields))) => fields,
+        _ => Map::new(),
+    }
+}
+
+/// Mengecek apakah nilai diff adalah penanda redaksi.
+pub fn is_redacted(value: &Value) -> bool {
+    value.get("redacted").and_then(Value::as_bool) == Some(true)
+}
+
+// Nilai `null` tetap `null` agar tetap terlihat kapan desc dikosongkan.
+fn redact(value: Value) -> Value {
+    if value.is_null() {
+        value
+    } else {
+        json!({ "redacted": true })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn sample_todo() -> todo_entity::Model {
+        let now = Utc::now().fixed_offset();
+        todo_entity::Model {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            workspace_id: None,
+            project_id: None,
+            assignee_id: None,
+            title: "write docs".to_string(),
+            desc: Some("secret notes".to_string()),
+            iscompleted: false,
+            completed_at: None,
+            due_at: None,
+            estimate_value: None,
+            estimate_unit: None,
+            external_uid: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn audit(redact_desc: bool) -> AuditContext {
+        AuditContext {
+            actor_id: Uuid::new_v4(),
+            redact_desc,
+            reverts_event_id: None,
+        }
+    }
+
+    #[test]
+    fn from_snapshots_should_record_only_changed_fields_on_update() {
+        let before = sample_todo();
+        let mut after = before.clone();
+        after.title = "write better docs".to_string();
+        after.updated_at = Utc::now().fixed_offset() + chrono::Duration::seconds(5);
+
+        let event = NewTodoEvent::from_snapshots(&audit(false), Some(&before), Some(&after))
+            .expect("event expected");
+
+        assert_eq!(event.kind, TodoEventKind::Updated);
+        assert_eq!(
+            event.changes,
+            json!({ "title": { "old": "write docs", "new": "write better docs" } })
+        );
+    }
+
+    #[test]
+    fn from_snapshots_should_mark_completion() {
+        let before = sample_todo();
+        let mut after = before.clone();
+        after.iscompleted = true;
+
+        let event = NewTodoEvent::from_snapshots(&audit(false), Some(&before), Some(&after))
+            .expect("event expected");
+
+        assert_eq!(event.kind, TodoEventKind::Completed);
+    }
+
+    #[test]
+    fn from_snapshots_should_mark_assignment() {
+        let before = sample_todo();
+        let mut after = before.clone();
+        let assignee_id = Uuid::new_v4();
+        after.assignee_id = Some(assignee_id);
+
+        let event = NewTodoEvent::from_snapshots(&audit(false), Some(&before), Some(&after))
+            .expect("event expected");
+
+        assert_eq!(event.kind, TodoEventKind::Assigned);
+        assert_eq!(
+            event.changes,
+            json!({ "assignee_id": { "old": null, "new": assignee_id } })
+        );
+    }
+
+    #[test]
+    fn from_snapshots_should_skip_when_nothing_changed() {
+        let before = sample_todo();
+        let event = NewTodoEvent::from_snapshots(&audit(false), Some(&before), Some(&before));
+        assert!(event.is_none());
+    }
+
+    #[test]
+    fn from_snapshots_should_redact_desc_when_configured() {
+        let before = sample_todo();
+
+        let event = NewTodoEvent::from_snapshots(&audit(true), Some(&before), None)
+            .expect("event expected");
+
+        assert_eq!(event.kind, TodoEventKind::Deleted);
+        assert_eq!(event.changes["desc"]["old"], json!({ "redacted": true }));
+        assert_eq!(event.changes["title"]["old"], "write docs");
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `todo_events` (audit log perubahan todo).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[sea_orm(table_name = "todo_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    /// Pemilik todo saat event terjadi; dipakai untuk scope query history.
+    pub user_id: Uuid,
+    /// User yang melakukan perubahan.
+    pub actor_id: Uuid,
+    pub event_type: String,
+    /// Diff per field dengan bentuk `{ "<field>": { "old": ..., "new": ... } }`.
+    pub changes: Json,
+    pub created_at: DateTime<FixedOffset>,
+    /// Terisi bila event ini adalah kompensasi undo atas event lain.
+    pub reverts_event_id: Option<Uuid>,
+    /// Terisi bila event ini sudah dibatalkan lewat undo.
+    pub undone_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,179 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    routing::get,
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::TodoEventResponse;
+use super::service::{self, HistoryError};
+
+/// Registrasi route history, di-mount di bawah prefix `/todos`.
+///
+/// - `GET /{id}/history` daftar perubahan todo
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/{id}/history", get(get_todo_history))
+}
+
+/// GET `/todos/{id}/history`
+/// Output: `200 OK` + daftar event urut terlama, `404` bila todo tidak punya history.
+pub async fn get_todo_history(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<TodoEventResponse>>>, ApiError> {
+    let events = service::get_todo_history(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_history_error)?;
+    info!(todo_id = %todo_id, count = events.len(), "todo history fetched");
+    let data = events.into_iter().map(TodoEventResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// Mapping error domain history ke `ApiError`.
+fn map_history_error(err: HistoryError) -> ApiError {
+    match err {
+        HistoryError::NotFound => {
+            warn!("todo history not found");
+            ApiError::not_found("todo history not found")
+        }
+        HistoryError::Database(db_err) => {
+            error!(error = %db_err, "todo history database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint history terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> (Router, DatabaseConnection) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        let app = Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db.clone(), default_user_id));
+        (app, db)
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn history_should_survive_todo_deletion() {
+        let (app, _) = build_test_app().await;
+
+        let create_req = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "history todo" }).to_string()))
+            .expect("request should be valid");
+        let create_res = app.clone().oneshot(create_req).await.expect("response expected");
+        let todo_id = read_json(create_res).await["data"]["id"]
+            .as_str()
+            .expect("todo id must exist")
+            .to_string();
+
+        let update_req = Request::builder()
+            .method("PATCH")
+            .uri(format!("/todos/{todo_id}"))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "iscompleted": true }).to_string()))
+            .expect("request should be valid");
+        app.clone().oneshot(update_req).await.expect("response expected");
+
+        let delete_req = Request::builder()
+            .method("DELETE")
+            .uri(format!("/todos/{todo_id}"))
+            .body(Body::empty())
+            .expect("request should be valid");
+        app.clone().oneshot(delete_req).await.expect("response expected");
+
+        let history_req = Request::builder()
+            .method("GET")
+            .uri(format!("/todos/{todo_id}/history"))
+            .body(Body::empty())
+            .expect("request should be valid");
+        let history_res = app.oneshot(history_req).await.expect("response expected");
+        assert_eq!(history_res.status(), StatusCode::OK);
+        let body = read_json(history_res).await;
+        let event_types: Vec<&str> = body["data"]
+            .as_array()
+            .expect("data must be an array")
+            .iter()
+            .map(|event| event["event_type"].as_str().expect("event_type must exist"))
+            .collect();
+        assert_eq!(event_types, vec!["created", "completed", "deleted"]);
+        assert_eq!(body["data"][1]["changes"]["iscompleted"], json!({ "old": false, "new": true }));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn history_of_existing_todo_without_events_should_be_empty() {
+        let (app, db) = build_test_app().await;
+        let create_req = Request::builder()
+            .method("POST")
+            .uri("/todos")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "title": "pruned history" }).to_string()))
+            .expect("request should be valid");
+        let create_res = app.clone().oneshot(create_req).await.expect("response expected");
+        let todo_id = read_json(create_res).await["data"]["id"]
+            .as_str()
+            .expect("todo id must exist")
+            .to_string();
+        // Mensimulasikan event yang sudah dihapus retensi.
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM todo_events WHERE todo_id = $1",
+            [Uuid::parse_str(&todo_id).expect("todo id must be a uuid").into()],
+        ))
+        .await
+        .expect("events must be deleted");
+
+        let history_req = Request::builder()
+            .method("GET")
+            .uri(format!("/todos/{todo_id}/history"))
+            .body(Body::empty())
+            .expect("request should be valid");
+        let history_res = app.oneshot(history_req).await.expect("response expected");
+        assert_eq!(history_res.status(), StatusCode::OK);
+        assert_eq!(read_json(history_res).await["data"], json!([]));
+    }
+}
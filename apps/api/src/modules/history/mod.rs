@@ -0,0 +1,7 @@
+//! Modul audit log todo: entity, repository, service, dan handler HTTP history.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,107 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use super::dto::NewTodoEvent;
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan satu event audit.
+/// Generic terhadap `ConnectionTrait` agar bisa dipanggil di dalam transaksi mutasi todo.
+pub async fn insert_event<C: ConnectionTrait>(conn: &C, event: NewTodoEvent) -> Result<Model, DbErr> {
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        todo_id: Set(event.todo_id),
+        user_id: Set(event.user_id),
+        actor_id: Set(event.actor_id),
+        event_type: Set(event.kind.as_str().to_string()),
+        changes: Set(event.changes),
+        created_at: Set(Utc::now().fixed_offset()),
+        reverts_event_id: Set(event.reverts_event_id),
+        undone_at: Set(None),
+    };
+    active.insert(conn).await
+}
+
+/// Menandai event sebagai sudah dibatalkan lewat undo.
+pub async fn mark_event_undone<C: ConnectionTrait>(conn: &C, event_id: Uuid) -> Result<(), DbErr> {
+    Entity::update_many()
+        .col_expr(Column::UndoneAt, Expr::value(Utc::now().fixed_offset()))
+        .filter(Column::Id.eq(event_id))
+        .exec(conn)
+        .await?;
+    Ok(())
+}
+
+/// Mengambil event "hidup" terakhir milik pelaku: bukan kompensasi undo dan belum dibatalkan.
+/// Pencarian lintas pemilik todo (pelaku bisa mengubah todo yang dibagikan kepadanya);
+/// bila `todo_id` diisi, pencarian dibatasi pada todo tersebut.
+pub async fn find_latest_undoable_event(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    todo_id: Option<Uuid>,
+) -> Result<Option<Model>, DbErr> {
+    let mut query = Entity::find()
+        .filter(Column::ActorId.eq(actor_id))
+        .filter(Column::RevertsEventId.is_null())
+        .filter(Column::UndoneAt.is_null());
+    if let Some(todo_id) = todo_id {
+        query = query.filter(Column::TodoId.eq(todo_id));
+    }
+    query
+        .order_by(Column::CreatedAt, Order::Desc)
+        .one(conn)
+        .await
+}
+
+/// Mengecek apakah ada event hidup pada todo yang sama setelah event `event`.
+pub async fn has_newer_live_events<C: ConnectionTrait>(conn: &C, event: &Model) -> Result<bool, DbErr> {
+    let newer = Entity::find()
+        .filter(Column::TodoId.eq(event.todo_id))
+        .filter(Column::Id.ne(event.id))
+        .filter(Column::CreatedAt.gte(event.created_at))
+        .filter(Column::RevertsEventId.is_null())
+        .filter(Column::UndoneAt.is_null())
+        .count(conn)
+        .await?;
+    Ok(newer > 0)
+}
+
+/// Mengambil event by id sambil mengunci barisnya sampai transaksi selesai, agar undo yang
+/// berjalan bersamaan atas event yang sama diproses satu per satu.
+pub async fn find_event_for_update<C: ConnectionTrait>(conn: &C, event_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(event_id))
+        .lock_exclusive()
+        .one(conn)
+        .await
+}
+
+/// Mengambil seluruh event satu todo dalam scope user, urut terlama lebih dulu.
+pub async fn list_events_for_todo(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Menghapus event yang lebih tua dari `cutoff`.
+/// Mengembalikan jumlah baris yang terhapus.
+pub async fn delete_events_before(
+    conn: &DatabaseConnection,
+    cutoff: DateTime<FixedOffset>,
+) -> Result<u64, DbErr> {
+    let result = Entity::delete_many()
+        .filter(Column::CreatedAt.lt(cutoff))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
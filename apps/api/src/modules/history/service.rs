@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, DbErr};
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::common::authz::{self, AccessError, Permission, Resource};
+use crate::config::history::HistorySettings;
+use crate::modules::todo::repository as todo_repository;
+
+use super::entity::Model;
+use super::repository;
+
+/// Interval pembersihan event yang melewati masa retensi.
+const RETENTION_SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Error domain history yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum HistoryError {
+    NotFound,
+    Database(DbErr),
+}
+
+impl From<DbErr> for HistoryError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Mengambil history satu todo, termasuk todo yang sudah dihapus.
+/// Todo yang masih ada butuh `todo.read` dan boleh mengembalikan daftar kosong (mis. event
+/// sudah terhapus retensi); history todo terhapus hanya untuk pemiliknya dan mengembalikan
+/// `NotFound` bila tidak ada event yang boleh dilihat.
+pub async fn get_todo_history(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Vec<Model>, HistoryError> {
+    if let Some(todo) = todo_repository::find_todo_by_id(conn, todo_id).await? {
+        match authz::authorize(conn, user_id, Permission::TodoRead, Resource::Todo(&todo)).await {
+            Ok(_) => {}
+            Err(AccessError::Database(err)) => return Err(err.into()),
+            Err(AccessError::NotFound | AccessError::Forbidden) => return Err(HistoryError::NotFound),
+        }
+        return repository::list_events_for_todo(conn, todo.user_id, todo_id)
+            .await
+            .map_err(HistoryError::from);
+    }
+    let events = repository::list_events_for_todo(conn, user_id, todo_id).await?;
+    if events.is_empty() {
+        return Err(HistoryError::NotFound);
+    }
+    Ok(events)
+}
+
+/// Menghapus event yang lebih tua dari `retention_days`.
+/// Tidak melakukan apa pun bila retensi tidak dikonfigurasi.
+pub async fn prune_expired_events(
+    conn: &DatabaseConnection,
+    settings: &HistorySettings,
+) -> Result<u64, DbErr> {
+    let Some(retention_days) = settings.retention_days else {
+        return Ok(0);
+    };
+    let cutoff = Utc::now().fixed_offset() - chrono::Duration::days(i64::from(retention_days));
+    repository::delete_events_before(conn, cutoff).await
+}
+
+/// Loop background untuk menjalankan `prune_expired_events` secara berkala.
+pub async fn run_retention_loop(conn: DatabaseConnection, settings: HistorySettings) {
+    let mut interval = tokio::time::interval(RETENTION_SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        match prune_expired_events(&conn, &settings).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(deleted, "expired todo events pruned"),
+            Err(err) => error!(error = %err, "failed to prune expired todo events"),
+        }
+    }
+}
@@ -1,3 +1,27 @@
 //! Kumpulan modul fitur berdasarkan domain bisnis.
 
+pub mod app_password;
+pub mod attachment;
+pub mod caldav;
+pub mod calendar;
+pub mod checklist;
+pub mod collab;
+pub mod comment;
+pub mod dependency;
+pub mod event_stream;
+pub mod history;
+pub mod notification;
+pub mod outbox;
+pub mod project;
+pub mod reminder;
+pub mod report;
+pub mod sync;
+pub mod sharing;
+pub mod template;
+pub mod time_entry;
 pub mod todo;
+pub mod trash;
+pub mod undo;
+pub mod user;
+pub mod webhook;
+pub mod workspace;
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::infrastructure::notifier::NotificationKind;
+
+use super::entity;
+
+/// Query string untuk daftar notifikasi.
+#[derive(Debug, Default, Deserialize)]
+pub struct NotificationQuery {
+    /// Jumlah item per halaman (default 20, maksimal 100).
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// Bila `true`, hanya notifikasi yang belum dibaca.
+    #[serde(default)]
+    pub unread_only: bool,
+}
+
+/// Payload request untuk mengubah preferensi, mis. `{ "preferences": { "commented": false } }`.
+/// Jenis yang tidak dikirim tidak diubah.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePreferencesRequest {
+    pub preferences: HashMap<String, bool>,
+}
+
+/// Bentuk data notifikasi yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    pub kind: String,
+    pub todo_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub message: String,
+    pub read_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for NotificationResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            todo_id: value.todo_id,
+            actor_id: value.actor_id,
+            message: value.message,
+            read_at: value.read_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Satu halaman inbox beserta jumlah notifikasi yang belum dibaca.
+#[derive(Debug, Serialize)]
+pub struct NotificationPage {
+    pub items: Vec<NotificationResponse>,
+    /// Jumlah seluruh notifikasi belum dibaca, tidak terpengaruh paging.
+    pub unread_count: u64,
+    /// Jumlah seluruh item yang cocok dengan filter.
+    pub total: u64,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+/// Hasil `read-all`.
+#[derive(Debug, Serialize)]
+pub struct MarkAllReadResponse {
+    pub updated: u64,
+}
+
+/// Preferensi satu jenis notifikasi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PreferenceResponse {
+    pub kind: NotificationKind,
+    pub enabled: bool,
+}
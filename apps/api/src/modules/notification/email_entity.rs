@@ -0,0 +1,30 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `email_outbox` (antrean email notifikasi).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "email_outbox")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Penerima notifikasi.
+    pub user_id: Uuid,
+    /// Alamat email user saat notifikasi diantrekan.
+    pub to_address: String,
+    pub subject: String,
+    pub body: String,
+    /// Jenis notifikasi, sama dengan `notifications.kind`.
+    pub kind: String,
+    /// Todo terkait; dikosongkan bila todo dihapus.
+    pub todo_id: Option<Uuid>,
+    /// `pending` sampai diambil pengirim email, lalu `sent`.
+    pub status: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub sent_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `notifications` (inbox in-app).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[sea_orm(table_name = "notifications")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Penerima notifikasi.
+    pub user_id: Uuid,
+    /// Salah satu dari `assigned`, `commented`, `reminder`.
+    pub kind: String,
+    /// Todo terkait; dikosongkan bila todo dihapus.
+    pub todo_id: Option<Uuid>,
+    /// User yang memicu event; `None` untuk event sistem.
+    pub actor_id: Option<Uuid>,
+    pub message: String,
+    /// `None` selama belum dibaca.
+    pub read_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
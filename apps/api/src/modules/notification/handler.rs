@@ -0,0 +1,311 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    routing::{get, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{
+    MarkAllReadResponse, NotificationPage, NotificationQuery, NotificationResponse, PreferenceResponse,
+    UpdatePreferencesRequest,
+};
+use super::service::{self, NotificationError};
+
+/// Registrasi route inbox notifikasi, di-mount di bawah prefix `/notifications`.
+///
+/// - `GET /` daftar notifikasi user saat ini beserta jumlah yang belum dibaca
+/// - `POST /read-all` tandai semua notifikasi sebagai dibaca
+/// - `POST /{id}/read` tandai satu notifikasi sebagai dibaca
+/// - `GET /preferences` preferensi per jenis notifikasi
+/// - `PUT /preferences` ubah preferensi per jenis notifikasi
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_notifications))
+        .route("/read-all", post(mark_all_read))
+        .route("/{id}/read", post(mark_read))
+        .route("/preferences", get(get_preferences).put(update_preferences))
+}
+
+/// GET `/notifications`
+/// Query: `limit` (default 20, maks 100), `offset`, `unread_only` opsional.
+/// Output: `200 OK` + `SuccessResponse<NotificationPage>`, terbaru lebih dulu.
+pub async fn list_notifications(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<NotificationQuery>,
+) -> Result<Json<SuccessResponse<NotificationPage>>, ApiError> {
+    let page = service::list_notifications(&state.db, user.id, query)
+        .await
+        .map_err(map_notification_error)?;
+    info!(count = page.items.len(), unread = page.unread_count, "notifications listed");
+    Ok(Json(SuccessResponse { data: page }))
+}
+
+/// POST `/notifications/{id}/read`
+/// Output: `200 OK` dengan notifikasi yang sudah ditandai dibaca.
+pub async fn mark_read(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(notification_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<NotificationResponse>>, ApiError> {
+    let notification = service::mark_read(&state.db, user.id, notification_id)
+        .await
+        .map_err(map_notification_error)?;
+    info!(notification_id = %notification_id, "notification marked read");
+    Ok(Json(SuccessResponse {
+        data: NotificationResponse::from(notification),
+    }))
+}
+
+/// POST `/notifications/read-all`
+/// Output: `200 OK` + jumlah notifikasi yang berubah status.
+pub async fn mark_all_read(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<MarkAllReadResponse>>, ApiError> {
+    let updated = service::mark_all_read(&state.db, user.id)
+        .await
+        .map_err(map_notification_error)?;
+    info!(updated, "notifications marked read");
+    Ok(Json(SuccessResponse {
+        data: MarkAllReadResponse { updated },
+    }))
+}
+
+/// GET `/notifications/preferences`
+/// Output: `200 OK` + status aktif setiap jenis notifikasi.
+pub async fn get_preferences(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<PreferenceResponse>>>, ApiError> {
+    let preferences = service::get_preferences(&state.db, user.id)
+        .await
+        .map_err(map_notification_error)?;
+    Ok(Json(SuccessResponse { data: preferences }))
+}
+
+/// PUT `/notifications/preferences`
+///
+/// Input: `UpdatePreferencesRequest`.
+/// Output: `200 OK` + preferensi lengkap setelah perubahan.
+pub async fn update_preferences(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<UpdatePreferencesRequest>,
+) -> Result<Json<SuccessResponse<Vec<PreferenceResponse>>>, ApiError> {
+    let preferences = service::update_preferences(&state.db, user.id, payload)
+        .await
+        .map_err(map_notification_error)?;
+    info!("notification preferences updated");
+    Ok(Json(SuccessResponse { data: preferences }))
+}
+
+/// Mapping error domain notifikasi ke `ApiError`.
+fn map_notification_error(err: NotificationError) -> ApiError {
+    match err {
+        NotificationError::Validation(message) => {
+            warn!(reason = %message, "notification validation failed");
+            ApiError::bad_request(message)
+        }
+        NotificationError::NotFound => {
+            warn!("notification not found");
+            ApiError::not_found("notification not found")
+        }
+        NotificationError::Database(db_err) => {
+            error!(error = %db_err, "notification database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi inbox notifikasi terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::{Database, DatabaseConnection};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::config::outbox::OutboxSettings;
+    use crate::modules::comment::handler as comment_handler;
+    use crate::modules::outbox::service as outbox_service;
+    use crate::modules::sharing::handler as sharing_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // Owner dan kolaborator acak agar inbox tidak tercampur data test lain.
+    async fn build_test_app() -> (Router, DatabaseConnection, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let owner_id = Uuid::new_v4();
+        let collaborator_id = Uuid::new_v4();
+        for user_id in [owner_id, collaborator_id] {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let app = Router::new()
+            .nest("/notifications", routes())
+            .nest(
+                "/todos",
+                todo_handler::routes()
+                    .merge(comment_handler::routes())
+                    .merge(sharing_handler::todo_routes()),
+            )
+            .with_state(AppState::for_tests(db.clone(), owner_id));
+        (app, db, collaborator_id)
+    }
+
+    async fn send(
+        app: &Router,
+        as_user: Option<Uuid>,
+        method: &str,
+        uri: String,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(user_id) = as_user {
+            builder = builder.header(USER_ID_HEADER, user_id.to_string());
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn inbox_should_collect_assignment_and_comment_events() {
+        let (app, db, collaborator) = build_test_app().await;
+        let peer = Some(collaborator);
+        let todo = read_json(
+            send(&app, None, "POST", "/todos".to_string(), Some(json!({ "title": "triage bugs" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        send(
+            &app,
+            None,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{collaborator}"),
+            Some(json!({ "role": "editor" })),
+        )
+        .await;
+        let assigned = send(
+            &app,
+            None,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "assignee_id": collaborator })),
+        )
+        .await;
+        assert_eq!(assigned.status(), StatusCode::OK);
+        // Notifikasi assignment dibuat relay outbox setelah perubahan ter-commit.
+        let subscribers = outbox_service::default_subscribers();
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("uuid");
+        outbox_service::relay_pending(&db, &OutboxSettings::default(), &subscribers, Some(todo_uuid))
+            .await
+            .expect("relay must succeed");
+
+        let inbox = read_json(send(&app, peer, "GET", "/notifications".to_string(), None).await).await;
+        assert_eq!(inbox["data"]["unread_count"], 1);
+        assert_eq!(inbox["data"]["items"][0]["kind"], "assigned");
+        assert_eq!(inbox["data"]["items"][0]["message"], "You were assigned \"triage bugs\"");
+        let notification_id = inbox["data"]["items"][0]["id"].as_str().expect("notification id").to_string();
+
+        let read = send(&app, peer, "POST", format!("/notifications/{notification_id}/read"), None).await;
+        assert_eq!(read.status(), StatusCode::OK);
+        assert!(read_json(read).await["data"]["read_at"].is_string());
+        let foreign = send(&app, None, "POST", format!("/notifications/{notification_id}/read"), None).await;
+        assert_eq!(foreign.status(), StatusCode::NOT_FOUND);
+
+        // Kolaborator mematikan notifikasi komentar; owner tetap menerimanya.
+        let unknown = send(
+            &app,
+            peer,
+            "PUT",
+            "/notifications/preferences".to_string(),
+            Some(json!({ "preferences": { "mentioned": true } })),
+        )
+        .await;
+        assert_eq!(unknown.status(), StatusCode::BAD_REQUEST);
+        let preferences = read_json(
+            send(
+                &app,
+                peer,
+                "PUT",
+                "/notifications/preferences".to_string(),
+                Some(json!({ "preferences": { "commented": false } })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(
+            preferences["data"],
+            json!([
+                { "kind": "assigned", "enabled": true },
+                { "kind": "commented", "enabled": false },
+                { "kind": "reminder", "enabled": true }
+            ])
+        );
+
+        send(
+            &app,
+            None,
+            "POST",
+            format!("/todos/{todo_id}/comments"),
+            Some(json!({ "body": "owner note" })),
+        )
+        .await;
+        send(
+            &app,
+            peer,
+            "POST",
+            format!("/todos/{todo_id}/comments"),
+            Some(json!({ "body": "on it" })),
+        )
+        .await;
+        let peer_unread = read_json(
+            send(&app, peer, "GET", "/notifications?unread_only=true".to_string(), None).await,
+        )
+        .await;
+        assert_eq!(peer_unread["data"]["total"], 0);
+
+        let owner_inbox = read_json(send(&app, None, "GET", "/notifications?limit=1".to_string(), None).await).await;
+        assert_eq!(owner_inbox["data"]["unread_count"], 1);
+        assert_eq!(owner_inbox["data"]["items"][0]["kind"], "commented");
+        assert_eq!(owner_inbox["data"]["items"][0]["actor_id"], collaborator.to_string());
+
+        let cleared = read_json(send(&app, None, "POST", "/notifications/read-all".to_string(), None).await).await;
+        assert_eq!(cleared["data"]["updated"], 1);
+        let bad_limit = send(&app, None, "GET", "/notifications?limit=0".to_string(), None).await;
+        assert_eq!(bad_limit.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,12 @@
+//! Modul notifikasi in-app: inbox per user, preferensi per jenis event, dan `Notifier` berbasis inbox
+//! serta antrean email.
+
+pub mod dto;
+pub mod email_entity;
+pub mod entity;
+pub mod handler;
+pub mod notifier;
+pub mod preference_entity;
+pub mod repository;
+pub mod service;
+pub mod subscriber;
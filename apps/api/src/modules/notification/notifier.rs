@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+
+use crate::infrastructure::notifier::{Notification, Notifier, NotifyError};
+
+use super::service;
+
+/// `Notifier` yang menyimpan notifikasi ke inbox in-app dengan menghormati preferensi user.
+pub struct InboxNotifier;
+
+#[async_trait]
+impl Notifier for InboxNotifier {
+    async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+        service::deliver(txn, notification)
+            .await
+            .map(|_| ())
+            .map_err(|err| NotifyError(err.to_string()))
+    }
+}
+
+/// `Notifier` yang menulis email ke antrean `email_outbox` dengan menghormati preferensi user.
+/// Pengiriman email sebenarnya dilakukan proses pengirim yang membaca baris `pending`.
+pub struct EmailOutboxNotifier;
+
+#[async_trait]
+impl Notifier for EmailOutboxNotifier {
+    async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+        service::queue_email(txn, notification)
+            .await
+            .map(|_| ())
+            .map_err(|err| NotifyError(err.to_string()))
+    }
+}
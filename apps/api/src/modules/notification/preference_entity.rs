@@ -0,0 +1,21 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `notification_preferences`.
+/// Jenis notifikasi tanpa baris dianggap aktif.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "notification_preferences")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub kind: String,
+    pub enabled: bool,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,163 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+    sea_query::{Expr, OnConflict},
+};
+use uuid::Uuid;
+
+use crate::infrastructure::notifier::{Notification, NotificationKind};
+
+use super::entity::{ActiveModel, Column, Entity, Model};
+use super::{email_entity, preference_entity};
+
+/// Menyimpan notifikasi baru ke inbox penerima.
+pub async fn insert_notification<C: ConnectionTrait>(conn: &C, notification: &Notification) -> Result<Model, DbErr> {
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(notification.user_id),
+        kind: Set(notification.kind.as_str().to_string()),
+        todo_id: Set(notification.todo_id),
+        actor_id: Set(notification.actor_id),
+        message: Set(notification.message.clone()),
+        read_at: Set(None),
+        created_at: Set(Utc::now().fixed_offset()),
+    };
+    active.insert(conn).await
+}
+
+/// Memasukkan email notifikasi ke antrean `email_outbox` dengan status `pending`.
+pub async fn insert_email<C: ConnectionTrait>(
+    conn: &C,
+    notification: &Notification,
+    to_address: String,
+    subject: String,
+) -> Result<email_entity::Model, DbErr> {
+    let active = email_entity::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(notification.user_id),
+        to_address: Set(to_address),
+        subject: Set(subject),
+        body: Set(notification.message.clone()),
+        kind: Set(notification.kind.as_str().to_string()),
+        todo_id: Set(notification.todo_id),
+        status: Set("pending".to_string()),
+        created_at: Set(Utc::now().fixed_offset()),
+        sent_at: Set(None),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil satu halaman inbox user, terbaru lebih dulu.
+pub async fn list_notifications(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    unread_only: bool,
+    limit: u64,
+    offset: u64,
+) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(inbox_condition(user_id, unread_only))
+        .order_by(Column::CreatedAt, Order::Desc)
+        .order_by(Column::Id, Order::Desc)
+        .limit(limit)
+        .offset(offset)
+        .all(conn)
+        .await
+}
+
+/// Jumlah notifikasi user, opsional hanya yang belum dibaca.
+pub async fn count_notifications(conn: &DatabaseConnection, user_id: Uuid, unread_only: bool) -> Result<u64, DbErr> {
+    Entity::find()
+        .filter(inbox_condition(user_id, unread_only))
+        .count(conn)
+        .await
+}
+
+/// Menandai satu notifikasi milik user sebagai dibaca; notifikasi yang sudah dibaca tidak diubah.
+/// Mengembalikan `None` bila notifikasi tidak ditemukan di inbox user.
+pub async fn mark_read(conn: &DatabaseConnection, user_id: Uuid, notification_id: Uuid) -> Result<Option<Model>, DbErr> {
+    let Some(existing) = Entity::find()
+        .filter(Column::Id.eq(notification_id))
+        .filter(Column::UserId.eq(user_id))
+        .one(conn)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if existing.read_at.is_some() {
+        return Ok(Some(existing));
+    }
+    let mut active: ActiveModel = existing.into();
+    active.read_at = Set(Some(Utc::now().fixed_offset()));
+    active.update(conn).await.map(Some)
+}
+
+/// Menandai seluruh notifikasi user yang belum dibaca; mengembalikan jumlah yang berubah.
+pub async fn mark_all_read(conn: &DatabaseConnection, user_id: Uuid) -> Result<u64, DbErr> {
+    let result = Entity::update_many()
+        .col_expr(Column::ReadAt, Expr::value(Utc::now().fixed_offset()))
+        .filter(inbox_condition(user_id, true))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
+
+/// Mengambil preferensi yang pernah disimpan user.
+pub async fn list_preferences<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<preference_entity::Model>, DbErr> {
+    preference_entity::Entity::find()
+        .filter(preference_entity::Column::UserId.eq(user_id))
+        .all(conn)
+        .await
+}
+
+/// Status aktif satu jenis notifikasi untuk user; `None` bila belum pernah diatur.
+pub async fn find_preference<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    kind: NotificationKind,
+) -> Result<Option<bool>, DbErr> {
+    let preference = preference_entity::Entity::find_by_id((user_id, kind.as_str().to_string()))
+        .one(conn)
+        .await?;
+    Ok(preference.map(|preference| preference.enabled))
+}
+
+/// Menyimpan preferensi beberapa jenis notifikasi sekaligus (insert atau update).
+pub async fn upsert_preferences(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    preferences: &[(NotificationKind, bool)],
+) -> Result<(), DbErr> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+    for (kind, enabled) in preferences {
+        let active = preference_entity::ActiveModel {
+            user_id: Set(user_id),
+            kind: Set(kind.as_str().to_string()),
+            enabled: Set(*enabled),
+            updated_at: Set(now),
+        };
+        preference_entity::Entity::insert(active)
+            .on_conflict(
+                OnConflict::columns([preference_entity::Column::UserId, preference_entity::Column::Kind])
+                    .update_columns([preference_entity::Column::Enabled, preference_entity::Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec(&txn)
+            .await?;
+    }
+    txn.commit().await
+}
+
+fn inbox_condition(user_id: Uuid, unread_only: bool) -> Condition {
+    let condition = Condition::all().add(Column::UserId.eq(user_id));
+    if unread_only {
+        condition.add(Column::ReadAt.is_null())
+    } else {
+        condition
+    }
+}
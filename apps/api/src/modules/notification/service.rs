@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::infrastructure::notifier::{Notification, NotificationKind};
+use crate::modules::outbox::dto::TodoSnapshot;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::user::repository as user_repository;
+
+use super::dto::{NotificationPage, NotificationQuery, NotificationResponse, PreferenceResponse, UpdatePreferencesRequest};
+use super::entity::Model;
+use super::repository;
+
+const DEFAULT_PAGE_LIMIT: u64 = 20;
+const MAX_PAGE_LIMIT: u64 = 100;
+
+/// Error domain notifikasi yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum NotificationError {
+    Validation(String),
+    NotFound,
+    Database(DbErr),
+}
+
+impl From<DbErr> for NotificationError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Mengambil satu halaman inbox user beserta jumlah notifikasi yang belum dibaca.
+pub async fn list_notifications(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    query: NotificationQuery,
+) -> Result<NotificationPage, NotificationError> {
+    let (limit, offset) = page_bounds(&query)?;
+    let items = repository::list_notifications(conn, user_id, query.unread_only, limit, offset).await?;
+    let total = repository::count_notifications(conn, user_id, query.unread_only).await?;
+    let unread_count = if query.unread_only {
+        total
+    } else {
+        repository::count_notifications(conn, user_id, true).await?
+    };
+    Ok(NotificationPage {
+        items: items.into_iter().map(NotificationResponse::from).collect(),
+        unread_count,
+        total,
+        limit,
+        offset,
+    })
+}
+
+/// Menandai satu notifikasi sebagai dibaca; idempoten untuk notifikasi yang sudah dibaca.
+pub async fn mark_read(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    notification_id: Uuid,
+) -> Result<Model, NotificationError> {
+    repository::mark_read(conn, user_id, notification_id)
+        .await?
+        .ok_or(NotificationError::NotFound)
+}
+
+/// Menandai seluruh notifikasi user sebagai dibaca.
+pub async fn mark_all_read(conn: &DatabaseConnection, user_id: Uuid) -> Result<u64, NotificationError> {
+    Ok(repository::mark_all_read(conn, user_id).await?)
+}
+
+/// Preferensi user untuk semua jenis notifikasi; jenis yang belum diatur bernilai aktif.
+pub async fn get_preferences(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> Result<Vec<PreferenceResponse>, NotificationError> {
+    let stored: HashMap<String, bool> = repository::list_preferences(conn, user_id)
+        .await?
+        .into_iter()
+        .map(|preference| (preference.kind, preference.enabled))
+        .collect();
+    Ok(NotificationKind::ALL
+        .into_iter()
+        .map(|kind| PreferenceResponse {
+            kind,
+            enabled: stored.get(kind.as_str()).copied().unwrap_or(true),
+        })
+        .collect())
+}
+
+/// Mengubah preferensi beberapa jenis notifikasi lalu mengembalikan preferensi lengkap.
+pub async fn update_preferences(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: UpdatePreferencesRequest,
+) -> Result<Vec<PreferenceResponse>, NotificationError> {
+    let changes = parse_preferences(&request.preferences)?;
+    repository::upsert_preferences(conn, user_id, &changes).await?;
+    get_preferences(conn, user_id).await
+}
+
+/// Menyimpan notifikasi ke inbox bila jenisnya tidak dimatikan penerima.
+/// Mengembalikan `false` bila notifikasi dilewati karena preferensi.
+pub async fn deliver<C: ConnectionTrait>(conn: &C, notification: &Notification) -> Result<bool, DbErr> {
+    let enabled = repository::find_preference(conn, notification.user_id, notification.kind)
+        .await?
+        .unwrap_or(true);
+    if !enabled {
+        return Ok(false);
+    }
+    repository::insert_notification(conn, notification).await?;
+    Ok(true)
+}
+
+/// Memasukkan notifikasi ke antrean email bila jenisnya tidak dimatikan penerima.
+/// Mengembalikan `false` bila dilewati karena preferensi atau user sudah tidak ada.
+pub async fn queue_email<C: ConnectionTrait>(conn: &C, notification: &Notification) -> Result<bool, DbErr> {
+    let enabled = repository::find_preference(conn, notification.user_id, notification.kind)
+        .await?
+        .unwrap_or(true);
+    if !enabled {
+        return Ok(false);
+    }
+    let Some(user) = user_repository::find_user_by_id(conn, notification.user_id).await? else {
+        return Ok(false);
+    };
+    repository::insert_email(conn, notification, user.email, email_subject(notification.kind)).await?;
+    Ok(true)
+}
+
+// Subjek email per jenis notifikasi; isi email memakai pesan notifikasi apa adanya.
+fn email_subject(kind: NotificationKind) -> String {
+    match kind {
+        NotificationKind::Assigned => "A todo was assigned to you",
+        NotificationKind::Commented => "New comment on a todo",
+        NotificationKind::Reminder => "Todo reminder",
+    }
+    .to_string()
+}
+
+/// Mengirim notifikasi dari komentar baru secara best-effort:
+/// kegagalan hanya dicatat agar tidak menggagalkan operasi utama.
+pub async fn publish<C: ConnectionTrait>(conn: &C, notifications: Vec<Notification>) {
+    for notification in notifications {
+        if let Err(err) = deliver(conn, &notification).await {
+            warn!(
+                error = %err,
+                user_id = %notification.user_id,
+                kind = notification.kind.as_str(),
+                "failed to store notification"
+            );
+        }
+    }
+}
+
+/// Menghitung limit/offset efektif dari query string.
+pub fn page_bounds(query: &NotificationQuery) -> Result<(u64, u64), NotificationError> {
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        return Err(NotificationError::Validation(format!(
+            "limit must be between 1 and {MAX_PAGE_LIMIT}"
+        )));
+    }
+    Ok((limit, query.offset.unwrap_or(0)))
+}
+
+/// Validasi nama jenis notifikasi pada payload preferensi.
+pub fn parse_preferences(raw: &HashMap<String, bool>) -> Result<Vec<(NotificationKind, bool)>, NotificationError> {
+    let mut unknown: Vec<&str> = raw
+        .keys()
+        .filter(|kind| NotificationKind::parse(kind).is_none())
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        unknown.sort_unstable();
+        return Err(NotificationError::Validation(format!(
+            "unknown notification kind: {}",
+            unknown.join(", ")
+        )));
+    }
+    let mut changes: Vec<(NotificationKind, bool)> = raw
+        .iter()
+        .filter_map(|(kind, enabled)| NotificationKind::parse(kind).map(|kind| (kind, *enabled)))
+        .collect();
+    changes.sort_unstable_by_key(|(kind, _)| kind.as_str());
+    Ok(changes)
+}
+
+/// Daftar penerima event tanpa pelaku dan tanpa duplikat, urutan dipertahankan.
+pub fn recipients(candidates: impl IntoIterator<Item = Option<Uuid>>, actor_id: Uuid) -> Vec<Uuid> {
+    let mut result = Vec::new();
+    for user_id in candidates.into_iter().flatten() {
+        if user_id != actor_id && !result.contains(&user_id) {
+            result.push(user_id);
+        }
+    }
+    result
+}
+
+/// Notifikasi untuk assignee baru; `None` bila assignee tidak berubah, dikosongkan,
+/// atau pelaku menugaskan dirinya sendiri.
+pub fn assignment_notification(todo: &TodoSnapshot, actor_id: Uuid, previous_assignee: Option<Uuid>) -> Option<Notification> {
+    let assignee_id = todo.assignee_id?;
+    if Some(assignee_id) == previous_assignee || assignee_id == actor_id {
+        return None;
+    }
+    Some(Notification {
+        user_id: assignee_id,
+        kind: NotificationKind::Assigned,
+        todo_id: Some(todo.id),
+        actor_id: Some(actor_id),
+        message: format!("You were assigned \"{}\"", todo.title),
+    })
+}
+
+/// Notifikasi komentar baru untuk pemilik dan assignee todo, kecuali penulis komentar.
+pub fn comment_notifications(todo: &Todo, author_id: Uuid) -> Vec<Notification> {
+    recipients([Some(todo.user_id), todo.assignee_id], author_id)
+        .into_iter()
+        .map(|user_id| Notification {
+            user_id,
+            kind: NotificationKind::Commented,
+            todo_id: Some(todo.id),
+            actor_id: Some(author_id),
+            message: format!("New comment on \"{}\"", todo.title),
+        })
+        .collect()
+}
+
+// Modul tests unit untuk paging, validasi preferensi, dan pemilihan penerima.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_bounds_should_default_and_reject_out_of_range_limit() {
+        assert_eq!(page_bounds(&NotificationQuery::default()).unwrap(), (20, 0));
+        let query = NotificationQuery {
+            limit: Some(100),
+            offset: Some(40),
+            unread_only: false,
+        };
+        assert_eq!(page_bounds(&query).unwrap(), (100, 40));
+        for limit in [0, 101] {
+            let query = NotificationQuery {
+                limit: Some(limit),
+                ..NotificationQuery::default()
+            };
+            assert!(matches!(page_bounds(&query), Err(NotificationError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn parse_preferences_should_reject_unknown_kinds() {
+        let raw = HashMap::from([("reminder".to_string(), false), ("commented".to_string(), true)]);
+        assert_eq!(
+            parse_preferences(&raw).unwrap(),
+            vec![(NotificationKind::Commented, true), (NotificationKind::Reminder, false)]
+        );
+
+        let raw = HashMap::from([("mentioned".to_string(), true), ("assigned".to_string(), true)]);
+        match parse_preferences(&raw) {
+            Err(NotificationError::Validation(message)) => assert!(message.contains("mentioned")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn recipients_should_skip_actor_and_duplicates() {
+        let actor = Uuid::new_v4();
+        let owner = Uuid::new_v4();
+        let assignee = Uuid::new_v4();
+        assert_eq!(
+            recipients([Some(owner), Some(assignee), Some(owner), None, Some(actor)], actor),
+            vec![owner, assignee]
+        );
+        assert!(recipients([Some(actor)], actor).is_empty());
+    }
+}
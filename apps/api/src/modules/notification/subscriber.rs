@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+
+use crate::modules::outbox::dto::{TodoEvent, TodoEventPayload};
+use crate::modules::outbox::entity::Model as OutboxEvent;
+use crate::modules::outbox::subscriber::{OutboxSubscriber, SubscriberError};
+
+use super::service;
+
+/// Subscriber outbox yang membuat notifikasi `assigned` dari event `todo.created`/`todo.updated`.
+/// Notifikasi ditulis lewat transaksi relay sehingga tidak terduplikasi saat event di-retry.
+pub struct AssignmentSubscriber;
+
+#[async_trait]
+impl OutboxSubscriber for AssignmentSubscriber {
+    fn name(&self) -> &'static str {
+        "notification.assignment"
+    }
+
+    async fn handle(&self, txn: &DatabaseTransaction, event: &OutboxEvent) -> Result<(), SubscriberError> {
+        let relevant = [TodoEvent::Created, TodoEvent::Updated]
+            .iter()
+            .any(|kind| kind.as_str() == event.event_type);
+        if !relevant {
+            return Ok(());
+        }
+        let payload: TodoEventPayload = serde_json::from_value(event.payload.clone())?;
+        let todo = payload.snapshot()?;
+        if let Some(notification) =
+            service::assignment_notification(&todo, payload.actor_id, payload.previous_assignee_id)
+        {
+            service::deliver(txn, &notification).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Channel `LISTEN/NOTIFY` yang menerima `sequence` setiap event todo saat transaksinya ter-commit.
+pub const TODO_EVENTS_CHANNEL: &str = "todo_events";
+
+/// Jenis event domain todo yang dicatat ke outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoEvent {
+    Created,
+    Updated,
+    /// Dicatat bersama `todo.updated` saat todo berganti status menjadi selesai.
+    Completed,
+    Deleted,
+}
+
+impl TodoEvent {
+    /// Nilai kolom `event_type`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "todo.created",
+            Self::Updated => "todo.updated",
+            Self::Completed => "todo.completed",
+            Self::Deleted => "todo.deleted",
+        }
+    }
+}
+
+/// Status event di outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxStatus {
+    /// Menunggu diproses atau dijadwalkan retry.
+    Pending,
+    /// Semua subscriber sukses.
+    Processed,
+    /// Gagal sampai batas percobaan.
+    Failed,
+}
+
+impl OutboxStatus {
+    /// Nilai yang disimpan di kolom `status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Processed => "processed",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Payload event todo di outbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoEventPayload {
+    /// User yang melakukan perubahan.
+    pub actor_id: Uuid,
+    /// Assignee sebelum perubahan; hanya terisi untuk `todo.updated`/`todo.completed`.
+    #[serde(default)]
+    pub previous_assignee_id: Option<Uuid>,
+    /// Data todo setelah perubahan (sebelum dihapus untuk `todo.deleted`), berbentuk `TodoResponse`.
+    pub todo: Value,
+}
+
+impl TodoEventPayload {
+    /// Field todo yang dibutuhkan subscriber.
+    pub fn snapshot(&self) -> Result<TodoSnapshot, serde_json::Error> {
+        TodoSnapshot::deserialize(&self.todo)
+    }
+}
+
+/// Snapshot todo langsung dari kolom `payload`; `None` bila payload tidak valid.
+pub fn event_snapshot(payload: &Value) -> Option<TodoSnapshot> {
+    payload.get("todo").and_then(|todo| TodoSnapshot::deserialize(todo).ok())
+}
+
+/// Sebagian field todo dari payload event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TodoSnapshot {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[serde(default)]
+    pub workspace_id: Option<Uuid>,
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub assignee_id: Option<Uuid>,
+    pub title: String,
+}
+
+/// Event baru yang akan ditulis ke outbox.
+#[derive(Debug, Clone)]
+pub struct NewOutboxEvent {
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: Value,
+}
+
+/// Hasil satu putaran relay untuk satu event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayUpdate {
+    pub status: OutboxStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+    pub handled_by: Vec<String>,
+    pub last_error: Option<String>,
+    pub processed_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Nama subscriber dari kolom JSON `handled_by`; nilai non-string diabaikan.
+pub fn handled_names(handled_by: &Value) -> Vec<String> {
+    handled_by
+        .as_array()
+        .map(|items| items.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
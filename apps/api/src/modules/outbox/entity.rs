@@ -0,0 +1,35 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `outbox_events`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "outbox_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Urutan alokasi event; relay memproses event dengan urutan ini. Bukan urutan commit:
+    /// transaksi yang lebih lama bisa ter-commit setelah event dengan `sequence` lebih besar.
+    pub sequence: i64,
+    /// Id transaksi Postgres yang menulis event (diisi default kolom).
+    pub txid: i64,
+    /// Mis. `todo.created`.
+    pub event_type: String,
+    /// Id entitas sumber event (saat ini id todo).
+    pub aggregate_id: Uuid,
+    pub payload: Json,
+    /// `pending`, `processed`, atau `failed`.
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<FixedOffset>,
+    /// Array nama subscriber yang sudah sukses memproses event ini.
+    pub handled_by: Json,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub processed_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,8 @@
+//! Modul transactional outbox: event domain todo dicatat satu transaksi dengan perubahan datanya,
+//! lalu diteruskan relay ke subscriber secara asinkron.
+
+pub mod dto;
+pub mod entity;
+pub mod repository;
+pub mod service;
+pub mod subscriber;
@@ -0,0 +1,112 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, Statement, Value as DbValue,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::dto::{NewOutboxEvent, OutboxStatus, RelayUpdate};
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menulis event ke outbox; dipanggil dengan transaksi yang sama dengan perubahan datanya.
+pub async fn insert_event<C: ConnectionTrait>(conn: &C, event: NewOutboxEvent) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        event_type: Set(event.event_type),
+        aggregate_id: Set(event.aggregate_id),
+        payload: Set(event.payload),
+        status: Set(OutboxStatus::Pending.as_str().to_string()),
+        attempts: Set(0),
+        next_attempt_at: Set(now),
+        handled_by: Set(Value::Array(Vec::new())),
+        last_error: Set(None),
+        created_at: Set(now),
+        processed_at: Set(None),
+        ..Default::default()
+    };
+    active.insert(conn).await
+}
+
+/// Mengunci event `pending` yang jadwalnya sudah tiba, urut `sequence`.
+/// `aggregate_id` opsional membatasi ke event satu entitas.
+///
+/// Memakai `FOR UPDATE SKIP LOCKED` sehingga beberapa relay yang berjalan bersamaan
+/// mendapat event berbeda; kunci bertahan sampai transaksi `conn` selesai.
+pub async fn claim_pending<C: ConnectionTrait>(
+    conn: &C,
+    now: DateTime<FixedOffset>,
+    limit: u64,
+    aggregate_id: Option<Uuid>,
+) -> Result<Vec<Model>, DbErr> {
+    let sql = "SELECT * FROM outbox_events \
+               WHERE status = 'pending' AND next_attempt_at <= $1 \
+               AND ($3::uuid IS NULL OR aggregate_id = $3) \
+               ORDER BY sequence \
+               LIMIT $2 \
+               FOR UPDATE SKIP LOCKED";
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let aggregate_id: DbValue = aggregate_id.into();
+    Entity::find()
+        .from_raw_sql(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [now.into(), limit.into(), aggregate_id],
+        ))
+        .all(conn)
+        .await
+}
+
+/// Mengambil event berdasarkan `sequence`, apa pun statusnya.
+pub async fn find_by_sequence<C: ConnectionTrait>(conn: &C, sequence: i64) -> Result<Option<Model>, DbErr> {
+    Entity::find().filter(Column::Sequence.eq(sequence)).one(conn).await
+}
+
+/// Event yang ditulis transaksi dengan id `>= txid`, urut `sequence`, apa pun statusnya.
+/// Event dari transaksi di bawah watermark (`pg_snapshot_xmin`) pasti sudah terlihat, jadi
+/// membaca ulang dari watermark terakhir tidak melewatkan event yang ter-commit belakangan.
+pub async fn list_from_txid<C: ConnectionTrait>(conn: &C, txid: i64, limit: u64) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Txid.gte(txid))
+        .order_by_asc(Column::Sequence)
+        .limit(limit)
+        .all(conn)
+        .await
+}
+
+/// `(sequence, txid)` seluruh event yang ditulis transaksi dengan id `>= txid`.
+pub async fn list_positions_from_txid<C: ConnectionTrait>(conn: &C, txid: i64) -> Result<Vec<(i64, i64)>, DbErr> {
+    Entity::find()
+        .select_only()
+        .column(Column::Sequence)
+        .column(Column::Txid)
+        .filter(Column::Txid.gte(txid))
+        .into_tuple()
+        .all(conn)
+        .await
+}
+
+/// Menyimpan hasil satu putaran relay untuk event.
+pub async fn record_result<C: ConnectionTrait>(conn: &C, existing: Model, update: RelayUpdate) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = existing.into();
+    active.status = Set(update.status.as_str().to_string());
+    active.attempts = Set(update.attempts);
+    if let Some(next_attempt_at) = update.next_attempt_at {
+        active.next_attempt_at = Set(next_attempt_at);
+    }
+    active.handled_by = Set(Value::from(update.handled_by));
+    active.last_error = Set(update.last_error);
+    active.processed_at = Set(update.processed_at);
+    active.update(conn).await
+}
+
+/// Menghapus event `processed` yang lebih lama dari `before`; mengembalikan jumlah baris terhapus.
+pub async fn purge_processed(conn: &DatabaseConnection, before: DateTime<FixedOffset>) -> Result<u64, DbErr> {
+    let result = Entity::delete_many()
+        .filter(Column::Status.eq(OutboxStatus::Processed.as_str()))
+        .filter(Column::ProcessedAt.lt(before))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
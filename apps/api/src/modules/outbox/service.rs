@@ -0,0 +1,415 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, TransactionTrait};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::config::outbox::OutboxSettings;
+use crate::infrastructure::db::listener;
+use crate::modules::notification::subscriber::AssignmentSubscriber;
+use crate::modules::todo::dto::TodoResponse;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::webhook::subscriber::WebhookSubscriber;
+
+use super::dto::{
+    NewOutboxEvent, OutboxStatus, RelayUpdate, TODO_EVENTS_CHANNEL, TodoEvent, TodoEventPayload, handled_names,
+};
+use super::entity::Model;
+use super::repository;
+use super::subscriber::OutboxSubscriber;
+
+/// Jeda retry maksimal setelah subscriber gagal.
+const MAX_RETRY_DELAY_MINUTES: i64 = 60;
+
+/// Subscriber bawaan aplikasi: antrean webhook dan notifikasi assignment.
+pub fn default_subscribers() -> Vec<Arc<dyn OutboxSubscriber>> {
+    vec![Arc::new(WebhookSubscriber), Arc::new(AssignmentSubscriber)]
+}
+
+/// Mencatat event todo ke outbox. Harus dipanggil dengan transaksi yang sama dengan
+/// perubahan todo agar event hanya ada bila perubahannya ter-commit.
+/// `sequence` tiap event juga dikirim lewat `NOTIFY` ke `TODO_EVENTS_CHANNEL` saat commit.
+pub async fn record_todo_events<C: ConnectionTrait>(
+    conn: &C,
+    events: &[TodoEvent],
+    todo: &Todo,
+    actor_id: Uuid,
+    previous_assignee_id: Option<Uuid>,
+) -> Result<(), DbErr> {
+    let payload = TodoEventPayload {
+        actor_id,
+        previous_assignee_id,
+        todo: serde_json::to_value(TodoResponse::from(todo.clone()))
+            .map_err(|err| DbErr::Custom(format!("failed to serialize todo event: {err}")))?,
+    };
+    let payload = serde_json::to_value(payload)
+        .map_err(|err| DbErr::Custom(format!("failed to serialize todo event: {err}")))?;
+    for event in events {
+        let new_event = NewOutboxEvent {
+            event_type: event.as_str().to_string(),
+            aggregate_id: todo.id,
+            payload: payload.clone(),
+        };
+        let inserted = repository::insert_event(conn, new_event).await?;
+        listener::notify(conn, TODO_EVENTS_CHANNEL, &inserted.sequence.to_string()).await?;
+    }
+    Ok(())
+}
+
+/// Event yang dicatat untuk update todo: selalu `todo.updated`, ditambah `todo.completed`
+/// bila todo berganti status menjadi selesai.
+pub fn update_events(before: &Todo, after: &Todo) -> Vec<TodoEvent> {
+    let mut events = vec![TodoEvent::Updated];
+    if !before.iscompleted && after.iscompleted {
+        events.push(TodoEvent::Completed);
+    }
+    events
+}
+
+/// Loop relay outbox untuk dijalankan lewat `tokio::spawn` saat startup.
+///
+/// Tick pertama langsung berjalan sehingga event yang ter-commit sebelum restart diproses
+/// saat startup. Aman dijalankan di banyak instance sekaligus karena setiap batch dikunci
+/// dengan `SKIP LOCKED`. Event `processed` yang melewati masa retensi ikut dibersihkan.
+pub async fn run_relay(conn: DatabaseConnection, settings: OutboxSettings, subscribers: Vec<Arc<dyn OutboxSubscriber>>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.poll_interval_secs));
+    let retention = TimeDelta::hours(i64::try_from(settings.retention_hours).unwrap_or(i64::MAX / 3_600_000));
+    loop {
+        interval.tick().await;
+        match relay_pending(&conn, &settings, &subscribers, None).await {
+            Ok(0) => {}
+            Ok(processed) => info!(processed, "outbox events relayed"),
+            Err(err) => error!(error = %err, "failed to relay outbox events"),
+        }
+        match repository::purge_processed(&conn, Utc::now().fixed_offset() - retention).await {
+            Ok(0) => {}
+            Ok(purged) => info!(purged, "processed outbox events purged"),
+            Err(err) => error!(error = %err, "failed to purge outbox events"),
+        }
+    }
+}
+
+/// Meneruskan seluruh event `pending` yang jadwalnya sudah tiba ke subscriber, batch demi batch.
+/// `aggregate_id` opsional membatasi ke event satu todo.
+///
+/// Setiap batch diproses dalam satu transaksi: efek database subscriber, daftar `handled_by`,
+/// dan status event ter-commit bersama. Bila relay mati sebelum commit, tidak ada yang tercatat
+/// dan batch diproses ulang pada polling berikutnya. Subscriber yang sudah sukses pada
+/// percobaan sebelumnya tidak dipanggil lagi saat event di-retry.
+/// Mengembalikan jumlah event yang diproses.
+pub async fn relay_pending(
+    conn: &DatabaseConnection,
+    settings: &OutboxSettings,
+    subscribers: &[Arc<dyn OutboxSubscriber>],
+    aggregate_id: Option<Uuid>,
+) -> Result<u64, DbErr> {
+    let mut processed = 0;
+    loop {
+        let now = Utc::now().fixed_offset();
+        let txn = conn.begin().await?;
+        let claimed = repository::claim_pending(&txn, now, settings.batch_size, aggregate_id).await?;
+        let batch_len = claimed.len() as u64;
+        for event in claimed {
+            let (handled_by, last_error) = dispatch(&txn, &event, subscribers).await?;
+            let update = relay_update(&event, subscribers, handled_by, last_error, settings.max_attempts, now);
+            if update.status != OutboxStatus::Processed {
+                warn!(
+                    event_id = %event.id,
+                    event_type = %event.event_type,
+                    error = ?update.last_error,
+                    "outbox event relay failed"
+                );
+            }
+            repository::record_result(&txn, event, update).await?;
+        }
+        txn.commit().await?;
+        processed += batch_len;
+        if batch_len < settings.batch_size {
+            return Ok(processed);
+        }
+    }
+}
+
+/// Status berikutnya setelah satu putaran relay. Event selesai bila semua subscriber terdaftar
+/// sudah sukses; bila belum, dijadwalkan ulang dengan jeda eksponensial (1, 2, 4, ... menit,
+/// maksimal 60) sampai `max_attempts` tercapai, lalu ditandai `failed`.
+pub fn relay_update(
+    event: &Model,
+    subscribers: &[Arc<dyn OutboxSubscriber>],
+    handled_by: Vec<String>,
+    last_error: Option<String>,
+    max_attempts: u32,
+    now: DateTime<FixedOffset>,
+) -> RelayUpdate {
+    let attempts = event.attempts.saturating_add(1);
+    let complete = subscribers
+        .iter()
+        .all(|subscriber| handled_by.iter().any(|name| name == subscriber.name()));
+    if complete {
+        return RelayUpdate {
+            status: OutboxStatus::Processed,
+            attempts,
+            next_attempt_at: None,
+            handled_by,
+            last_error: None,
+            processed_at: Some(now),
+        };
+    }
+    if u32::try_from(attempts).unwrap_or(u32::MAX) >= max_attempts {
+        return RelayUpdate {
+            status: OutboxStatus::Failed,
+            attempts,
+            next_attempt_at: None,
+            handled_by,
+            last_error,
+            processed_at: None,
+        };
+    }
+    let exponent = u32::try_from(attempts - 1).unwrap_or(0).min(6);
+    let delay = (1_i64 << exponent).min(MAX_RETRY_DELAY_MINUTES);
+    RelayUpdate {
+        status: OutboxStatus::Pending,
+        attempts,
+        next_attempt_at: Some(now + TimeDelta::minutes(delay)),
+        handled_by,
+        last_error,
+        processed_at: None,
+    }
+}
+
+// Memanggil subscriber yang belum sukses, masing-masing di savepoint sendiri sehingga
+// kegagalan satu subscriber hanya membatalkan efeknya sendiri.
+async fn dispatch(
+    txn: &DatabaseTransaction,
+    event: &Model,
+    subscribers: &[Arc<dyn OutboxSubscriber>],
+) -> Result<(Vec<String>, Option<String>), DbErr> {
+    let mut handled_by = handled_names(&event.handled_by);
+    let mut errors = Vec::new();
+    for subscriber in subscribers {
+        if handled_by.iter().any(|name| name == subscriber.name()) {
+            continue;
+        }
+        let savepoint = txn.begin().await?;
+        match subscriber.handle(&savepoint, event).await {
+            Ok(()) => {
+                savepoint.commit().await?;
+                handled_by.push(subscriber.name().to_string());
+            }
+            Err(err) => {
+                savepoint.rollback().await?;
+                errors.push(format!("{}: {}", subscriber.name(), err.0));
+            }
+        }
+    }
+    let last_error = (!errors.is_empty()).then(|| errors.join("; "));
+    Ok((handled_by, last_error))
+}
+
+// Modul tests untuk relay outbox: unit test jadwal retry dan integrasi terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::TimeZone;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, Database, EntityTrait, QueryFilter, Set};
+    use serde_json::{Value, json};
+
+    use crate::config::history::HistorySettings;
+    use crate::modules::history::dto::AuditContext;
+    use crate::modules::outbox::entity::{ActiveModel, Column, Entity};
+    use crate::modules::outbox::subscriber::SubscriberError;
+    use crate::modules::todo::dto::NewTodo;
+    use crate::modules::todo::repository as todo_repository;
+    use crate::modules::todo::service as todo_service;
+
+    use super::*;
+
+    struct Named(&'static str);
+
+    #[async_trait]
+    impl OutboxSubscriber for Named {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        async fn handle(&self, _txn: &DatabaseTransaction, _event: &Model) -> Result<(), SubscriberError> {
+            Ok(())
+        }
+    }
+
+    // Mencatat event yang diterima; bila `fail_first`, percobaan pertama selalu gagal.
+    struct Recording {
+        name: &'static str,
+        fail_first: bool,
+        calls: Mutex<Vec<Uuid>>,
+    }
+
+    impl Recording {
+        fn new(name: &'static str, fail_first: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                fail_first,
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.lock().expect("lock").len()
+        }
+    }
+
+    #[async_trait]
+    impl OutboxSubscriber for Recording {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn handle(&self, _txn: &DatabaseTransaction, event: &Model) -> Result<(), SubscriberError> {
+            let mut calls = self.calls.lock().expect("lock");
+            calls.push(event.id);
+            if self.fail_first && calls.len() == 1 {
+                return Err(SubscriberError("receiver unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    fn at(hour: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2026, 2, 20, hour, 0, 0).unwrap().fixed_offset()
+    }
+
+    fn event(attempts: i32) -> Model {
+        Model {
+            id: Uuid::new_v4(),
+            sequence: 1,
+            txid: 1,
+            event_type: TodoEvent::Created.as_str().to_string(),
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({}),
+            status: OutboxStatus::Pending.as_str().to_string(),
+            attempts,
+            next_attempt_at: at(9),
+            handled_by: json!([]),
+            last_error: None,
+            created_at: at(9),
+            processed_at: None,
+        }
+    }
+
+    #[test]
+    fn relay_update_should_complete_only_when_every_subscriber_succeeded() {
+        let subscribers: Vec<Arc<dyn OutboxSubscriber>> = vec![Arc::new(Named("a")), Arc::new(Named("b"))];
+        let done = relay_update(&event(0), &subscribers, vec!["b".into(), "a".into()], None, 3, at(9));
+        assert_eq!(done.status, OutboxStatus::Processed);
+        assert_eq!(done.processed_at, Some(at(9)));
+
+        let partial = relay_update(&event(0), &subscribers, vec!["a".into()], Some("b: boom".into()), 3, at(9));
+        assert_eq!(partial.status, OutboxStatus::Pending);
+        assert_eq!(partial.handled_by, vec!["a".to_string()]);
+        assert_eq!(partial.next_attempt_at, Some(at(9) + TimeDelta::minutes(1)));
+        let later = relay_update(&event(1), &subscribers, vec![], Some("boom".into()), 3, at(9));
+        assert_eq!(later.next_attempt_at, Some(at(9) + TimeDelta::minutes(2)));
+
+        let failed = relay_update(&event(2), &subscribers, vec![], Some("boom".into()), 3, at(9));
+        assert_eq!(failed.status, OutboxStatus::Failed);
+        assert_eq!(failed.attempts, 3);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn outbox_should_follow_commit_and_retry_only_failed_subscribers() {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let audit = AuditContext::new(user_id, &HistorySettings::default());
+
+        // Perubahan yang di-rollback tidak meninggalkan event.
+        let txn = db.begin().await.expect("transaction must start");
+        let new_todo = NewTodo {
+            id: Uuid::new_v4(),
+            user_id,
+            workspace_id: None,
+            project_id: None,
+            assignee_id: None,
+            title: "rolled back".to_string(),
+            desc: None,
+            estimate: None,
+            due_at: None,
+            completed_at: None,
+            external_uid: None,
+        };
+        let ghost = todo_repository::create_todo(&txn, new_todo, &audit)
+            .await
+            .expect("insert must succeed");
+        record_todo_events(&txn, &[TodoEvent::Created], &ghost, user_id, None)
+            .await
+            .expect("outbox insert must succeed");
+        txn.rollback().await.expect("rollback must succeed");
+        let orphaned = Entity::find()
+            .filter(Column::AggregateId.eq(ghost.id))
+            .all(&db)
+            .await
+            .expect("query must succeed");
+        assert!(orphaned.is_empty());
+
+        let request = serde_json::from_value(json!({ "title": "relay me" })).expect("request must parse");
+        let todo = todo_service::create_todo(&db, user_id, None, request, &audit)
+            .await
+            .expect("create must succeed");
+        let stored = Entity::find()
+            .filter(Column::AggregateId.eq(todo.id))
+            .one(&db)
+            .await
+            .expect("query must succeed")
+            .expect("created event must be recorded");
+        assert_eq!(stored.event_type, "todo.created");
+        assert_eq!(stored.payload["todo"]["title"], "relay me");
+
+        let steady = Recording::new("steady", false);
+        let flaky = Recording::new("flaky", true);
+        let subscribers: Vec<Arc<dyn OutboxSubscriber>> = vec![steady.clone(), flaky.clone()];
+        let settings = OutboxSettings::default();
+        relay_pending(&db, &settings, &subscribers, Some(todo.id))
+            .await
+            .expect("relay must succeed");
+        let retried = Entity::find_by_id(stored.id)
+            .one(&db)
+            .await
+            .expect("query must succeed")
+            .expect("event must exist");
+        assert_eq!(retried.status, "pending");
+        assert_eq!(retried.handled_by, Value::from(vec!["steady"]));
+        assert!(retried.last_error.as_deref().is_some_and(|err| err.contains("flaky")));
+
+        // Retry dimajukan agar tidak menunggu jeda backoff; subscriber yang sudah sukses dilewati.
+        let mut due: ActiveModel = retried.into();
+        due.next_attempt_at = Set(Utc::now().fixed_offset());
+        due.update(&db).await.expect("reschedule must succeed");
+        relay_pending(&db, &settings, &subscribers, Some(todo.id))
+            .await
+            .expect("relay must succeed");
+        relay_pending(&db, &settings, &subscribers, Some(todo.id))
+            .await
+            .expect("relay must succeed");
+        assert_eq!(steady.calls(), 1);
+        assert_eq!(flaky.calls(), 2);
+        let done = Entity::find_by_id(stored.id)
+            .one(&db)
+            .await
+            .expect("query must succeed")
+            .expect("event must exist");
+        assert_eq!(done.status, "processed");
+        assert!(done.processed_at.is_some());
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use sea_orm::{DatabaseTransaction, DbErr};
+
+use super::entity::Model;
+
+/// Kontrak penerima event outbox yang didaftarkan ke relay.
+///
+/// `handle` dipanggil di dalam savepoint transaksi relay: perubahan database lewat `txn`
+/// ikut ter-commit bersama status event, sehingga subscriber berbasis database diproses
+/// tepat sekali. Efek di luar database (HTTP, dsb.) bisa terulang bila relay mati sebelum
+/// commit, jadi subscriber seperti itu sebaiknya deduplikasi lewat `event.id`.
+#[async_trait]
+pub trait OutboxSubscriber: Send + Sync {
+    /// Nama unik dan stabil; dicatat di `handled_by` agar subscriber yang sudah sukses
+    /// tidak dipanggil ulang saat event di-retry.
+    fn name(&self) -> &'static str;
+
+    async fn handle(&self, txn: &DatabaseTransaction, event: &Model) -> Result<(), SubscriberError>;
+}
+
+/// Error dari subscriber; pesannya disimpan sebagai alasan gagal di outbox.
+#[derive(Debug)]
+pub struct SubscriberError(pub String);
+
+impl fmt::Display for SubscriberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "outbox subscriber failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for SubscriberError {}
+
+impl From<DbErr> for SubscriberError {
+    fn from(value: DbErr) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<serde_json::Error> for SubscriberError {
+    fn from(value: serde_json::Error) -> Self {
+        Self(format!("invalid event payload: {value}"))
+    }
+}
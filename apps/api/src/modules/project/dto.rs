@@ -0,0 +1,49 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::sharing::dto::ShareRole;
+
+use super::entity;
+
+/// Payload request untuk membuat project baru.
+#[derive(Debug, Deserialize)]
+pub struct CreateProjectRequest {
+    pub name: String,
+}
+
+/// Payload request untuk mengubah project.
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectRequest {
+    /// Nama baru; `None` berarti tidak diubah.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Bentuk data project yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct ProjectResponse {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub workspace_id: Option<Uuid>,
+    pub name: String,
+    /// Role efektif user saat ini pada project.
+    pub role: ShareRole,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl ProjectResponse {
+    /// Mapping model database + role user saat ini ke response HTTP.
+    pub fn new(value: entity::Model, role: ShareRole) -> Self {
+        Self {
+            id: value.id,
+            owner_id: value.owner_id,
+            workspace_id: value.workspace_id,
+            name: value.name,
+            role,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::sharing::dto::ShareRole;
+
+use super::dto::{CreateProjectRequest, ProjectResponse, UpdateProjectRequest};
+use super::service::{self, ProjectError};
+
+/// Registrasi route project, di-mount di bawah prefix `/projects`.
+///
+/// - `POST /` buat project
+/// - `GET /` daftar project milik/dibagikan ke user
+/// - `GET /{id}` detail project
+/// - `PATCH /{id}` ubah project (owner saja)
+/// - `DELETE /{id}` hapus project (owner saja)
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_project).get(list_projects))
+        .route(
+            "/{id}",
+            get(get_project).patch(update_project).delete(delete_project),
+        )
+}
+
+/// POST `/projects`
+///
+/// Input: `CreateProjectRequest`.
+/// Output: `201 Created` + `SuccessResponse<ProjectResponse>`.
+pub async fn create_project(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateProjectRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<ProjectResponse>>), ApiError> {
+    let project = service::create_project(&state.db, user.id, user.workspace_id, payload)
+        .await
+        .map_err(map_project_error)?;
+    info!(project_id = %project.id, "project created");
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: ProjectResponse::new(project, ShareRole::Owner),
+        }),
+    ))
+}
+
+/// GET `/projects`
+/// Output: `200 OK` + daftar project beserta role user.
+pub async fn list_projects(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<ProjectResponse>>>, ApiError> {
+    let projects = service::list_projects(&state.db, user.id, user.workspace_id)
+        .await
+        .map_err(map_project_error)?;
+    info!(count = projects.len(), "projects listed");
+    let data = projects
+        .into_iter()
+        .map(|(project, role)| ProjectResponse::new(project, role))
+        .collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// GET `/projects/{id}`
+/// Output: `200 OK` bila bisa diakses, `404` bila tidak.
+pub async fn get_project(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<ProjectResponse>>, ApiError> {
+    let (project, role) = service::get_project(&state.db, user.id, project_id)
+        .await
+        .map_err(map_project_error)?;
+    info!(project_id = %project_id, "project detail fetched");
+    Ok(Json(SuccessResponse {
+        data: ProjectResponse::new(project, role),
+    }))
+}
+
+/// PATCH `/projects/{id}`
+/// Input: `UpdateProjectRequest`.
+/// Output: `200 OK` dengan data project terbaru.
+pub async fn update_project(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(project_id): Path<Uuid>,
+    Json(payload): Json<UpdateProjectRequest>,
+) -> Result<Json<SuccessResponse<ProjectResponse>>, ApiError> {
+    let (project, role) = service::update_project(&state.db, user.id, project_id, payload)
+        .await
+        .map_err(map_project_error)?;
+    info!(project_id = %project_id, "project updated");
+    Ok(Json(SuccessResponse {
+        data: ProjectResponse::new(project, role),
+    }))
+}
+
+/// DELETE `/projects/{id}`
+/// Output: `204 No Content`.
+pub async fn delete_project(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_project(&state.db, user.id, project_id)
+        .await
+        .map_err(map_project_error)?;
+    info!(project_id = %project_id, "project deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain project ke `ApiError`.
+fn map_project_error(err: ProjectError) -> ApiError {
+    match err {
+        ProjectError::Validation(message) => {
+            warn!(reason = %message, "project validation failed");
+            ApiError::bad_request(message)
+        }
+        ProjectError::NotFound => {
+            warn!("project not found");
+            ApiError::not_found("project not found")
+        }
+        ProjectError::Forbidden => {
+            warn!("project modification by non-owner rejected");
+            ApiError::forbidden("only project owners can modify this project")
+        }
+        ProjectError::Database(db_err) => {
+            error!(error = %db_err, "project database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Modul project: pengelompokan todo yang bisa dibagikan ke user lain.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,97 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    Order, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use crate::modules::sharing::repository as sharing_repository;
+use crate::modules::workspace::repository as workspace_repository;
+
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+
+/// Menyimpan project baru milik `owner_id`, opsional di dalam workspace.
+pub async fn create_project(
+    conn: &DatabaseConnection,
+    owner_id: Uuid,
+    workspace_id: Option<Uuid>,
+    name: String,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        owner_id: Set(owner_id),
+        workspace_id: Set(workspace_id),
+        name: Set(name),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil satu project berdasarkan id tanpa filter akses.
+pub async fn find_project_by_id<C: ConnectionTrait>(conn: &C, project_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(project_id).one(conn).await
+}
+
+/// Mengambil project milik user, yang dibagikan ke user, atau di workspace tempat user
+/// menjadi member non-guest; dibatasi pada workspace aktif (`None` = ruang pribadi).
+pub async fn list_accessible_projects(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Vec<Model>, DbErr> {
+    let scope = match workspace_id {
+        Some(workspace_id) => Column::WorkspaceId.eq(workspace_id),
+        None => Column::WorkspaceId.is_null(),
+    };
+    Entity::find()
+        .filter(accessible_condition(user_id))
+        .filter(scope)
+        .order_by(Column::CreatedAt, Order::Desc)
+        .all(conn)
+        .await
+}
+
+/// Seperti `list_accessible_projects` tetapi lintas seluruh workspace (dipakai CalDAV,
+/// yang tidak mengenal workspace aktif), urut paling lama dibuat.
+pub async fn list_all_accessible_projects(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(accessible_condition(user_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+// Project milik user, dibagikan ke user, atau di workspace tempat user member non-guest.
+fn accessible_condition(user_id: Uuid) -> Condition {
+    Condition::any()
+        .add(Column::OwnerId.eq(user_id))
+        .add(Column::Id.in_subquery(sharing_repository::shared_project_ids_query(user_id)))
+        .add(Column::WorkspaceId.in_subquery(workspace_repository::content_workspace_ids_query(user_id)))
+}
+
+/// Mengambil project berdasarkan daftar id (dipakai `shared-with-me`).
+pub async fn find_projects_by_ids(conn: &DatabaseConnection, project_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    if project_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Entity::find()
+        .filter(Column::Id.is_in(project_ids.iter().copied()))
+        .all(conn)
+        .await
+}
+
+/// Mengganti nama project dan memperbarui `updated_at`.
+pub async fn rename_project(conn: &DatabaseConnection, existing: Model, name: String) -> Result<Model, DbErr> {
+    let mut active: entity::ActiveModel = existing.into();
+    active.name = Set(name);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus project; todo di dalamnya tetap ada dengan `project_id` = null.
+pub async fn delete_project(conn: &DatabaseConnection, project_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(project_id).exec(conn).await?;
+    Ok(())
+}
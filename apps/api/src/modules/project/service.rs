@@ -0,0 +1,149 @@
+use sea_orm::{DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::sharing::dto::ShareRole;
+use crate::modules::sharing::repository as sharing_repository;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::workspace::service as workspace_service;
+
+use super::dto::{CreateProjectRequest, UpdateProjectRequest};
+use super::entity::Model;
+use super::repository;
+
+const MAX_NAME_LEN: usize = 200;
+
+/// Error domain project yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum ProjectError {
+    Validation(String),
+    NotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for ProjectError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for ProjectError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Membuat project baru dengan user saat ini sebagai owner, di workspace aktif
+/// (`None` = ruang pribadi). Membuat project di workspace butuh `workspace.write`.
+pub async fn create_project(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    request: CreateProjectRequest,
+) -> Result<Model, ProjectError> {
+    let name = normalize_name(request.name)?;
+    if let Some(workspace_id) = workspace_id {
+        workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceWrite).await?;
+    }
+    repository::create_project(conn, user_id, workspace_id, name)
+        .await
+        .map_err(ProjectError::from)
+}
+
+/// Mengambil project di workspace aktif yang bisa diakses user, beserta role efektifnya.
+pub async fn list_projects(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Vec<(Model, ShareRole)>, ProjectError> {
+    let projects = repository::list_accessible_projects(conn, user_id, workspace_id).await?;
+    let grants = sharing_repository::list_grants_for_grantee(conn, user_id).await?;
+    let via_workspace = match workspace_id {
+        Some(workspace_id) => sharing_service::workspace_content_role(conn, user_id, workspace_id).await?,
+        None => None,
+    };
+    let with_roles = projects
+        .into_iter()
+        .map(|project| {
+            let grant = grants
+                .iter()
+                .find(|grant| grant.project_id == Some(project.id))
+                .and_then(|grant| ShareRole::parse(&grant.role));
+            let role = sharing_service::strongest_role(project.owner_id == user_id, &[grant, via_workspace])
+                .unwrap_or(ShareRole::Viewer);
+            (project, role)
+        })
+        .collect();
+    Ok(with_roles)
+}
+
+/// Mengambil detail project yang bisa diakses user.
+pub async fn get_project(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+) -> Result<(Model, ShareRole), ProjectError> {
+    sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectRead)
+        .await
+        .map_err(ProjectError::from)
+}
+
+/// Mengubah project; hanya owner.
+pub async fn update_project(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    request: UpdateProjectRequest,
+) -> Result<(Model, ShareRole), ProjectError> {
+    let (project, role) =
+        sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectManage).await?;
+    let Some(name) = request.name else {
+        return Ok((project, role));
+    };
+    let name = normalize_name(name)?;
+    let updated = repository::rename_project(conn, project, name).await?;
+    Ok((updated, role))
+}
+
+/// Menghapus project; hanya owner. Todo di dalamnya dilepas dari project, bukan dihapus.
+pub async fn delete_project(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+) -> Result<(), ProjectError> {
+    sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectManage).await?;
+    repository::delete_project(conn, project_id)
+        .await
+        .map_err(ProjectError::from)
+}
+
+/// Normalisasi dan validasi nama project.
+pub fn normalize_name(raw_name: String) -> Result<String, ProjectError> {
+    let name = raw_name.trim();
+    if name.is_empty() {
+        return Err(ProjectError::Validation("name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(ProjectError::Validation(format!(
+            "name must be at most {MAX_NAME_LEN} characters"
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_name_should_trim_and_validate() {
+        assert_eq!(normalize_name("  Rilis v2  ".to_string()).ok(), Some("Rilis v2".to_string()));
+        assert!(matches!(normalize_name("   ".to_string()), Err(ProjectError::Validation(_))));
+        assert!(matches!(normalize_name("a".repeat(201)), Err(ProjectError::Validation(_))));
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity;
+
+/// Payload request untuk membuat reminder; isi tepat salah satu field.
+#[derive(Debug, Deserialize)]
+pub struct CreateReminderRequest {
+    /// Waktu kirim absolut.
+    #[serde(default)]
+    pub remind_at: Option<DateTime<FixedOffset>>,
+    /// Menit sebelum tenggat todo (`due_at`).
+    #[serde(default)]
+    pub offset_minutes: Option<i32>,
+}
+
+/// Status pengiriman reminder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReminderStatus {
+    /// Menunggu jatuh tempo atau jadwal retry.
+    Pending,
+    Sent,
+    /// Tidak dikirim karena todo sudah selesai atau penerima kehilangan akses.
+    Skipped,
+    /// Gagal terkirim sampai batas percobaan.
+    Failed,
+}
+
+impl ReminderStatus {
+    /// Nilai yang disimpan di kolom `status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Sent => "sent",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Parsing nilai kolom `status`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "pending" => Some(Self::Pending),
+            "sent" => Some(Self::Sent),
+            "skipped" => Some(Self::Skipped),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Reminder baru yang sudah tervalidasi.
+#[derive(Debug, Clone)]
+pub struct NewReminder {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub remind_at: Option<DateTime<FixedOffset>>,
+    pub offset_minutes: Option<i32>,
+}
+
+/// Hasil satu percobaan kirim yang disimpan kembali oleh scheduler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryUpdate {
+    pub status: ReminderStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Bentuk data reminder yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct ReminderResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub remind_at: Option<DateTime<FixedOffset>>,
+    pub offset_minutes: Option<i32>,
+    /// Waktu kirim efektif; `None` bila reminder relatif tetapi todo belum punya tenggat.
+    pub fire_at: Option<DateTime<FixedOffset>>,
+    pub status: ReminderStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl ReminderResponse {
+    /// Mapping model database + waktu kirim efektif ke response HTTP.
+    pub fn new(value: entity::Model, fire_at: Option<DateTime<FixedOffset>>) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            user_id: value.user_id,
+            remind_at: value.remind_at,
+            offset_minutes: value.offset_minutes,
+            fire_at,
+            status: ReminderStatus::parse(&value.status).unwrap_or(ReminderStatus::Pending),
+            attempts: value.attempts,
+            last_error: value.last_error,
+            sent_at: value.sent_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
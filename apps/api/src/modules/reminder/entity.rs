@@ -0,0 +1,33 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `reminders`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[sea_orm(table_name = "reminders")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    /// Penerima reminder.
+    pub user_id: Uuid,
+    /// Waktu kirim absolut; terisi bila `offset_minutes` kosong.
+    pub remind_at: Option<DateTime<FixedOffset>>,
+    /// Menit sebelum `todos.due_at`; terisi bila `remind_at` kosong.
+    pub offset_minutes: Option<i32>,
+    /// `pending`, `sent`, `skipped`, atau `failed`.
+    pub status: String,
+    pub attempts: i32,
+    /// Jadwal percobaan ulang setelah pengiriman gagal.
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
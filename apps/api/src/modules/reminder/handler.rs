@@ -0,0 +1,428 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{CreateReminderRequest, ReminderResponse};
+use super::service::{self, ReminderError};
+
+/// Registrasi route reminder, di-mount di bawah prefix `/todos`.
+///
+/// - `GET /{id}/reminders` daftar reminder milik user saat ini
+/// - `POST /{id}/reminders` buat reminder absolut atau relatif terhadap tenggat
+/// - `DELETE /{id}/reminders/{reminder_id}` hapus reminder
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/reminders", get(list_reminders).post(create_reminder))
+        .route("/{id}/reminders/{reminder_id}", delete(delete_reminder))
+}
+
+/// GET `/todos/{id}/reminders`
+/// Output: `200 OK` + daftar reminder milik user saat ini.
+pub async fn list_reminders(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<ReminderResponse>>>, ApiError> {
+    let (reminders, due_at) = service::list_reminders(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_reminder_error)?;
+    info!(todo_id = %todo_id, count = reminders.len(), "reminders listed");
+    let data = reminders
+        .into_iter()
+        .map(|reminder| {
+            let fire_at = service::fire_time(reminder.remind_at, reminder.offset_minutes, due_at);
+            ReminderResponse::new(reminder, fire_at)
+        })
+        .collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// POST `/todos/{id}/reminders`
+///
+/// Input: `CreateReminderRequest`.
+/// Output: `201 Created` + `SuccessResponse<ReminderResponse>`.
+pub async fn create_reminder(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<CreateReminderRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<ReminderResponse>>), ApiError> {
+    let (reminder, due_at) = service::create_reminder(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_reminder_error)?;
+    info!(todo_id = %todo_id, reminder_id = %reminder.id, "reminder created");
+    let fire_at = service::fire_time(reminder.remind_at, reminder.offset_minutes, due_at);
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: ReminderResponse::new(reminder, fire_at),
+        }),
+    ))
+}
+
+/// DELETE `/todos/{id}/reminders/{reminder_id}`
+/// Output: `204 No Content`.
+pub async fn delete_reminder(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, reminder_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_reminder(&state.db, user.id, todo_id, reminder_id)
+        .await
+        .map_err(map_reminder_error)?;
+    info!(todo_id = %todo_id, reminder_id = %reminder_id, "reminder deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain reminder ke `ApiError`.
+fn map_reminder_error(err: ReminderError) -> ApiError {
+    match err {
+        ReminderError::Validation(message) => {
+            warn!(reason = %message, "reminder validation failed");
+            ApiError::bad_request(message)
+        }
+        ReminderError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        ReminderError::NotFound => {
+            warn!("reminder not found");
+            ApiError::not_found("reminder not found")
+        }
+        ReminderError::Forbidden => {
+            warn!("reminder action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        ReminderError::Database(db_err) => {
+            error!(error = %db_err, "reminder database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint dan scheduler reminder terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use chrono::{TimeDelta, Utc};
+    use sea_orm::{
+        ActiveModelTrait, ColumnTrait, Database, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, Set,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::config::reminder::ReminderSettings;
+    use crate::infrastructure::notifier::{FanOutNotifier, Notification, Notifier, NotifyError};
+    use crate::modules::notification::{
+        email_entity, entity as notification_entity,
+        notifier::{EmailOutboxNotifier, InboxNotifier},
+    };
+    use crate::modules::reminder::entity::{ActiveModel, Entity, Model};
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+    use crate::modules::webhook::{
+        delivery_entity, dto::WebhookEvent, notifier::WebhookNotifier, repository as webhook_repository,
+    };
+
+    use super::*;
+
+    async fn connect() -> (DatabaseConnection, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        (db, default_user_id)
+    }
+
+    async fn build_test_app(db: DatabaseConnection, default_user_id: Uuid) -> Router {
+        Router::new()
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        sent: Mutex<Vec<Notification>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify(&self, _txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+            self.sent.lock().expect("lock").push(notification.clone());
+            Ok(())
+        }
+    }
+
+    // Menulis ke inbox lalu gagal, meniru kanal yang error setelah sebagian tulisannya jalan.
+    struct FailingAfterInboxNotifier;
+
+    #[async_trait]
+    impl Notifier for FailingAfterInboxNotifier {
+        async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+            InboxNotifier.notify(txn, notification).await?;
+            Err(NotifyError("channel unavailable".to_string()))
+        }
+    }
+
+    async fn insert_due_reminder(db: &DatabaseConnection, todo_id: Uuid, user_id: Uuid) -> Model {
+        let now = Utc::now().fixed_offset();
+        ActiveModel {
+            id: Set(Uuid::new_v4()),
+            todo_id: Set(todo_id),
+            user_id: Set(user_id),
+            remind_at: Set(Some(now - TimeDelta::minutes(10))),
+            offset_minutes: Set(None),
+            status: Set("pending".to_string()),
+            attempts: Set(0),
+            next_attempt_at: Set(None),
+            last_error: Set(None),
+            sent_at: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await
+        .expect("reminder insert must succeed")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn reminder_endpoints_should_resolve_offset_from_due_at() {
+        let (db, user_id) = connect().await;
+        let app = build_test_app(db, user_id).await;
+        let todo = read_json(
+            send(
+                &app,
+                "POST",
+                "/todos".to_string(),
+                Some(json!({ "title": "file taxes", "due_at": "2099-04-15T12:00:00+00:00" })),
+            )
+            .await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        assert_eq!(todo["data"]["due_at"], "2099-04-15T12:00:00Z");
+
+        let relative = send(
+            &app,
+            "POST",
+            format!("/todos/{todo_id}/reminders"),
+            Some(json!({ "offset_minutes": 90 })),
+        )
+        .await;
+        assert_eq!(relative.status(), StatusCode::CREATED);
+        assert_eq!(read_json(relative).await["data"]["fire_at"], "2099-04-15T10:30:00Z");
+
+        let both = send(
+            &app,
+            "POST",
+            format!("/todos/{todo_id}/reminders"),
+            Some(json!({ "offset_minutes": 5, "remind_at": "2099-04-01T00:00:00+00:00" })),
+        )
+        .await;
+        assert_eq!(both.status(), StatusCode::BAD_REQUEST);
+
+        send(
+            &app,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "due_at": "2099-05-01T12:00:00+00:00" })),
+        )
+        .await;
+        let listed = read_json(send(&app, "GET", format!("/todos/{todo_id}/reminders"), None).await).await;
+        assert_eq!(listed["data"][0]["fire_at"], "2099-05-01T10:30:00Z");
+        assert_eq!(listed["data"][0]["status"], "pending");
+
+        let reminder_id = listed["data"][0]["id"].as_str().expect("reminder id").to_string();
+        let deleted = send(&app, "DELETE", format!("/todos/{todo_id}/reminders/{reminder_id}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn scheduler_should_deliver_due_reminder_exactly_once_across_workers() {
+        let (db, user_id) = connect().await;
+        let app = build_test_app(db.clone(), user_id).await;
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "standup" }))).await,
+        )
+        .await;
+        let todo_id = Uuid::parse_str(todo["data"]["id"].as_str().expect("todo id")).expect("uuid");
+
+        // Reminder yang jatuh tempo selama server mati dibuat langsung lewat database.
+        let reminder = insert_due_reminder(&db, todo_id, user_id).await;
+
+        let settings = ReminderSettings {
+            batch_size: 1,
+            ..ReminderSettings::default()
+        };
+        let first = RecordingNotifier::default();
+        let second = RecordingNotifier::default();
+        let (a, b) = tokio::join!(
+            service::deliver_due_reminders(&db, &settings, &first),
+            service::deliver_due_reminders(&db, &settings, &second),
+        );
+        a.expect("worker a must succeed");
+        b.expect("worker b must succeed");
+        let deliveries = [&first, &second]
+            .iter()
+            .flat_map(|notifier| notifier.sent.lock().expect("lock").clone())
+            .filter(|notification| notification.todo_id == Some(todo_id))
+            .count();
+        assert_eq!(deliveries, 1);
+
+        let stored = Entity::find_by_id(reminder.id)
+            .one(&db)
+            .await
+            .expect("query must succeed")
+            .expect("reminder must exist");
+        assert_eq!(stored.status, "sent");
+        assert!(stored.sent_at.is_some());
+
+        let again = RecordingNotifier::default();
+        service::deliver_due_reminders(&db, &settings, &again)
+            .await
+            .expect("redelivery run must succeed");
+        assert!(again.sent.lock().expect("lock").iter().all(|n| n.todo_id != Some(todo_id)));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn scheduler_should_roll_back_inbox_write_when_channel_fails() {
+        let (db, user_id) = connect().await;
+        let app = build_test_app(db.clone(), user_id).await;
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "renew passport" }))).await,
+        )
+        .await;
+        let todo_id = Uuid::parse_str(todo["data"]["id"].as_str().expect("todo id")).expect("uuid");
+        let reminder = insert_due_reminder(&db, todo_id, user_id).await;
+
+        let settings = ReminderSettings {
+            batch_size: 1,
+            ..ReminderSettings::default()
+        };
+        service::deliver_due_reminders(&db, &settings, &FailingAfterInboxNotifier)
+            .await
+            .expect("scheduler run must succeed");
+
+        let stored = Entity::find_by_id(reminder.id)
+            .one(&db)
+            .await
+            .expect("query must succeed")
+            .expect("reminder must exist");
+        assert_eq!(stored.status, "pending");
+        assert_eq!(stored.attempts, 1);
+        let inbox = notification_entity::Entity::find()
+            .filter(notification_entity::Column::TodoId.eq(todo_id))
+            .all(&db)
+            .await
+            .expect("query must succeed");
+        assert!(inbox.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn scheduler_should_fan_out_reminder_to_inbox_email_and_webhook() {
+        let (db, user_id) = connect().await;
+        let app = build_test_app(db.clone(), user_id).await;
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "water plants" }))).await,
+        )
+        .await;
+        let todo_id = Uuid::parse_str(todo["data"]["id"].as_str().expect("todo id")).expect("uuid");
+        let endpoint = webhook_repository::create_endpoint(
+            &db,
+            user_id,
+            "https://hooks.example.com/notifications".to_string(),
+            "secret".to_string(),
+            &[WebhookEvent::Notification],
+        )
+        .await
+        .expect("endpoint insert must succeed");
+        insert_due_reminder(&db, todo_id, user_id).await;
+
+        let notifier = FanOutNotifier::new(vec![
+            Arc::new(InboxNotifier),
+            Arc::new(EmailOutboxNotifier),
+            Arc::new(WebhookNotifier),
+        ]);
+        let settings = ReminderSettings {
+            batch_size: 1,
+            ..ReminderSettings::default()
+        };
+        service::deliver_due_reminders(&db, &settings, &notifier)
+            .await
+            .expect("scheduler run must succeed");
+
+        let inbox = notification_entity::Entity::find()
+            .filter(notification_entity::Column::TodoId.eq(todo_id))
+            .all(&db)
+            .await
+            .expect("query must succeed");
+        assert_eq!(inbox.len(), 1);
+        let emails = email_entity::Entity::find()
+            .filter(email_entity::Column::TodoId.eq(todo_id))
+            .all(&db)
+            .await
+            .expect("query must succeed");
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].status, "pending");
+        assert_eq!(emails[0].kind, "reminder");
+        let deliveries = delivery_entity::Entity::find()
+            .filter(delivery_entity::Column::EndpointId.eq(endpoint.id))
+            .all(&db)
+            .await
+            .expect("query must succeed");
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].event_type, "notification.created");
+        assert_eq!(deliveries[0].payload["data"]["notification"]["todo_id"], json!(todo_id));
+
+        webhook_repository::delete_endpoint(&db, endpoint.id)
+            .await
+            .expect("endpoint cleanup must succeed");
+    }
+}
@@ -0,0 +1,7 @@
+//! Modul reminder todo: DTO, entity, repository, service (termasuk scheduler), dan handler HTTP.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
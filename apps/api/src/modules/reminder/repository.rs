@@ -0,0 +1,113 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, Statement,
+};
+use uuid::Uuid;
+
+use super::dto::{DeliveryUpdate, NewReminder, ReminderStatus};
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan reminder baru dengan status `pending`.
+pub async fn create_reminder(conn: &DatabaseConnection, new_reminder: NewReminder) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        todo_id: Set(new_reminder.todo_id),
+        user_id: Set(new_reminder.user_id),
+        remind_at: Set(new_reminder.remind_at),
+        offset_minutes: Set(new_reminder.offset_minutes),
+        status: Set(ReminderStatus::Pending.as_str().to_string()),
+        attempts: Set(0),
+        next_attempt_at: Set(None),
+        last_error: Set(None),
+        sent_at: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil reminder milik user pada satu todo, urut waktu dibuat.
+pub async fn list_reminders(conn: &DatabaseConnection, todo_id: Uuid, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::UserId.eq(user_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Jumlah reminder milik user pada satu todo.
+pub async fn count_reminders(conn: &DatabaseConnection, todo_id: Uuid, user_id: Uuid) -> Result<u64, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::UserId.eq(user_id))
+        .count(conn)
+        .await
+}
+
+/// Mengambil satu reminder milik user dalam scope todo tertentu.
+pub async fn find_reminder(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    user_id: Uuid,
+    reminder_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(reminder_id))
+        .filter(Column::TodoId.eq(todo_id))
+        .filter(Column::UserId.eq(user_id))
+        .one(conn)
+        .await
+}
+
+/// Menghapus reminder berdasarkan id.
+pub async fn delete_reminder(conn: &DatabaseConnection, reminder_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(reminder_id).exec(conn).await?;
+    Ok(())
+}
+
+/// Mengunci reminder `pending` yang sudah jatuh tempo per `now`, paling lama lebih dulu.
+///
+/// Memakai `FOR UPDATE SKIP LOCKED` sehingga beberapa instance yang memanggil bersamaan
+/// mendapat baris berbeda; kunci bertahan sampai transaksi `conn` selesai.
+pub async fn claim_due_reminders<C: ConnectionTrait>(
+    conn: &C,
+    now: DateTime<FixedOffset>,
+    limit: u64,
+) -> Result<Vec<Model>, DbErr> {
+    let sql = "SELECT r.* FROM reminders r \
+               JOIN todos t ON t.id = r.todo_id \
+               WHERE r.status = 'pending' \
+               AND (r.next_attempt_at IS NULL OR r.next_attempt_at <= $1) \
+               AND COALESCE(r.remind_at, t.due_at - make_interval(mins => r.offset_minutes)) <= $1 \
+               ORDER BY COALESCE(r.remind_at, t.due_at - make_interval(mins => r.offset_minutes)), r.id \
+               LIMIT $2 \
+               FOR UPDATE OF r SKIP LOCKED";
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    Entity::find()
+        .from_raw_sql(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [now.into(), limit.into()],
+        ))
+        .all(conn)
+        .await
+}
+
+/// Menyimpan hasil percobaan kirim reminder.
+pub async fn record_delivery<C: ConnectionTrait>(
+    conn: &C,
+    existing: Model,
+    update: DeliveryUpdate,
+) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = existing.into();
+    active.status = Set(update.status.as_str().to_string());
+    active.attempts = Set(update.attempts);
+    active.next_attempt_at = Set(update.next_attempt_at);
+    active.last_error = Set(update.last_error);
+    active.sent_at = Set(update.sent_at);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
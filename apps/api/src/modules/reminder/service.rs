@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use sea_orm::{DatabaseConnection, DatabaseTransaction, DbErr, TransactionTrait};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::config::reminder::ReminderSettings;
+use crate::infrastructure::notifier::{Notification, NotificationKind, Notifier, NotifyError};
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+
+use super::dto::{CreateReminderRequest, DeliveryUpdate, NewReminder, ReminderStatus};
+use super::entity::Model;
+use super::repository;
+
+/// Offset maksimal sebelum tenggat: 30 hari.
+const MAX_OFFSET_MINUTES: i32 = 43_200;
+const MAX_REMINDERS_PER_TODO: u64 = 20;
+/// Jeda retry maksimal setelah pengiriman gagal.
+const MAX_RETRY_DELAY_MINUTES: i64 = 60;
+
+/// Error domain reminder yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum ReminderError {
+    Validation(String),
+    TodoNotFound,
+    NotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for ReminderError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for ReminderError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Membuat reminder untuk user saat ini pada todo yang bisa ia lihat.
+/// Mengembalikan reminder beserta tenggat todo untuk menghitung waktu kirim efektif.
+pub async fn create_reminder(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    request: CreateReminderRequest,
+) -> Result<(Model, Option<DateTime<FixedOffset>>), ReminderError> {
+    let todo = sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    let now = Utc::now().fixed_offset();
+    validate_schedule(&request, todo.due_at, now)?;
+    let count = repository::count_reminders(conn, todo_id, user_id).await?;
+    if count >= MAX_REMINDERS_PER_TODO {
+        return Err(ReminderError::Validation(format!(
+            "a todo can have at most {MAX_REMINDERS_PER_TODO} reminders per user"
+        )));
+    }
+    let new_reminder = NewReminder {
+        todo_id,
+        user_id,
+        remind_at: request.remind_at,
+        offset_minutes: request.offset_minutes,
+    };
+    let reminder = repository::create_reminder(conn, new_reminder).await?;
+    Ok((reminder, todo.due_at))
+}
+
+/// Mengambil reminder milik user saat ini pada satu todo beserta tenggat todo.
+pub async fn list_reminders(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<(Vec<Model>, Option<DateTime<FixedOffset>>), ReminderError> {
+    let todo = sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    let reminders = repository::list_reminders(conn, todo_id, user_id).await?;
+    Ok((reminders, todo.due_at))
+}
+
+/// Menghapus reminder milik user saat ini.
+pub async fn delete_reminder(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    reminder_id: Uuid,
+) -> Result<(), ReminderError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    let reminder = repository::find_reminder(conn, todo_id, user_id, reminder_id)
+        .await?
+        .ok_or(ReminderError::NotFound)?;
+    repository::delete_reminder(conn, reminder.id)
+        .await
+        .map_err(ReminderError::from)
+}
+
+/// Loop scheduler reminder untuk dijalankan lewat `tokio::spawn` saat startup.
+///
+/// Tick pertama langsung berjalan sehingga reminder yang terlewat selama server mati
+/// dikirim sekali saat startup. Aman dijalankan di banyak instance sekaligus karena
+/// setiap batch dikunci dengan `SKIP LOCKED`.
+pub async fn run_scheduler(conn: DatabaseConnection, settings: ReminderSettings, notifier: Arc<dyn Notifier>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.poll_interval_secs));
+    let mut startup = true;
+    loop {
+        interval.tick().await;
+        match deliver_due_reminders(&conn, &settings, notifier.as_ref()).await {
+            Ok(0) => {}
+            Ok(processed) if startup => info!(processed, "missed reminders processed on startup"),
+            Ok(processed) => info!(processed, "due reminders processed"),
+            Err(err) => error!(error = %err, "failed to process due reminders"),
+        }
+        startup = false;
+    }
+}
+
+/// Memproses seluruh reminder yang jatuh tempo, batch demi batch.
+///
+/// Setiap batch dikunci, dikirim, lalu statusnya disimpan dalam satu transaksi. Notifier menulis
+/// lewat transaksi yang sama (per reminder di savepoint), sehingga notifikasi dan status `sent`
+/// ter-commit bersama: bila proses mati sebelum commit, keduanya batal dan batch dikirim ulang
+/// pada polling berikutnya tanpa menggandakan notifikasi.
+/// Mengembalikan jumlah reminder yang diproses (terkirim, dilewati, atau gagal).
+pub async fn deliver_due_reminders(
+    conn: &DatabaseConnection,
+    settings: &ReminderSettings,
+    notifier: &dyn Notifier,
+) -> Result<u64, DbErr> {
+    let mut processed = 0;
+    loop {
+        let now = Utc::now().fixed_offset();
+        let txn = conn.begin().await?;
+        let claimed = repository::claim_due_reminders(&txn, now, settings.batch_size).await?;
+        let batch_len = claimed.len() as u64;
+        let todo_ids: Vec<Uuid> = claimed.iter().map(|reminder| reminder.todo_id).collect();
+        let todos: HashMap<Uuid, Todo> = todo_repository::find_todos_by_ids(&txn, &todo_ids)
+            .await?
+            .into_iter()
+            .map(|todo| (todo.id, todo))
+            .collect();
+        for reminder in claimed {
+            let update = match todos.get(&reminder.todo_id) {
+                Some(todo) if !todo.iscompleted => {
+                    match sharing_service::authorize_todo(&txn, reminder.user_id, todo.id, Permission::TodoRead).await {
+                        Ok(_) => {
+                            let notification = reminder_notification(&reminder, todo);
+                            let result = notify_in_savepoint(&txn, notifier, &notification).await?;
+                            if let Err(err) = &result {
+                                warn!(reminder_id = %reminder.id, error = %err, "reminder delivery failed");
+                            }
+                            delivery_update(reminder.attempts, settings.max_attempts, result, now)
+                        }
+                        Err(AccessError::Database(err)) => return Err(err),
+                        Err(_) => skipped(reminder.attempts, "recipient can no longer access the todo"),
+                    }
+                }
+                Some(_) => skipped(reminder.attempts, "todo already completed"),
+                None => skipped(reminder.attempts, "todo not found"),
+            };
+            repository::record_delivery(&txn, reminder, update).await?;
+        }
+        txn.commit().await?;
+        processed += batch_len;
+        if batch_len < settings.batch_size {
+            return Ok(processed);
+        }
+    }
+}
+
+// Menjalankan notifier di savepoint agar tulisan kanal yang gagal dibatalkan tanpa
+// membatalkan reminder lain di batch yang sama.
+async fn notify_in_savepoint(
+    txn: &DatabaseTransaction,
+    notifier: &dyn Notifier,
+    notification: &Notification,
+) -> Result<Result<(), NotifyError>, DbErr> {
+    let savepoint = txn.begin().await?;
+    match notifier.notify(&savepoint, notification).await {
+        Ok(()) => {
+            savepoint.commit().await?;
+            Ok(Ok(()))
+        }
+        Err(err) => {
+            savepoint.rollback().await?;
+            Ok(Err(err))
+        }
+    }
+}
+
+/// Waktu kirim efektif: `remind_at`, atau `offset_minutes` sebelum `due_at`.
+/// `None` bila reminder relatif tetapi todo belum punya tenggat.
+pub fn fire_time(
+    remind_at: Option<DateTime<FixedOffset>>,
+    offset_minutes: Option<i32>,
+    due_at: Option<DateTime<FixedOffset>>,
+) -> Option<DateTime<FixedOffset>> {
+    match (remind_at, offset_minutes) {
+        (Some(remind_at), _) => Some(remind_at),
+        (None, Some(offset)) => due_at.map(|due_at| due_at - TimeDelta::minutes(i64::from(offset))),
+        (None, None) => None,
+    }
+}
+
+/// Validasi jadwal reminder baru: tepat salah satu dari `remind_at`/`offset_minutes`,
+/// offset dalam batas, dan waktu kirim (bila sudah bisa dihitung) belum lewat.
+pub fn validate_schedule(
+    request: &CreateReminderRequest,
+    due_at: Option<DateTime<FixedOffset>>,
+    now: DateTime<FixedOffset>,
+) -> Result<(), ReminderError> {
+    match (request.remind_at, request.offset_minutes) {
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(ReminderError::Validation(
+                "exactly one of remind_at or offset_minutes is required".to_string(),
+            ));
+        }
+        (None, Some(offset)) if !(0..=MAX_OFFSET_MINUTES).contains(&offset) => {
+            return Err(ReminderError::Validation(format!(
+                "offset_minutes must be between 0 and {MAX_OFFSET_MINUTES}"
+            )));
+        }
+        _ => {}
+    }
+    if let Some(fire_at) = fire_time(request.remind_at, request.offset_minutes, due_at)
+        && fire_at <= now
+    {
+        return Err(ReminderError::Validation("reminder time must be in the future".to_string()));
+    }
+    Ok(())
+}
+
+/// Status berikutnya setelah satu percobaan kirim. Kegagalan dijadwalkan ulang dengan jeda
+/// eksponensial (1, 2, 4, ... menit, maksimal 60) sampai `max_attempts` tercapai.
+pub fn delivery_update(
+    attempts: i32,
+    max_attempts: u32,
+    result: Result<(), NotifyError>,
+    now: DateTime<FixedOffset>,
+) -> DeliveryUpdate {
+    let attempts = attempts.saturating_add(1);
+    match result {
+        Ok(()) => DeliveryUpdate {
+            status: ReminderStatus::Sent,
+            attempts,
+            next_attempt_at: None,
+            last_error: None,
+            sent_at: Some(now),
+        },
+        Err(err) if u32::try_from(attempts).unwrap_or(u32::MAX) >= max_attempts => DeliveryUpdate {
+            status: ReminderStatus::Failed,
+            attempts,
+            next_attempt_at: None,
+            last_error: Some(err.0),
+            sent_at: None,
+        },
+        Err(err) => {
+            let exponent = u32::try_from(attempts - 1).unwrap_or(0).min(6);
+            let delay = (1_i64 << exponent).min(MAX_RETRY_DELAY_MINUTES);
+            DeliveryUpdate {
+                status: ReminderStatus::Pending,
+                attempts,
+                next_attempt_at: Some(now + TimeDelta::minutes(delay)),
+                last_error: Some(err.0),
+                sent_at: None,
+            }
+        }
+    }
+}
+
+// Reminder yang tidak lagi relevan ditutup tanpa dikirim.
+fn skipped(attempts: i32, reason: &str) -> DeliveryUpdate {
+    DeliveryUpdate {
+        status: ReminderStatus::Skipped,
+        attempts,
+        next_attempt_at: None,
+        last_error: Some(reason.to_string()),
+        sent_at: None,
+    }
+}
+
+fn reminder_notification(reminder: &Model, todo: &Todo) -> Notification {
+    let message = match todo.due_at {
+        Some(due_at) => format!("Reminder: {} is due at {}", todo.title, due_at.to_rfc3339()),
+        None => format!("Reminder: {}", todo.title),
+    };
+    Notification {
+        user_id: reminder.user_id,
+        kind: NotificationKind::Reminder,
+        todo_id: Some(todo.id),
+        actor_id: None,
+        message,
+    }
+}
+
+// Modul tests untuk unit testing jadwal dan retry reminder.
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2026, 3, 1, hour, minute, 0)
+            .single()
+            .expect("valid datetime")
+            .fixed_offset()
+    }
+
+    fn request(remind_at: Option<DateTime<FixedOffset>>, offset_minutes: Option<i32>) -> CreateReminderRequest {
+        CreateReminderRequest {
+            remind_at,
+            offset_minutes,
+        }
+    }
+
+    #[test]
+    fn fire_time_should_prefer_absolute_and_resolve_offset_from_due() {
+        assert_eq!(fire_time(Some(at(9, 0)), None, Some(at(12, 0))), Some(at(9, 0)));
+        assert_eq!(fire_time(None, Some(30), Some(at(12, 0))), Some(at(11, 30)));
+        assert_eq!(fire_time(None, Some(30), None), None);
+    }
+
+    #[test]
+    fn validate_schedule_should_require_exactly_one_future_time() {
+        let now = at(8, 0);
+        assert!(validate_schedule(&request(Some(at(9, 0)), None), None, now).is_ok());
+        assert!(validate_schedule(&request(None, Some(15)), None, now).is_ok());
+        assert!(validate_schedule(&request(None, Some(15)), Some(at(10, 0)), now).is_ok());
+        for invalid in [
+            request(None, None),
+            request(Some(at(9, 0)), Some(15)),
+            request(Some(at(7, 0)), None),
+            request(None, Some(-1)),
+            request(None, Some(MAX_OFFSET_MINUTES + 1)),
+        ] {
+            assert!(matches!(validate_schedule(&invalid, None, now), Err(ReminderError::Validation(_))));
+        }
+        assert!(matches!(
+            validate_schedule(&request(None, Some(120)), Some(at(9, 0)), now),
+            Err(ReminderError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn delivery_update_should_back_off_then_fail() {
+        let now = at(8, 0);
+        let sent = delivery_update(0, 3, Ok(()), now);
+        assert_eq!(sent.status, ReminderStatus::Sent);
+        assert_eq!(sent.sent_at, Some(now));
+
+        let first = delivery_update(0, 3, Err(NotifyError("down".to_string())), now);
+        assert_eq!(first.status, ReminderStatus::Pending);
+        assert_eq!(first.next_attempt_at, Some(at(8, 1)));
+        let second = delivery_update(1, 3, Err(NotifyError("down".to_string())), now);
+        assert_eq!(second.next_attempt_at, Some(at(8, 2)));
+
+        let last = delivery_update(2, 3, Err(NotifyError("down".to_string())), now);
+        assert_eq!(last.status, ReminderStatus::Failed);
+        assert_eq!(last.attempts, 3);
+        assert_eq!(last.last_error.as_deref(), Some("down"));
+    }
+}
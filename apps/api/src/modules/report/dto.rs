@@ -0,0 +1,78 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Query string untuk burndown project; kedua tanggal inklusif dan dihitung dalam UTC.
+#[derive(Debug, Deserialize)]
+pub struct BurndownQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+/// Sisa estimasi pada akhir satu hari.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    pub remaining_minutes: i64,
+    pub remaining_points: i64,
+    /// Jumlah todo berestimasi yang masih terbuka.
+    pub open_todos: u64,
+}
+
+/// Seri burndown satu project yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct BurndownResponse {
+    pub project_id: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub series: Vec<BurndownPoint>,
+}
+
+/// Query string untuk statistik penyelesaian todo.
+#[derive(Debug, Default, Deserialize)]
+pub struct StatsQuery {
+    /// Batasi ke satu project.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    /// Jumlah hari terakhir pada `completions_per_day` (default 30).
+    #[serde(default)]
+    pub days: Option<u32>,
+    /// Jumlah minggu terakhir pada `completions_per_week` (default 12).
+    #[serde(default)]
+    pub weeks: Option<u32>,
+}
+
+/// Jumlah todo yang selesai pada satu hari (UTC).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyCompletions {
+    pub date: NaiveDate,
+    pub count: u64,
+}
+
+/// Jumlah todo yang selesai pada satu minggu; minggu dimulai hari Senin (UTC).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WeeklyCompletions {
+    pub week_start: NaiveDate,
+    pub count: u64,
+}
+
+/// Streak penyelesaian yang masih berjalan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CompletionStreaks {
+    /// Hari berturut-turut dengan minimal satu todo selesai, berakhir hari ini atau kemarin.
+    pub days: u32,
+    /// Minggu berturut-turut dengan minimal satu todo selesai, berakhir minggu ini atau minggu lalu.
+    pub weeks: u32,
+}
+
+/// Statistik penyelesaian todo yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct StatsResponse {
+    pub open: u64,
+    pub completed: u64,
+    pub completions_per_day: Vec<DailyCompletions>,
+    pub completions_per_week: Vec<WeeklyCompletions>,
+    /// Rata-rata detik dari todo dibuat sampai selesai; `null` bila belum ada yang selesai.
+    pub avg_time_to_complete_secs: Option<i64>,
+    pub streaks: CompletionStreaks,
+}
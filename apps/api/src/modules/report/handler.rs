@@ -0,0 +1,267 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    routing::get,
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{BurndownQuery, BurndownResponse, StatsQuery, StatsResponse};
+use super::service::{self, ReportError};
+
+/// Registrasi route laporan per project, di-mount di bawah prefix `/projects`.
+///
+/// - `GET /{id}/burndown` seri sisa estimasi harian project
+pub fn project_routes() -> Router<AppState> {
+    Router::new().route("/{id}/burndown", get(get_project_burndown))
+}
+
+/// Route tanpa prefix resource:
+/// - `GET /stats` statistik penyelesaian todo di workspace aktif
+pub fn user_routes() -> Router<AppState> {
+    Router::new().route("/stats", get(get_stats))
+}
+
+/// GET `/projects/{id}/burndown`
+/// Query: `from`, `to` (`YYYY-MM-DD`, inklusif, UTC).
+/// Output: `200 OK` + `SuccessResponse<BurndownResponse>`.
+pub async fn get_project_burndown(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<BurndownQuery>,
+) -> Result<Json<SuccessResponse<BurndownResponse>>, ApiError> {
+    let burndown = service::project_burndown(&state.db, user.id, project_id, query)
+        .await
+        .map_err(map_report_error)?;
+    info!(project_id = %project_id, days = burndown.series.len(), "project burndown computed");
+    Ok(Json(SuccessResponse { data: burndown }))
+}
+
+/// GET `/stats`
+/// Query: `project_id`, `days` (default 30), `weeks` (default 12) opsional.
+/// Output: `200 OK` + `SuccessResponse<StatsResponse>`.
+pub async fn get_stats(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<SuccessResponse<StatsResponse>>, ApiError> {
+    let stats = service::todo_stats(&state.db, user.id, user.workspace_id, query)
+        .await
+        .map_err(map_report_error)?;
+    info!(open = stats.open, completed = stats.completed, "todo stats computed");
+    Ok(Json(SuccessResponse { data: stats }))
+}
+
+/// Mapping error domain laporan ke `ApiError`.
+fn map_report_error(err: ReportError) -> ApiError {
+    match err {
+        ReportError::Validation(message) => {
+            warn!(reason = %message, "report validation failed");
+            ApiError::bad_request(message)
+        }
+        ReportError::ProjectNotFound => {
+            warn!("project not found");
+            ApiError::not_found("project not found")
+        }
+        ReportError::Forbidden => {
+            warn!("report rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this project")
+        }
+        ReportError::Database(db_err) => {
+            error!(error = %db_err, "report database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint laporan terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use chrono::Utc;
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::project::handler as project_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .merge(user_routes())
+            .nest("/todos", todo_handler::routes())
+            .nest("/projects", project_handler::routes().merge(project_routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn burndown_should_reflect_estimates_and_completions() {
+        let app = build_test_app().await;
+        let project = read_json(
+            send(&app, "POST", "/projects".to_string(), Some(json!({ "name": "burndown sprint" }))).await,
+        )
+        .await;
+        let project_id = project["data"]["id"].as_str().expect("project id must exist").to_string();
+
+        let mut todo_ids = Vec::new();
+        for (title, estimate) in [
+            ("spec", json!({ "value": 5, "unit": "points" })),
+            ("build", json!({ "value": 3, "unit": "points" })),
+            ("review", json!({ "value": 45, "unit": "minutes" })),
+        ] {
+            let created = read_json(
+                send(
+                    &app,
+                    "POST",
+                    "/todos".to_string(),
+                    Some(json!({ "title": title, "project_id": project_id, "estimate": estimate })),
+                )
+                .await,
+            )
+            .await;
+            assert_eq!(created["data"]["estimate"], estimate);
+            todo_ids.push(created["data"]["id"].as_str().expect("todo id must exist").to_string());
+        }
+
+        let invalid = send(
+            &app,
+            "PATCH",
+            format!("/todos/{}", todo_ids[1]),
+            Some(json!({ "estimate": { "value": 0, "unit": "points" } })),
+        )
+        .await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let completed = read_json(
+            send(&app, "PATCH", format!("/todos/{}", todo_ids[0]), Some(json!({ "iscompleted": true }))).await,
+        )
+        .await;
+        assert!(completed["data"]["completed_at"].is_string());
+        let unestimated = read_json(
+            send(&app, "PATCH", format!("/todos/{}", todo_ids[1]), Some(json!({ "estimate": null }))).await,
+        )
+        .await;
+        assert!(unestimated["data"]["estimate"].is_null());
+
+        let today = Utc::now().date_naive();
+        let yesterday = today.pred_opt().expect("valid date");
+        let burndown = read_json(
+            send(
+                &app,
+                "GET",
+                format!("/projects/{project_id}/burndown?from={yesterday}&to={today}"),
+                None,
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(
+            burndown["data"]["series"],
+            json!([
+                { "date": yesterday.to_string(), "remaining_minutes": 0, "remaining_points": 0, "open_todos": 0 },
+                { "date": today.to_string(), "remaining_minutes": 45, "remaining_points": 0, "open_todos": 1 }
+            ])
+        );
+
+        let reversed = send(
+            &app,
+            "GET",
+            format!("/projects/{project_id}/burndown?from={today}&to={yesterday}"),
+            None,
+        )
+        .await;
+        assert_eq!(reversed.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn stats_should_track_completion_toggles() {
+        let app = build_test_app().await;
+        let project = read_json(
+            send(&app, "POST", "/projects".to_string(), Some(json!({ "name": "stats project" }))).await,
+        )
+        .await;
+        let project_id = project["data"]["id"].as_str().expect("project id must exist").to_string();
+        let mut todo_ids = Vec::new();
+        for title in ["first", "second", "third"] {
+            let created = read_json(
+                send(
+                    &app,
+                    "POST",
+                    "/todos".to_string(),
+                    Some(json!({ "title": title, "project_id": project_id })),
+                )
+                .await,
+            )
+            .await;
+            todo_ids.push(created["data"]["id"].as_str().expect("todo id must exist").to_string());
+        }
+        for todo_id in &todo_ids[..2] {
+            send(&app, "PATCH", format!("/todos/{todo_id}"), Some(json!({ "iscompleted": true }))).await;
+        }
+        let reopened = read_json(
+            send(&app, "PATCH", format!("/todos/{}", todo_ids[1]), Some(json!({ "iscompleted": false }))).await,
+        )
+        .await;
+        assert!(reopened["data"]["completed_at"].is_null());
+
+        let stats = read_json(
+            send(&app, "GET", format!("/stats?project_id={project_id}&days=2&weeks=1"), None).await,
+        )
+        .await;
+        assert_eq!(stats["data"]["open"], 2);
+        assert_eq!(stats["data"]["completed"], 1);
+        assert_eq!(stats["data"]["completions_per_day"][1]["count"], 1);
+        assert_eq!(stats["data"]["completions_per_week"][0]["count"], 1);
+        assert_eq!(stats["data"]["streaks"]["days"], 1);
+        assert!(stats["data"]["avg_time_to_complete_secs"].is_number());
+
+        let invalid = send(&app, "GET", "/stats?days=0".to_string(), None).await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    }
+}
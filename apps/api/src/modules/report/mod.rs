@@ -0,0 +1,5 @@
+//! Modul laporan (burndown project): DTO, service, dan handler HTTP.
+
+pub mod dto;
+pub mod handler;
+pub mod service;
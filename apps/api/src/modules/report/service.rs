@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::dto::{Estimate, EstimateUnit, TodoFilter};
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+
+use super::dto::{
+    BurndownPoint, BurndownQuery, BurndownResponse, CompletionStreaks, DailyCompletions, StatsQuery,
+    StatsResponse, WeeklyCompletions,
+};
+
+/// Rentang maksimum burndown agar response tetap kecil.
+const MAX_BURNDOWN_DAYS: u64 = 366;
+const DEFAULT_STATS_DAYS: u32 = 30;
+const MAX_STATS_DAYS: u32 = 366;
+const DEFAULT_STATS_WEEKS: u32 = 12;
+const MAX_STATS_WEEKS: u32 = 104;
+
+/// Error domain laporan yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum ReportError {
+    Validation(String),
+    ProjectNotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for ReportError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for ReportError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::ProjectNotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Seri sisa estimasi harian satu project; butuh `project.read`.
+pub async fn project_burndown(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    project_id: Uuid,
+    query: BurndownQuery,
+) -> Result<BurndownResponse, ReportError> {
+    validate_range(query.from, query.to)?;
+    sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectRead).await?;
+    let todos = todo_repository::find_todos_by_project(conn, project_id).await?;
+    Ok(BurndownResponse {
+        project_id,
+        from: query.from,
+        to: query.to,
+        series: burndown_series(&todos, query.from, query.to),
+    })
+}
+
+/// Menghitung sisa estimasi pada akhir setiap hari (UTC) dari `from` sampai `to`.
+/// Todo dihitung sejak dibuat sampai waktu selesainya; todo tanpa estimasi diabaikan.
+pub fn burndown_series(todos: &[Todo], from: NaiveDate, to: NaiveDate) -> Vec<BurndownPoint> {
+    let estimated: Vec<(Estimate, &Todo)> = todos
+        .iter()
+        .filter_map(|todo| Some((Estimate::from_model(todo)?, todo)))
+        .collect();
+    from.iter_days()
+        .take_while(|date| *date <= to)
+        .map(|date| {
+            let day_end = day_end(date);
+            let mut point = BurndownPoint {
+                date,
+                remaining_minutes: 0,
+                remaining_points: 0,
+                open_todos: 0,
+            };
+            for (estimate, todo) in &estimated {
+                let open = todo.created_at < day_end
+                    && completion_time(todo).is_none_or(|completed_at| completed_at >= day_end);
+                if !open {
+                    continue;
+                }
+                point.open_todos += 1;
+                match estimate.unit {
+                    EstimateUnit::Minutes => point.remaining_minutes += i64::from(estimate.value),
+                    EstimateUnit::Points => point.remaining_points += i64::from(estimate.value),
+                }
+            }
+            point
+        })
+        .collect()
+}
+
+/// Statistik penyelesaian todo yang bisa diakses user di workspace aktif,
+/// opsional dibatasi ke satu project (butuh `project.read`).
+pub async fn todo_stats(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    query: StatsQuery,
+) -> Result<StatsResponse, ReportError> {
+    let days = window_len("days", query.days, DEFAULT_STATS_DAYS, MAX_STATS_DAYS)?;
+    let weeks = window_len("weeks", query.weeks, DEFAULT_STATS_WEEKS, MAX_STATS_WEEKS)?;
+    if let Some(project_id) = query.project_id {
+        sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectRead).await?;
+    }
+    let filter = TodoFilter {
+        workspace_id,
+        project_id: query.project_id,
+        assignee: None,
+    };
+    let todos = todo_repository::list_accessible_todos(conn, user_id, filter).await?;
+    Ok(completion_stats(&todos, Utc::now().date_naive(), days, weeks))
+}
+
+/// Menyusun statistik penyelesaian dari daftar todo. Hari dan minggu dihitung dalam UTC
+/// dan berakhir pada `today`; hari/minggu tanpa penyelesaian tetap muncul dengan `count` 0.
+pub fn completion_stats(todos: &[Todo], today: NaiveDate, days: u32, weeks: u32) -> StatsResponse {
+    let mut per_day: HashMap<NaiveDate, u64> = HashMap::new();
+    let mut per_week: HashMap<NaiveDate, u64> = HashMap::new();
+    let (mut completed, mut total_secs, mut timed) = (0u64, 0i64, 0i64);
+    for todo in todos.iter().filter(|todo| todo.iscompleted) {
+        completed += 1;
+        let Some(completed_at) = completion_time(todo) else {
+            continue;
+        };
+        let date = completed_at.naive_utc().date();
+        *per_day.entry(date).or_default() += 1;
+        *per_week.entry(week_start(date)).or_default() += 1;
+        total_secs += (completed_at - todo.created_at).num_seconds().max(0);
+        timed += 1;
+    }
+
+    let completions_per_day = (0..days)
+        .rev()
+        .filter_map(|offset| today.checked_sub_days(Days::new(u64::from(offset))))
+        .map(|date| DailyCompletions {
+            date,
+            count: per_day.get(&date).copied().unwrap_or(0),
+        })
+        .collect();
+    let this_week = week_start(today);
+    let completions_per_week = (0..weeks)
+        .rev()
+        .filter_map(|offset| this_week.checked_sub_days(Days::new(7 * u64::from(offset))))
+        .map(|week_start| WeeklyCompletions {
+            week_start,
+            count: per_week.get(&week_start).copied().unwrap_or(0),
+        })
+        .collect();
+
+    StatsResponse {
+        open: todos.len() as u64 - completed,
+        completed,
+        completions_per_day,
+        completions_per_week,
+        avg_time_to_complete_secs: (timed > 0).then(|| total_secs / timed),
+        streaks: CompletionStreaks {
+            days: current_streak(&per_day, today, 1),
+            weeks: current_streak(&per_week, this_week, 7),
+        },
+    }
+}
+
+// Menghitung periode berturut-turut yang punya penyelesaian, mundur dari `latest`.
+// Periode terbaru yang masih kosong belum memutus streak (hari/minggu belum berakhir).
+fn current_streak(counts: &HashMap<NaiveDate, u64>, latest: NaiveDate, step_days: u64) -> u32 {
+    let step = Days::new(step_days);
+    let mut cursor = if counts.contains_key(&latest) {
+        Some(latest)
+    } else {
+        latest.checked_sub_days(step)
+    };
+    let mut streak = 0;
+    while let Some(period) = cursor.filter(|period| counts.contains_key(period)) {
+        streak += 1;
+        cursor = period.checked_sub_days(step);
+    }
+    streak
+}
+
+// Senin pada minggu yang memuat `date`.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+}
+
+// Panjang jendela statistik: default bila kosong, ditolak bila di luar 1..=max.
+fn window_len(field: &str, value: Option<u32>, default: u32, max: u32) -> Result<u32, ReportError> {
+    let value = value.unwrap_or(default);
+    if !(1..=max).contains(&value) {
+        return Err(ReportError::Validation(format!("{field} must be between 1 and {max}")));
+    }
+    Ok(value)
+}
+
+// Rentang tanggal harus urut dan tidak melebihi batas.
+fn validate_range(from: NaiveDate, to: NaiveDate) -> Result<(), ReportError> {
+    if to < from {
+        return Err(ReportError::Validation("to must not be before from".to_string()));
+    }
+    if from.checked_add_days(Days::new(MAX_BURNDOWN_DAYS)).is_some_and(|limit| to >= limit) {
+        return Err(ReportError::Validation(format!(
+            "date range must be at most {MAX_BURNDOWN_DAYS} days"
+        )));
+    }
+    Ok(())
+}
+
+// Todo selesai sebelum `completed_at` dicatat memakai `updated_at` sebagai perkiraan.
+fn completion_time(todo: &Todo) -> Option<DateTime<FixedOffset>> {
+    todo.completed_at
+        .or_else(|| todo.iscompleted.then_some(todo.updated_at))
+}
+
+// Awal hari berikutnya (UTC), batas eksklusif "akhir hari".
+fn day_end(date: NaiveDate) -> DateTime<FixedOffset> {
+    date.succ_opt()
+        .unwrap_or(date)
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc()
+        .fixed_offset()
+}
+
+// Modul tests untuk unit testing perhitungan seri burndown.
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0)
+            .single()
+            .expect("valid timestamp")
+            .fixed_offset()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).expect("valid date")
+    }
+
+    fn sample_todo(value: Option<i32>, unit: &str, created_at: DateTime<FixedOffset>) -> Todo {
+        Todo {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            workspace_id: None,
+            project_id: Some(Uuid::new_v4()),
+            assignee_id: None,
+            title: "estimated".to_string(),
+            desc: None,
+            iscompleted: false,
+            completed_at: None,
+            due_at: None,
+            estimate_value: value,
+            estimate_unit: value.map(|_| unit.to_string()),
+            external_uid: None,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn burndown_series_should_drop_estimates_on_completion_day() {
+        let mut first = sample_todo(Some(5), "points", at(1, 9));
+        first.iscompleted = true;
+        first.completed_at = Some(at(2, 15));
+        let second = sample_todo(Some(3), "points", at(1, 10));
+        let minutes = sample_todo(Some(90), "minutes", at(2, 8));
+        let unestimated = sample_todo(None, "points", at(1, 8));
+
+        let series = burndown_series(&[first, second, minutes, unestimated], date(1), date(3));
+
+        let remaining: Vec<(i64, i64, u64)> = series
+            .iter()
+            .map(|point| (point.remaining_points, point.remaining_minutes, point.open_todos))
+            .collect();
+        assert_eq!(remaining, vec![(8, 0, 2), (3, 90, 2), (3, 90, 2)]);
+        assert_eq!(series[2].date, date(3));
+    }
+
+    #[test]
+    fn burndown_series_should_fall_back_to_updated_at_for_legacy_completions() {
+        let mut legacy = sample_todo(Some(2), "points", at(1, 9));
+        legacy.iscompleted = true;
+        legacy.updated_at = at(2, 9);
+
+        let series = burndown_series(&[legacy], date(1), date(2));
+        assert_eq!(series[0].remaining_points, 2);
+        assert_eq!(series[1].remaining_points, 0);
+    }
+
+    #[test]
+    fn completion_stats_should_count_periods_average_and_streaks() {
+        // 2026-03-04 adalah hari Rabu; minggunya dimulai 2026-03-02.
+        let today = date(4);
+        let mut todos = Vec::new();
+        for (created, completed) in [(at(1, 8), at(2, 8)), (at(2, 9), at(3, 9)), (at(3, 10), at(3, 12))] {
+            let mut todo = sample_todo(None, "points", created);
+            todo.iscompleted = true;
+            todo.completed_at = Some(completed);
+            todos.push(todo);
+        }
+        todos.push(sample_todo(None, "points", at(4, 8)));
+
+        let stats = completion_stats(&todos, today, 3, 2);
+
+        assert_eq!((stats.open, stats.completed), (1, 3));
+        let per_day: Vec<(NaiveDate, u64)> = stats
+            .completions_per_day
+            .iter()
+            .map(|day| (day.date, day.count))
+            .collect();
+        assert_eq!(per_day, vec![(date(2), 1), (date(3), 2), (date(4), 0)]);
+        let per_week: Vec<(NaiveDate, u64)> = stats
+            .completions_per_week
+            .iter()
+            .map(|week| (week.week_start, week.count))
+            .collect();
+        let previous_week = NaiveDate::from_ymd_opt(2026, 2, 23).expect("valid date");
+        assert_eq!(per_week, vec![(previous_week, 0), (date(2), 3)]);
+        assert_eq!(stats.avg_time_to_complete_secs, Some((86_400 * 2 + 7_200) / 3));
+        // Hari ini belum ada yang selesai, tetapi streak 2 hari (tanggal 2 dan 3) masih berjalan.
+        assert_eq!(stats.streaks, CompletionStreaks { days: 2, weeks: 1 });
+    }
+
+    #[test]
+    fn completion_stats_should_reset_streak_after_gap() {
+        let mut old = sample_todo(None, "points", at(1, 8));
+        old.iscompleted = true;
+        old.completed_at = Some(at(1, 9));
+        let stats = completion_stats(&[old], date(4), 7, 1);
+        assert_eq!(stats.streaks.days, 0);
+        assert_eq!(stats.avg_time_to_complete_secs, Some(3_600));
+    }
+
+    #[test]
+    fn window_len_should_apply_default_and_bounds() {
+        assert_eq!(window_len("days", None, 30, 366).ok(), Some(30));
+        assert!(matches!(window_len("days", Some(0), 30, 366), Err(ReportError::Validation(_))));
+        assert!(matches!(window_len("weeks", Some(105), 12, 104), Err(ReportError::Validation(_))));
+    }
+
+    #[test]
+    fn validate_range_should_reject_reversed_and_oversized_ranges() {
+        assert!(validate_range(date(1), date(1)).is_ok());
+        assert!(matches!(validate_range(date(2), date(1)), Err(ReportError::Validation(_))));
+        let too_far = date(1) + Days::new(MAX_BURNDOWN_DAYS);
+        assert!(matches!(validate_range(date(1), too_far), Err(ReportError::Validation(_))));
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::project::dto::ProjectResponse;
+use crate::modules::todo::dto::TodoResponse;
+
+use super::entity;
+
+/// Tingkat akses ke project/todo, urut dari yang paling lemah.
+///
+/// - `viewer`: hanya baca,
+/// - `editor`: baca + ubah isi (todo, komentar, lampiran),
+/// - `owner`: editor + hapus dan kelola share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareRole {
+    Viewer,
+    Editor,
+    Owner,
+}
+
+impl ShareRole {
+    /// Nilai string yang disimpan di kolom `role`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::Editor => "editor",
+            Self::Owner => "owner",
+        }
+    }
+
+    /// Kebalikan dari `as_str`; `None` bila nilai tidak dikenal.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "viewer" => Some(Self::Viewer),
+            "editor" => Some(Self::Editor),
+            "owner" => Some(Self::Owner),
+            _ => None,
+        }
+    }
+}
+
+/// Resource yang bisa dibagikan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareTarget {
+    Project(Uuid),
+    Todo(Uuid),
+}
+
+/// Field todo yang menentukan akses; bisa dibentuk dari snapshot todo yang sudah dihapus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TodoScope {
+    pub todo_id: Uuid,
+    pub owner_id: Uuid,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+}
+
+/// Payload request untuk memberi/mengubah akses user ke resource.
+#[derive(Debug, Deserialize)]
+pub struct ShareRequest {
+    /// `viewer`, `editor`, atau `owner`.
+    pub role: String,
+}
+
+/// Bentuk data share grant yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct ShareResponse {
+    pub id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub todo_id: Option<Uuid>,
+    pub grantee_id: Uuid,
+    pub role: String,
+    pub granted_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for ShareResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            project_id: value.project_id,
+            todo_id: value.todo_id,
+            grantee_id: value.grantee_id,
+            role: value.role,
+            granted_by: value.granted_by,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Project yang dibagikan ke user saat ini beserta grant-nya.
+#[derive(Debug, Serialize)]
+pub struct SharedProjectResponse {
+    pub role: String,
+    pub granted_by: Uuid,
+    pub shared_at: DateTime<FixedOffset>,
+    pub project: ProjectResponse,
+}
+
+/// Todo yang dibagikan langsung ke user saat ini beserta grant-nya.
+#[derive(Debug, Serialize)]
+pub struct SharedTodoResponse {
+    pub role: String,
+    pub granted_by: Uuid,
+    pub shared_at: DateTime<FixedOffset>,
+    pub todo: TodoResponse,
+}
+
+/// Response `GET /shared-with-me`.
+#[derive(Debug, Serialize)]
+pub struct SharedWithMeResponse {
+    pub projects: Vec<SharedProjectResponse>,
+    pub todos: Vec<SharedTodoResponse>,
+}
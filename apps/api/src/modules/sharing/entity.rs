@@ -0,0 +1,25 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `share_grants`.
+/// Tepat satu dari `project_id` atau `todo_id` terisi (dijaga constraint DB).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "share_grants")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub todo_id: Option<Uuid>,
+    pub grantee_id: Uuid,
+    /// Salah satu dari `viewer`, `editor`, `owner`.
+    pub role: String,
+    pub granted_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
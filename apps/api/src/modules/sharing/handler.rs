@@ -0,0 +1,503 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, put},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::dependency::service as dependency_service;
+use crate::modules::project::dto::ProjectResponse;
+use crate::modules::todo::dto::TodoResponse;
+
+use super::dto::{
+    ShareRequest, ShareResponse, ShareRole, ShareTarget, SharedProjectResponse, SharedTodoResponse,
+    SharedWithMeResponse,
+};
+use super::service::{self, ShareError};
+
+/// Route share todo, di-mount di bawah prefix `/todos`.
+///
+/// - `GET /{id}/shares` daftar grant (owner saja)
+/// - `PUT /{id}/shares/{user_id}` beri/ubah akses
+/// - `DELETE /{id}/shares/{user_id}` cabut akses
+pub fn todo_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/shares", get(list_todo_shares))
+        .route(
+            "/{id}/shares/{user_id}",
+            put(put_todo_share).delete(revoke_todo_share),
+        )
+}
+
+/// Route share project, di-mount di bawah prefix `/projects`.
+///
+/// - `GET /{id}/shares` daftar grant (owner saja)
+/// - `PUT /{id}/shares/{user_id}` beri/ubah akses
+/// - `DELETE /{id}/shares/{user_id}` cabut akses
+pub fn project_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/shares", get(list_project_shares))
+        .route(
+            "/{id}/shares/{user_id}",
+            put(put_project_share).delete(revoke_project_share),
+        )
+}
+
+/// Route tanpa prefix resource:
+/// - `GET /shared-with-me` project dan todo yang dibagikan ke user saat ini
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/shared-with-me", get(shared_with_me))
+}
+
+/// PUT `/todos/{id}/shares/{user_id}`
+/// Input: `ShareRequest`.
+/// Output: `200 OK` + grant terbaru.
+pub async fn put_todo_share(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, grantee_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<ShareRequest>,
+) -> Result<Json<SuccessResponse<ShareResponse>>, ApiError> {
+    put_share(&state, user, ShareTarget::Todo(todo_id), grantee_id, payload).await
+}
+
+/// GET `/todos/{id}/shares`
+/// Output: `200 OK` + daftar grant pada todo.
+pub async fn list_todo_shares(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<ShareResponse>>>, ApiError> {
+    list_shares(&state, user, ShareTarget::Todo(todo_id)).await
+}
+
+/// DELETE `/todos/{id}/shares/{user_id}`
+/// Output: `204 No Content`.
+pub async fn revoke_todo_share(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, grantee_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    revoke_share(&state, user, ShareTarget::Todo(todo_id), grantee_id).await
+}
+
+/// PUT `/projects/{id}/shares/{user_id}`
+/// Input: `ShareRequest`.
+/// Output: `200 OK` + grant terbaru.
+pub async fn put_project_share(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((project_id, grantee_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<ShareRequest>,
+) -> Result<Json<SuccessResponse<ShareResponse>>, ApiError> {
+    put_share(&state, user, ShareTarget::Project(project_id), grantee_id, payload).await
+}
+
+/// GET `/projects/{id}/shares`
+/// Output: `200 OK` + daftar grant pada project.
+pub async fn list_project_shares(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<ShareResponse>>>, ApiError> {
+    list_shares(&state, user, ShareTarget::Project(project_id)).await
+}
+
+/// DELETE `/projects/{id}/shares/{user_id}`
+/// Output: `204 No Content`.
+pub async fn revoke_project_share(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((project_id, grantee_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    revoke_share(&state, user, ShareTarget::Project(project_id), grantee_id).await
+}
+
+/// GET `/shared-with-me`
+/// Output: `200 OK` + `SharedWithMeResponse`.
+pub async fn shared_with_me(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<SharedWithMeResponse>>, ApiError> {
+    let shared = service::shared_with_me(&state.db, user.id)
+        .await
+        .map_err(|err| map_share_error(ShareError::Database(err)))?;
+    info!(
+        projects = shared.projects.len(),
+        todos = shared.todos.len(),
+        "shared items listed"
+    );
+
+    let projects = shared
+        .projects
+        .into_iter()
+        .map(|(grant, project)| {
+            let role = ShareRole::parse(&grant.role).unwrap_or(ShareRole::Viewer);
+            SharedProjectResponse {
+                role: grant.role,
+                granted_by: grant.granted_by,
+                shared_at: grant.created_at,
+                project: ProjectResponse::new(project, role),
+            }
+        })
+        .collect();
+    let todo_ids: Vec<Uuid> = shared.todos.iter().map(|(_, todo)| todo.id).collect();
+    let blocked = dependency_service::blocked_todo_ids(&state.db, &todo_ids)
+        .await
+        .map_err(|err| map_share_error(ShareError::Database(err)))?;
+    let todos = shared
+        .todos
+        .into_iter()
+        .map(|(grant, todo)| {
+            let mut todo = TodoResponse::from(todo);
+            todo.is_blocked = blocked.contains(&todo.id);
+            SharedTodoResponse {
+                role: grant.role,
+                granted_by: grant.granted_by,
+                shared_at: grant.created_at,
+                todo,
+            }
+        })
+        .collect();
+    Ok(Json(SuccessResponse {
+        data: SharedWithMeResponse { projects, todos },
+    }))
+}
+
+// Handler bersama untuk PUT share project/todo.
+async fn put_share(
+    state: &AppState,
+    user: CurrentUser,
+    target: ShareTarget,
+    grantee_id: Uuid,
+    payload: ShareRequest,
+) -> Result<Json<SuccessResponse<ShareResponse>>, ApiError> {
+    let grant = service::share(&state.db, user.id, target, grantee_id, payload)
+        .await
+        .map_err(map_share_error)?;
+    info!(target = ?target, grantee_id = %grantee_id, role = %grant.role, "share granted");
+    Ok(Json(SuccessResponse { data: grant.into() }))
+}
+
+// Handler bersama untuk GET daftar share project/todo.
+async fn list_shares(
+    state: &AppState,
+    user: CurrentUser,
+    target: ShareTarget,
+) -> Result<Json<SuccessResponse<Vec<ShareResponse>>>, ApiError> {
+    let grants = service::list_shares(&state.db, user.id, target)
+        .await
+        .map_err(map_share_error)?;
+    info!(target = ?target, count = grants.len(), "shares listed");
+    let data = grants.into_iter().map(ShareResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+// Handler bersama untuk DELETE share project/todo.
+async fn revoke_share(
+    state: &AppState,
+    user: CurrentUser,
+    target: ShareTarget,
+    grantee_id: Uuid,
+) -> Result<StatusCode, ApiError> {
+    service::revoke_share(&state.db, user.id, target, grantee_id)
+        .await
+        .map_err(map_share_error)?;
+    info!(target = ?target, grantee_id = %grantee_id, "share revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain share ke `ApiError`.
+fn map_share_error(err: ShareError) -> ApiError {
+    match err {
+        ShareError::Validation(message) => {
+            warn!(reason = %message, "share validation failed");
+            ApiError::bad_request(message)
+        }
+        ShareError::NotFound => {
+            warn!("shared resource not found");
+            ApiError::not_found("resource not found")
+        }
+        ShareError::Forbidden => {
+            warn!("share management by non-owner rejected");
+            ApiError::forbidden("only owners can manage sharing")
+        }
+        ShareError::UserNotFound => {
+            warn!("share grantee not found");
+            ApiError::not_found("user not found")
+        }
+        ShareError::GrantNotFound => {
+            warn!("share grant not found");
+            ApiError::not_found("share not found")
+        }
+        ShareError::Database(db_err) => {
+            error!(error = %db_err, "share database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint sharing terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::modules::project::handler as project_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // Owner dan kolaborator acak agar tidak bentrok dengan data test lain.
+    async fn build_test_app() -> (Router, Uuid, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let owner_id = Uuid::new_v4();
+        let collaborator_id = Uuid::new_v4();
+        for user_id in [owner_id, collaborator_id] {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let app = Router::new()
+            .merge(routes())
+            .merge(todo_handler::user_routes())
+            .nest("/todos", todo_handler::routes().merge(todo_routes()))
+            .nest("/projects", project_handler::routes().merge(project_routes()))
+            .with_state(AppState::for_tests(db, owner_id));
+        (app, owner_id, collaborator_id)
+    }
+
+    async fn send(
+        app: &Router,
+        as_user: Option<Uuid>,
+        method: &str,
+        uri: String,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(user_id) = as_user {
+            builder = builder.header(USER_ID_HEADER, user_id.to_string());
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn todo_share_should_grant_and_revoke_access() {
+        let (app, _, collaborator) = build_test_app().await;
+        let peer = Some(collaborator);
+        let todo = read_json(
+            send(&app, None, "POST", "/todos".to_string(), Some(json!({ "title": "shared" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        let hidden = send(&app, peer, "GET", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(hidden.status(), StatusCode::NOT_FOUND);
+
+        let granted = send(
+            &app,
+            None,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{collaborator}"),
+            Some(json!({ "role": "viewer" })),
+        )
+        .await;
+        assert_eq!(granted.status(), StatusCode::OK);
+
+        let visible = send(&app, peer, "GET", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(visible.status(), StatusCode::OK);
+        let read_only = send(
+            &app,
+            peer,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "title": "edited" })),
+        )
+        .await;
+        assert_eq!(read_only.status(), StatusCode::FORBIDDEN);
+        let not_owner = send(
+            &app,
+            peer,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{collaborator}"),
+            Some(json!({ "role": "owner" })),
+        )
+        .await;
+        assert_eq!(not_owner.status(), StatusCode::FORBIDDEN);
+
+        let shared = read_json(send(&app, peer, "GET", "/shared-with-me".to_string(), None).await).await;
+        assert_eq!(shared["data"]["todos"][0]["todo"]["id"], todo_id.as_str());
+        assert_eq!(shared["data"]["todos"][0]["role"], "viewer");
+
+        send(
+            &app,
+            None,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{collaborator}"),
+            Some(json!({ "role": "editor" })),
+        )
+        .await;
+        let edited = send(
+            &app,
+            peer,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "title": "edited" })),
+        )
+        .await;
+        assert_eq!(edited.status(), StatusCode::OK);
+
+        let revoked = send(&app, None, "DELETE", format!("/todos/{todo_id}/shares/{collaborator}"), None).await;
+        assert_eq!(revoked.status(), StatusCode::NO_CONTENT);
+        let gone = send(&app, peer, "GET", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(gone.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn project_share_should_expose_project_todos() {
+        let (app, _, collaborator) = build_test_app().await;
+        let peer = Some(collaborator);
+        let project = read_json(
+            send(&app, None, "POST", "/projects".to_string(), Some(json!({ "name": "Rilis" }))).await,
+        )
+        .await;
+        let project_id = project["data"]["id"].as_str().expect("project id must exist").to_string();
+        send(
+            &app,
+            None,
+            "POST",
+            "/todos".to_string(),
+            Some(json!({ "title": "in project", "project_id": project_id })),
+        )
+        .await;
+
+        let before = read_json(send(&app, peer, "GET", "/todos".to_string(), None).await).await;
+        assert_eq!(before["data"], json!([]));
+
+        send(
+            &app,
+            None,
+            "PUT",
+            format!("/projects/{project_id}/shares/{collaborator}"),
+            Some(json!({ "role": "editor" })),
+        )
+        .await;
+        let listed = read_json(
+            send(&app, peer, "GET", format!("/todos?project_id={project_id}"), None).await,
+        )
+        .await;
+        assert_eq!(listed["data"].as_array().map(Vec::len), Some(1));
+        let created = send(
+            &app,
+            peer,
+            "POST",
+            "/todos".to_string(),
+            Some(json!({ "title": "from collaborator", "project_id": project_id })),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let project_view = read_json(send(&app, peer, "GET", format!("/projects/{project_id}"), None).await).await;
+        assert_eq!(project_view["data"]["role"], "editor");
+
+        send(&app, None, "DELETE", format!("/projects/{project_id}/shares/{collaborator}"), None).await;
+        let after = read_json(send(&app, peer, "GET", "/todos".to_string(), None).await).await;
+        assert_eq!(after["data"].as_array().map(Vec::len), Some(1));
+        assert_eq!(after["data"][0]["title"], "from collaborator");
+
+        let unknown = send(&app, Some(Uuid::new_v4()), "GET", "/todos".to_string(), None).await;
+        assert_eq!(unknown.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn assignee_should_require_editor_access() {
+        let (app, _, collaborator) = build_test_app().await;
+        let peer = Some(collaborator);
+        let rejected = send(
+            &app,
+            None,
+            "POST",
+            "/todos".to_string(),
+            Some(json!({ "title": "delegated", "assignee_id": collaborator })),
+        )
+        .await;
+        assert_eq!(rejected.status(), StatusCode::BAD_REQUEST);
+
+        let todo = read_json(
+            send(&app, None, "POST", "/todos".to_string(), Some(json!({ "title": "delegated" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        send(
+            &app,
+            None,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{collaborator}"),
+            Some(json!({ "role": "editor" })),
+        )
+        .await;
+        let assigned = send(
+            &app,
+            None,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "assignee_id": collaborator })),
+        )
+        .await;
+        assert_eq!(assigned.status(), StatusCode::OK);
+
+        let mine = read_json(send(&app, peer, "GET", "/assigned-to-me".to_string(), None).await).await;
+        assert_eq!(mine["data"][0]["id"], todo_id.as_str());
+        let filtered = read_json(send(&app, peer, "GET", "/todos?assignee=me".to_string(), None).await).await;
+        assert_eq!(filtered["data"].as_array().map(Vec::len), Some(1));
+        let unassigned = read_json(send(&app, None, "GET", "/todos?assignee=none".to_string(), None).await).await;
+        assert_eq!(unassigned["data"].as_array().map(Vec::len), Some(0));
+
+        let cleared = read_json(
+            send(
+                &app,
+                None,
+                "PATCH",
+                format!("/todos/{todo_id}"),
+                Some(json!({ "assignee_id": null })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(cleared["data"]["assignee_id"], Value::Null);
+    }
+}
@@ -0,0 +1,7 @@
+//! Modul sharing: share grant project/todo antar user dan pengecekan akses berbasis role.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
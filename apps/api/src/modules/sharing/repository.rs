@@ -0,0 +1,153 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, Order, QueryFilter,
+    QueryOrder, Set, sea_query::{Query, SelectStatement},
+};
+use uuid::Uuid;
+
+use crate::modules::project::entity as project_entity;
+use crate::modules::todo::entity as todo_entity;
+
+use super::dto::{ShareRole, ShareTarget};
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+
+/// Membuat grant baru atau mengganti role grant yang sudah ada untuk pasangan (resource, grantee).
+pub async fn upsert_grant<C: ConnectionTrait>(
+    conn: &C,
+    target: ShareTarget,
+    grantee_id: Uuid,
+    role: ShareRole,
+    granted_by: Uuid,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    if let Some(existing) = find_grant(conn, target, grantee_id).await? {
+        let mut active: entity::ActiveModel = existing.into();
+        active.role = Set(role.as_str().to_string());
+        active.granted_by = Set(granted_by);
+        active.updated_at = Set(now);
+        return active.update(conn).await;
+    }
+
+    let (project_id, todo_id) = match target {
+        ShareTarget::Project(id) => (Some(id), None),
+        ShareTarget::Todo(id) => (None, Some(id)),
+    };
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        project_id: Set(project_id),
+        todo_id: Set(todo_id),
+        grantee_id: Set(grantee_id),
+        role: Set(role.as_str().to_string()),
+        granted_by: Set(granted_by),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil grant milik `grantee_id` pada resource tertentu.
+pub async fn find_grant<C: ConnectionTrait>(
+    conn: &C,
+    target: ShareTarget,
+    grantee_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(target_condition(target))
+        .filter(Column::GranteeId.eq(grantee_id))
+        .one(conn)
+        .await
+}
+
+/// Mengambil seluruh grant pada satu resource, urut terlama lebih dulu.
+pub async fn list_grants_for_target<C: ConnectionTrait>(
+    conn: &C,
+    target: ShareTarget,
+) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(target_condition(target))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil seluruh grant yang diterima user, urut terbaru lebih dulu.
+pub async fn list_grants_for_grantee<C: ConnectionTrait>(
+    conn: &C,
+    grantee_id: Uuid,
+) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::GranteeId.eq(grantee_id))
+        .order_by(Column::CreatedAt, Order::Desc)
+        .all(conn)
+        .await
+}
+
+/// Mencabut grant; mengembalikan `true` bila ada baris terhapus.
+pub async fn delete_grant<C: ConnectionTrait>(
+    conn: &C,
+    target: ShareTarget,
+    grantee_id: Uuid,
+) -> Result<bool, DbErr> {
+    let result = Entity::delete_many()
+        .filter(target_condition(target))
+        .filter(Column::GranteeId.eq(grantee_id))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected > 0)
+}
+
+/// Menghapus seluruh grant milik user atas project/todo di dalam workspace (saat member dikeluarkan).
+pub async fn delete_grants_in_workspace<C: ConnectionTrait>(
+    conn: &C,
+    grantee_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<u64, DbErr> {
+    let workspace_projects = Query::select()
+        .column(project_entity::Column::Id)
+        .from(project_entity::Entity)
+        .and_where(project_entity::Column::WorkspaceId.eq(workspace_id))
+        .to_owned();
+    let workspace_todos = Query::select()
+        .column(todo_entity::Column::Id)
+        .from(todo_entity::Entity)
+        .and_where(todo_entity::Column::WorkspaceId.eq(workspace_id))
+        .to_owned();
+    let result = Entity::delete_many()
+        .filter(Column::GranteeId.eq(grantee_id))
+        .filter(
+            Condition::any()
+                .add(Column::ProjectId.in_subquery(workspace_projects))
+                .add(Column::TodoId.in_subquery(workspace_todos)),
+        )
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
+
+/// Subquery id todo yang dibagikan langsung ke user (dipakai filter list todo).
+pub fn shared_todo_ids_query(grantee_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(Column::TodoId)
+        .from(Entity)
+        .and_where(Column::GranteeId.eq(grantee_id))
+        .and_where(Column::TodoId.is_not_null())
+        .to_owned()
+}
+
+/// Subquery id project yang dibagikan ke user (dipakai filter list project/todo).
+pub fn shared_project_ids_query(grantee_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(Column::ProjectId)
+        .from(Entity)
+        .and_where(Column::GranteeId.eq(grantee_id))
+        .and_where(Column::ProjectId.is_not_null())
+        .to_owned()
+}
+
+// Filter kolom resource sesuai jenis target.
+fn target_condition(target: ShareTarget) -> sea_orm::sea_query::SimpleExpr {
+    match target {
+        ShareTarget::Project(id) => Column::ProjectId.eq(id),
+        ShareTarget::Todo(id) => Column::TodoId.eq(id),
+    }
+}
@@ -0,0 +1,303 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::common::authz::{self, AccessError, Permission, Resource};
+use crate::modules::project::entity::Model as Project;
+use crate::modules::project::repository as project_repository;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::user::repository as user_repository;
+use crate::modules::workspace::dto::WorkspaceRole;
+use crate::modules::workspace::member_entity::Model as WorkspaceMember;
+use crate::modules::workspace::repository as workspace_repository;
+
+use super::dto::{ShareRequest, ShareRole, ShareTarget, TodoScope};
+use super::entity::Model;
+use super::repository;
+
+/// Error domain pengelolaan share yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum ShareError {
+    Validation(String),
+    /// Project/todo tidak ditemukan atau tidak bisa diakses.
+    NotFound,
+    Forbidden,
+    UserNotFound,
+    GrantNotFound,
+    Database(DbErr),
+}
+
+impl From<DbErr> for ShareError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for ShareError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Project dan todo yang dibagikan langsung ke user, masing-masing dengan grant-nya.
+#[derive(Debug, Default)]
+pub struct SharedWithMe {
+    pub projects: Vec<(Model, Project)>,
+    pub todos: Vec<(Model, Todo)>,
+}
+
+/// Role efektif user pada todo: pemilik todo, grant langsung, role pada project induk,
+/// atau role di workspace. Todo di workspace hanya bisa diakses member workspace tersebut,
+/// termasuk oleh pemilik aslinya setelah ia dikeluarkan.
+pub async fn todo_role<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    todo: &Todo,
+) -> Result<Option<ShareRole>, DbErr> {
+    let scope = TodoScope {
+        todo_id: todo.id,
+        owner_id: todo.user_id,
+        workspace_id: todo.workspace_id,
+        project_id: todo.project_id,
+    };
+    scope_role(conn, user_id, scope).await
+}
+
+/// Sama dengan `todo_role`, tetapi dari `TodoScope` sehingga bisa dipakai untuk snapshot
+/// todo (mis. event todo yang sudah dihapus).
+pub async fn scope_role<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    scope: TodoScope,
+) -> Result<Option<ShareRole>, DbErr> {
+    let via_workspace = match scope.workspace_id {
+        Some(workspace_id) => match workspace_repository::find_member(conn, workspace_id, user_id).await? {
+            Some(member) => member_content_role(&member),
+            None => return Ok(None),
+        },
+        None => None,
+    };
+    let direct = grant_role(conn, ShareTarget::Todo(scope.todo_id), user_id).await?;
+    let via_project = match scope.project_id {
+        Some(project_id) => match project_repository::find_project_by_id(conn, project_id).await? {
+            Some(project) => project_role(conn, user_id, &project).await?,
+            None => None,
+        },
+        None => None,
+    };
+    Ok(strongest_role(
+        scope.owner_id == user_id,
+        &[direct, via_project, via_workspace],
+    ))
+}
+
+/// Role efektif user pada project: pemilik project, grant project, atau role di workspace.
+/// Seperti todo, project di workspace hanya bisa diakses member workspace tersebut.
+pub async fn project_role<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    project: &Project,
+) -> Result<Option<ShareRole>, DbErr> {
+    let via_workspace = match project.workspace_id {
+        Some(workspace_id) => match workspace_repository::find_member(conn, workspace_id, user_id).await? {
+            Some(member) => member_content_role(&member),
+            None => return Ok(None),
+        },
+        None => None,
+    };
+    let grant = grant_role(conn, ShareTarget::Project(project.id), user_id).await?;
+    Ok(strongest_role(project.owner_id == user_id, &[grant, via_workspace]))
+}
+
+/// Role otomatis user atas isi workspace; `None` bila bukan member atau hanya guest.
+pub async fn workspace_content_role<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<Option<ShareRole>, DbErr> {
+    let member = workspace_repository::find_member(conn, workspace_id, user_id).await?;
+    Ok(member.as_ref().and_then(member_content_role))
+}
+
+/// Mengambil todo bila user punya permission `action` padanya.
+pub async fn authorize_todo<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    todo_id: Uuid,
+    action: Permission,
+) -> Result<Todo, AccessError> {
+    let todo = todo_repository::find_todo_by_id(conn, todo_id)
+        .await?
+        .ok_or(AccessError::NotFound)?;
+    authz::authorize(conn, user_id, action, Resource::Todo(&todo)).await?;
+    Ok(todo)
+}
+
+/// Mengambil project beserta role user bila user punya permission `action` padanya.
+pub async fn authorize_project<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    project_id: Uuid,
+    action: Permission,
+) -> Result<(Project, ShareRole), AccessError> {
+    let project = project_repository::find_project_by_id(conn, project_id)
+        .await?
+        .ok_or(AccessError::NotFound)?;
+    let role = authz::authorize(conn, user_id, action, Resource::Project(&project)).await?;
+    Ok((project, role.share_role()))
+}
+
+/// Role tertinggi dari kepemilikan langsung dan seluruh grant yang berlaku.
+pub fn strongest_role(is_owner: bool, grants: &[Option<ShareRole>]) -> Option<ShareRole> {
+    if is_owner {
+        return Some(ShareRole::Owner);
+    }
+    grants.iter().flatten().copied().max()
+}
+
+/// Memberi atau mengubah akses `grantee_id` pada resource; hanya owner resource yang boleh.
+pub async fn share(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    target: ShareTarget,
+    grantee_id: Uuid,
+    request: ShareRequest,
+) -> Result<Model, ShareError> {
+    let role = parse_role(&request.role)?;
+    let (owner_id, workspace_id) = authorize_target(conn, actor_id, target).await?;
+    if grantee_id == owner_id {
+        return Err(ShareError::Validation("user already owns this resource".to_string()));
+    }
+    if !user_repository::user_exists(conn, grantee_id).await? {
+        return Err(ShareError::UserNotFound);
+    }
+    if let Some(workspace_id) = workspace_id
+        && workspace_repository::find_member(conn, workspace_id, grantee_id).await?.is_none()
+    {
+        return Err(ShareError::Validation(
+            "user is not a member of this workspace".to_string(),
+        ));
+    }
+    repository::upsert_grant(conn, target, grantee_id, role, actor_id)
+        .await
+        .map_err(ShareError::from)
+}
+
+/// Daftar grant pada resource; hanya owner resource yang boleh melihat.
+pub async fn list_shares(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    target: ShareTarget,
+) -> Result<Vec<Model>, ShareError> {
+    authorize_target(conn, actor_id, target).await?;
+    repository::list_grants_for_target(conn, target)
+        .await
+        .map_err(ShareError::from)
+}
+
+/// Mencabut akses `grantee_id`; berlaku langsung untuk request berikutnya.
+/// Owner resource boleh mencabut siapa pun, grantee boleh melepas aksesnya sendiri.
+pub async fn revoke_share(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    target: ShareTarget,
+    grantee_id: Uuid,
+) -> Result<(), ShareError> {
+    if actor_id != grantee_id {
+        authorize_target(conn, actor_id, target).await?;
+    }
+    if !repository::delete_grant(conn, target, grantee_id).await? {
+        return Err(ShareError::GrantNotFound);
+    }
+    Ok(())
+}
+
+/// Project dan todo yang dibagikan langsung ke user.
+pub async fn shared_with_me(conn: &DatabaseConnection, user_id: Uuid) -> Result<SharedWithMe, DbErr> {
+    let grants = repository::list_grants_for_grantee(conn, user_id).await?;
+    let project_ids: Vec<Uuid> = grants.iter().filter_map(|grant| grant.project_id).collect();
+    let todo_ids: Vec<Uuid> = grants.iter().filter_map(|grant| grant.todo_id).collect();
+    let projects = project_repository::find_projects_by_ids(conn, &project_ids).await?;
+    let todos = todo_repository::find_todos_by_ids(conn, &todo_ids).await?;
+
+    let mut shared = SharedWithMe::default();
+    for grant in grants {
+        if let Some(project) = grant
+            .project_id
+            .and_then(|id| projects.iter().find(|project| project.id == id).cloned())
+        {
+            shared.projects.push((grant, project));
+        } else if let Some(todo) = grant
+            .todo_id
+            .and_then(|id| todos.iter().find(|todo| todo.id == id).cloned())
+        {
+            shared.todos.push((grant, todo));
+        }
+    }
+    Ok(shared)
+}
+
+/// Validasi nilai role dari request.
+pub fn parse_role(raw: &str) -> Result<ShareRole, ShareError> {
+    ShareRole::parse(raw.trim()).ok_or_else(|| {
+        ShareError::Validation("role must be one of viewer, editor, owner".to_string())
+    })
+}
+
+// Role dari grant langsung; nilai role tak dikenal diperlakukan sebagai tanpa akses.
+async fn grant_role<C: ConnectionTrait>(
+    conn: &C,
+    target: ShareTarget,
+    user_id: Uuid,
+) -> Result<Option<ShareRole>, DbErr> {
+    let grant = repository::find_grant(conn, target, user_id).await?;
+    Ok(grant.and_then(|grant| ShareRole::parse(&grant.role)))
+}
+
+// Role workspace tak dikenal diperlakukan sebagai guest (tanpa akses otomatis).
+fn member_content_role(member: &WorkspaceMember) -> Option<ShareRole> {
+    WorkspaceRole::parse(&member.role).and_then(WorkspaceRole::content_role)
+}
+
+// Cek izin kelola share atas target dan kembalikan id pemilik asli serta workspace-nya.
+async fn authorize_target(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    target: ShareTarget,
+) -> Result<(Uuid, Option<Uuid>), AccessError> {
+    match target {
+        ShareTarget::Project(id) => {
+            let (project, _) = authorize_project(conn, actor_id, id, Permission::ProjectShare).await?;
+            Ok((project.owner_id, project.workspace_id))
+        }
+        ShareTarget::Todo(id) => {
+            let todo = authorize_todo(conn, actor_id, id, Permission::TodoShare).await?;
+            Ok((todo.user_id, todo.workspace_id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strongest_role_should_prefer_ownership_and_highest_grant() {
+        assert_eq!(strongest_role(true, &[Some(ShareRole::Viewer)]), Some(ShareRole::Owner));
+        assert_eq!(
+            strongest_role(false, &[Some(ShareRole::Viewer), None, Some(ShareRole::Editor)]),
+            Some(ShareRole::Editor)
+        );
+        assert_eq!(strongest_role(false, &[None, None]), None);
+    }
+
+    #[test]
+    fn parse_role_should_reject_unknown_value() {
+        assert!(matches!(parse_role(" editor "), Ok(ShareRole::Editor)));
+        assert!(matches!(parse_role("admin"), Err(ShareError::Validation(_))));
+    }
+}
@@ -0,0 +1,188 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::todo::dto::{CreateTodoRequest, TodoExtras, TodoResponse, UpdateTodoRequest};
+use crate::modules::todo::entity::Model as TodoModel;
+
+use super::entity;
+
+/// Query `GET /sync`.
+#[derive(Debug, Deserialize)]
+pub struct SyncQuery {
+    /// Token `next_token` dari sync sebelumnya; tanpa token berarti full sync.
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Data tambahan todo, sama seperti `GET /todos?include=`.
+    #[serde(default)]
+    pub include: Option<String>,
+}
+
+/// Hasil tarik perubahan sebelum dipetakan ke response.
+#[derive(Debug)]
+pub struct SyncChanges {
+    pub next_token: i64,
+    /// `true` bila tanpa token: `updated` berisi seluruh todo dan `deleted` selalu kosong.
+    pub full: bool,
+    pub updated: Vec<TodoModel>,
+    pub deleted: Vec<entity::Model>,
+}
+
+/// Response `GET /sync`.
+#[derive(Debug, Serialize)]
+pub struct SyncPullResponse {
+    /// Dikirim sebagai `since` pada sync berikutnya.
+    pub next_token: String,
+    pub full: bool,
+    /// Todo yang dibuat atau berubah sejak token.
+    pub updated: Vec<TodoResponse>,
+    /// Tombstone todo yang terhapus (atau pindah workspace) sejak token.
+    pub deleted: Vec<DeletedTodo>,
+}
+
+/// Tombstone todo di response sync.
+#[derive(Debug, Serialize)]
+pub struct DeletedTodo {
+    pub id: Uuid,
+    pub deleted_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for DeletedTodo {
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.todo_id,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+/// Payload `POST /sync`.
+#[derive(Debug, Deserialize)]
+pub struct SyncPushRequest {
+    pub mutations: Vec<SyncMutation>,
+}
+
+/// Satu mutasi dari client, dibedakan lewat field `op`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum SyncMutation {
+    /// Membuat todo dengan id buatan client; aman dikirim ulang.
+    Create { id: Uuid, todo: CreateTodoRequest },
+    /// Update parsial; bila `base_updated_at` diisi dan berbeda dari server, mutasi konflik.
+    Update {
+        id: Uuid,
+        #[serde(default)]
+        base_updated_at: Option<DateTime<FixedOffset>>,
+        changes: UpdateTodoRequest,
+    },
+    /// Menghapus todo; todo yang sudah terhapus dianggap berhasil.
+    Delete {
+        id: Uuid,
+        #[serde(default)]
+        base_updated_at: Option<DateTime<FixedOffset>>,
+    },
+}
+
+impl SyncMutation {
+    pub fn id(&self) -> Uuid {
+        match self {
+            Self::Create { id, .. } | Self::Update { id, .. } | Self::Delete { id, .. } => *id,
+        }
+    }
+
+    pub fn op(&self) -> &'static str {
+        match self {
+            Self::Create { .. } => "create",
+            Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
+        }
+    }
+}
+
+/// Alasan mutasi tidak diterapkan karena data server sudah berubah.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictReason {
+    /// `updated_at` server berbeda dari `base_updated_at` client.
+    Modified,
+    /// Todo sudah dihapus di server.
+    Deleted,
+}
+
+/// Hasil penerapan satu mutasi.
+#[derive(Debug)]
+pub enum MutationStatus {
+    /// Todo terbaru; `None` untuk delete.
+    Applied(Option<TodoModel>),
+    /// Todo versi server bila masih ada.
+    Conflict(ConflictReason, Option<TodoModel>),
+    /// Kode dan pesan error; mutasi tidak mengubah data.
+    Rejected(&'static str, String),
+}
+
+/// Hasil satu mutasi beserta identitasnya.
+#[derive(Debug)]
+pub struct MutationOutcome {
+    pub id: Uuid,
+    pub op: &'static str,
+    pub status: MutationStatus,
+}
+
+impl MutationOutcome {
+    /// Todo yang ikut dikirim di response, untuk memuat data tambahannya sekaligus.
+    pub fn todo(&self) -> Option<&TodoModel> {
+        match &self.status {
+            MutationStatus::Applied(todo) | MutationStatus::Conflict(_, todo) => todo.as_ref(),
+            MutationStatus::Rejected(..) => None,
+        }
+    }
+}
+
+/// Response `POST /sync`, urutan sama dengan `mutations`.
+#[derive(Debug, Serialize)]
+pub struct SyncPushResponse {
+    pub results: Vec<MutationResult>,
+}
+
+/// Hasil satu mutasi di response.
+#[derive(Debug, Serialize)]
+pub struct MutationResult {
+    pub id: Uuid,
+    pub op: &'static str,
+    /// `applied`, `conflict`, atau `rejected`.
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict: Option<ConflictReason>,
+    /// Todo terbaru (applied) atau versi server (conflict).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo: Option<TodoResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<MutationError>,
+}
+
+/// Detail mutasi yang ditolak.
+#[derive(Debug, Serialize)]
+pub struct MutationError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl MutationResult {
+    /// Mapping hasil domain ke response, dilengkapi data tambahan todo.
+    pub fn from_outcome(outcome: MutationOutcome, extras: &TodoExtras) -> Self {
+        let todo_response = |todo: Option<TodoModel>| todo.map(|todo| TodoResponse::from(todo).with_extras(extras));
+        let (status, conflict, todo, error) = match outcome.status {
+            MutationStatus::Applied(todo) => ("applied", None, todo_response(todo), None),
+            MutationStatus::Conflict(reason, todo) => ("conflict", Some(reason), todo_response(todo), None),
+            MutationStatus::Rejected(code, message) => ("rejected", None, None, Some(MutationError { code, message })),
+        };
+        Self {
+            id: outcome.id,
+            op: outcome.op,
+            status,
+            conflict,
+            todo,
+            error,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `todo_tombstones`; baris diisi trigger `todos_track_sync`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "todo_tombstones")]
+pub struct Model {
+    /// Id todo yang terhapus atau pindah workspace.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub todo_id: Uuid,
+    /// Pemilik todo saat terhapus.
+    pub user_id: Uuid,
+    /// Workspace asal todo; `None` untuk ruang pribadi.
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    /// UID eksternal todo saat terhapus; dipakai CalDAV untuk menyusun href resource.
+    pub external_uid: Option<String>,
+    /// Id transaksi Postgres yang menghapus todo; dibandingkan dengan token sync.
+    pub sync_txid: i64,
+    pub deleted_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
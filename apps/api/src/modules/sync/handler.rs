@@ -0,0 +1,312 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    routing::get,
+};
+use tracing::{error, info, warn};
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::todo::dto::{TodoIncludes, TodoResponse};
+use crate::modules::todo::service as todo_service;
+
+use super::dto::{MutationResult, SyncPullResponse, SyncPushRequest, SyncPushResponse, SyncQuery};
+use super::service::{self, SyncError};
+
+/// Registrasi route sinkronisasi client offline.
+///
+/// - `GET /sync` tarik perubahan todo sejak token
+/// - `POST /sync` kirim batch mutasi client
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/sync", get(pull_changes).post(push_mutations))
+}
+
+/// GET `/sync`
+/// Query: `since` opsional (token dari sync sebelumnya), `include` opsional seperti `GET /todos`.
+/// Output: `200 OK` + `SyncPullResponse` untuk workspace aktif, `400` bila token tidak valid.
+pub async fn pull_changes(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<SyncQuery>,
+) -> Result<Json<SuccessResponse<SyncPullResponse>>, ApiError> {
+    let includes = todo_service::parse_includes(query.include.as_deref())
+        .map_err(|err| map_sync_error(err.into()))?;
+    let since = query
+        .since
+        .as_deref()
+        .map(service::parse_token)
+        .transpose()
+        .map_err(map_sync_error)?;
+    let changes = service::pull_changes(&state.db, user.id, user.workspace_id, since)
+        .await
+        .map_err(map_sync_error)?;
+    let extras = todo_service::load_extras(&state.db, &changes.updated, includes)
+        .await
+        .map_err(|err| map_sync_error(err.into()))?;
+    info!(
+        since = ?since,
+        next_token = changes.next_token,
+        updated = changes.updated.len(),
+        deleted = changes.deleted.len(),
+        "sync changes pulled"
+    );
+    Ok(Json(SuccessResponse {
+        data: SyncPullResponse {
+            next_token: changes.next_token.to_string(),
+            full: changes.full,
+            updated: changes
+                .updated
+                .into_iter()
+                .map(|todo| TodoResponse::from(todo).with_extras(&extras))
+                .collect(),
+            deleted: changes.deleted.into_iter().map(Into::into).collect(),
+        },
+    }))
+}
+
+/// POST `/sync`
+/// Input: `SyncPushRequest` (maksimal 100 mutasi).
+/// Output: `200 OK` + hasil per mutasi (`applied`, `conflict`, atau `rejected`) sesuai urutan kiriman.
+pub async fn push_mutations(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<SyncPushRequest>,
+) -> Result<Json<SuccessResponse<SyncPushResponse>>, ApiError> {
+    let audit = AuditContext::new(user.id, &state.history);
+    let outcomes = service::apply_mutations(
+        &state.db,
+        user.id,
+        user.workspace_id,
+        payload.mutations,
+        &audit,
+        &state.dependencies,
+    )
+    .await
+    .map_err(map_sync_error)?;
+    let todos: Vec<_> = outcomes.iter().filter_map(|outcome| outcome.todo().cloned()).collect();
+    let extras = todo_service::load_extras(&state.db, &todos, TodoIncludes::default())
+        .await
+        .map_err(|err| map_sync_error(err.into()))?;
+    info!(count = outcomes.len(), "sync mutations applied");
+    let results = outcomes
+        .into_iter()
+        .map(|outcome| MutationResult::from_outcome(outcome, &extras))
+        .collect();
+    Ok(Json(SuccessResponse {
+        data: SyncPushResponse { results },
+    }))
+}
+
+/// Mapping error domain sync ke `ApiError` agar response error konsisten.
+fn map_sync_error(err: SyncError) -> ApiError {
+    match err {
+        SyncError::Validation(message) => {
+            warn!(reason = %message, "sync validation failed");
+            ApiError::bad_request(message)
+        }
+        SyncError::NotFound => {
+            warn!("sync todo not found");
+            ApiError::not_found("todo not found")
+        }
+        SyncError::Forbidden => {
+            warn!("sync forbidden");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        SyncError::Database(db_err) => {
+            error!(error = %db_err, "sync database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests untuk integrasi end-to-end API sync.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::Database;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::common::current_user::USER_ID_HEADER;
+    use crate::modules::sharing::handler as sharing_handler;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // User acak per test agar hasil sync tidak tercampur data test lain.
+    async fn build_test_app() -> (Router, Uuid, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let owner_id = Uuid::new_v4();
+        let stranger_id = Uuid::new_v4();
+        for user_id in [owner_id, stranger_id] {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let app = Router::new()
+            .merge(routes())
+            .nest("/todos", todo_handler::routes().merge(sharing_handler::todo_routes()))
+            .with_state(AppState::for_tests(db, owner_id));
+        (app, owner_id, stranger_id)
+    }
+
+    async fn send(
+        app: &Router,
+        as_user: Uuid,
+        method: &str,
+        uri: String,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(USER_ID_HEADER, as_user.to_string());
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        let response = app.clone().oneshot(request).await.expect("response expected");
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).expect("body must be valid json")
+        };
+        (status, body)
+    }
+
+    fn ids(list: &Value) -> Vec<String> {
+        list.as_array()
+            .expect("list must be an array")
+            .iter()
+            .map(|item| item["id"].as_str().expect("id must be a string").to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn sync_should_round_trip_mutations_tombstones_and_conflicts() {
+        let (app, owner_id, stranger_id) = build_test_app().await;
+        let keep_id = Uuid::new_v4();
+        let drop_id = Uuid::new_v4();
+
+        // Push create dengan id client; kiriman ulang tetap `applied` tanpa duplikasi.
+        let creates = json!({ "mutations": [
+            { "op": "create", "id": keep_id, "todo": { "title": "offline keep" } },
+            { "op": "create", "id": drop_id, "todo": { "title": "offline drop" } },
+            { "op": "create", "id": Uuid::new_v4(), "todo": { "title": "   " } },
+        ]});
+        let (status, body) = send(&app, owner_id, "POST", "/sync".to_string(), Some(creates.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        let results = &body["data"]["results"];
+        assert_eq!(results[0]["status"], "applied");
+        assert_eq!(results[0]["todo"]["id"], keep_id.to_string());
+        assert_eq!(results[2]["status"], "rejected");
+        assert_eq!(results[2]["error"]["code"], "validation");
+        let base_updated_at = results[0]["todo"]["updated_at"].clone();
+        let (_, body) = send(&app, owner_id, "POST", "/sync".to_string(), Some(creates)).await;
+        assert_eq!(body["data"]["results"][1]["status"], "applied");
+
+        // Full sync memuat kedua todo dan menerbitkan token.
+        let (status, body) = send(&app, owner_id, "GET", "/sync".to_string(), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["full"], true);
+        let updated = ids(&body["data"]["updated"]);
+        assert!(updated.contains(&keep_id.to_string()) && updated.contains(&drop_id.to_string()));
+        assert_eq!(updated.len(), 2);
+        let token = body["data"]["next_token"].as_str().expect("token must exist").to_string();
+
+        // Perubahan dari jalur todo biasa ikut tercatat; user lain tidak melihatnya.
+        let (status, _) = send(
+            &app,
+            owner_id,
+            "PATCH",
+            format!("/todos/{keep_id}"),
+            Some(json!({ "title": "edited online" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let mutations = json!({ "mutations": [
+            { "op": "update", "id": keep_id, "base_updated_at": base_updated_at, "changes": { "title": "edited offline" } },
+            { "op": "delete", "id": drop_id },
+            { "op": "delete", "id": drop_id },
+            { "op": "update", "id": drop_id, "changes": { "title": "too late" } },
+        ]});
+        let (_, body) = send(&app, owner_id, "POST", "/sync".to_string(), Some(mutations)).await;
+        let results = &body["data"]["results"];
+        assert_eq!(results[0]["status"], "conflict");
+        assert_eq!(results[0]["conflict"], "modified");
+        assert_eq!(results[0]["todo"]["title"], "edited online");
+        assert_eq!(results[1]["status"], "applied");
+        assert_eq!(results[2]["status"], "applied");
+        assert_eq!(results[3]["conflict"], "deleted");
+
+        // Re-create id yang sudah dihapus tidak menghidupkan todo kembali.
+        let recreate = json!({ "mutations": [{ "op": "create", "id": drop_id, "todo": { "title": "zombie" } }] });
+        let (_, body) = send(&app, owner_id, "POST", "/sync".to_string(), Some(recreate)).await;
+        assert_eq!(body["data"]["results"][0]["conflict"], "deleted");
+
+        let (status, body) = send(&app, owner_id, "GET", format!("/sync?since={token}"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["full"], false);
+        assert_eq!(ids(&body["data"]["updated"]), vec![keep_id.to_string()]);
+        assert_eq!(ids(&body["data"]["deleted"]), vec![drop_id.to_string()]);
+
+        let (_, body) = send(&app, stranger_id, "GET", format!("/sync?since={token}"), None).await;
+        assert_eq!(body["data"]["updated"], json!([]));
+        assert_eq!(body["data"]["deleted"], json!([]));
+        let hijack = json!({ "mutations": [{ "op": "create", "id": keep_id, "todo": { "title": "mine now" } }] });
+        let (_, body) = send(&app, stranger_id, "POST", "/sync".to_string(), Some(hijack)).await;
+        assert_eq!(body["data"]["results"][0]["error"]["code"], "id_taken");
+
+        let (status, _) = send(&app, owner_id, "GET", "/sync?since=abc".to_string(), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(&app, owner_id, "GET", format!("/sync?since={}", i64::MAX), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn sync_should_report_deleted_todos_that_were_shared_directly() {
+        let (app, owner_id, grantee_id) = build_test_app().await;
+        let (_, body) = send(&app, owner_id, "POST", "/todos".to_string(), Some(json!({ "title": "shared" }))).await;
+        let todo_id = body["data"]["id"].as_str().expect("todo id must exist").to_string();
+        let (status, _) = send(
+            &app,
+            owner_id,
+            "PUT",
+            format!("/todos/{todo_id}/shares/{grantee_id}"),
+            Some(json!({ "role": "viewer" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send(&app, grantee_id, "GET", "/sync".to_string(), None).await;
+        assert_eq!(ids(&body["data"]["updated"]), vec![todo_id.clone()]);
+        let token = body["data"]["next_token"].as_str().expect("token must exist").to_string();
+
+        let (status, _) = send(&app, owner_id, "DELETE", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (_, body) = send(&app, grantee_id, "GET", format!("/sync?since={token}"), None).await;
+        assert_eq!(ids(&body["data"]["deleted"]), vec![todo_id]);
+    }
+}
@@ -0,0 +1,8 @@
+//! Modul sinkronisasi inkremental untuk client offline-first: tarik perubahan todo sejak token
+//! terakhir (termasuk tombstone) dan kirim batch mutasi dengan laporan konflik per item.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
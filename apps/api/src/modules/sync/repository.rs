@@ -0,0 +1,110 @@
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, DbBackend, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Statement,
+    sea_query::{Alias, Expr},
+};
+use uuid::Uuid;
+
+use crate::modules::sharing::repository as sharing_repository;
+use crate::modules::todo::entity as todo_entity;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::workspace::repository as workspace_repository;
+
+use super::entity::{Column, Entity, Model};
+
+/// Batas atas perubahan yang pasti sudah ter-commit: id transaksi tertua yang masih berjalan.
+/// Perubahan dengan `sync_txid` di bawah nilai ini tidak akan muncul belakangan.
+pub async fn current_watermark<C: ConnectionTrait>(conn: &C) -> Result<i64, DbErr> {
+    let row = conn
+        .query_one(Statement::from_string(
+            DbBackend::Postgres,
+            "SELECT pg_snapshot_xmin(pg_current_snapshot())::text::bigint AS watermark",
+        ))
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("snapshot watermark".to_string()))?;
+    row.try_get("", "watermark")
+}
+
+/// Todo yang bisa diakses user di workspace aktif dengan `sync_txid >= since`;
+/// tanpa `since` berarti seluruh todo.
+pub async fn list_changed_todos<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    since: Option<i64>,
+) -> Result<Vec<todo_entity::Model>, DbErr> {
+    let mut query = todo_entity::Entity::find().filter(todo_repository::accessible_condition(user_id));
+    query = match workspace_id {
+        Some(workspace_id) => query.filter(todo_entity::Column::WorkspaceId.eq(workspace_id)),
+        None => query.filter(todo_entity::Column::WorkspaceId.is_null()),
+    };
+    if let Some(since) = since {
+        query = query.filter(Expr::col((todo_entity::Entity, Alias::new("sync_txid"))).gte(since));
+    }
+    query
+        .order_by(todo_entity::Column::UpdatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Tombstone di workspace aktif dengan `sync_txid >= since` yang relevan bagi user, dengan
+/// cakupan yang sama seperti `todo_repository::accessible_condition`: todo miliknya, dibagikan
+/// langsung ke user saat dihapus, di project milik/dibagikan ke user, atau di workspace tempat
+/// user member non-guest.
+pub async fn list_tombstones<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    since: i64,
+) -> Result<Vec<Model>, DbErr> {
+    let mut query = Entity::find()
+        .filter(
+            Condition::any()
+                .add(Column::UserId.eq(user_id))
+                .add(Expr::cust_with_values("$1 = ANY(grantee_ids)", [user_id]))
+                .add(Column::ProjectId.in_subquery(todo_repository::owned_project_ids_query(user_id)))
+                .add(Column::ProjectId.in_subquery(sharing_repository::shared_project_ids_query(user_id)))
+                .add(Column::WorkspaceId.in_subquery(workspace_repository::content_workspace_ids_query(user_id))),
+        )
+        .filter(Column::SyncTxid.gte(since));
+    query = match workspace_id {
+        Some(workspace_id) => query.filter(Column::WorkspaceId.eq(workspace_id)),
+        None => query.filter(Column::WorkspaceId.is_null()),
+    };
+    query.order_by(Column::SyncTxid, Order::Asc).all(conn).await
+}
+
+/// Todo di project dengan `sync_txid >= since`; tanpa `since` berarti seluruh todo project.
+/// Akses ke project dicek di service.
+pub async fn list_changed_project_todos<C: ConnectionTrait>(
+    conn: &C,
+    project_id: Uuid,
+    since: Option<i64>,
+) -> Result<Vec<todo_entity::Model>, DbErr> {
+    let mut query = todo_entity::Entity::find().filter(todo_entity::Column::ProjectId.eq(project_id));
+    if let Some(since) = since {
+        query = query.filter(Expr::col((todo_entity::Entity, Alias::new("sync_txid"))).gte(since));
+    }
+    query
+        .order_by(todo_entity::Column::UpdatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Tombstone todo yang terhapus dari project dengan `sync_txid >= since`.
+pub async fn list_project_tombstones<C: ConnectionTrait>(
+    conn: &C,
+    project_id: Uuid,
+    since: i64,
+) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::ProjectId.eq(project_id))
+        .filter(Column::SyncTxid.gte(since))
+        .order_by(Column::SyncTxid, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Tombstone satu todo, bila todo tersebut pernah dihapus.
+pub async fn find_tombstone<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(todo_id).one(conn).await
+}
@@ -0,0 +1,224 @@
+use sea_orm::{DatabaseConnection, DbErr};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::config::dependency::DependencySettings;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::todo::entity::Model as TodoModel;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service::{self as todo_service, TodoError};
+
+use super::dto::{ConflictReason, MutationOutcome, MutationStatus, SyncChanges, SyncMutation};
+use super::repository;
+
+/// Batas jumlah mutasi per `POST /sync`.
+pub const MAX_SYNC_MUTATIONS: usize = 100;
+
+/// Error domain sinkronisasi yang dipetakan ke HTTP error di handler.
+#[derive(Debug)]
+pub enum SyncError {
+    Validation(String),
+    NotFound,
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for SyncError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<TodoError> for SyncError {
+    // Hanya parsing `?include=` dan pemuatan data tambahan yang meneruskan `TodoError` ke sini.
+    fn from(value: TodoError) -> Self {
+        match value {
+            TodoError::Validation(message) => Self::Validation(message),
+            TodoError::NotFound => Self::NotFound,
+            TodoError::Forbidden => Self::Forbidden,
+            TodoError::Blocked(_) => Self::Validation("todo is blocked by unfinished todos".to_string()),
+            TodoError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Parsing token `since`; token berupa bilangan bulat non-negatif yang diterbitkan server.
+pub fn parse_token(raw: &str) -> Result<i64, SyncError> {
+    raw.trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|token| *token >= 0)
+        .ok_or_else(|| SyncError::Validation("since must be a token returned by a previous sync".to_string()))
+}
+
+/// Perubahan todo di workspace aktif sejak token `since`.
+///
+/// `next_token` adalah id transaksi tertua yang masih berjalan, sehingga perubahan yang
+/// ter-commit belakangan dari transaksi lebih lama tetap terambil di sync berikutnya.
+/// Akibatnya perubahan yang sama bisa terkirim lagi; client cukup menimpa berdasarkan id.
+/// Tanpa `since`, seluruh todo dikirim tanpa tombstone (full sync).
+pub async fn pull_changes(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    since: Option<i64>,
+) -> Result<SyncChanges, SyncError> {
+    let watermark = repository::current_watermark(conn).await?;
+    if let Some(since) = since
+        && since > watermark
+    {
+        return Err(SyncError::Validation(
+            "sync token is ahead of the server; perform a full sync".to_string(),
+        ));
+    }
+    let updated = repository::list_changed_todos(conn, user_id, workspace_id, since).await?;
+    let deleted = match since {
+        Some(since) => repository::list_tombstones(conn, user_id, workspace_id, since).await?,
+        None => Vec::new(),
+    };
+    Ok(SyncChanges {
+        next_token: watermark,
+        full: since.is_none(),
+        updated,
+        deleted,
+    })
+}
+
+/// Menerapkan batch mutasi client satu per satu lewat aturan todo biasa (validasi, izin, outbox).
+/// Tiap mutasi berdiri sendiri: kegagalan satu mutasi tidak membatalkan mutasi lain.
+pub async fn apply_mutations(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    mutations: Vec<SyncMutation>,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
+) -> Result<Vec<MutationOutcome>, SyncError> {
+    if mutations.len() > MAX_SYNC_MUTATIONS {
+        return Err(SyncError::Validation(format!(
+            "at most {MAX_SYNC_MUTATIONS} mutations are allowed per sync"
+        )));
+    }
+    let mut outcomes = Vec::with_capacity(mutations.len());
+    for mutation in mutations {
+        let id = mutation.id();
+        let op = mutation.op();
+        let status = apply_mutation(conn, user_id, workspace_id, mutation, audit, dependencies)
+            .await
+            .unwrap_or_else(|err| rejection(id, err));
+        outcomes.push(MutationOutcome { id, op, status });
+    }
+    Ok(outcomes)
+}
+
+// Menerapkan satu mutasi; create dengan id yang sudah ada dan terbaca dianggap kiriman ulang.
+async fn apply_mutation(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    mutation: SyncMutation,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
+) -> Result<MutationStatus, TodoError> {
+    match mutation {
+        SyncMutation::Create { id, todo } => {
+            if todo_repository::find_todo_by_id(conn, id).await?.is_some() {
+                return match todo_service::get_todo(conn, user_id, id).await {
+                    Ok(existing) => Ok(MutationStatus::Applied(Some(existing))),
+                    Err(TodoError::NotFound) => Ok(MutationStatus::Rejected(
+                        "id_taken",
+                        "todo id is already in use".to_string(),
+                    )),
+                    Err(err) => Err(err),
+                };
+            }
+            // Kiriman ulang create setelah todo dihapus tidak boleh menghidupkannya lagi.
+            if repository::find_tombstone(conn, id).await?.is_some() {
+                return Ok(MutationStatus::Conflict(ConflictReason::Deleted, None));
+            }
+            let mut new_todo = todo_service::prepare_new_todo(conn, user_id, workspace_id, todo).await?;
+            new_todo.id = id;
+            let created = todo_service::insert_todo(conn, user_id, new_todo, audit).await?;
+            Ok(MutationStatus::Applied(Some(created)))
+        }
+        SyncMutation::Update { id, base_updated_at, changes } => {
+            let Some(current) = current_todo(conn, user_id, id).await? else {
+                return Ok(MutationStatus::Conflict(ConflictReason::Deleted, None));
+            };
+            if base_updated_at.is_some_and(|base| base != current.updated_at) {
+                return Ok(MutationStatus::Conflict(ConflictReason::Modified, Some(current)));
+            }
+            let updated = todo_service::update_todo(conn, user_id, id, changes, audit, dependencies).await?;
+            Ok(MutationStatus::Applied(Some(updated)))
+        }
+        SyncMutation::Delete { id, base_updated_at } => {
+            let Some(current) = current_todo(conn, user_id, id).await? else {
+                return Ok(MutationStatus::Applied(None));
+            };
+            if base_updated_at.is_some_and(|base| base != current.updated_at) {
+                return Ok(MutationStatus::Conflict(ConflictReason::Modified, Some(current)));
+            }
+            todo_service::delete_todo(conn, user_id, id, audit).await?;
+            Ok(MutationStatus::Applied(None))
+        }
+    }
+}
+
+// Todo versi server; `None` bila todo sudah terhapus (ada tombstone).
+async fn current_todo(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+) -> Result<Option<TodoModel>, TodoError> {
+    match todo_service::get_todo(conn, user_id, todo_id).await {
+        Ok(todo) => Ok(Some(todo)),
+        Err(TodoError::NotFound) if repository::find_tombstone(conn, todo_id).await?.is_some() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// Memetakan error todo ke kode `rejected` per mutasi.
+fn rejection(todo_id: Uuid, err: TodoError) -> MutationStatus {
+    match err {
+        TodoError::Validation(message) => MutationStatus::Rejected("validation", message),
+        TodoError::NotFound => MutationStatus::Rejected("not_found", "todo not found".to_string()),
+        TodoError::Forbidden => {
+            MutationStatus::Rejected("forbidden", "insufficient permission for this todo".to_string())
+        }
+        TodoError::Blocked(_) => {
+            MutationStatus::Rejected("blocked", "todo is blocked by unfinished todos".to_string())
+        }
+        TodoError::Database(db_err) => {
+            error!(todo_id = %todo_id, error = %db_err, "sync mutation database operation failed");
+            MutationStatus::Rejected("internal", "unexpected database error".to_string())
+        }
+    }
+}
+
+// Modul tests untuk logic murni sinkronisasi.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_token_should_accept_issued_tokens_only() {
+        assert_eq!(parse_token(" 1024 ").ok(), Some(1024));
+        assert_eq!(parse_token("0").ok(), Some(0));
+        assert!(parse_token("-1").is_err());
+        assert!(parse_token("abc").is_err());
+        assert!(parse_token("").is_err());
+    }
+
+    #[test]
+    fn rejection_should_map_todo_errors_to_codes() {
+        let id = Uuid::new_v4();
+        let code = |err| match rejection(id, err) {
+            MutationStatus::Rejected(code, _) => code,
+            other => panic!("unexpected status {other:?}"),
+        };
+        assert_eq!(code(TodoError::Validation("bad".to_string())), "validation");
+        assert_eq!(code(TodoError::NotFound), "not_found");
+        assert_eq!(code(TodoError::Forbidden), "forbidden");
+        assert_eq!(code(TodoError::Blocked(vec![id])), "blocked");
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset};
+use sea_orm::prelude::Json;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::todo::dto::{Estimate, EstimateUnit, deserialize_nullable};
+
+use super::entity;
+
+/// Payload request untuk membuat template todo.
+///
+/// `title`, `desc`, dan teks checklist boleh memuat placeholder `{{nama}}`.
+#[derive(Debug, Deserialize)]
+pub struct CreateTemplateRequest {
+    pub name: String,
+    pub title: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+    #[serde(default)]
+    pub estimate: Option<Estimate>,
+    /// Teks item checklist sesuai urutan.
+    #[serde(default)]
+    pub checklist: Vec<String>,
+    /// Tenggat todo hasil instantiate, dalam menit setelah waktu instantiate.
+    #[serde(default)]
+    pub due_offset_minutes: Option<i32>,
+}
+
+/// Payload request untuk mengubah template; field yang tidak dikirim tidak diubah.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTemplateRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// `null` berarti deskripsi dihapus.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub desc: Option<Option<String>>,
+    /// `null` berarti estimasi dihapus.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub estimate: Option<Option<Estimate>>,
+    /// Checklist pengganti (seluruh item).
+    #[serde(default)]
+    pub checklist: Option<Vec<String>>,
+    /// `null` berarti todo hasil instantiate dibuat tanpa tenggat.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub due_offset_minutes: Option<Option<i32>>,
+}
+
+/// Payload request untuk membuat todo dari template.
+#[derive(Debug, Default, Deserialize)]
+pub struct InstantiateTemplateRequest {
+    /// Nilai untuk setiap placeholder `{{nama}}` di template.
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+    /// Project tujuan; harus berada di workspace yang sama dengan template.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+}
+
+/// Data template yang sudah tervalidasi untuk disimpan repository.
+#[derive(Debug, Clone)]
+pub struct TemplateFields {
+    pub name: String,
+    pub title: String,
+    pub desc: Option<String>,
+    pub estimate: Option<Estimate>,
+    pub checklist: Vec<String>,
+    pub due_offset_minutes: Option<i32>,
+}
+
+/// Bentuk data template yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct TemplateResponse {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub workspace_id: Option<Uuid>,
+    pub name: String,
+    pub title: String,
+    pub desc: Option<String>,
+    pub estimate: Option<Estimate>,
+    pub checklist: Vec<String>,
+    pub due_offset_minutes: Option<i32>,
+    /// Nama placeholder yang harus diisi saat instantiate, urut abjad.
+    pub variables: Vec<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl TemplateResponse {
+    /// Mapping model database + daftar placeholder ke response HTTP.
+    pub fn new(value: entity::Model, variables: Vec<String>) -> Self {
+        let estimate = template_estimate(&value);
+        let checklist = checklist_texts(&value.checklist);
+        Self {
+            id: value.id,
+            owner_id: value.owner_id,
+            workspace_id: value.workspace_id,
+            name: value.name,
+            title: value.title,
+            desc: value.desc,
+            estimate,
+            checklist,
+            due_offset_minutes: value.due_offset_minutes,
+            variables,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Membaca estimasi dari kolom template; `None` bila kosong atau satuan tidak dikenal.
+pub fn template_estimate(model: &entity::Model) -> Option<Estimate> {
+    let unit = EstimateUnit::parse(model.estimate_unit.as_deref()?)?;
+    Some(Estimate {
+        value: model.estimate_value?,
+        unit,
+    })
+}
+
+/// Membaca teks checklist dari kolom JSON; elemen yang bukan string diabaikan.
+pub fn checklist_texts(value: &Json) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
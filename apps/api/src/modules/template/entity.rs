@@ -0,0 +1,31 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `todo_templates`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[sea_orm(table_name = "todo_templates")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    /// `None` berarti template pribadi milik `owner_id`.
+    pub workspace_id: Option<Uuid>,
+    pub name: String,
+    pub title: String,
+    pub desc: Option<String>,
+    pub estimate_value: Option<i32>,
+    pub estimate_unit: Option<String>,
+    /// Array JSON berisi teks item checklist sesuai urutan.
+    pub checklist: Json,
+    /// Jarak tenggat todo hasil instantiate dari waktu instantiate, dalam menit.
+    pub due_offset_minutes: Option<i32>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use serde_json::json;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::checklist::dto::ChecklistItemResponse;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::todo::dto::{TodoExtras, TodoResponse};
+
+use super::dto::{CreateTemplateRequest, InstantiateTemplateRequest, TemplateResponse, UpdateTemplateRequest};
+use super::entity::Model;
+use super::service::{self, TemplateError};
+
+/// Registrasi route template todo, di-mount di bawah prefix `/templates`.
+///
+/// - `POST /` buat template di workspace aktif
+/// - `GET /` daftar template di workspace aktif
+/// - `GET /{id}` detail template
+/// - `PATCH /{id}` ubah template (pembuat atau pengelola workspace)
+/// - `DELETE /{id}` hapus template (pembuat atau pengelola workspace)
+/// - `POST /{id}/instantiate` buat todo dari template
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_template).get(list_templates))
+        .route(
+            "/{id}",
+            get(get_template).patch(update_template).delete(delete_template),
+        )
+        .route("/{id}/instantiate", post(instantiate_template))
+}
+
+/// POST `/templates`
+///
+/// Input: `CreateTemplateRequest`.
+/// Output: `201 Created` + `SuccessResponse<TemplateResponse>`.
+pub async fn create_template(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateTemplateRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<TemplateResponse>>), ApiError> {
+    let template = service::create_template(&state.db, user.id, user.workspace_id, payload)
+        .await
+        .map_err(map_template_error)?;
+    info!(template_id = %template.id, "template created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: to_response(template) })))
+}
+
+/// GET `/templates`
+/// Output: `200 OK` + daftar template di workspace aktif.
+pub async fn list_templates(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<TemplateResponse>>>, ApiError> {
+    let templates = service::list_templates(&state.db, user.id, user.workspace_id)
+        .await
+        .map_err(map_template_error)?;
+    info!(count = templates.len(), "templates listed");
+    Ok(Json(SuccessResponse {
+        data: templates.into_iter().map(to_response).collect(),
+    }))
+}
+
+/// GET `/templates/{id}`
+/// Output: `200 OK` bila bisa diakses, `404` bila tidak.
+pub async fn get_template(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<TemplateResponse>>, ApiError> {
+    let template = service::get_template(&state.db, user.id, template_id)
+        .await
+        .map_err(map_template_error)?;
+    info!(template_id = %template_id, "template detail fetched");
+    Ok(Json(SuccessResponse { data: to_response(template) }))
+}
+
+/// PATCH `/templates/{id}`
+///
+/// Input: `UpdateTemplateRequest`.
+/// Output: `200 OK` dengan data template terbaru.
+pub async fn update_template(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(template_id): Path<Uuid>,
+    Json(payload): Json<UpdateTemplateRequest>,
+) -> Result<Json<SuccessResponse<TemplateResponse>>, ApiError> {
+    let template = service::update_template(&state.db, user.id, template_id, payload)
+        .await
+        .map_err(map_template_error)?;
+    info!(template_id = %template_id, "template updated");
+    Ok(Json(SuccessResponse { data: to_response(template) }))
+}
+
+/// DELETE `/templates/{id}`
+/// Output: `204 No Content`.
+pub async fn delete_template(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_template(&state.db, user.id, template_id)
+        .await
+        .map_err(map_template_error)?;
+    info!(template_id = %template_id, "template deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST `/templates/{id}/instantiate`
+///
+/// Input: `InstantiateTemplateRequest`.
+/// Output: `201 Created` + todo baru beserta checklist-nya.
+pub async fn instantiate_template(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(template_id): Path<Uuid>,
+    Json(payload): Json<InstantiateTemplateRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<TodoResponse>>), ApiError> {
+    let audit = AuditContext::new(user.id, &state.history);
+    let (todo, items) = service::instantiate_template(&state.db, user.id, template_id, payload, &audit)
+        .await
+        .map_err(map_template_error)?;
+    info!(template_id = %template_id, todo_id = %todo.id, "template instantiated");
+    let extras = TodoExtras {
+        checklists: Some(HashMap::from([(
+            todo.id,
+            items.into_iter().map(ChecklistItemResponse::from).collect(),
+        )])),
+        ..TodoExtras::default()
+    };
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: TodoResponse::from(todo).with_extras(&extras),
+        }),
+    ))
+}
+
+// Response template beserta daftar placeholder-nya.
+fn to_response(template: Model) -> TemplateResponse {
+    let variables = service::template_variables(&template);
+    TemplateResponse::new(template, variables)
+}
+
+/// Mapping error domain template ke `ApiError`.
+fn map_template_error(err: TemplateError) -> ApiError {
+    match err {
+        TemplateError::Validation(message) => {
+            warn!(reason = %message, "template validation failed");
+            ApiError::bad_request(message)
+        }
+        TemplateError::NotFound => {
+            warn!("template not found");
+            ApiError::not_found("template not found")
+        }
+        TemplateError::Forbidden => {
+            warn!("template action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this template")
+        }
+        TemplateError::MissingVariables(names) => {
+            warn!(missing = ?names, "template variables missing");
+            ApiError::bad_request("missing template variables").with_details(json!({ "missing": names }))
+        }
+        TemplateError::Database(db_err) => {
+            error!(error = %db_err, "template database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint template terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+    use sea_orm::Database;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::modules::todo::repository as todo_repository;
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .nest("/templates", routes())
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn template_flow_should_instantiate_todo_with_checklist() {
+        let app = build_test_app().await;
+        let created = send(
+            &app,
+            "POST",
+            "/templates".to_string(),
+            Some(json!({
+                "name": "release",
+                "title": "Release {{version}}",
+                "desc": "Ship {{ version }} to {{env}}",
+                "estimate": { "value": 3, "unit": "points" },
+                "checklist": ["tag {{version}}", "publish notes"]
+            })),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let template = read_json(created).await;
+        assert_eq!(template["data"]["variables"], json!(["env", "version"]));
+        let template_id = template["data"]["id"].as_str().expect("template id must exist").to_string();
+
+        let missing = send(
+            &app,
+            "POST",
+            format!("/templates/{template_id}/instantiate"),
+            Some(json!({ "variables": { "version": "1.4.0" } })),
+        )
+        .await;
+        assert_eq!(missing.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(read_json(missing).await["error"]["details"]["missing"], json!(["env"]));
+
+        let instantiated = send(
+            &app,
+            "POST",
+            format!("/templates/{template_id}/instantiate"),
+            Some(json!({ "variables": { "version": "1.4.0", "env": "prod" } })),
+        )
+        .await;
+        assert_eq!(instantiated.status(), StatusCode::CREATED);
+        let todo = read_json(instantiated).await;
+        assert_eq!(todo["data"]["title"], "Release 1.4.0");
+        assert_eq!(todo["data"]["desc"], "Ship 1.4.0 to prod");
+        assert_eq!(todo["data"]["estimate"], json!({ "value": 3, "unit": "points" }));
+        assert_eq!(todo["data"]["checklist"][0]["text"], "tag 1.4.0");
+        assert_eq!(todo["data"]["checklist"][1]["position"], 1);
+
+        let updated = read_json(
+            send(
+                &app,
+                "PATCH",
+                format!("/templates/{template_id}"),
+                Some(json!({ "desc": null, "checklist": [] })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(updated["data"]["variables"], json!(["version"]));
+        assert_eq!(updated["data"]["desc"], Value::Null);
+
+        let deleted = send(&app, "DELETE", format!("/templates/{template_id}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+        let gone = send(&app, "GET", format!("/templates/{template_id}"), None).await;
+        assert_eq!(gone.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn instantiate_should_resolve_due_offset_from_instantiation_time() {
+        let app = build_test_app().await;
+        let template = read_json(
+            send(
+                &app,
+                "POST",
+                "/templates".to_string(),
+                Some(json!({ "name": "weekly report", "title": "Weekly report", "due_offset_minutes": 2 * 24 * 60 })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(template["data"]["due_offset_minutes"], 2 * 24 * 60);
+        let template_id = template["data"]["id"].as_str().expect("template id must exist").to_string();
+
+        let before = Utc::now();
+        let todo = read_json(send(&app, "POST", format!("/templates/{template_id}/instantiate"), Some(json!({}))).await).await;
+        let after = Utc::now();
+        let due_at: DateTime<FixedOffset> =
+            serde_json::from_value(todo["data"]["due_at"].clone()).expect("due_at must be a timestamp");
+        let offset = TimeDelta::days(2);
+        assert!(due_at >= before + offset - TimeDelta::seconds(1) && due_at <= after + offset);
+
+        let cleared = read_json(
+            send(
+                &app,
+                "PATCH",
+                format!("/templates/{template_id}"),
+                Some(json!({ "due_offset_minutes": null })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(cleared["data"]["due_offset_minutes"], Value::Null);
+        let todo = read_json(send(&app, "POST", format!("/templates/{template_id}/instantiate"), Some(json!({}))).await).await;
+        assert_eq!(todo["data"]["due_at"], Value::Null);
+
+        let invalid = send(
+            &app,
+            "PATCH",
+            format!("/templates/{template_id}"),
+            Some(json!({ "due_offset_minutes": -5 })),
+        )
+        .await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    }
+}
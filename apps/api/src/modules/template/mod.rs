@@ -0,0 +1,7 @@
+//! Modul template todo: DTO, entity, repository, service, dan handler HTTP.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,79 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+    prelude::Json,
+};
+use uuid::Uuid;
+
+use super::dto::TemplateFields;
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan template baru milik `owner_id`, opsional di dalam workspace.
+pub async fn create_template(
+    conn: &DatabaseConnection,
+    owner_id: Uuid,
+    workspace_id: Option<Uuid>,
+    fields: TemplateFields,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        owner_id: Set(owner_id),
+        workspace_id: Set(workspace_id),
+        name: Set(fields.name),
+        title: Set(fields.title),
+        desc: Set(fields.desc),
+        estimate_value: Set(fields.estimate.map(|estimate| estimate.value)),
+        estimate_unit: Set(fields.estimate.map(|estimate| estimate.unit.as_str().to_string())),
+        checklist: Set(Json::from(fields.checklist)),
+        due_offset_minutes: Set(fields.due_offset_minutes),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil satu template berdasarkan id tanpa filter akses.
+pub async fn find_template_by_id(conn: &DatabaseConnection, template_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(template_id).one(conn).await
+}
+
+/// Mengambil template di workspace aktif, atau template pribadi milik user bila
+/// `workspace_id` bernilai `None`; urut nama.
+pub async fn list_templates(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Vec<Model>, DbErr> {
+    let query = match workspace_id {
+        Some(workspace_id) => Entity::find().filter(Column::WorkspaceId.eq(workspace_id)),
+        None => Entity::find()
+            .filter(Column::WorkspaceId.is_null())
+            .filter(Column::OwnerId.eq(user_id)),
+    };
+    query
+        .order_by(Column::Name, Order::Asc)
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Menimpa seluruh isi template dengan `fields`.
+pub async fn update_template(conn: &DatabaseConnection, existing: Model, fields: TemplateFields) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = existing.into();
+    active.name = Set(fields.name);
+    active.title = Set(fields.title);
+    active.desc = Set(fields.desc);
+    active.estimate_value = Set(fields.estimate.map(|estimate| estimate.value));
+    active.estimate_unit = Set(fields.estimate.map(|estimate| estimate.unit.as_str().to_string()));
+    active.checklist = Set(Json::from(fields.checklist));
+    active.due_offset_minutes = Set(fields.due_offset_minutes);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus template berdasarkan id.
+pub async fn delete_template(conn: &DatabaseConnection, template_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(template_id).exec(conn).await?;
+    Ok(())
+}
@@ -0,0 +1,421 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use chrono::{TimeDelta, Utc};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::checklist::entity::Model as ChecklistItem;
+use crate::modules::checklist::repository as checklist_repository;
+use crate::modules::checklist::service::{self as checklist_service, ChecklistError};
+use crate::modules::history::dto::AuditContext;
+use crate::modules::outbox::dto::TodoEvent;
+use crate::modules::outbox::service as outbox_service;
+use crate::modules::todo::dto::CreateTodoRequest;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service::{self as todo_service, TodoError};
+use crate::modules::workspace::service as workspace_service;
+
+use super::dto::{
+    CreateTemplateRequest, InstantiateTemplateRequest, TemplateFields, UpdateTemplateRequest, checklist_texts,
+    template_estimate,
+};
+use super::entity::Model;
+use super::repository;
+
+const MAX_NAME_LEN: usize = 100;
+/// Sama dengan batas jumlah item checklist per todo.
+const MAX_CHECKLIST_ITEMS: usize = 200;
+/// Tenggat relatif paling jauh: satu tahun.
+const MAX_DUE_OFFSET_MINUTES: i32 = 366 * 24 * 60;
+
+/// Error domain template yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum TemplateError {
+    Validation(String),
+    /// Template tidak ada atau tidak terlihat oleh user.
+    NotFound,
+    Forbidden,
+    /// Placeholder yang belum diberi nilai saat instantiate, urut abjad.
+    MissingVariables(Vec<String>),
+    Database(DbErr),
+}
+
+impl From<DbErr> for TemplateError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for TemplateError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<TodoError> for TemplateError {
+    fn from(value: TodoError) -> Self {
+        match value {
+            TodoError::Validation(message) => Self::Validation(message),
+            TodoError::NotFound => Self::NotFound,
+            TodoError::Forbidden => Self::Forbidden,
+            TodoError::Blocked(_) => Self::Validation("todo is blocked".to_string()),
+            TodoError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+impl From<ChecklistError> for TemplateError {
+    fn from(value: ChecklistError) -> Self {
+        match value {
+            ChecklistError::Validation(message) => Self::Validation(format!("checklist {message}")),
+            ChecklistError::TodoNotFound | ChecklistError::NotFound => Self::NotFound,
+            ChecklistError::Forbidden => Self::Forbidden,
+            ChecklistError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Membuat template di workspace aktif (`None` = template pribadi).
+/// Membuat template di workspace butuh `workspace.write`.
+pub async fn create_template(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    request: CreateTemplateRequest,
+) -> Result<Model, TemplateError> {
+    let fields = validate_fields(TemplateFields {
+        name: request.name,
+        title: request.title,
+        desc: request.desc,
+        estimate: request.estimate,
+        checklist: request.checklist,
+        due_offset_minutes: request.due_offset_minutes,
+    })?;
+    if let Some(workspace_id) = workspace_id {
+        workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceWrite).await?;
+    }
+    repository::create_template(conn, user_id, workspace_id, fields)
+        .await
+        .map_err(TemplateError::from)
+}
+
+/// Mengambil template di workspace aktif (butuh `workspace.read`), atau template pribadi
+/// milik user bila tidak ada workspace aktif.
+pub async fn list_templates(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Vec<Model>, TemplateError> {
+    if let Some(workspace_id) = workspace_id {
+        workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceRead).await?;
+    }
+    repository::list_templates(conn, user_id, workspace_id)
+        .await
+        .map_err(TemplateError::from)
+}
+
+/// Mengambil detail template yang bisa dilihat user.
+pub async fn get_template(conn: &DatabaseConnection, user_id: Uuid, template_id: Uuid) -> Result<Model, TemplateError> {
+    find_readable_template(conn, user_id, template_id).await
+}
+
+/// Mengubah template; hanya pembuatnya atau pengelola workspace (`workspace.manage`).
+pub async fn update_template(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    template_id: Uuid,
+    request: UpdateTemplateRequest,
+) -> Result<Model, TemplateError> {
+    let existing = find_manageable_template(conn, user_id, template_id).await?;
+    let fields = validate_fields(TemplateFields {
+        name: request.name.unwrap_or_else(|| existing.name.clone()),
+        title: request.title.unwrap_or_else(|| existing.title.clone()),
+        desc: request.desc.unwrap_or_else(|| existing.desc.clone()),
+        estimate: request.estimate.unwrap_or_else(|| template_estimate(&existing)),
+        checklist: request
+            .checklist
+            .unwrap_or_else(|| checklist_texts(&existing.checklist)),
+        due_offset_minutes: request.due_offset_minutes.unwrap_or(existing.due_offset_minutes),
+    })?;
+    repository::update_template(conn, existing, fields)
+        .await
+        .map_err(TemplateError::from)
+}
+
+/// Menghapus template; hanya pembuatnya atau pengelola workspace (`workspace.manage`).
+pub async fn delete_template(conn: &DatabaseConnection, user_id: Uuid, template_id: Uuid) -> Result<(), TemplateError> {
+    let existing = find_manageable_template(conn, user_id, template_id).await?;
+    repository::delete_template(conn, existing.id)
+        .await
+        .map_err(TemplateError::from)
+}
+
+/// Membuat todo beserta checklist-nya dari template dalam satu transaksi.
+///
+/// Seluruh placeholder harus diberi nilai lewat `variables`. Todo dibuat di workspace
+/// template (template pribadi menghasilkan todo pribadi) dengan aturan akses yang sama
+/// seperti `POST /todos`; `due_at` diisi waktu instantiate + `due_offset_minutes` bila ada.
+pub async fn instantiate_template(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    template_id: Uuid,
+    request: InstantiateTemplateRequest,
+    audit: &AuditContext,
+) -> Result<(Todo, Vec<ChecklistItem>), TemplateError> {
+    let template = find_readable_template(conn, user_id, template_id).await?;
+    let missing: Vec<String> = template_variables(&template)
+        .into_iter()
+        .filter(|name| !request.variables.contains_key(name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(TemplateError::MissingVariables(missing));
+    }
+
+    let variables = &request.variables;
+    let checklist = checklist_texts(&template.checklist)
+        .iter()
+        .map(|text| checklist_service::normalize_text(substitute(text, variables)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let todo_request = CreateTodoRequest {
+        title: substitute(&template.title, variables),
+        desc: template.desc.as_deref().map(|desc| substitute(desc, variables)),
+        project_id: request.project_id,
+        assignee_id: None,
+        estimate: template_estimate(&template),
+        due_at: template
+            .due_offset_minutes
+            .map(|minutes| Utc::now().fixed_offset() + TimeDelta::minutes(i64::from(minutes))),
+    };
+    let new_todo = todo_service::prepare_new_todo(conn, user_id, template.workspace_id, todo_request).await?;
+
+    let txn = conn.begin().await?;
+    let todo = todo_repository::create_todo(&txn, new_todo, audit).await?;
+    let items = checklist_repository::insert_items(&txn, todo.id, checklist).await?;
+    outbox_service::record_todo_events(&txn, &[TodoEvent::Created], &todo, user_id, None).await?;
+    txn.commit().await?;
+    Ok((todo, items))
+}
+
+/// Seluruh nama placeholder di title, desc, dan checklist template, unik dan urut abjad.
+pub fn template_variables(template: &Model) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    let checklist = checklist_texts(&template.checklist);
+    let texts = [Some(template.title.as_str()), template.desc.as_deref()]
+        .into_iter()
+        .flatten()
+        .chain(checklist.iter().map(String::as_str));
+    for text in texts {
+        names.extend(extract_variables(text));
+    }
+    names.into_iter().collect()
+}
+
+/// Nama placeholder `{{nama}}` di `text`, unik dan urut abjad.
+/// Isi kurung yang bukan nama valid (`[A-Za-z_][A-Za-z0-9_]*`) dibiarkan sebagai teks biasa.
+pub fn extract_variables(text: &str) -> Vec<String> {
+    placeholders(text)
+        .into_iter()
+        .map(|(_, name)| name.to_string())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Mengganti setiap placeholder dengan nilainya; placeholder tanpa nilai dibiarkan apa adanya.
+pub fn substitute(text: &str, variables: &HashMap<String, String>) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for (range, name) in placeholders(text) {
+        if let Some(value) = variables.get(name) {
+            rendered.push_str(&text[cursor..range.start]);
+            rendered.push_str(value);
+            cursor = range.end;
+        }
+    }
+    rendered.push_str(&text[cursor..]);
+    rendered
+}
+
+/// Normalisasi dan validasi isi template memakai aturan yang sama dengan todo dan checklist.
+pub fn validate_fields(fields: TemplateFields) -> Result<TemplateFields, TemplateError> {
+    let name = fields.name.trim();
+    if name.is_empty() {
+        return Err(TemplateError::Validation("name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(TemplateError::Validation(format!(
+            "name must be at most {MAX_NAME_LEN} characters"
+        )));
+    }
+    if fields
+        .due_offset_minutes
+        .is_some_and(|minutes| !(0..=MAX_DUE_OFFSET_MINUTES).contains(&minutes))
+    {
+        return Err(TemplateError::Validation(format!(
+            "due_offset_minutes must be between 0 and {MAX_DUE_OFFSET_MINUTES}"
+        )));
+    }
+    if fields.checklist.len() > MAX_CHECKLIST_ITEMS {
+        return Err(TemplateError::Validation(format!(
+            "checklist must have at most {MAX_CHECKLIST_ITEMS} items"
+        )));
+    }
+    let checklist = fields
+        .checklist
+        .into_iter()
+        .map(checklist_service::normalize_text)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TemplateFields {
+        name: name.to_string(),
+        title: todo_service::normalize_title(fields.title)?,
+        desc: todo_service::normalize_desc(fields.desc)?,
+        estimate: todo_service::validate_estimate(fields.estimate)?,
+        checklist,
+        due_offset_minutes: fields.due_offset_minutes,
+    })
+}
+
+// Posisi dan nama setiap placeholder valid di `text`, urut kemunculan.
+fn placeholders(text: &str) -> Vec<(Range<usize>, &str)> {
+    let mut found = Vec::new();
+    let mut cursor = 0;
+    while let Some(offset) = text[cursor..].find("{{") {
+        let start = cursor + offset;
+        let inner_start = start + 2;
+        let Some(close) = text[inner_start..].find("}}") else {
+            break;
+        };
+        let inner_end = inner_start + close;
+        let name = text[inner_start..inner_end].trim();
+        if is_variable_name(name) {
+            found.push((start..inner_end + 2, name));
+            cursor = inner_end + 2;
+        } else {
+            // Geser satu karakter agar `{{{nama}}}` tetap dikenali.
+            cursor = start + 1;
+        }
+    }
+    found
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Template pribadi hanya terlihat oleh pembuatnya; template workspace butuh `workspace.read`.
+async fn find_readable_template(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    template_id: Uuid,
+) -> Result<Model, TemplateError> {
+    let template = repository::find_template_by_id(conn, template_id)
+        .await?
+        .ok_or(TemplateError::NotFound)?;
+    match template.workspace_id {
+        Some(workspace_id) => {
+            workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceRead).await?;
+        }
+        None if template.owner_id != user_id => return Err(TemplateError::NotFound),
+        None => {}
+    }
+    Ok(template)
+}
+
+// Selain pembuatnya, template workspace hanya bisa diubah oleh role dengan `workspace.manage`.
+async fn find_manageable_template(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    template_id: Uuid,
+) -> Result<Model, TemplateError> {
+    let template = find_readable_template(conn, user_id, template_id).await?;
+    if template.owner_id != user_id
+        && let Some(workspace_id) = template.workspace_id
+    {
+        workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceManage).await?;
+    }
+    Ok(template)
+}
+
+// Modul tests untuk unit testing placeholder dan validasi template.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn extract_variables_should_return_sorted_unique_names() {
+        assert_eq!(
+            extract_variables("Release {{ version }} to {{env}} ({{version}})"),
+            vec!["env".to_string(), "version".to_string()]
+        );
+        assert!(extract_variables("no {{1bad}} {{with space}} {{}} {{open").is_empty());
+    }
+
+    #[test]
+    fn substitute_should_replace_known_and_keep_unknown_placeholders() {
+        let values = vars(&[("version", "1.2.0"), ("env", "prod")]);
+        assert_eq!(substitute("Release {{version}} to {{ env }}", &values), "Release 1.2.0 to prod");
+        assert_eq!(substitute("{{other}} {{version}}", &values), "{{other}} 1.2.0");
+        assert_eq!(substitute("{{{version}}}", &values), "{1.2.0}");
+        assert_eq!(substitute("{{ version", &values), "{{ version");
+    }
+
+    #[test]
+    fn substitute_should_not_expand_placeholders_inside_values() {
+        let values = vars(&[("a", "{{b}}"), ("b", "x")]);
+        assert_eq!(substitute("{{a}}", &values), "{{b}}");
+    }
+
+    #[test]
+    fn validate_fields_should_normalize_and_reject_invalid_input() {
+        let fields = validate_fields(TemplateFields {
+            name: "  release ".to_string(),
+            title: " Release {{version}} ".to_string(),
+            desc: None,
+            estimate: None,
+            checklist: vec![" tag ".to_string()],
+            due_offset_minutes: Some(90),
+        })
+        .expect("fields must be valid");
+        assert_eq!(fields.name, "release");
+        assert_eq!(fields.checklist, vec!["tag".to_string()]);
+        assert_eq!(fields.due_offset_minutes, Some(90));
+
+        let empty_item = validate_fields(TemplateFields {
+            name: "release".to_string(),
+            title: "Release".to_string(),
+            desc: None,
+            estimate: None,
+            checklist: vec!["  ".to_string()],
+            due_offset_minutes: None,
+        });
+        assert!(matches!(empty_item, Err(TemplateError::Validation(_))));
+
+        for offset in [-1, MAX_DUE_OFFSET_MINUTES + 1] {
+            let out_of_range = validate_fields(TemplateFields {
+                name: "release".to_string(),
+                title: "Release".to_string(),
+                desc: None,
+                estimate: None,
+                checklist: Vec::new(),
+                due_offset_minutes: Some(offset),
+            });
+            assert!(matches!(out_of_range, Err(TemplateError::Validation(_))), "{offset}");
+        }
+    }
+}
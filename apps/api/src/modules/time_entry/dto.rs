@@ -0,0 +1,110 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::todo::dto::deserialize_nullable;
+
+use super::entity;
+
+/// Payload request untuk mencatat waktu secara manual.
+#[derive(Debug, Deserialize)]
+pub struct CreateTimeEntryRequest {
+    pub started_at: DateTime<FixedOffset>,
+    pub stopped_at: DateTime<FixedOffset>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// Payload request untuk mengubah catatan waktu.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTimeEntryRequest {
+    /// Waktu mulai baru; `None` berarti tidak diubah.
+    #[serde(default)]
+    pub started_at: Option<DateTime<FixedOffset>>,
+    /// Waktu selesai baru; mengisinya pada timer yang berjalan sekaligus menghentikannya.
+    #[serde(default)]
+    pub stopped_at: Option<DateTime<FixedOffset>>,
+    /// Catatan baru; field tidak dikirim berarti tidak diubah, `null` berarti dikosongkan.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub note: Option<Option<String>>,
+}
+
+/// Perubahan catatan waktu hasil validasi sebelum dikirim ke repository.
+#[derive(Debug, Clone)]
+pub struct TimeEntryChanges {
+    pub started_at: DateTime<FixedOffset>,
+    pub stopped_at: Option<DateTime<FixedOffset>>,
+    pub note: Option<String>,
+}
+
+/// Query string untuk total waktu.
+#[derive(Debug, Default, Deserialize)]
+pub struct TimeTotalsQuery {
+    /// Awal rentang (inklusif); catatan yang melewati batas dihitung sebagian.
+    #[serde(default)]
+    pub from: Option<DateTime<FixedOffset>>,
+    /// Akhir rentang (eksklusif).
+    #[serde(default)]
+    pub to: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub todo_id: Option<Uuid>,
+}
+
+/// Total durasi satu todo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TodoTimeTotal {
+    pub todo_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub total_secs: i64,
+}
+
+/// Total durasi satu project; `project_id` null untuk todo tanpa project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectTimeTotal {
+    pub project_id: Option<Uuid>,
+    pub total_secs: i64,
+}
+
+/// Ringkasan total waktu yang dikirim kembali ke client.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct TimeTotalsResponse {
+    pub total_secs: i64,
+    pub todos: Vec<TodoTimeTotal>,
+    pub projects: Vec<ProjectTimeTotal>,
+}
+
+/// Bentuk data catatan waktu yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct TimeEntryResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub stopped_at: Option<DateTime<FixedOffset>>,
+    /// Durasi dalam detik; `null` selama timer masih berjalan.
+    pub duration_secs: Option<i64>,
+    pub note: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::Model> for TimeEntryResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            user_id: value.user_id,
+            started_at: value.started_at,
+            stopped_at: value.stopped_at,
+            duration_secs: value
+                .stopped_at
+                .map(|stopped_at| (stopped_at - value.started_at).num_seconds()),
+            note: value.note,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
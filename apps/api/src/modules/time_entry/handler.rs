@@ -0,0 +1,352 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, patch, post},
+};
+use serde_json::json;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{
+    CreateTimeEntryRequest, TimeEntryResponse, TimeTotalsQuery, TimeTotalsResponse, UpdateTimeEntryRequest,
+};
+use super::service::{self, TimeEntryError};
+
+/// Registrasi route time tracking, di-mount di bawah prefix `/todos`.
+///
+/// - `POST /{id}/timer/start` mulai timer user pada todo
+/// - `POST /{id}/timer/stop` hentikan timer user pada todo
+/// - `GET /{id}/time-entries` daftar catatan waktu todo
+/// - `POST /{id}/time-entries` catat waktu manual
+/// - `PATCH /{id}/time-entries/{entry_id}` ubah catatan waktu sendiri
+/// - `DELETE /{id}/time-entries/{entry_id}` hapus catatan waktu sendiri
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/timer/start", post(start_timer))
+        .route("/{id}/timer/stop", post(stop_timer))
+        .route("/{id}/time-entries", get(list_entries).post(create_entry))
+        .route("/{id}/time-entries/{entry_id}", patch(update_entry).delete(delete_entry))
+}
+
+/// Route tanpa prefix resource:
+/// - `GET /timer` timer user yang sedang berjalan (atau `null`)
+/// - `GET /time-entries/totals` total waktu per todo dan project
+pub fn user_routes() -> Router<AppState> {
+    Router::new()
+        .route("/timer", get(get_running_timer))
+        .route("/time-entries/totals", get(get_time_totals))
+}
+
+/// POST `/todos/{id}/timer/start`
+/// Output: `201 Created` + `SuccessResponse<TimeEntryResponse>`,
+/// `409` bila user masih punya timer berjalan.
+pub async fn start_timer(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<SuccessResponse<TimeEntryResponse>>), ApiError> {
+    let entry = service::start_timer(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, entry_id = %entry.id, "timer started");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: entry.into() })))
+}
+
+/// POST `/todos/{id}/timer/stop`
+/// Output: `200 OK` + catatan waktu yang dihentikan, `404` bila tidak ada timer berjalan pada todo.
+pub async fn stop_timer(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<TimeEntryResponse>>, ApiError> {
+    let entry = service::stop_timer(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, entry_id = %entry.id, "timer stopped");
+    Ok(Json(SuccessResponse { data: entry.into() }))
+}
+
+/// GET `/todos/{id}/time-entries`
+/// Output: `200 OK` + daftar catatan waktu urut waktu mulai terbaru.
+pub async fn list_entries(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<TimeEntryResponse>>>, ApiError> {
+    let entries = service::list_entries(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, count = entries.len(), "time entries listed");
+    Ok(Json(SuccessResponse {
+        data: entries.into_iter().map(TimeEntryResponse::from).collect(),
+    }))
+}
+
+/// POST `/todos/{id}/time-entries`
+///
+/// Input: `CreateTimeEntryRequest`.
+/// Output: `201 Created` + `SuccessResponse<TimeEntryResponse>`.
+pub async fn create_entry(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(payload): Json<CreateTimeEntryRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<TimeEntryResponse>>), ApiError> {
+    let entry = service::create_entry(&state.db, user.id, todo_id, payload)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, entry_id = %entry.id, "time entry created");
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data: entry.into() })))
+}
+
+/// PATCH `/todos/{id}/time-entries/{entry_id}`
+///
+/// Input: `UpdateTimeEntryRequest`.
+/// Output: `200 OK` + catatan waktu terbaru, `403` bila bukan milik user.
+pub async fn update_entry(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, entry_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateTimeEntryRequest>,
+) -> Result<Json<SuccessResponse<TimeEntryResponse>>, ApiError> {
+    let entry = service::update_entry(&state.db, user.id, todo_id, entry_id, payload)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, entry_id = %entry_id, "time entry updated");
+    Ok(Json(SuccessResponse { data: entry.into() }))
+}
+
+/// DELETE `/todos/{id}/time-entries/{entry_id}`
+/// Output: `204 No Content`.
+pub async fn delete_entry(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((todo_id, entry_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_entry(&state.db, user.id, todo_id, entry_id)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(todo_id = %todo_id, entry_id = %entry_id, "time entry deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET `/timer`
+/// Output: `200 OK` + timer yang sedang berjalan, `data: null` bila tidak ada.
+pub async fn get_running_timer(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Option<TimeEntryResponse>>>, ApiError> {
+    let entry = service::running_timer(&state.db, user.id)
+        .await
+        .map_err(map_time_entry_error)?;
+    Ok(Json(SuccessResponse {
+        data: entry.map(TimeEntryResponse::from),
+    }))
+}
+
+/// GET `/time-entries/totals`
+/// Query: `from`, `to` (RFC 3339), `project_id`, `todo_id` opsional.
+/// Output: `200 OK` + `TimeTotalsResponse` untuk workspace aktif.
+pub async fn get_time_totals(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<TimeTotalsQuery>,
+) -> Result<Json<SuccessResponse<TimeTotalsResponse>>, ApiError> {
+    let totals = service::time_totals(&state.db, user.id, user.workspace_id, query)
+        .await
+        .map_err(map_time_entry_error)?;
+    info!(total_secs = totals.total_secs, todo_count = totals.todos.len(), "time totals computed");
+    Ok(Json(SuccessResponse { data: totals }))
+}
+
+/// Mapping error domain time tracking ke `ApiError`.
+fn map_time_entry_error(err: TimeEntryError) -> ApiError {
+    match err {
+        TimeEntryError::Validation(message) => {
+            warn!(reason = %message, "time entry validation failed");
+            ApiError::bad_request(message)
+        }
+        TimeEntryError::TodoNotFound => {
+            warn!("todo not found");
+            ApiError::not_found("todo not found")
+        }
+        TimeEntryError::ProjectNotFound => {
+            warn!("project not found");
+            ApiError::not_found("project not found")
+        }
+        TimeEntryError::NotFound => {
+            warn!("time entry not found");
+            ApiError::not_found("time entry not found")
+        }
+        TimeEntryError::TodoForbidden => {
+            warn!("time tracking rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        TimeEntryError::Forbidden => {
+            warn!("time entry change rejected for non-owner");
+            ApiError::forbidden("only the owner can modify this time entry")
+        }
+        TimeEntryError::TimerRunning { entry_id, todo_id } => {
+            warn!(entry_id = %entry_id, todo_id = %todo_id, "timer already running");
+            ApiError::conflict("a timer is already running")
+                .with_details(json!({ "running_entry_id": entry_id, "todo_id": todo_id }))
+        }
+        TimeEntryError::Database(db_err) => {
+            error!(error = %db_err, "time entry database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint time tracking terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::Database;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    async fn build_test_app() -> Router {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let default_user_id = env::var("DEFAULT_USER_ID")
+            .ok()
+            .and_then(|v| Uuid::parse_str(&v).ok())
+            .unwrap_or_else(|| Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("valid uuid"));
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, default_user_id)
+            .await
+            .expect("default user seed must succeed");
+        Router::new()
+            .merge(user_routes())
+            .nest("/todos", todo_handler::routes().merge(routes()))
+            .with_state(AppState::for_tests(db, default_user_id))
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    async fn create_todo(app: &Router, title: &str) -> String {
+        let todo = read_json(send(app, "POST", "/todos".to_string(), Some(json!({ "title": title }))).await).await;
+        todo["data"]["id"].as_str().expect("todo id must exist").to_string()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn timer_flow_should_allow_one_running_timer_and_sum_totals() {
+        let app = build_test_app().await;
+        let first = create_todo(&app, "write report").await;
+        let second = create_todo(&app, "review report").await;
+
+        // Sisa timer dari test sebelumnya dihentikan dulu.
+        let current = read_json(send(&app, "GET", "/timer".to_string(), None).await).await;
+        if let Some(todo_id) = current["data"]["todo_id"].as_str() {
+            send(&app, "POST", format!("/todos/{todo_id}/timer/stop"), None).await;
+        }
+
+        let started = send(&app, "POST", format!("/todos/{first}/timer/start"), None).await;
+        assert_eq!(started.status(), StatusCode::CREATED);
+        let started = read_json(started).await;
+        assert!(started["data"]["duration_secs"].is_null());
+
+        let again = send(&app, "POST", format!("/todos/{second}/timer/start"), None).await;
+        assert_eq!(again.status(), StatusCode::CONFLICT);
+        let again = read_json(again).await;
+        assert_eq!(again["error"]["details"]["running_entry_id"], started["data"]["id"]);
+
+        let wrong_todo = send(&app, "POST", format!("/todos/{second}/timer/stop"), None).await;
+        assert_eq!(wrong_todo.status(), StatusCode::NOT_FOUND);
+        let stopped = read_json(send(&app, "POST", format!("/todos/{first}/timer/stop"), None).await).await;
+        assert!(stopped["data"]["stopped_at"].is_string());
+        let current = read_json(send(&app, "GET", "/timer".to_string(), None).await).await;
+        assert!(current["data"].is_null());
+
+        let invalid = send(
+            &app,
+            "POST",
+            format!("/todos/{second}/time-entries"),
+            Some(json!({ "started_at": "2026-01-05T10:00:00Z", "stopped_at": "2026-01-05T09:00:00Z" })),
+        )
+        .await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+        let manual = send(
+            &app,
+            "POST",
+            format!("/todos/{second}/time-entries"),
+            Some(json!({
+                "started_at": "2026-01-05T09:00:00Z",
+                "stopped_at": "2026-01-05T10:30:00Z",
+                "note": "  pairing  "
+            })),
+        )
+        .await;
+        assert_eq!(manual.status(), StatusCode::CREATED);
+        let manual = read_json(manual).await;
+        assert_eq!(manual["data"]["duration_secs"], 5400);
+        assert_eq!(manual["data"]["note"], "pairing");
+        let entry_id = manual["data"]["id"].as_str().expect("entry id must exist");
+
+        let updated = read_json(
+            send(
+                &app,
+                "PATCH",
+                format!("/todos/{second}/time-entries/{entry_id}"),
+                Some(json!({ "stopped_at": "2026-01-05T10:00:00Z" })),
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(updated["data"]["duration_secs"], 3600);
+
+        let totals = read_json(
+            send(
+                &app,
+                "GET",
+                format!("/time-entries/totals?todo_id={second}&from=2026-01-05T09:30:00Z&to=2026-01-06T00:00:00Z"),
+                None,
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(totals["data"]["total_secs"], 1800);
+        assert_eq!(totals["data"]["todos"][0]["todo_id"], second.as_str());
+
+        let deleted = send(&app, "DELETE", format!("/todos/{second}/time-entries/{entry_id}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+        let list = read_json(send(&app, "GET", format!("/todos/{second}/time-entries"), None).await).await;
+        assert_eq!(list["data"], json!([]));
+    }
+}
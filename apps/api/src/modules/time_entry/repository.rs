@@ -0,0 +1,106 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, Order,
+    QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use super::dto::TimeEntryChanges;
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+
+/// Menyimpan catatan waktu baru; `stopped_at` kosong berarti timer berjalan.
+pub async fn create_entry(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    user_id: Uuid,
+    entry: TimeEntryChanges,
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    ActiveModel {
+        id: Set(Uuid::new_v4()),
+        todo_id: Set(todo_id),
+        user_id: Set(user_id),
+        started_at: Set(entry.started_at),
+        stopped_at: Set(entry.stopped_at),
+        note: Set(entry.note),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(conn)
+    .await
+}
+
+/// Mengambil timer user yang masih berjalan (paling banyak satu).
+pub async fn find_running_entry(conn: &DatabaseConnection, user_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::StoppedAt.is_null())
+        .one(conn)
+        .await
+}
+
+/// Mengambil satu catatan waktu berdasarkan id dalam scope todo tertentu.
+pub async fn find_entry(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    entry_id: Uuid,
+) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(entry_id)
+        .filter(Column::TodoId.eq(todo_id))
+        .one(conn)
+        .await
+}
+
+/// Mengambil seluruh catatan waktu satu todo, urut waktu mulai terbaru.
+pub async fn list_entries(conn: &DatabaseConnection, todo_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::TodoId.eq(todo_id))
+        .order_by(Column::StartedAt, Order::Desc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil catatan waktu milik banyak todo yang beririsan dengan rentang `[from, to)`.
+/// Batas yang kosong berarti tidak dibatasi.
+pub async fn list_entries_in_range(
+    conn: &DatabaseConnection,
+    todo_ids: &[Uuid],
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Result<Vec<Model>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut query = Entity::find().filter(Column::TodoId.is_in(todo_ids.iter().copied()));
+    if let Some(from) = from {
+        query = query.filter(
+            Condition::any()
+                .add(Column::StoppedAt.is_null())
+                .add(Column::StoppedAt.gt(from)),
+        );
+    }
+    if let Some(to) = to {
+        query = query.filter(Column::StartedAt.lt(to));
+    }
+    query.all(conn).await
+}
+
+/// Menyimpan perubahan catatan waktu dan memperbarui `updated_at`.
+pub async fn update_entry(
+    conn: &DatabaseConnection,
+    existing: Model,
+    changes: TimeEntryChanges,
+) -> Result<Model, DbErr> {
+    let mut active: entity::ActiveModel = existing.into();
+    active.started_at = Set(changes.started_at);
+    active.stopped_at = Set(changes.stopped_at);
+    active.note = Set(changes.note);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus catatan waktu berdasarkan id.
+pub async fn delete_entry(conn: &DatabaseConnection, entry_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(entry_id).exec(conn).await?;
+    Ok(())
+}
@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{DatabaseConnection, DbErr, SqlErr};
+use uuid::Uuid;
+
+use crate::common::authz::{AccessError, Permission};
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::todo::dto::TodoFilter;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+
+use super::dto::{
+    CreateTimeEntryRequest, ProjectTimeTotal, TimeEntryChanges, TimeTotalsQuery, TimeTotalsResponse,
+    TodoTimeTotal, UpdateTimeEntryRequest,
+};
+use super::entity::Model;
+use super::repository;
+
+const MAX_NOTE_LEN: usize = 500;
+
+/// Error domain time tracking yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum TimeEntryError {
+    Validation(String),
+    TodoNotFound,
+    /// Project pada filter total tidak ditemukan atau tidak bisa diakses.
+    ProjectNotFound,
+    NotFound,
+    /// Role user pada todo kurang untuk mencatat waktu.
+    TodoForbidden,
+    /// Hanya pemilik catatan waktu yang boleh mengubah atau menghapusnya.
+    Forbidden,
+    /// User masih punya timer berjalan pada todo lain atau todo yang sama.
+    TimerRunning { entry_id: Uuid, todo_id: Uuid },
+    Database(DbErr),
+}
+
+impl From<DbErr> for TimeEntryError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for TimeEntryError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::TodoNotFound,
+            AccessError::Forbidden => Self::TodoForbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Memulai timer user pada todo; butuh `todo.write`.
+/// Satu user hanya boleh punya satu timer berjalan di seluruh todo.
+pub async fn start_timer(conn: &DatabaseConnection, user_id: Uuid, todo_id: Uuid) -> Result<Model, TimeEntryError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
+    if let Some(running) = repository::find_running_entry(conn, user_id).await? {
+        return Err(running_error(&running));
+    }
+    let entry = TimeEntryChanges {
+        started_at: Utc::now().fixed_offset(),
+        stopped_at: None,
+        note: None,
+    };
+    match repository::create_entry(conn, todo_id, user_id, entry).await {
+        Ok(entry) => Ok(entry),
+        // Dua request start bersamaan: unique index timer berjalan menolak yang kalah.
+        Err(err) if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+            match repository::find_running_entry(conn, user_id).await? {
+                Some(running) => Err(running_error(&running)),
+                None => Err(err.into()),
+            }
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Menghentikan timer user yang berjalan pada todo.
+/// Tidak butuh role pada todo agar timer tetap bisa dihentikan setelah akses dicabut.
+pub async fn stop_timer(conn: &DatabaseConnection, user_id: Uuid, todo_id: Uuid) -> Result<Model, TimeEntryError> {
+    let running = repository::find_running_entry(conn, user_id)
+        .await?
+        .filter(|entry| entry.todo_id == todo_id)
+        .ok_or(TimeEntryError::NotFound)?;
+    let changes = TimeEntryChanges {
+        started_at: running.started_at,
+        stopped_at: Some(Utc::now().fixed_offset().max(running.started_at)),
+        note: running.note.clone(),
+    };
+    repository::update_entry(conn, running, changes)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Timer user yang sedang berjalan, bila ada.
+pub async fn running_timer(conn: &DatabaseConnection, user_id: Uuid) -> Result<Option<Model>, TimeEntryError> {
+    repository::find_running_entry(conn, user_id)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Mencatat waktu secara manual; butuh `todo.write`.
+pub async fn create_entry(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    request: CreateTimeEntryRequest,
+) -> Result<Model, TimeEntryError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
+    let entry = TimeEntryChanges {
+        started_at: request.started_at,
+        stopped_at: Some(request.stopped_at),
+        note: normalize_note(request.note)?,
+    };
+    validate_range(&entry)?;
+    repository::create_entry(conn, todo_id, user_id, entry)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Mengambil catatan waktu satu todo dari seluruh user; butuh `todo.read`.
+pub async fn list_entries(conn: &DatabaseConnection, user_id: Uuid, todo_id: Uuid) -> Result<Vec<Model>, TimeEntryError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    repository::list_entries(conn, todo_id)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Mengubah catatan waktu milik user sendiri.
+pub async fn update_entry(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    entry_id: Uuid,
+    request: UpdateTimeEntryRequest,
+) -> Result<Model, TimeEntryError> {
+    let existing = find_own_entry(conn, user_id, todo_id, entry_id).await?;
+    let changes = apply_update(&existing, request)?;
+    repository::update_entry(conn, existing, changes)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Menghapus catatan waktu milik user sendiri.
+pub async fn delete_entry(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    entry_id: Uuid,
+) -> Result<(), TimeEntryError> {
+    let existing = find_own_entry(conn, user_id, todo_id, entry_id).await?;
+    repository::delete_entry(conn, existing.id)
+        .await
+        .map_err(TimeEntryError::from)
+}
+
+/// Total waktu tercatat (seluruh user) per todo dan per project dalam rentang waktu,
+/// untuk todo yang bisa diakses user di workspace aktif. Timer yang masih berjalan
+/// dihitung sampai sekarang.
+pub async fn time_totals(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    query: TimeTotalsQuery,
+) -> Result<TimeTotalsResponse, TimeEntryError> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from >= to
+    {
+        return Err(TimeEntryError::Validation("from must be before to".to_string()));
+    }
+    if let Some(project_id) = query.project_id {
+        match sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectRead).await {
+            Ok(_) => {}
+            Err(AccessError::Database(err)) => return Err(err.into()),
+            Err(_) => return Err(TimeEntryError::ProjectNotFound),
+        }
+    }
+    let todos = match query.todo_id {
+        Some(todo_id) => {
+            let todo = sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+            if query.project_id.is_some_and(|project_id| todo.project_id != Some(project_id)) {
+                Vec::new()
+            } else {
+                vec![todo]
+            }
+        }
+        None => {
+            let filter = TodoFilter {
+                workspace_id,
+                project_id: query.project_id,
+                assignee: None,
+            };
+            todo_repository::list_accessible_todos(conn, user_id, filter).await?
+        }
+    };
+    let todo_ids: Vec<Uuid> = todos.iter().map(|todo| todo.id).collect();
+    let entries = repository::list_entries_in_range(conn, &todo_ids, query.from, query.to).await?;
+    Ok(summarize(&entries, &todos, query.from, query.to, Utc::now().fixed_offset()))
+}
+
+/// Menjumlahkan durasi catatan waktu per todo dan per project.
+/// Durasi dipotong ke rentang `[from, to)`; timer berjalan dihitung sampai `now`.
+/// Hasil diurutkan dari total terbesar.
+pub fn summarize(
+    entries: &[Model],
+    todos: &[Todo],
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+    now: DateTime<FixedOffset>,
+) -> TimeTotalsResponse {
+    let mut per_todo: HashMap<Uuid, i64> = HashMap::new();
+    for entry in entries {
+        let start = from.map_or(entry.started_at, |from| entry.started_at.max(from));
+        let stop = entry.stopped_at.unwrap_or(now);
+        let end = to.map_or(stop, |to| stop.min(to));
+        let secs = (end - start).num_seconds();
+        if secs > 0 {
+            *per_todo.entry(entry.todo_id).or_default() += secs;
+        }
+    }
+
+    let mut todo_totals: Vec<TodoTimeTotal> = todos
+        .iter()
+        .filter_map(|todo| {
+            let total_secs = *per_todo.get(&todo.id)?;
+            Some(TodoTimeTotal {
+                todo_id: todo.id,
+                project_id: todo.project_id,
+                total_secs,
+            })
+        })
+        .collect();
+    todo_totals.sort_by(|a, b| b.total_secs.cmp(&a.total_secs).then(a.todo_id.cmp(&b.todo_id)));
+
+    let mut per_project: HashMap<Option<Uuid>, i64> = HashMap::new();
+    for total in &todo_totals {
+        *per_project.entry(total.project_id).or_default() += total.total_secs;
+    }
+    let mut project_totals: Vec<ProjectTimeTotal> = per_project
+        .into_iter()
+        .map(|(project_id, total_secs)| ProjectTimeTotal { project_id, total_secs })
+        .collect();
+    project_totals.sort_by(|a, b| b.total_secs.cmp(&a.total_secs).then(a.project_id.cmp(&b.project_id)));
+
+    TimeTotalsResponse {
+        total_secs: todo_totals.iter().map(|total| total.total_secs).sum(),
+        todos: todo_totals,
+        projects: project_totals,
+    }
+}
+
+/// Normalisasi catatan: trim, kosong dianggap tidak ada, maksimal 500 karakter.
+pub fn normalize_note(note: Option<String>) -> Result<Option<String>, TimeEntryError> {
+    let Some(note) = note.map(|note| note.trim().to_string()).filter(|note| !note.is_empty()) else {
+        return Ok(None);
+    };
+    if note.chars().count() > MAX_NOTE_LEN {
+        return Err(TimeEntryError::Validation(format!(
+            "note must be at most {MAX_NOTE_LEN} characters"
+        )));
+    }
+    Ok(Some(note))
+}
+
+/// Menggabungkan perubahan ke catatan waktu yang ada lalu memvalidasi rentangnya.
+pub fn apply_update(existing: &Model, request: UpdateTimeEntryRequest) -> Result<TimeEntryChanges, TimeEntryError> {
+    let changes = TimeEntryChanges {
+        started_at: request.started_at.unwrap_or(existing.started_at),
+        stopped_at: request.stopped_at.or(existing.stopped_at),
+        note: match request.note {
+            Some(note) => normalize_note(note)?,
+            None => existing.note.clone(),
+        },
+    };
+    validate_range(&changes)?;
+    Ok(changes)
+}
+
+// Error konflik yang menunjuk timer yang sedang berjalan.
+fn running_error(running: &Model) -> TimeEntryError {
+    TimeEntryError::TimerRunning {
+        entry_id: running.id,
+        todo_id: running.todo_id,
+    }
+}
+
+// Waktu selesai harus setelah waktu mulai, dan waktu mulai tidak boleh di masa depan.
+fn validate_range(entry: &TimeEntryChanges) -> Result<(), TimeEntryError> {
+    if entry.started_at > Utc::now().fixed_offset() {
+        return Err(TimeEntryError::Validation("started_at must not be in the future".to_string()));
+    }
+    if entry.stopped_at.is_some_and(|stopped_at| stopped_at <= entry.started_at) {
+        return Err(TimeEntryError::Validation("stopped_at must be after started_at".to_string()));
+    }
+    Ok(())
+}
+
+// Mengambil catatan waktu setelah memastikan user masih bisa melihat todo dan adalah pemiliknya.
+async fn find_own_entry(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    todo_id: Uuid,
+    entry_id: Uuid,
+) -> Result<Model, TimeEntryError> {
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead).await?;
+    let entry = repository::find_entry(conn, todo_id, entry_id)
+        .await?
+        .ok_or(TimeEntryError::NotFound)?;
+    if entry.user_id != user_id {
+        return Err(TimeEntryError::Forbidden);
+    }
+    Ok(entry)
+}
+
+// Modul tests untuk unit testing agregasi dan validasi catatan waktu.
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn sample_todo(project_id: Option<Uuid>) -> Todo {
+        let now = Utc::now().fixed_offset();
+        Todo {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            workspace_id: None,
+            project_id,
+            assignee_id: None,
+            title: "billable".to_string(),
+            desc: None,
+            iscompleted: false,
+            completed_at: None,
+            due_at: None,
+            estimate_value: None,
+            estimate_unit: None,
+            external_uid: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn sample_entry(todo_id: Uuid, started_at: DateTime<FixedOffset>, secs: Option<i64>) -> Model {
+        Model {
+            id: Uuid::new_v4(),
+            todo_id,
+            user_id: Uuid::new_v4(),
+            started_at,
+            stopped_at: secs.map(|secs| started_at + Duration::seconds(secs)),
+            note: None,
+            created_at: started_at,
+            updated_at: started_at,
+        }
+    }
+
+    #[test]
+    fn summarize_should_group_by_todo_and_project() {
+        let project_id = Uuid::new_v4();
+        let (first, second, loose) = (sample_todo(Some(project_id)), sample_todo(Some(project_id)), sample_todo(None));
+        let base = Utc::now().fixed_offset() - Duration::hours(5);
+        let entries = [
+            sample_entry(first.id, base, Some(600)),
+            sample_entry(first.id, base + Duration::hours(1), Some(300)),
+            sample_entry(second.id, base, Some(120)),
+            sample_entry(loose.id, base, Some(60)),
+        ];
+        let totals = summarize(&entries, &[first.clone(), second.clone(), loose.clone()], None, None, base);
+
+        assert_eq!(totals.total_secs, 1080);
+        assert_eq!(totals.todos[0].todo_id, first.id);
+        assert_eq!(totals.todos[0].total_secs, 900);
+        assert_eq!(
+            totals.projects,
+            vec![
+                ProjectTimeTotal { project_id: Some(project_id), total_secs: 1020 },
+                ProjectTimeTotal { project_id: None, total_secs: 60 },
+            ]
+        );
+    }
+
+    #[test]
+    fn summarize_should_clip_to_range_and_count_running_timers() {
+        let todo = sample_todo(None);
+        let base = Utc::now().fixed_offset() - Duration::hours(5);
+        let entries = [
+            // 10 menit, hanya 5 menit terakhir di dalam rentang.
+            sample_entry(todo.id, base - Duration::minutes(5), Some(600)),
+            // Timer berjalan sejak base + 1 jam; dihitung sampai `now` tetapi dipotong `to`.
+            sample_entry(todo.id, base + Duration::hours(1), None),
+        ];
+        let now = base + Duration::hours(3);
+        let totals = summarize(&entries, std::slice::from_ref(&todo), Some(base), Some(base + Duration::hours(2)), now);
+        assert_eq!(totals.total_secs, 300 + 3600);
+    }
+
+    #[test]
+    fn apply_update_should_validate_resulting_range() {
+        let base = Utc::now().fixed_offset() - Duration::hours(2);
+        let entry = sample_entry(Uuid::new_v4(), base, None);
+        let stop = UpdateTimeEntryRequest {
+            started_at: None,
+            stopped_at: Some(base + Duration::minutes(30)),
+            note: Some(Some("  pairing ".to_string())),
+        };
+        let changes = apply_update(&entry, stop).expect("update should be valid");
+        assert_eq!(changes.stopped_at, Some(base + Duration::minutes(30)));
+        assert_eq!(changes.note.as_deref(), Some("pairing"));
+
+        let backwards = UpdateTimeEntryRequest {
+            started_at: None,
+            stopped_at: Some(base - Duration::minutes(1)),
+            note: None,
+        };
+        assert!(matches!(apply_update(&entry, backwards), Err(TimeEntryError::Validation(_))));
+    }
+
+    #[test]
+    fn normalize_note_should_trim_and_limit_length() {
+        assert_eq!(normalize_note(Some("   ".to_string())).ok(), Some(None));
+        assert!(matches!(
+            normalize_note(Some("a".repeat(MAX_NOTE_LEN + 1))),
+            Err(TimeEntryError::Validation(_))
+        ));
+    }
+}
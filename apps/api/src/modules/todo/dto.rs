@@ -1,7 +1,11 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, FixedOffset};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use crate::modules::checklist::dto::{ChecklistItemResponse, ChecklistProgress};
+
 use super::entity;
 
 /// Payload request untuk membuat todo baru.
@@ -11,6 +15,18 @@ pub struct CreateTodoRequest {
     /// Deskripsi opsional; jika tidak dikirim akan bernilai `None`.
     #[serde(default)]
     pub desc: Option<String>,
+    /// Project tujuan; user harus minimal editor pada project tersebut.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    /// User yang ditugasi; harus punya minimal role editor pada todo.
+    #[serde(default)]
+    pub assignee_id: Option<Uuid>,
+    /// Estimasi usaha opsional.
+    #[serde(default)]
+    pub estimate: Option<Estimate>,
+    /// Tenggat waktu opsional.
+    #[serde(default)]
+    pub due_at: Option<DateTime<FixedOffset>>,
 }
 
 /// Payload request untuk update parsial todo.
@@ -25,6 +41,127 @@ pub struct UpdateTodoRequest {
     /// Status selesai; `None` berarti tidak diubah.
     #[serde(default)]
     pub iscompleted: Option<bool>,
+    /// Assignee baru; field tidak dikirim berarti tidak diubah, `null` berarti dilepas.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub assignee_id: Option<Option<Uuid>>,
+    /// Estimasi baru; field tidak dikirim berarti tidak diubah, `null` berarti dihapus.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub estimate: Option<Option<Estimate>>,
+    /// Tenggat baru; field tidak dikirim berarti tidak diubah, `null` berarti dihapus.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub due_at: Option<Option<DateTime<FixedOffset>>>,
+}
+
+/// Satuan estimasi usaha todo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EstimateUnit {
+    Minutes,
+    Points,
+}
+
+impl EstimateUnit {
+    /// Nilai yang disimpan di kolom `estimate_unit`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Minutes => "minutes",
+            Self::Points => "points",
+        }
+    }
+
+    /// Parsing nilai kolom `estimate_unit`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "minutes" => Some(Self::Minutes),
+            "points" => Some(Self::Points),
+            _ => None,
+        }
+    }
+}
+
+/// Estimasi usaha todo dalam menit atau story point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Estimate {
+    pub value: i32,
+    pub unit: EstimateUnit,
+}
+
+impl Estimate {
+    /// Membaca estimasi dari kolom todo; `None` bila kosong atau satuan tidak dikenal.
+    pub fn from_model(model: &entity::Model) -> Option<Self> {
+        let unit = EstimateUnit::parse(model.estimate_unit.as_deref()?)?;
+        Some(Self {
+            value: model.estimate_value?,
+            unit,
+        })
+    }
+}
+
+/// Query string untuk list/detail todo.
+#[derive(Debug, Default, Deserialize)]
+pub struct TodoQuery {
+    /// Data tambahan dipisah koma: `comment_count`, `checklist`, `checklist_progress`.
+    #[serde(default)]
+    pub include: Option<String>,
+    /// Filter todo dalam satu project.
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    /// Filter assignee: `me`, `none`, atau uuid user.
+    #[serde(default)]
+    pub assignee: Option<String>,
+}
+
+/// Filter assignee hasil parsing `?assignee=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssigneeFilter {
+    User(Uuid),
+    Unassigned,
+}
+
+/// Filter daftar todo yang sudah divalidasi.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TodoFilter {
+    /// Workspace aktif; `None` berarti ruang pribadi.
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub assignee: Option<AssigneeFilter>,
+}
+
+/// Data todo baru hasil normalisasi sebelum dikirim ke repository.
+#[derive(Debug, Clone)]
+pub struct NewTodo {
+    /// Id todo; acak, atau id buatan client saat sinkronisasi offline.
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub assignee_id: Option<Uuid>,
+    pub title: String,
+    pub desc: Option<String>,
+    pub estimate: Option<Estimate>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    /// Diisi bila todo dibuat langsung dalam status selesai (mis. hasil impor).
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    /// UID `VTODO` asal untuk todo hasil impor iCalendar.
+    pub external_uid: Option<String>,
+}
+
+/// Data tambahan yang diminta client lewat `?include=`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TodoIncludes {
+    pub comment_count: bool,
+    pub checklist: bool,
+    pub checklist_progress: bool,
+}
+
+/// Data tambahan hasil `TodoIncludes` yang sudah dimuat per todo.
+#[derive(Debug, Default)]
+pub struct TodoExtras {
+    pub comment_counts: Option<HashMap<Uuid, u64>>,
+    pub checklists: Option<HashMap<Uuid, Vec<ChecklistItemResponse>>>,
+    pub checklist_progress: Option<HashMap<Uuid, ChecklistProgress>>,
+    /// Todo yang masih punya pemblokir belum selesai; selalu dimuat.
+    pub blocked: HashSet<Uuid>,
 }
 
 /// Bentuk data todo yang dikirim kembali ke client.
@@ -32,17 +169,45 @@ pub struct UpdateTodoRequest {
 pub struct TodoResponse {
     pub id: Uuid,
     pub user_id: Uuid,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub assignee_id: Option<Uuid>,
     pub title: String,
     pub desc: Option<String>,
     pub iscompleted: bool,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub estimate: Option<Estimate>,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    /// `true` bila masih ada todo pemblokir yang belum selesai.
+    pub is_blocked: bool,
+    /// Hanya dikirim bila diminta lewat `?include=comment_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<u64>,
+    /// Hanya dikirim bila diminta lewat `?include=checklist`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<Vec<ChecklistItemResponse>>,
+    /// Hanya dikirim bila diminta lewat `?include=checklist_progress`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checklist_progress: Option<ChecklistProgress>,
 }
 
-/// Wrapper sukses untuk semua response API.
-#[derive(Debug, Serialize)]
-pub struct SuccessResponse<T> {
-    pub data: T,
+impl TodoResponse {
+    /// Melengkapi response dengan data tambahan yang diminta client.
+    pub fn with_extras(mut self, extras: &TodoExtras) -> Self {
+        if let Some(counts) = &extras.comment_counts {
+            self.comment_count = Some(counts.get(&self.id).copied().unwrap_or(0));
+        }
+        if let Some(checklists) = &extras.checklists {
+            self.checklist = Some(checklists.get(&self.id).cloned().unwrap_or_default());
+        }
+        if let Some(progress) = &extras.checklist_progress {
+            self.checklist_progress = Some(progress.get(&self.id).copied().unwrap_or_default());
+        }
+        self.is_blocked = extras.blocked.contains(&self.id);
+        self
+    }
 }
 
 /// Payload update hasil normalisasi sebelum dikirim ke repository.
@@ -51,6 +216,11 @@ pub struct UpdateTodoPayload {
     pub title: Option<String>,
     pub desc: Option<String>,
     pub iscompleted: Option<bool>,
+    pub assignee_id: Option<Option<Uuid>>,
+    pub estimate: Option<Option<Estimate>>,
+    pub due_at: Option<Option<DateTime<FixedOffset>>>,
+    /// Diisi service saat status selesai berganti; `Some(None)` mengosongkan.
+    pub completed_at: Option<Option<DateTime<FixedOffset>>>,
 }
 
 impl From<UpdateTodoRequest> for UpdateTodoPayload {
@@ -60,6 +230,10 @@ impl From<UpdateTodoRequest> for UpdateTodoPayload {
             title: value.title,
             desc: value.desc,
             iscompleted: value.iscompleted,
+            assignee_id: value.assignee_id,
+            estimate: value.estimate,
+            due_at: value.due_at,
+            completed_at: None,
         }
     }
 }
@@ -67,14 +241,35 @@ impl From<UpdateTodoRequest> for UpdateTodoPayload {
 impl From<entity::Model> for TodoResponse {
     /// Mapping model database ke response HTTP.
     fn from(value: entity::Model) -> Self {
+        let estimate = Estimate::from_model(&value);
         Self {
             id: value.id,
             user_id: value.user_id,
+            workspace_id: value.workspace_id,
+            project_id: value.project_id,
+            assignee_id: value.assignee_id,
             title: value.title,
             desc: value.desc,
             iscompleted: value.iscompleted,
+            completed_at: value.completed_at,
+            due_at: value.due_at,
+            estimate,
             created_at: value.created_at,
             updated_at: value.updated_at,
+            is_blocked: false,
+            comment_count: None,
+            checklist: None,
+            checklist_progress: None,
         }
     }
 }
+
+/// Membedakan field yang dikirim `null` (`Some(None)`) dari field yang tidak dikirim (`None`).
+/// Dipakai bersama `#[serde(default)]`.
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
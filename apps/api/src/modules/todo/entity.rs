@@ -1,15 +1,22 @@
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, FixedOffset};
 
 /// Entity SeaORM untuk tabel `todos`.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+/// Kolom `sync_txid` sengaja tidak dipetakan karena diisi trigger database (lihat `modules/sync`).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "todos")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub user_id: Uuid,
+    /// Workspace pemilik todo; `None` untuk todo di ruang pribadi.
+    pub workspace_id: Option<Uuid>,
+    /// Project induk; `None` untuk todo lepas.
+    pub project_id: Option<Uuid>,
+    /// User yang ditugasi mengerjakan todo; berbeda dari pemilik (`user_id`).
+    pub assignee_id: Option<Uuid>,
     pub title: String,
     /// `desc` dipakai sebagai nama kolom agar kompatibel dengan schema yang ada.
     #[sea_orm(column_name = "desc")]
@@ -17,6 +24,16 @@ pub struct Model {
     /// Tetap memakai nama kolom `iscompleted` agar sesuai kontrak data existing.
     #[sea_orm(column_name = "iscompleted")]
     pub iscompleted: bool,
+    /// Waktu todo terakhir ditandai selesai; `None` selama belum selesai.
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    /// Tenggat waktu opsional; dipakai juga sebagai acuan reminder relatif.
+    pub due_at: Option<DateTime<FixedOffset>>,
+    /// Besar estimasi usaha; selalu terisi bersama `estimate_unit`.
+    pub estimate_value: Option<i32>,
+    /// Satuan estimasi: `minutes` atau `points`.
+    pub estimate_unit: Option<String>,
+    /// UID `VTODO` asal todo hasil impor iCalendar; unik per pemilik.
+    pub external_uid: Option<String>,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
 }
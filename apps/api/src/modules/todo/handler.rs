@@ -1,16 +1,20 @@
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     routing::{get, post},
 };
+use serde_json::json;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
 use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::history::dto::AuditContext;
 
-use super::dto::{CreateTodoRequest, SuccessResponse, TodoResponse, UpdateTodoRequest};
+use super::dto::{CreateTodoRequest, TodoIncludes, TodoQuery, TodoResponse, UpdateTodoRequest};
 use super::service::{self, TodoError};
 
 /// Registrasi route todo.
@@ -26,15 +30,28 @@ pub fn routes() -> Router<AppState> {
         .route("/{id}", get(get_todo).patch(update_todo).delete(delete_todo))
 }
 
+/// Route tanpa prefix resource:
+/// - `GET /assigned-to-me` todo yang ditugaskan ke user saat ini, lintas pemilik
+pub fn user_routes() -> Router<AppState> {
+    Router::new().route("/assigned-to-me", get(list_assigned_todos))
+}
+
 /// POST `/todos`
 ///
 /// Input: `CreateTodoRequest`.
 /// Output: `201 Created` + `SuccessResponse<TodoResponse>`.
 pub async fn create_todo(
     State(state): State<AppState>,
+    user: CurrentUser,
     Json(payload): Json<CreateTodoRequest>,
 ) -> Result<(StatusCode, Json<SuccessResponse<TodoResponse>>), ApiError> {
-    let todo = service::create_todo(&state.db, state.default_user_id, payload)
+    let todo = service::create_todo(
+        &state.db,
+        user.id,
+        user.workspace_id,
+        payload,
+        &audit_context(&state, user),
+    )
         .await
         .map_err(map_todo_error)?;
     info!(todo_id = %todo.id, "todo created");
@@ -42,59 +59,123 @@ pub async fn create_todo(
 }
 
 /// GET `/todos`
-/// Output: `200 OK` + daftar todo milik user default.
+/// Query: `include` opsional (`comment_count`), `project_id` opsional.
+/// Output: `200 OK` + daftar todo milik/dibagikan ke user.
 pub async fn list_todos(
     State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<TodoQuery>,
 ) -> Result<Json<SuccessResponse<Vec<TodoResponse>>>, ApiError> {
-    let todos = service::list_todos(&state.db, state.default_user_id)
+    let includes = service::parse_includes(query.include.as_deref()).map_err(map_todo_error)?;
+    let filter = service::parse_filter(&query, user.id, user.workspace_id).map_err(map_todo_error)?;
+    let todos = service::list_todos(&state.db, user.id, filter)
+        .await
+        .map_err(map_todo_error)?;
+    let extras = service::load_extras(&state.db, &todos, includes)
         .await
         .map_err(map_todo_error)?;
     info!(count = todos.len(), "todos listed");
-    let data = todos.into_iter().map(TodoResponse::from).collect();
+    let data = todos
+        .into_iter()
+        .map(|todo| TodoResponse::from(todo).with_extras(&extras))
+        .collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// GET `/assigned-to-me`
+/// Query: `include` opsional (`comment_count`).
+/// Output: `200 OK` + array todo dengan `assignee_id` = user saat ini.
+pub async fn list_assigned_todos(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(query): Query<TodoQuery>,
+) -> Result<Json<SuccessResponse<Vec<TodoResponse>>>, ApiError> {
+    let includes = service::parse_includes(query.include.as_deref()).map_err(map_todo_error)?;
+    let todos = service::list_assigned_todos(&state.db, user.id, user.workspace_id)
+        .await
+        .map_err(map_todo_error)?;
+    let extras = service::load_extras(&state.db, &todos, includes)
+        .await
+        .map_err(map_todo_error)?;
+    info!(count = todos.len(), "assigned todos listed");
+    let data = todos
+        .into_iter()
+        .map(|todo| TodoResponse::from(todo).with_extras(&extras))
+        .collect();
     Ok(Json(SuccessResponse { data }))
 }
 
 /// GET `/todos/{id}`
+/// Query: `include` opsional (`comment_count`).
 /// Output: `200 OK` bila ditemukan, `404` bila tidak ada.
 pub async fn get_todo(
     State(state): State<AppState>,
+    user: CurrentUser,
     Path(todo_id): Path<Uuid>,
+    Query(query): Query<TodoQuery>,
 ) -> Result<Json<SuccessResponse<TodoResponse>>, ApiError> {
-    let todo = service::get_todo(&state.db, state.default_user_id, todo_id)
+    let includes = service::parse_includes(query.include.as_deref()).map_err(map_todo_error)?;
+    let todo = service::get_todo(&state.db, user.id, todo_id)
+        .await
+        .map_err(map_todo_error)?;
+    let extras = service::load_extras(&state.db, std::slice::from_ref(&todo), includes)
         .await
         .map_err(map_todo_error)?;
     info!(todo_id = %todo_id, "todo detail fetched");
-    Ok(Json(SuccessResponse { data: todo.into() }))
+    Ok(Json(SuccessResponse {
+        data: TodoResponse::from(todo).with_extras(&extras),
+    }))
 }
 
 /// PATCH `/todos/{id}`
 /// Input: `UpdateTodoRequest` (parsial).
-/// Output: `200 OK` dengan data todo terbaru.
+/// Output: `200 OK` dengan data todo terbaru, `409` bila todo ditandai selesai
+/// padahal pemblokirnya belum selesai (id pemblokir di `error.details.blocking_ids`).
 pub async fn update_todo(
     State(state): State<AppState>,
+    user: CurrentUser,
     Path(todo_id): Path<Uuid>,
     Json(payload): Json<UpdateTodoRequest>,
 ) -> Result<Json<SuccessResponse<TodoResponse>>, ApiError> {
-    let todo = service::update_todo(&state.db, state.default_user_id, todo_id, payload)
+    let todo = service::update_todo(
+        &state.db,
+        user.id,
+        todo_id,
+        payload,
+        &audit_context(&state, user),
+        &state.dependencies,
+    )
+        .await
+        .map_err(map_todo_error)?;
+    let extras = service::load_extras(&state.db, std::slice::from_ref(&todo), TodoIncludes::default())
         .await
         .map_err(map_todo_error)?;
     info!(todo_id = %todo_id, "todo updated");
-    Ok(Json(SuccessResponse { data: todo.into() }))
+    Ok(Json(SuccessResponse {
+        data: TodoResponse::from(todo).with_extras(&extras),
+    }))
 }
 
 /// DELETE `/todos/{id}`
 /// Output: `204 No Content` bila sukses, `404` bila id tidak ditemukan.
+/// Isi lampiran todo baru dihapus dari storage setelah masa undo habis.
 pub async fn delete_todo(
     State(state): State<AppState>,
+    user: CurrentUser,
     Path(todo_id): Path<Uuid>,
 ) -> Result<StatusCode, ApiError> {
-    service::delete_todo(&state.db, state.default_user_id, todo_id)
+    service::delete_todo(&state.db, user.id, todo_id, &audit_context(&state, user))
         .await
         .map_err(map_todo_error)?;
     info!(todo_id = %todo_id, "todo deleted");
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Konteks audit untuk mutasi dengan user saat ini sebagai pelaku.
+fn audit_context(state: &AppState, user: CurrentUser) -> AuditContext {
+    AuditContext::new(user.id, &state.history)
+}
+
 /// Mapping error domain todo ke `ApiError` agar response error konsisten.
 fn map_todo_error(err: TodoError) -> ApiError {
     match err {
@@ -106,6 +187,15 @@ fn map_todo_error(err: TodoError) -> ApiError {
             warn!("todo not found");
             ApiError::not_found("todo not found")
         }
+        TodoError::Forbidden => {
+            warn!("todo action rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        TodoError::Blocked(blocking_ids) => {
+            warn!(blocking = blocking_ids.len(), "todo completion rejected while blocked");
+            ApiError::conflict("todo is blocked by unfinished todos")
+                .with_details(json!({ "blocking_ids": blocking_ids }))
+        }
         TodoError::Database(db_err) => {
             error!(error = %db_err, "todo database operation failed");
             ApiError::internal("unexpected database error")
@@ -129,7 +219,9 @@ mod tests {
     use tower::ServiceExt;
     use uuid::Uuid;
 
-    use crate::{app_state::AppState, modules::todo::repository};
+    use crate::{
+        app_state::AppState, modules::todo::repository,
+    };
 
     use super::*;
 
@@ -148,7 +240,7 @@ mod tests {
             .expect("default user seed must succeed");
         Router::new()
             .nest("/todos", routes())
-            .with_state(AppState::new(db, default_user_id))
+            .with_state(AppState::for_tests(db, default_user_id))
     }
 
     async fn read_json(response: axum::response::Response) -> Value {
@@ -1,11 +1,19 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
-    QueryFilter, QueryOrder, Set, Statement, ConnectionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    QueryFilter, QueryOrder, QuerySelect, Set, Statement, ConnectionTrait, TransactionTrait,
+    sea_query::{Query, SelectStatement},
 };
 use uuid::Uuid;
 
-use super::dto::UpdateTodoPayload;
+use crate::modules::history::dto::{AuditContext, NewTodoEvent};
+use crate::modules::history::repository as history_repository;
+use crate::modules::project::entity as project_entity;
+use crate::modules::sharing::repository as sharing_repository;
+use crate::modules::trash::repository as trash_repository;
+use crate::modules::workspace::repository as workspace_repository;
+
+use super::dto::{AssigneeFilter, NewTodo, TodoFilter, UpdateTodoPayload};
 use super::entity::{self, ActiveModel, Column, Entity, Model};
 
 /// Menjamin user default tersedia untuk mode no-auth.
@@ -23,56 +31,189 @@ pub async fn ensure_default_user(conn: &DatabaseConnection, user_id: Uuid) -> Re
 }
 
 /// Menyimpan todo baru ke database untuk user tertentu.
-pub async fn create_todo(
-    conn: &DatabaseConnection,
-    user_id: Uuid,
-    title: String,
-    desc: Option<String>,
+/// Event `created` dicatat dalam transaksi yang sama (savepoint bila `conn` sudah berupa transaksi).
+pub async fn create_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    new_todo: NewTodo,
+    audit: &AuditContext,
 ) -> Result<Model, DbErr> {
     // `created_at` dan `updated_at` diset sama saat insert awal.
     let now = Utc::now().fixed_offset();
     let active = ActiveModel {
-        id: Set(Uuid::new_v4()),
-        user_id: Set(user_id),
-        title: Set(title),
-        desc: Set(desc),
-        iscompleted: Set(false),
+        id: Set(new_todo.id),
+        user_id: Set(new_todo.user_id),
+        workspace_id: Set(new_todo.workspace_id),
+        project_id: Set(new_todo.project_id),
+        assignee_id: Set(new_todo.assignee_id),
+        title: Set(new_todo.title),
+        desc: Set(new_todo.desc),
+        iscompleted: Set(new_todo.completed_at.is_some()),
+        completed_at: Set(new_todo.completed_at),
+        due_at: Set(new_todo.due_at),
+        estimate_value: Set(new_todo.estimate.map(|estimate| estimate.value)),
+        estimate_unit: Set(new_todo.estimate.map(|estimate| estimate.unit.as_str().to_string())),
+        external_uid: Set(new_todo.external_uid),
         created_at: Set(now),
         updated_at: Set(now),
     };
-    active.insert(conn).await
+
+    let txn = conn.begin().await?;
+    let created = active.insert(&txn).await?;
+    record_event(&txn, audit, None, Some(&created)).await?;
+    txn.commit().await?;
+    Ok(created)
 }
 
-/// Mengambil daftar todo milik user, urut terbaru lebih dulu.
-pub async fn list_todos(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
-    // Semua query dibatasi per user agar data antar user tidak tercampur.
-    Entity::find()
-        .filter(Column::UserId.eq(user_id))
+/// Mengambil daftar todo yang bisa diakses user, urut terbaru lebih dulu:
+/// milik sendiri, dibagikan langsung, berada di project milik/dibagikan ke user,
+/// atau berada di workspace tempat user menjadi member non-guest.
+/// Hasil selalu dibatasi pada workspace aktif `filter.workspace_id` (`None` = ruang pribadi),
+/// lalu opsional per project dan/atau assignee.
+pub async fn list_accessible_todos(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    filter: TodoFilter,
+) -> Result<Vec<Model>, DbErr> {
+    let mut query = Entity::find().filter(accessible_condition(user_id));
+    query = match filter.workspace_id {
+        Some(workspace_id) => query.filter(Column::WorkspaceId.eq(workspace_id)),
+        None => query.filter(Column::WorkspaceId.is_null()),
+    };
+    if let Some(project_id) = filter.project_id {
+        query = query.filter(Column::ProjectId.eq(project_id));
+    }
+    match filter.assignee {
+        Some(AssigneeFilter::User(assignee_id)) => query = query.filter(Column::AssigneeId.eq(assignee_id)),
+        Some(AssigneeFilter::Unassigned) => query = query.filter(Column::AssigneeId.is_null()),
+        None => {}
+    }
+    query
         .order_by(Column::CreatedAt, Order::Desc)
         .all(conn)
         .await
 }
 
-/// Mengambil satu todo berdasarkan `todo_id` dalam scope user tertentu.
-pub async fn find_todo_by_id(
-    conn: &DatabaseConnection,
+/// Kondisi todo yang bisa diakses user (lihat `list_accessible_todos`), tanpa batas workspace.
+pub fn accessible_condition(user_id: Uuid) -> Condition {
+    Condition::any()
+        .add(Column::UserId.eq(user_id))
+        .add(Column::Id.in_subquery(sharing_repository::shared_todo_ids_query(user_id)))
+        .add(Column::ProjectId.in_subquery(owned_project_ids_query(user_id)))
+        .add(Column::ProjectId.in_subquery(sharing_repository::shared_project_ids_query(user_id)))
+        .add(Column::WorkspaceId.in_subquery(workspace_repository::content_workspace_ids_query(user_id)))
+}
+
+/// Subquery id project milik user.
+pub fn owned_project_ids_query(user_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(project_entity::Column::Id)
+        .from(project_entity::Entity)
+        .and_where(project_entity::Column::OwnerId.eq(user_id))
+        .to_owned()
+}
+
+/// Mengambil satu todo berdasarkan `todo_id` tanpa filter akses.
+/// Pengecekan akses dilakukan di service (lihat `sharing::service::authorize_todo`).
+pub async fn find_todo_by_id<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(todo_id).one(conn).await
+}
+
+/// Todo milik user di workspace (`None` = ruang pribadi) dengan UID `VTODO` tertentu
+/// (dipakai impor iCalendar dan CalDAV).
+pub async fn find_todo_by_external_uid<C: ConnectionTrait>(
+    conn: &C,
     user_id: Uuid,
-    todo_id: Uuid,
+    workspace_id: Option<Uuid>,
+    external_uid: &str,
 ) -> Result<Option<Model>, DbErr> {
+    let workspace = match workspace_id {
+        Some(workspace_id) => Column::WorkspaceId.eq(workspace_id),
+        None => Column::WorkspaceId.is_null(),
+    };
     Entity::find()
         .filter(Column::UserId.eq(user_id))
-        .filter(Column::Id.eq(todo_id))
+        .filter(workspace)
+        .filter(Column::ExternalUid.eq(external_uid))
         .one(conn)
         .await
 }
 
+/// Todo di project berdasarkan nama resource CalDAV: UID eksternal, atau id todo
+/// untuk todo tanpa UID eksternal.
+pub async fn find_todo_by_resource_name<C: ConnectionTrait>(
+    conn: &C,
+    project_id: Uuid,
+    name: &str,
+) -> Result<Option<Model>, DbErr> {
+    let mut by_name = Condition::any().add(Column::ExternalUid.eq(name));
+    if let Ok(todo_id) = Uuid::parse_str(name) {
+        by_name = by_name.add(
+            Condition::all()
+                .add(Column::ExternalUid.is_null())
+                .add(Column::Id.eq(todo_id)),
+        );
+    }
+    Entity::find()
+        .filter(Column::ProjectId.eq(project_id))
+        .filter(by_name)
+        .one(conn)
+        .await
+}
+
+/// Id todo di workspace yang ditugaskan ke user (dipakai saat member dikeluarkan).
+pub async fn find_workspace_todo_ids_assigned_to<C: ConnectionTrait>(
+    conn: &C,
+    workspace_id: Uuid,
+    assignee_id: Uuid,
+) -> Result<Vec<Uuid>, DbErr> {
+    Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(Column::WorkspaceId.eq(workspace_id))
+        .filter(Column::AssigneeId.eq(assignee_id))
+        .into_tuple()
+        .all(conn)
+        .await
+}
+
+/// Mengambil todo berdasarkan daftar id tanpa filter akses.
+pub async fn find_todos_by_ids<C: ConnectionTrait>(conn: &C, todo_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    if todo_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Entity::find()
+        .filter(Column::Id.is_in(todo_ids.iter().copied()))
+        .order_by(Column::CreatedAt, Order::Desc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil seluruh todo dalam satu project tanpa filter akses, urut paling lama dibuat.
+pub async fn find_todos_by_project(conn: &DatabaseConnection, project_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::ProjectId.eq(project_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
 /// Menerapkan perubahan parsial ke todo yang sudah ada.
-pub async fn update_todo(
-    conn: &DatabaseConnection,
-    existing: Model,
+///
+/// Baris todo dikunci (`SELECT ... FOR UPDATE`) agar snapshot sebelum perubahan
+/// yang dicatat di history akurat walau ada request paralel.
+/// Mengembalikan `None` bila todo tidak ditemukan.
+pub async fn update_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    todo_id: Uuid,
     changes: UpdateTodoPayload,
-) -> Result<Model, DbErr> {
-    let mut active: entity::ActiveModel = existing.into();
+    audit: &AuditContext,
+) -> Result<Option<Model>, DbErr> {
+    let txn = conn.begin().await?;
+    let Some(existing) = find_todo_for_update(&txn, todo_id).await? else {
+        return Ok(None);
+    };
+
+    let mut active: entity::ActiveModel = existing.clone().into();
     if let Some(title) = changes.title {
         active.title = Set(title);
     }
@@ -82,21 +223,112 @@ pub async fn update_todo(
     if let Some(iscompleted) = changes.iscompleted {
         active.iscompleted = Set(iscompleted);
     }
+    if let Some(completed_at) = changes.completed_at {
+        active.completed_at = Set(completed_at);
+    }
+    if let Some(assignee_id) = changes.assignee_id {
+        active.assignee_id = Set(assignee_id);
+    }
+    if let Some(due_at) = changes.due_at {
+        active.due_at = Set(due_at);
+    }
+    if let Some(estimate) = changes.estimate {
+        active.estimate_value = Set(estimate.map(|estimate| estimate.value));
+        active.estimate_unit = Set(estimate.map(|estimate| estimate.unit.as_str().to_string()));
+    }
     active.updated_at = Set(Utc::now().fixed_offset());
-    active.update(conn).await
+    let updated = active.update(&txn).await?;
+
+    record_event(&txn, audit, Some(&existing), Some(&updated)).await?;
+    txn.commit().await?;
+    Ok(Some(updated))
 }
 
-/// Menghapus todo berdasarkan id dan user.
+/// Menghapus todo berdasarkan id, sekaligus mencatat event `deleted`.
+/// Data turunannya disalin ke tempat sampah dulu agar bisa dipulihkan lewat undo.
 /// Mengembalikan `true` bila ada baris terhapus, `false` bila todo tidak ditemukan.
-pub async fn delete_todo(
-    conn: &DatabaseConnection,
-    user_id: Uuid,
+pub async fn delete_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
     todo_id: Uuid,
+    audit: &AuditContext,
 ) -> Result<bool, DbErr> {
-    let result = Entity::delete_many()
-        .filter(Column::UserId.eq(user_id))
-        .filter(Column::Id.eq(todo_id))
-        .exec(conn)
+    let txn = conn.begin().await?;
+    let Some(existing) = find_todo_for_update(&txn, todo_id).await? else {
+        return Ok(false);
+    };
+
+    trash_repository::stash_children(&txn, existing.id).await?;
+    Entity::delete_many()
+        .filter(Column::Id.eq(existing.id))
+        .exec(&txn)
         .await?;
-    Ok(result.rows_affected > 0)
+
+    record_event(&txn, audit, Some(&existing), None).await?;
+    txn.commit().await?;
+    Ok(true)
+}
+
+/// Menulis ulang seluruh field todo dari snapshot hasil replay event (dipakai undo).
+/// `updated_at` tetap diperbarui karena undo adalah perubahan baru.
+/// Mengembalikan `None` bila todo tidak ditemukan.
+pub async fn replace_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    restored: Model,
+    audit: &AuditContext,
+) -> Result<Option<Model>, DbErr> {
+    let txn = conn.begin().await?;
+    let Some(existing) = find_todo_for_update(&txn, restored.id).await? else {
+        return Ok(None);
+    };
+
+    let mut active = ActiveModel::from(restored).reset_all();
+    active.updated_at = Set(Utc::now().fixed_offset());
+    let updated = active.update(&txn).await?;
+
+    record_event(&txn, audit, Some(&existing), Some(&updated)).await?;
+    txn.commit().await?;
+    Ok(Some(updated))
+}
+
+/// Memasukkan kembali todo yang terhapus dengan id dan timestamp aslinya (dipakai undo),
+/// beserta data turunannya yang masih ada di tempat sampah.
+pub async fn restore_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    snapshot: Model,
+    audit: &AuditContext,
+) -> Result<Model, DbErr> {
+    let active = ActiveModel::from(snapshot).reset_all();
+
+    let txn = conn.begin().await?;
+    let restored = active.insert(&txn).await?;
+    trash_repository::restore_children(&txn, restored.id).await?;
+    record_event(&txn, audit, None, Some(&restored)).await?;
+    txn.commit().await?;
+    Ok(restored)
+}
+
+/// Mengambil todo sambil mengunci barisnya sampai transaksi selesai.
+pub async fn find_todo_for_update<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(todo_id))
+        .lock_exclusive()
+        .one(conn)
+        .await
+}
+
+// Mencatat diff snapshot ke `todo_events`; dilewati bila tidak ada field yang berubah.
+// Bila mutasi adalah undo, event asal ditandai `undone_at` dalam transaksi yang sama.
+async fn record_event<C: ConnectionTrait>(
+    conn: &C,
+    audit: &AuditContext,
+    before: Option<&Model>,
+    after: Option<&Model>,
+) -> Result<(), DbErr> {
+    if let Some(event) = NewTodoEvent::from_snapshots(audit, before, after) {
+        history_repository::insert_event(conn, event).await?;
+    }
+    if let Some(reverted_id) = audit.reverts_event_id {
+        history_repository::mark_event_undone(conn, reverted_id).await?;
+    }
+    Ok(())
 }
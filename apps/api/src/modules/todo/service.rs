@@ -1,18 +1,43 @@
-use sea_orm::{DatabaseConnection, DbErr};
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
 use uuid::Uuid;
 
-use super::dto::{CreateTodoRequest, UpdateTodoPayload, UpdateTodoRequest};
+use crate::common::authz::{AccessError, Permission, Role};
+use crate::config::dependency::DependencySettings;
+use crate::modules::checklist::dto::ChecklistItemResponse;
+use crate::modules::checklist::service as checklist_service;
+use crate::modules::comment::service as comment_service;
+use crate::modules::dependency::service as dependency_service;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::outbox::dto::TodoEvent;
+use crate::modules::outbox::service as outbox_service;
+use crate::modules::sharing::dto::ShareRole;
+use crate::modules::sharing::service as sharing_service;
+use crate::modules::user::repository as user_repository;
+use crate::modules::workspace::service as workspace_service;
+
+use super::dto::{
+    AssigneeFilter, CreateTodoRequest, Estimate, NewTodo, TodoExtras, TodoFilter, TodoIncludes, TodoQuery,
+    UpdateTodoPayload, UpdateTodoRequest,
+};
 use super::entity::Model;
 use super::repository;
 
 const MAX_TITLE_LEN: usize = 200;
-const MAX_DESC_LEN: usize = 2000;
+/// Dipakai juga sebagai batas panjang komentar.
+pub const MAX_DESC_LEN: usize = 2000;
+/// Batas atas estimasi, baik dalam menit maupun story point.
+const MAX_ESTIMATE: i32 = 100_000;
 
 /// Error domain todo yang nantinya dipetakan ke HTTP error di layer handler.
 #[derive(Debug)]
 pub enum TodoError {
     Validation(String),
     NotFound,
+    /// User bisa melihat todo tetapi role-nya kurang untuk aksi ini.
+    Forbidden,
+    /// Todo belum bisa diselesaikan karena pemblokir (id terlampir) belum selesai.
+    Blocked(Vec<Uuid>),
     Database(DbErr),
 }
 
@@ -22,74 +47,377 @@ impl From<DbErr> for TodoError {
     }
 }
 
-/// Membuat todo baru setelah normalisasi title dan desc.
+impl From<AccessError> for TodoError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Membuat todo baru setelah normalisasi title dan desc, di workspace aktif (`None` = ruang pribadi).
+/// Bila `project_id` diisi, user butuh `project.write` pada project tersebut dan project harus
+/// berada di workspace yang sama; tanpa project, membuat todo di workspace butuh `workspace.write`.
+/// Assignee harus pembuat sendiri atau editor pada project/workspace tersebut.
+/// Event `todo.created` dicatat ke outbox dalam transaksi yang sama dengan insert.
 pub async fn create_todo(
     conn: &DatabaseConnection,
-    default_user_id: Uuid,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
     request: CreateTodoRequest,
+    audit: &AuditContext,
 ) -> Result<Model, TodoError> {
+    let new_todo = prepare_new_todo(conn, user_id, workspace_id, request).await?;
+    insert_todo(conn, user_id, new_todo, audit).await
+}
+
+/// Menyimpan todo hasil `prepare_new_todo` beserta event `todo.created` di outbox dalam satu transaksi.
+pub async fn insert_todo(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    new_todo: NewTodo,
+    audit: &AuditContext,
+) -> Result<Model, TodoError> {
+    let txn = conn.begin().await?;
+    let todo = repository::create_todo(&txn, new_todo, audit).await?;
+    outbox_service::record_todo_events(&txn, &[TodoEvent::Created], &todo, user_id, None).await?;
+    txn.commit().await?;
+    Ok(todo)
+}
+
+/// Validasi dan otorisasi pembuatan todo tanpa menyimpannya; dipakai `create_todo` dan
+/// pemanggil lain yang perlu menyisipkan todo di dalam transaksinya sendiri.
+pub async fn prepare_new_todo(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    request: CreateTodoRequest,
+) -> Result<NewTodo, TodoError> {
     let title = normalize_title(request.title)?;
     let desc = normalize_desc(request.desc)?;
+    let estimate = validate_estimate(request.estimate)?;
+    let project = match request.project_id {
+        Some(project_id) => {
+            match sharing_service::authorize_project(conn, user_id, project_id, Permission::ProjectWrite).await {
+                Ok((project, _)) => Some(project),
+                Err(AccessError::NotFound) => {
+                    return Err(TodoError::Validation("project_id does not exist".to_string()));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        None => None,
+    };
+    match &project {
+        Some(project) if project.workspace_id != workspace_id => {
+            return Err(TodoError::Validation(
+                "project_id belongs to a different workspace".to_string(),
+            ));
+        }
+        Some(_) => {}
+        None => {
+            if let Some(workspace_id) = workspace_id {
+                workspace_service::authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceWrite).await?;
+            }
+        }
+    }
+    if let Some(assignee_id) = request.assignee_id
+        && assignee_id != user_id
+    {
+        ensure_user_exists(conn, assignee_id).await?;
+        let role = match (&project, workspace_id) {
+            (Some(project), _) => sharing_service::project_role(conn, assignee_id, project).await?,
+            (None, Some(workspace_id)) => {
+                sharing_service::workspace_content_role(conn, assignee_id, workspace_id).await?
+            }
+            (None, None) => None,
+        };
+        ensure_assignable(role)?;
+    }
 
-    repository::create_todo(conn, default_user_id, title, desc)
-        .await
-        .map_err(TodoError::from)
+    Ok(NewTodo {
+        id: Uuid::new_v4(),
+        user_id,
+        workspace_id,
+        project_id: request.project_id,
+        assignee_id: request.assignee_id,
+        title,
+        desc,
+        estimate,
+        due_at: request.due_at,
+        completed_at: None,
+        external_uid: None,
+    })
 }
 
-/// Mengambil seluruh todo user default.
-pub async fn list_todos(conn: &DatabaseConnection, default_user_id: Uuid) -> Result<Vec<Model>, TodoError> {
-    repository::list_todos(conn, default_user_id)
+/// Mengambil seluruh todo yang bisa diakses user sesuai filter.
+pub async fn list_todos(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    filter: TodoFilter,
+) -> Result<Vec<Model>, TodoError> {
+    repository::list_accessible_todos(conn, user_id, filter)
         .await
         .map_err(TodoError::from)
 }
 
+/// Todo yang ditugaskan ke user di workspace aktif, lintas pemilik, selama user masih punya akses.
+pub async fn list_assigned_todos(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Vec<Model>, TodoError> {
+    let filter = TodoFilter {
+        workspace_id,
+        assignee: Some(AssigneeFilter::User(user_id)),
+        ..TodoFilter::default()
+    };
+    list_todos(conn, user_id, filter).await
+}
+
 /// Mengambil detail todo by id.
-/// Mengembalikan `NotFound` bila id tidak ada pada scope user.
+/// Mengembalikan `NotFound` bila id tidak ada atau tidak bisa diakses user.
 pub async fn get_todo(
     conn: &DatabaseConnection,
-    default_user_id: Uuid,
+    user_id: Uuid,
     todo_id: Uuid,
 ) -> Result<Model, TodoError> {
-    let todo = repository::find_todo_by_id(conn, default_user_id, todo_id)
+    sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoRead)
         .await
-        .map_err(TodoError::from)?;
-    todo.ok_or(TodoError::NotFound)
+        .map_err(TodoError::from)
 }
 
-/// Update parsial todo setelah validasi payload.
-pub async fn update_todo(
-    conn: &DatabaseConnection,
-    default_user_id: Uuid,
+/// Update parsial todo setelah validasi payload; butuh `todo.write`.
+/// Bila `dependencies.block_completion` aktif, todo dengan pemblokir yang belum selesai
+/// tidak bisa ditandai selesai. Status selesai dan pemblokir dibaca dari baris yang dikunci
+/// di dalam transaksi update. Bisa dipanggil di dalam transaksi pemanggil.
+pub async fn update_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    user_id: Uuid,
     todo_id: Uuid,
     request: UpdateTodoRequest,
+    audit: &AuditContext,
+    dependencies: &DependencySettings,
 ) -> Result<Model, TodoError> {
-    let existing = repository::find_todo_by_id(conn, default_user_id, todo_id)
-        .await
-        .map_err(TodoError::from)?
-        .ok_or(TodoError::NotFound)?;
+    let todo = sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoWrite).await?;
 
-    let changes = normalize_update_payload(request)?;
-    repository::update_todo(conn, existing, changes)
-        .await
-        .map_err(TodoError::from)
+    let mut changes = normalize_update_payload(request)?;
+    if let Some(Some(assignee_id)) = changes.assignee_id
+        && assignee_id != todo.user_id
+    {
+        ensure_user_exists(conn, assignee_id).await?;
+        ensure_assignable(sharing_service::todo_role(conn, assignee_id, &todo).await?)?;
+    }
+    let check_blockers = dependencies.block_completion && changes.iscompleted == Some(true);
+    let txn = conn.begin().await?;
+    // Graf dikunci sebelum baris todo, urutan yang sama dengan penambahan relasi (yang mengunci
+    // graf lalu menulis FK ke todo), agar keduanya tidak saling menunggu.
+    if check_blockers {
+        dependency_service::lock_graph(&txn, todo.workspace_id).await?;
+    }
+    let current = repository::find_todo_for_update(&txn, todo_id)
+        .await?
+        .ok_or(TodoError::NotFound)?;
+    if check_blockers && current.workspace_id != todo.workspace_id {
+        dependency_service::lock_graph(&txn, current.workspace_id).await?;
+    }
+    changes.completed_at = completion_change(current.iscompleted, changes.iscompleted, Utc::now().fixed_offset());
+    if check_blockers && !current.iscompleted {
+        let blocking_ids = dependency_service::open_blocker_ids(&txn, todo_id).await?;
+        if !blocking_ids.is_empty() {
+            return Err(TodoError::Blocked(blocking_ids));
+        }
+    }
+    let updated = repository::update_todo(&txn, todo_id, changes, audit)
+        .await?
+        .ok_or(TodoError::NotFound)?;
+    let events = outbox_service::update_events(&current, &updated);
+    outbox_service::record_todo_events(&txn, &events, &updated, user_id, current.assignee_id).await?;
+    txn.commit().await?;
+    Ok(updated)
 }
 
-/// Menghapus todo by id.
-/// Mengembalikan `NotFound` bila tidak ada data yang terhapus.
-pub async fn delete_todo(
-    conn: &DatabaseConnection,
-    default_user_id: Uuid,
+/// Menghapus todo by id; butuh `todo.delete`.
+/// Mengembalikan `NotFound` bila tidak ada data yang terhapus. Event `todo.deleted` membawa
+/// data todo terakhir sebelum dihapus. Bisa dipanggil di dalam transaksi pemanggil.
+pub async fn delete_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    user_id: Uuid,
     todo_id: Uuid,
+    audit: &AuditContext,
 ) -> Result<(), TodoError> {
-    let deleted = repository::delete_todo(conn, default_user_id, todo_id)
-        .await
-        .map_err(TodoError::from)?;
-    if !deleted {
+    let todo = sharing_service::authorize_todo(conn, user_id, todo_id, Permission::TodoDelete).await?;
+    let txn = conn.begin().await?;
+    if !repository::delete_todo(&txn, todo_id, audit).await? {
         return Err(TodoError::NotFound);
     }
+    outbox_service::record_todo_events(&txn, &[TodoEvent::Deleted], &todo, user_id, None).await?;
+    txn.commit().await?;
     Ok(())
 }
 
+/// Mengganti assignee todo tanpa cek izin (dipakai saat member dikeluarkan dari workspace).
+/// Bisa dipanggil di dalam transaksi pemanggil; event `todo.updated` dicatat ke outbox
+/// dalam transaksi yang sama. Mengembalikan `None` bila todo tidak ditemukan.
+pub async fn reassign_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    actor_id: Uuid,
+    todo_id: Uuid,
+    assignee_id: Option<Uuid>,
+    audit: &AuditContext,
+) -> Result<Option<Model>, DbErr> {
+    let txn = conn.begin().await?;
+    let Some(before) = repository::find_todo_for_update(&txn, todo_id).await? else {
+        return Ok(None);
+    };
+    let changes = UpdateTodoPayload {
+        title: None,
+        desc: None,
+        iscompleted: None,
+        assignee_id: Some(assignee_id),
+        estimate: None,
+        due_at: None,
+        completed_at: None,
+    };
+    let Some(updated) = repository::update_todo(&txn, todo_id, changes, audit).await? else {
+        return Ok(None);
+    };
+    let events = outbox_service::update_events(&before, &updated);
+    outbox_service::record_todo_events(&txn, &events, &updated, actor_id, before.assignee_id).await?;
+    txn.commit().await?;
+    Ok(Some(updated))
+}
+
+/// Menulis ulang todo dari snapshot hasil undo tanpa cek izin; pemanggil yang mengotorisasi.
+/// `current` adalah state todo sebelum undo dan menjadi dasar event outbox.
+/// Mengembalikan `None` bila todo tidak ditemukan.
+pub async fn replace_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    actor_id: Uuid,
+    current: &Model,
+    restored: Model,
+    audit: &AuditContext,
+) -> Result<Option<Model>, DbErr> {
+    let txn = conn.begin().await?;
+    let Some(updated) = repository::replace_todo(&txn, restored, audit).await? else {
+        return Ok(None);
+    };
+    let events = outbox_service::update_events(current, &updated);
+    outbox_service::record_todo_events(&txn, &events, &updated, actor_id, current.assignee_id).await?;
+    txn.commit().await?;
+    Ok(Some(updated))
+}
+
+/// Memasukkan kembali todo yang terhapus (undo) tanpa cek izin; event `todo.created`
+/// dicatat ke outbox dalam transaksi yang sama.
+pub async fn restore_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    actor_id: Uuid,
+    snapshot: Model,
+    audit: &AuditContext,
+) -> Result<Model, DbErr> {
+    let txn = conn.begin().await?;
+    let restored = repository::restore_todo(&txn, snapshot, audit).await?;
+    outbox_service::record_todo_events(&txn, &[TodoEvent::Created], &restored, actor_id, None).await?;
+    txn.commit().await?;
+    Ok(restored)
+}
+
+/// Menghapus todo (undo `created`) tanpa cek izin; event `todo.deleted` dicatat ke outbox
+/// dalam transaksi yang sama. Mengembalikan `false` bila todo tidak ditemukan.
+pub async fn remove_todo<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    actor_id: Uuid,
+    todo: &Model,
+    audit: &AuditContext,
+) -> Result<bool, DbErr> {
+    let txn = conn.begin().await?;
+    if !repository::delete_todo(&txn, todo.id, audit).await? {
+        return Ok(false);
+    }
+    outbox_service::record_todo_events(&txn, &[TodoEvent::Deleted], todo, actor_id, None).await?;
+    txn.commit().await?;
+    Ok(true)
+}
+
+/// Memuat data tambahan (`?include=`) serta status blokir untuk sekumpulan todo sekaligus.
+pub async fn load_extras(
+    conn: &DatabaseConnection,
+    todos: &[Model],
+    includes: TodoIncludes,
+) -> Result<TodoExtras, TodoError> {
+    let todo_ids: Vec<Uuid> = todos.iter().map(|todo| todo.id).collect();
+    let comment_counts = if includes.comment_count {
+        Some(comment_service::count_comments(conn, &todo_ids).await?)
+    } else {
+        None
+    };
+    let checklists = if includes.checklist {
+        let loaded = checklist_service::load_checklists(conn, &todo_ids).await?;
+        Some(
+            loaded
+                .into_iter()
+                .map(|(todo_id, items)| (todo_id, items.into_iter().map(ChecklistItemResponse::from).collect()))
+                .collect(),
+        )
+    } else {
+        None
+    };
+    let checklist_progress = if includes.checklist_progress {
+        Some(checklist_service::load_progress(conn, &todo_ids).await?)
+    } else {
+        None
+    };
+    let blocked = dependency_service::blocked_todo_ids(conn, &todo_ids).await?;
+    Ok(TodoExtras {
+        comment_counts,
+        checklists,
+        checklist_progress,
+        blocked,
+    })
+}
+
+/// Parsing query list todo menjadi `TodoFilter` di workspace aktif;
+/// `?assignee=me` diterjemahkan ke user saat ini.
+pub fn parse_filter(
+    query: &TodoQuery,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<TodoFilter, TodoError> {
+    let assignee = match query.assignee.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some("me") => Some(AssigneeFilter::User(user_id)),
+        Some("none") => Some(AssigneeFilter::Unassigned),
+        Some(raw) => Some(AssigneeFilter::User(Uuid::parse_str(raw).map_err(|_| {
+            TodoError::Validation("assignee must be me, none, or a user id".to_string())
+        })?)),
+    };
+    Ok(TodoFilter {
+        workspace_id,
+        project_id: query.project_id,
+        assignee,
+    })
+}
+
+/// Parsing `?include=` menjadi `TodoIncludes`; nilai tidak dikenal ditolak.
+pub fn parse_includes(raw: Option<&str>) -> Result<TodoIncludes, TodoError> {
+    let mut includes = TodoIncludes::default();
+    for item in raw.unwrap_or_default().split(',').map(str::trim) {
+        match item {
+            "" => {}
+            "comment_count" => includes.comment_count = true,
+            "checklist" => includes.checklist = true,
+            "checklist_progress" => includes.checklist_progress = true,
+            other => {
+                return Err(TodoError::Validation(format!("unknown include: {other}")));
+            }
+        }
+    }
+    Ok(includes)
+}
+
 /// Normalisasi dan validasi judul todo.
 pub fn normalize_title(raw_title: String) -> Result<String, TodoError> {
     let title = raw_title.trim();
@@ -127,18 +455,71 @@ pub fn normalize_update_payload(request: UpdateTodoRequest) -> Result<UpdateTodo
         None => None,
     };
     let desc = normalize_desc(request.desc)?;
+    let estimate = match request.estimate {
+        Some(estimate) => Some(validate_estimate(estimate)?),
+        None => None,
+    };
 
     Ok(UpdateTodoPayload {
         title,
         desc,
         iscompleted: request.iscompleted,
+        assignee_id: request.assignee_id,
+        estimate,
+        due_at: request.due_at,
+        completed_at: None,
     })
 }
 
+/// Perubahan `completed_at` saat status selesai berganti: diisi `now` ketika menjadi selesai,
+/// dikosongkan ketika dibuka kembali, dan tidak diubah bila status tetap.
+pub fn completion_change(
+    current: bool,
+    requested: Option<bool>,
+    now: DateTime<FixedOffset>,
+) -> Option<Option<DateTime<FixedOffset>>> {
+    match requested {
+        Some(iscompleted) if iscompleted != current => Some(iscompleted.then_some(now)),
+        _ => None,
+    }
+}
+
+/// Validasi estimasi bila dikirim: harus positif dan tidak melebihi batas.
+pub fn validate_estimate(estimate: Option<Estimate>) -> Result<Option<Estimate>, TodoError> {
+    if let Some(estimate) = estimate
+        && !(1..=MAX_ESTIMATE).contains(&estimate.value)
+    {
+        return Err(TodoError::Validation(format!(
+            "estimate value must be between 1 and {MAX_ESTIMATE}"
+        )));
+    }
+    Ok(estimate)
+}
+
+/// Assignee selain pemilik wajib punya `todo.write` agar bisa mengerjakan todo.
+pub fn ensure_assignable(assignee_role: Option<ShareRole>) -> Result<(), TodoError> {
+    if !assignee_role.is_some_and(|role| Role::Share(role).allows(Permission::TodoWrite)) {
+        return Err(TodoError::Validation(
+            "assignee must have editor access to the todo".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Assignee harus user terdaftar.
+async fn ensure_user_exists<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<(), TodoError> {
+    if !user_repository::user_exists(conn, user_id).await? {
+        return Err(TodoError::Validation("assignee does not exist".to_string()));
+    }
+    Ok(())
+}
+
 // --- IGNORE ---
 // Modul tests untuk unit testing fungsi normalisasi dan validasi pada service todo.
 #[cfg(test)]
 mod tests {
+    use crate::modules::todo::dto::EstimateUnit;
+
     use super::*;
 
     #[test]
@@ -153,11 +534,65 @@ mod tests {
             title: Some(" Updated ".to_string()),
             desc: None,
             iscompleted: Some(true),
+            assignee_id: Some(None),
+            estimate: None,
+            due_at: None,
         })
         .expect("payload should be valid");
 
         assert_eq!(payload.title, Some("Updated".to_string()));
         assert_eq!(payload.desc, None);
         assert_eq!(payload.iscompleted, Some(true));
+        assert_eq!(payload.assignee_id, Some(None));
+    }
+
+    #[test]
+    fn completion_change_should_stamp_only_on_toggle() {
+        let now = Utc::now().fixed_offset();
+        assert_eq!(completion_change(false, Some(true), now), Some(Some(now)));
+        assert_eq!(completion_change(true, Some(false), now), Some(None));
+        assert_eq!(completion_change(true, Some(true), now), None);
+        assert_eq!(completion_change(false, None, now), None);
+    }
+
+    #[test]
+    fn validate_estimate_should_reject_out_of_range_values() {
+        let estimate = |value| Some(Estimate { value, unit: EstimateUnit::Points });
+        assert_eq!(validate_estimate(estimate(8)).ok(), Some(estimate(8)));
+        assert!(matches!(validate_estimate(estimate(0)), Err(TodoError::Validation(_))));
+        assert!(matches!(validate_estimate(estimate(MAX_ESTIMATE + 1)), Err(TodoError::Validation(_))));
+    }
+
+    #[test]
+    fn parse_filter_should_resolve_assignee_me() {
+        let user_id = Uuid::new_v4();
+        let query = TodoQuery {
+            assignee: Some("me".to_string()),
+            ..TodoQuery::default()
+        };
+        let filter = parse_filter(&query, user_id, None).expect("filter should be valid");
+        assert_eq!(filter.assignee, Some(AssigneeFilter::User(user_id)));
+
+        let invalid = TodoQuery {
+            assignee: Some("someone".to_string()),
+            ..TodoQuery::default()
+        };
+        assert!(matches!(parse_filter(&invalid, user_id, None), Err(TodoError::Validation(_))));
+    }
+
+    #[test]
+    fn ensure_assignable_should_require_editor_role() {
+        assert!(ensure_assignable(Some(ShareRole::Editor)).is_ok());
+        assert!(matches!(ensure_assignable(Some(ShareRole::Viewer)), Err(TodoError::Validation(_))));
+        assert!(matches!(ensure_assignable(None), Err(TodoError::Validation(_))));
+    }
+
+    #[test]
+    fn parse_includes_should_reject_unknown_values() {
+        let includes = parse_includes(Some("comment_count, ")).expect("include should be valid");
+        assert!(includes.comment_count);
+        let includes = parse_includes(Some("checklist_progress")).expect("include should be valid");
+        assert!(includes.checklist_progress && !includes.checklist);
+        assert!(matches!(parse_includes(Some("owner")), Err(TodoError::Validation(_))));
     }
 }
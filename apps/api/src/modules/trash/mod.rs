@@ -0,0 +1,5 @@
+//! Modul tempat sampah todo: data turunan todo terhapus (komentar, lampiran, dependency, time
+//! entry, checklist, reminder) disimpan selama masa undo agar undo hapus mengembalikan todo utuh.
+
+pub mod repository;
+pub mod service;
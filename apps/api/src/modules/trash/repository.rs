@@ -0,0 +1,155 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, Statement};
+use serde_json::Value;
+use uuid::Uuid;
+
+// Tabel turunan todo: (nama tabel, filter baris milik todo `$1`, syarat baris boleh dipulihkan).
+// Syarat pemulihan menjaga FK tetap valid bila user atau todo lawan sudah dihapus.
+const CHILD_TABLES: &[(&str, &str, &str)] = &[
+    (
+        "todo_comments",
+        "t.todo_id = $1",
+        "EXISTS (SELECT 1 FROM users u WHERE u.id = r.author_id)",
+    ),
+    (
+        "todo_attachments",
+        "t.todo_id = $1",
+        "EXISTS (SELECT 1 FROM users u WHERE u.id = r.uploader_id)",
+    ),
+    (
+        "todo_dependencies",
+        "t.todo_id = $1 OR t.blocked_by_id = $1",
+        "EXISTS (SELECT 1 FROM todos x WHERE x.id = r.todo_id) \
+         AND EXISTS (SELECT 1 FROM todos x WHERE x.id = r.blocked_by_id) \
+         AND EXISTS (SELECT 1 FROM users u WHERE u.id = r.created_by)",
+    ),
+    (
+        "time_entries",
+        "t.todo_id = $1",
+        "EXISTS (SELECT 1 FROM users u WHERE u.id = r.user_id)",
+    ),
+    ("todo_checklist_items", "t.todo_id = $1", "TRUE"),
+    (
+        "reminders",
+        "t.todo_id = $1",
+        "EXISTS (SELECT 1 FROM users u WHERE u.id = r.user_id)",
+    ),
+];
+
+/// Menyalin baris turunan todo dan kunci blob lampirannya ke `todo_trash`.
+/// Harus dipanggil di transaksi penghapusan todo, sebelum baris todo dihapus (FK cascade).
+/// Bila todo dengan id sama pernah masuk tempat sampah, kunci blob lamanya tetap disimpan.
+pub async fn stash_children<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<(), DbErr> {
+    let children = CHILD_TABLES
+        .iter()
+        .map(|(table, filter, _)| {
+            format!("'{table}', (SELECT COALESCE(jsonb_agg(to_jsonb(t)), '[]'::jsonb) FROM {table} t WHERE {filter})")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!(
+        "INSERT INTO todo_trash (todo_id, children, storage_keys, deleted_at) \
+         SELECT $1, jsonb_build_object({children}), \
+         (SELECT COALESCE(jsonb_agg(a.storage_key), '[]'::jsonb) FROM todo_attachments a WHERE a.todo_id = $1), NOW() \
+         ON CONFLICT (todo_id) DO UPDATE SET \
+         children = EXCLUDED.children, \
+         storage_keys = todo_trash.storage_keys || EXCLUDED.storage_keys, \
+         deleted_at = EXCLUDED.deleted_at"
+    );
+    conn.execute(Statement::from_sql_and_values(DbBackend::Postgres, sql, [todo_id.into()]))
+        .await?;
+    Ok(())
+}
+
+/// Memulihkan baris turunan todo dari `todo_trash` lalu menghapus entri tempat sampahnya.
+/// Baris yang user atau todo lawannya sudah tidak ada, atau bentrok dengan data baru, dilewati.
+/// Tidak melakukan apa pun bila todo tidak ada di tempat sampah (mis. sudah dibersihkan).
+pub async fn restore_children<C: ConnectionTrait>(conn: &C, todo_id: Uuid) -> Result<(), DbErr> {
+    let row = conn
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM todo_trash WHERE todo_id = $1 RETURNING children",
+            [todo_id.into()],
+        ))
+        .await?;
+    let Some(row) = row else {
+        return Ok(());
+    };
+    let children: Value = row.try_get("", "children")?;
+    for (table, _, restorable) in CHILD_TABLES {
+        let Some(rows) = children.get(*table).filter(|rows| rows.as_array().is_some_and(|rows| !rows.is_empty()))
+        else {
+            continue;
+        };
+        let sql = format!(
+            "INSERT INTO {table} SELECT r.* FROM jsonb_populate_recordset(NULL::{table}, $1) r \
+             WHERE {restorable} ON CONFLICT DO NOTHING"
+        );
+        conn.execute(Statement::from_sql_and_values(DbBackend::Postgres, sql, [rows.clone().into()]))
+            .await?;
+    }
+    Ok(())
+}
+
+/// Menghapus entri tempat sampah yang lebih tua dari `before`.
+/// Mengembalikan kunci blob lampirannya untuk dihapus dari storage.
+pub async fn purge_before<C: ConnectionTrait>(conn: &C, before: DateTime<FixedOffset>) -> Result<Vec<String>, DbErr> {
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM todo_trash WHERE deleted_at < $1 RETURNING storage_keys",
+            [before.into()],
+        ))
+        .await?;
+    let mut keys = Vec::new();
+    for row in rows {
+        let storage_keys: Value = row.try_get("", "storage_keys")?;
+        keys.extend(
+            storage_keys
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(str::to_string),
+        );
+    }
+    Ok(keys)
+}
+
+/// Menandai blob lampiran untuk dihapus oleh loop pembersihan. Dipanggil di transaksi yang
+/// menghapus metadata lampiran, sehingga blob tidak pernah tertinggal tanpa jejak.
+pub async fn queue_blob_purge<C: ConnectionTrait>(conn: &C, storage_key: &str) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "INSERT INTO blob_purge_queue (storage_key) VALUES ($1) ON CONFLICT (storage_key) DO NOTHING",
+        [storage_key.into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Mengambil sampai `limit` kunci blob yang menunggu dihapus, terlama lebih dulu.
+pub async fn list_queued_blobs<C: ConnectionTrait>(conn: &C, limit: u64) -> Result<Vec<String>, DbErr> {
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT storage_key FROM blob_purge_queue ORDER BY queued_at LIMIT $1",
+            [(limit as i64).into()],
+        ))
+        .await?;
+    rows.iter().map(|row| row.try_get("", "storage_key")).collect()
+}
+
+/// Menghapus kunci blob yang sudah berhasil dihapus dari storage dari antrean.
+pub async fn dequeue_blobs<C: ConnectionTrait>(conn: &C, storage_keys: &[String]) -> Result<(), DbErr> {
+    if storage_keys.is_empty() {
+        return Ok(());
+    }
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "DELETE FROM blob_purge_queue WHERE storage_key = ANY($1)",
+        [storage_keys.to_vec().into()],
+    ))
+    .await?;
+    Ok(())
+}
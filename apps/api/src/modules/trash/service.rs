@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use tracing::{error, info};
+
+use crate::config::history::HistorySettings;
+use crate::infrastructure::storage::BlobStore;
+use crate::modules::attachment::service as attachment_service;
+
+use super::repository;
+
+/// Jumlah maksimal blob antrean yang dihapus per putaran pembersihan.
+const BLOB_PURGE_BATCH: u64 = 100;
+
+/// Interval pembersihan tempat sampah; juga menjadi jeda tambahan setelah masa undo habis agar
+/// undo yang berjalan tepat di batas waktu tidak kehilangan datanya.
+const PURGE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Loop pembersihan tempat sampah untuk dijalankan lewat `tokio::spawn` saat startup.
+pub async fn run_purge_loop(conn: DatabaseConnection, blob_store: Arc<dyn BlobStore>, settings: HistorySettings) {
+    let mut interval = tokio::time::interval(PURGE_INTERVAL);
+    loop {
+        interval.tick().await;
+        match purge_expired(&conn, blob_store.as_ref(), &settings).await {
+            Ok(0) => {}
+            Ok(purged) => info!(purged, "expired attachment blobs of deleted todos purged"),
+            Err(err) => error!(error = %err, "failed to purge todo trash"),
+        }
+        match purge_queued_blobs(&conn, blob_store.as_ref()).await {
+            Ok(0) => {}
+            Ok(purged) => info!(purged, "queued attachment blobs purged"),
+            Err(err) => error!(error = %err, "failed to purge queued attachment blobs"),
+        }
+    }
+}
+
+/// Menghapus blob lampiran yang ditandai lewat `queue_blob_purge`. Kunci baru dilepas dari
+/// antrean setelah blob berhasil dihapus (atau memang sudah tidak ada), sehingga kegagalan
+/// storage dicoba lagi pada putaran berikutnya. Mengembalikan jumlah blob yang dibersihkan.
+pub async fn purge_queued_blobs(conn: &DatabaseConnection, blob_store: &dyn BlobStore) -> Result<usize, DbErr> {
+    let storage_keys = repository::list_queued_blobs(conn, BLOB_PURGE_BATCH).await?;
+    let purged = attachment_service::purge_blobs(blob_store, &storage_keys).await;
+    repository::dequeue_blobs(conn, &purged).await?;
+    Ok(purged.len())
+}
+
+/// Menghapus data turunan todo terhapus yang sudah melewati masa undo, beserta blob lampirannya.
+/// Entri dihapus dari database lebih dulu; blob dihapus best-effort setelahnya.
+/// Mengembalikan jumlah blob yang dibersihkan.
+pub async fn purge_expired(
+    conn: &DatabaseConnection,
+    blob_store: &dyn BlobStore,
+    settings: &HistorySettings,
+) -> Result<usize, DbErr> {
+    let window = TimeDelta::try_seconds(i64::try_from(settings.undo_window_secs).unwrap_or(i64::MAX))
+        .unwrap_or(TimeDelta::MAX);
+    let grace = TimeDelta::from_std(PURGE_INTERVAL).unwrap_or(TimeDelta::zero());
+    let cutoff = Utc::now()
+        .checked_sub_signed(window)
+        .and_then(|cutoff| cutoff.checked_sub_signed(grace))
+        .unwrap_or(chrono::DateTime::<Utc>::MIN_UTC);
+    let storage_keys = repository::purge_before(conn, cutoff.fixed_offset()).await?;
+    attachment_service::purge_blobs(blob_store, &storage_keys).await;
+    Ok(storage_keys.len())
+}
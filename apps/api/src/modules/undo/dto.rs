@@ -0,0 +1,27 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::modules::todo::dto::TodoResponse;
+
+use super::service::UndoOutcome;
+
+/// Hasil undo yang dikirim ke client.
+#[derive(Debug, Serialize)]
+pub struct UndoResponse {
+    /// Event history yang dibatalkan.
+    pub reverted_event_id: Uuid,
+    pub reverted_event_type: String,
+    /// State todo setelah undo; `None` bila undo menghapus todo (membatalkan `created`).
+    pub todo: Option<TodoResponse>,
+}
+
+impl From<UndoOutcome> for UndoResponse {
+    /// Mapping hasil service ke response HTTP.
+    fn from(value: UndoOutcome) -> Self {
+        Self {
+            reverted_event_id: value.reverted.id,
+            reverted_event_type: value.reverted.event_type,
+            todo: value.todo.map(TodoResponse::from),
+        }
+    }
+}
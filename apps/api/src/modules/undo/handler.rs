@@ -0,0 +1,329 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    routing::post,
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::dependency::service as dependency_service;
+use crate::modules::history::dto::AuditContext;
+
+use super::dto::UndoResponse;
+use super::service::{self, UndoError, UndoOutcome};
+
+/// Registrasi route undo per todo, di-mount di bawah prefix `/todos`.
+///
+/// - `POST /{id}/undo` batalkan perubahan terakhir pada todo
+pub fn todo_routes() -> Router<AppState> {
+    Router::new().route("/{id}/undo", post(undo_todo))
+}
+
+/// Registrasi route undo lintas todo, di-mount di root `/api`.
+///
+/// - `POST /undo` batalkan perubahan terakhir user
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/undo", post(undo_last))
+}
+
+/// POST `/todos/{id}/undo`
+/// Output: `200 OK` + `UndoResponse`, `404` bila tidak ada yang bisa di-undo,
+/// `409` bila sudah lewat batas waktu atau todo berubah setelahnya.
+pub async fn undo_todo(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<UndoResponse>>, ApiError> {
+    let outcome = service::undo_todo(&state.db, todo_id, &audit_context(&state, user), &state.history)
+        .await
+        .map_err(map_undo_error)?;
+    info!(todo_id = %todo_id, event_id = %outcome.reverted.id, "todo change undone");
+    Ok(Json(SuccessResponse {
+        data: undo_response(&state, outcome).await?,
+    }))
+}
+
+/// POST `/undo`
+/// Output: sama seperti `POST /todos/{id}/undo`, untuk perubahan terakhir di semua todo.
+pub async fn undo_last(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<UndoResponse>>, ApiError> {
+    let outcome = service::undo_last(&state.db, &audit_context(&state, user), &state.history)
+        .await
+        .map_err(map_undo_error)?;
+    info!(
+        todo_id = %outcome.reverted.todo_id,
+        event_id = %outcome.reverted.id,
+        "last change undone"
+    );
+    Ok(Json(SuccessResponse {
+        data: undo_response(&state, outcome).await?,
+    }))
+}
+
+/// Mapping hasil undo ke response, termasuk status blokir todo sesudah undo.
+async fn undo_response(state: &AppState, outcome: UndoOutcome) -> Result<UndoResponse, ApiError> {
+    let todo_ids: Vec<Uuid> = outcome.todo.iter().map(|todo| todo.id).collect();
+    let blocked = dependency_service::blocked_todo_ids(&state.db, &todo_ids)
+        .await
+        .map_err(|err| map_undo_error(UndoError::Database(err)))?;
+    let mut response = UndoResponse::from(outcome);
+    if let Some(todo) = &mut response.todo {
+        todo.is_blocked = blocked.contains(&todo.id);
+    }
+    Ok(response)
+}
+
+/// Konteks audit untuk undo dengan user saat ini sebagai pelaku.
+fn audit_context(state: &AppState, user: CurrentUser) -> AuditContext {
+    AuditContext::new(user.id, &state.history)
+}
+
+/// Mapping error domain undo ke `ApiError`.
+fn map_undo_error(err: UndoError) -> ApiError {
+    match err {
+        UndoError::NothingToUndo => {
+            warn!("nothing to undo");
+            ApiError::not_found("nothing to undo")
+        }
+        UndoError::Expired => {
+            warn!("undo window expired");
+            ApiError::conflict("undo window has expired")
+        }
+        UndoError::Conflict(message) => {
+            warn!(reason = %message, "undo rejected");
+            ApiError::conflict(message)
+        }
+        UndoError::Forbidden => {
+            warn!("undo rejected for insufficient role");
+            ApiError::forbidden("insufficient permission for this todo")
+        }
+        UndoError::Database(db_err) => {
+            error!(error = %db_err, "undo database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint undo terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::{ColumnTrait, Database, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::modules::history::dto::AuditContext;
+    use crate::modules::outbox::entity as outbox_entity;
+    use crate::modules::todo::dto::NewTodo;
+    use crate::modules::todo::service as todo_service;
+    use crate::modules::workspace::dto::WorkspaceRole;
+    use crate::modules::workspace::entity as workspace_entity;
+    use crate::modules::workspace::repository as workspace_repository;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // Memakai user acak agar `POST /undo` tidak menyentuh data test lain yang berjalan paralel.
+    async fn build_test_app() -> (Router, DatabaseConnection, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let user_id = Uuid::new_v4();
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("test user seed must succeed");
+        let app = Router::new()
+            .merge(routes())
+            .nest("/todos", todo_handler::routes().merge(todo_routes()))
+            .with_state(AppState::for_tests(db.clone(), user_id));
+        (app, db, user_id)
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn undo_should_revert_update_delete_and_create_in_order() {
+        let (app, db, _) = build_test_app().await;
+
+        let created = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "original" }))).await,
+        )
+        .await;
+        let todo_id = created["data"]["id"].as_str().expect("todo id must exist").to_string();
+
+        send(&app, "PATCH", format!("/todos/{todo_id}"), Some(json!({ "title": "changed" }))).await;
+        let undo_update = send(&app, "POST", format!("/todos/{todo_id}/undo"), None).await;
+        assert_eq!(undo_update.status(), StatusCode::OK);
+        let undo_update = read_json(undo_update).await;
+        assert_eq!(undo_update["data"]["reverted_event_type"], "updated");
+        assert_eq!(undo_update["data"]["todo"]["title"], "original");
+
+        send(&app, "DELETE", format!("/todos/{todo_id}"), None).await;
+        let undo_delete = send(&app, "POST", "/undo".to_string(), None).await;
+        assert_eq!(undo_delete.status(), StatusCode::OK);
+        let undo_delete = read_json(undo_delete).await;
+        assert_eq!(undo_delete["data"]["todo"]["id"], created["data"]["id"]);
+        assert_eq!(undo_delete["data"]["todo"]["created_at"], created["data"]["created_at"]);
+
+        let undo_create = send(&app, "POST", format!("/todos/{todo_id}/undo"), None).await;
+        assert_eq!(undo_create.status(), StatusCode::OK);
+        assert_eq!(read_json(undo_create).await["data"]["todo"], Value::Null);
+
+        let detail = send(&app, "GET", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(detail.status(), StatusCode::NOT_FOUND);
+
+        let nothing_left = send(&app, "POST", "/undo".to_string(), None).await;
+        assert_eq!(nothing_left.status(), StatusCode::NOT_FOUND);
+
+        // Setiap undo ikut tercatat di outbox agar webhook dan event stream melihatnya.
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("todo id must be a uuid");
+        let event_types: Vec<String> = outbox_entity::Entity::find()
+            .filter(outbox_entity::Column::AggregateId.eq(todo_uuid))
+            .order_by_asc(outbox_entity::Column::Sequence)
+            .all(&db)
+            .await
+            .expect("outbox query must succeed")
+            .into_iter()
+            .map(|event| event.event_type)
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![
+                "todo.created",
+                "todo.updated",
+                "todo.updated",
+                "todo.deleted",
+                "todo.created",
+                "todo.deleted",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn undo_delete_should_require_write_access_to_the_deleted_todo() {
+        let (app, db, member_id) = build_test_app().await;
+        let owner_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, owner_id)
+            .await
+            .expect("owner seed must succeed");
+        let workspace = workspace_repository::create_workspace(&db, owner_id, "undo access".to_string())
+            .await
+            .expect("workspace must be created");
+        workspace_repository::add_member(&db, workspace.id, member_id, WorkspaceRole::Member)
+            .await
+            .expect("member must be added");
+        let audit = AuditContext {
+            actor_id: member_id,
+            redact_desc: false,
+            reverts_event_id: None,
+        };
+        let new_todo = NewTodo {
+            id: Uuid::new_v4(),
+            user_id: member_id,
+            workspace_id: Some(workspace.id),
+            project_id: None,
+            assignee_id: None,
+            title: "workspace todo".to_string(),
+            desc: None,
+            estimate: None,
+            due_at: None,
+            completed_at: None,
+            external_uid: None,
+        };
+        let todo = todo_service::insert_todo(&db, member_id, new_todo, &audit)
+            .await
+            .expect("todo must be created");
+        let deleted = send(&app, "DELETE", format!("/todos/{}", todo.id), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+
+        // Setelah keluar dari workspace, member tidak boleh memulihkan todo ke workspace itu.
+        workspace_repository::delete_member(&db, workspace.id, member_id)
+            .await
+            .expect("member must be removed");
+        let undo = send(&app, "POST", format!("/todos/{}/undo", todo.id), None).await;
+        assert_eq!(undo.status(), StatusCode::FORBIDDEN);
+        assert!(
+            todo_repository::find_todo_by_id(&db, todo.id)
+                .await
+                .expect("todo lookup must succeed")
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn undo_delete_should_conflict_when_workspace_was_deleted() {
+        let (app, db, owner_id) = build_test_app().await;
+        let workspace = workspace_repository::create_workspace(&db, owner_id, "undo gone".to_string())
+            .await
+            .expect("workspace must be created");
+        let audit = AuditContext {
+            actor_id: owner_id,
+            redact_desc: false,
+            reverts_event_id: None,
+        };
+        let new_todo = NewTodo {
+            id: Uuid::new_v4(),
+            user_id: owner_id,
+            workspace_id: Some(workspace.id),
+            project_id: None,
+            assignee_id: None,
+            title: "orphaned todo".to_string(),
+            desc: None,
+            estimate: None,
+            due_at: None,
+            completed_at: None,
+            external_uid: None,
+        };
+        let todo = todo_service::insert_todo(&db, owner_id, new_todo, &audit)
+            .await
+            .expect("todo must be created");
+        let deleted = send(&app, "DELETE", format!("/todos/{}", todo.id), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+        workspace_entity::Entity::delete_by_id(workspace.id)
+            .exec(&db)
+            .await
+            .expect("workspace must be deleted");
+
+        let undo = send(&app, "POST", format!("/todos/{}/undo", todo.id), None).await;
+        assert_eq!(undo.status(), StatusCode::CONFLICT);
+        assert!(
+            todo_repository::find_todo_by_id(&db, todo.id)
+                .await
+                .expect("todo lookup must succeed")
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Modul undo: membatalkan mutasi todo terakhir dengan me-replay event history.
+
+pub mod dto;
+pub mod handler;
+pub mod service;
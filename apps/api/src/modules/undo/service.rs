@@ -0,0 +1,327 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::common::authz::{self, AccessError, Permission, Resource};
+use crate::config::history::HistorySettings;
+use crate::modules::history::dto::{AuditContext, TodoEventKind, is_redacted};
+use crate::modules::history::entity::Model as TodoEvent;
+use crate::modules::history::repository as history_repository;
+use crate::modules::project::repository as project_repository;
+use crate::modules::todo::entity::Model as Todo;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service as todo_service;
+use crate::modules::user::repository as user_repository;
+use crate::modules::workspace::repository as workspace_repository;
+
+/// Error domain undo yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum UndoError {
+    /// Tidak ada event milik pelaku yang bisa dibatalkan.
+    NothingToUndo,
+    /// Event terakhir sudah melewati batas waktu undo.
+    Expired,
+    /// Undo ditolak karena state todo sudah berubah setelah event target.
+    Conflict(String),
+    /// Pelaku tidak lagi punya `todo.write` pada todo (mis. share dicabut).
+    Forbidden,
+    Database(DbErr),
+}
+
+impl From<DbErr> for UndoError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Event yang dibatalkan beserta state todo sesudah undo.
+#[derive(Debug)]
+pub struct UndoOutcome {
+    pub reverted: TodoEvent,
+    pub todo: Option<Todo>,
+}
+
+/// Membatalkan perubahan terakhir pelaku pada satu todo.
+pub async fn undo_todo(
+    conn: &DatabaseConnection,
+    todo_id: Uuid,
+    audit: &AuditContext,
+    settings: &HistorySettings,
+) -> Result<UndoOutcome, UndoError> {
+    let target = history_repository::find_latest_undoable_event(conn, audit.actor_id, Some(todo_id))
+        .await?
+        .ok_or(UndoError::NothingToUndo)?;
+    apply_undo(conn, target, audit, settings).await
+}
+
+/// Membatalkan perubahan terakhir pelaku pada todo mana pun.
+pub async fn undo_last(
+    conn: &DatabaseConnection,
+    audit: &AuditContext,
+    settings: &HistorySettings,
+) -> Result<UndoOutcome, UndoError> {
+    let target = history_repository::find_latest_undoable_event(conn, audit.actor_id, None)
+        .await?
+        .ok_or(UndoError::NothingToUndo)?;
+    apply_undo(conn, target, audit, settings).await
+}
+
+/// Menerapkan kebalikan `target`:
+/// - `created` -> todo dihapus,
+/// - `updated`/`completed` -> field pada diff dikembalikan ke nilai lama,
+/// - `deleted` -> todo dimasukkan kembali dengan id dan timestamp aslinya.
+///
+/// Ditolak bila ada event lain (yang belum di-undo) pada todo yang sama setelah `target`,
+/// atau bila pelaku tidak lagi punya role editor pada todo (untuk undo hapus: pada snapshot
+/// todo, yaitu pemilik, project, atau workspace-nya). Event target dan baris todo dikunci
+/// selama pengecekan dan perubahan sehingga keduanya melihat state yang sama.
+async fn apply_undo(
+    conn: &DatabaseConnection,
+    target: TodoEvent,
+    audit: &AuditContext,
+    settings: &HistorySettings,
+) -> Result<UndoOutcome, UndoError> {
+    let kind = TodoEventKind::parse(&target.event_type)
+        .ok_or_else(|| UndoError::Conflict(format!("event type {} cannot be undone", target.event_type)))?;
+    let audit = audit.reverting(target.id);
+
+    let txn = conn.begin().await?;
+    let target = history_repository::find_event_for_update(&txn, target.id)
+        .await?
+        .filter(|event| event.undone_at.is_none())
+        .ok_or(UndoError::NothingToUndo)?;
+    let current = todo_repository::find_todo_for_update(&txn, target.todo_id).await?;
+    ensure_within_window(&target, settings.undo_window_secs, Utc::now().fixed_offset())?;
+    if history_repository::has_newer_live_events(&txn, &target).await? {
+        return Err(UndoError::Conflict(
+            "todo was changed after the change being undone".to_string(),
+        ));
+    }
+
+    let todo = match (kind, current) {
+        (TodoEventKind::Created, Some(current)) => {
+            authorize_write(&txn, audit.actor_id, &current).await?;
+            todo_service::remove_todo(&txn, audit.actor_id, &current, &audit).await?;
+            None
+        }
+        (TodoEventKind::Updated | TodoEventKind::Completed | TodoEventKind::Assigned, Some(current)) => {
+            authorize_write(&txn, audit.actor_id, &current).await?;
+            let mut restored = revert_fields(&current, &target.changes)?;
+            clear_missing_assignee(&txn, &mut restored).await?;
+            let updated = todo_service::replace_todo(&txn, audit.actor_id, &current, restored, &audit)
+                .await?
+                .ok_or_else(todo_gone)?;
+            Some(updated)
+        }
+        (TodoEventKind::Deleted, None) => {
+            let mut snapshot = snapshot_before(&target.changes)?;
+            // Project induk bisa sudah dihapus sejak todo dihapus; todo dikembalikan tanpa project.
+            if let Some(project_id) = snapshot.project_id
+                && project_repository::find_project_by_id(&txn, project_id).await?.is_none()
+            {
+                snapshot.project_id = None;
+            }
+            // Workspace yang sudah dihapus tidak bisa dipulihkan; memindah todo ke ruang pribadi
+            // akan mengubah siapa yang bisa melihatnya, jadi undo ditolak.
+            if let Some(workspace_id) = snapshot.workspace_id
+                && workspace_repository::find_workspace_by_id(&txn, workspace_id).await?.is_none()
+            {
+                return Err(UndoError::Conflict("workspace of the deleted todo no longer exists".to_string()));
+            }
+            authorize_write(&txn, audit.actor_id, &snapshot).await?;
+            clear_missing_assignee(&txn, &mut snapshot).await?;
+            Some(todo_service::restore_todo(&txn, audit.actor_id, snapshot, &audit).await?)
+        }
+        (TodoEventKind::Deleted, Some(_)) => {
+            return Err(UndoError::Conflict("todo already exists".to_string()));
+        }
+        (_, None) => return Err(todo_gone()),
+    };
+    txn.commit().await?;
+
+    Ok(UndoOutcome {
+        reverted: target,
+        todo,
+    })
+}
+
+/// Menolak undo untuk event yang lebih tua dari `window_secs`.
+pub fn ensure_within_window(
+    event: &TodoEvent,
+    window_secs: u64,
+    now: DateTime<FixedOffset>,
+) -> Result<(), UndoError> {
+    let window = chrono::Duration::seconds(i64::try_from(window_secs).unwrap_or(i64::MAX));
+    if now.signed_duration_since(event.created_at) > window {
+        return Err(UndoError::Expired);
+    }
+    Ok(())
+}
+
+/// Mengembalikan field pada `changes` ke nilai `old`.
+/// Ditolak bila nilai saat ini tidak lagi sama dengan nilai `new` di event.
+pub fn revert_fields(current: &Todo, changes: &Value) -> Result<Todo, UndoError> {
+    let mut fields = match serde_json::to_value(current) {
+        Ok(Value::Object(fields)) => fields,
+        _ => return Err(unreplayable()),
+    };
+    for (key, change) in changes.as_object().ok_or_else(unreplayable)? {
+        let old = change.get("old").cloned().unwrap_or(Value::Null);
+        let new = change.get("new").cloned().unwrap_or(Value::Null);
+        if is_redacted(&old) || is_redacted(&new) {
+            return Err(redacted());
+        }
+        if fields.get(key) != Some(&new) {
+            return Err(UndoError::Conflict(format!(
+                "field {key} was changed after the change being undone"
+            )));
+        }
+        fields.insert(key.clone(), old);
+    }
+    serde_json::from_value(Value::Object(fields)).map_err(|_| unreplayable())
+}
+
+/// Menyusun ulang todo dari nilai `old` pada event `deleted`.
+pub fn snapshot_before(changes: &Value) -> Result<Todo, UndoError> {
+    let mut fields = Map::new();
+    for (key, change) in changes.as_object().ok_or_else(unreplayable)? {
+        let old = change.get("old").cloned().unwrap_or(Value::Null);
+        if is_redacted(&old) {
+            return Err(redacted());
+        }
+        fields.insert(key.clone(), old);
+    }
+    serde_json::from_value(Value::Object(fields)).map_err(|_| unreplayable())
+}
+
+// Pelaku harus punya `todo.write` pada todo saat ini, atau pada snapshot todo yang dipulihkan.
+async fn authorize_write<C: ConnectionTrait>(conn: &C, actor_id: Uuid, todo: &Todo) -> Result<(), UndoError> {
+    match authz::authorize(conn, actor_id, Permission::TodoWrite, Resource::Todo(todo)).await {
+        Ok(_) => Ok(()),
+        Err(AccessError::Database(err)) => Err(err.into()),
+        Err(AccessError::NotFound | AccessError::Forbidden) => Err(UndoError::Forbidden),
+    }
+}
+
+// Assignee lama bisa sudah dihapus; todo dipulihkan tanpa assignee agar FK tetap valid.
+async fn clear_missing_assignee<C: ConnectionTrait>(conn: &C, todo: &mut Todo) -> Result<(), DbErr> {
+    if let Some(assignee_id) = todo.assignee_id
+        && !user_repository::user_exists(conn, assignee_id).await?
+    {
+        todo.assignee_id = None;
+    }
+    Ok(())
+}
+
+fn todo_gone() -> UndoError {
+    UndoError::Conflict("todo no longer exists".to_string())
+}
+
+fn redacted() -> UndoError {
+    UndoError::Conflict("change contains a redacted description and cannot be undone".to_string())
+}
+
+fn unreplayable() -> UndoError {
+    UndoError::Conflict("change cannot be replayed".to_string())
+}
+
+// Modul tests untuk unit testing replay event pada undo.
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn sample_todo() -> Todo {
+        let now = Utc::now().fixed_offset();
+        Todo {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            workspace_id: None,
+            project_id: None,
+            assignee_id: None,
+            title: "after".to_string(),
+            desc: None,
+            iscompleted: true,
+            completed_at: None,
+            due_at: None,
+            estimate_value: None,
+            estimate_unit: None,
+            external_uid: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn sample_event(created_at: DateTime<FixedOffset>) -> TodoEvent {
+        TodoEvent {
+            id: Uuid::new_v4(),
+            todo_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            actor_id: Uuid::new_v4(),
+            event_type: "updated".to_string(),
+            changes: json!({}),
+            created_at,
+            reverts_event_id: None,
+            undone_at: None,
+        }
+    }
+
+    #[test]
+    fn ensure_within_window_should_reject_old_events() {
+        let now = Utc::now().fixed_offset();
+        let event = sample_event(now - chrono::Duration::seconds(301));
+        assert!(matches!(ensure_within_window(&event, 300, now), Err(UndoError::Expired)));
+        assert!(ensure_within_window(&sample_event(now), 300, now).is_ok());
+    }
+
+    #[test]
+    fn revert_fields_should_restore_old_values() {
+        let current = sample_todo();
+        let changes = json!({
+            "title": { "old": "before", "new": "after" },
+            "iscompleted": { "old": false, "new": true }
+        });
+
+        let restored = revert_fields(&current, &changes).expect("revert should succeed");
+
+        assert_eq!(restored.title, "before");
+        assert!(!restored.iscompleted);
+        assert_eq!(restored.id, current.id);
+    }
+
+    #[test]
+    fn revert_fields_should_conflict_when_value_diverged() {
+        let current = sample_todo();
+        let changes = json!({ "title": { "old": "before", "new": "something else" } });
+
+        let result = revert_fields(&current, &changes);
+
+        assert!(matches!(result, Err(UndoError::Conflict(_))));
+    }
+
+    #[test]
+    fn snapshot_before_should_rebuild_deleted_todo() {
+        let original = sample_todo();
+        let changes = Value::Object(
+            serde_json::to_value(&original)
+                .expect("todo must serialize")
+                .as_object()
+                .expect("todo must be an object")
+                .iter()
+                .map(|(key, value)| (key.clone(), json!({ "old": value, "new": null })))
+                .collect(),
+        );
+
+        let snapshot = snapshot_before(&changes).expect("snapshot should rebuild");
+
+        assert_eq!(snapshot, original);
+    }
+
+    #[test]
+    fn snapshot_before_should_reject_redacted_desc() {
+        let changes = json!({ "desc": { "old": { "redacted": true }, "new": null } });
+        assert!(matches!(snapshot_before(&changes), Err(UndoError::Conflict(_))));
+    }
+}
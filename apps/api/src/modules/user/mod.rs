@@ -0,0 +1,4 @@
+//! Modul user: entity dan repository untuk lookup user (belum ada endpoint HTTP).
+
+pub mod entity;
+pub mod repository;
@@ -0,0 +1,29 @@
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
+    sea_query::{Expr, Func},
+};
+use uuid::Uuid;
+
+use super::entity::{Column, Entity, Model};
+
+/// Mengecek apakah user dengan id tertentu terdaftar.
+pub async fn user_exists<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<bool, DbErr> {
+    let count = Entity::find()
+        .filter(Column::Id.eq(user_id))
+        .count(conn)
+        .await?;
+    Ok(count > 0)
+}
+
+/// Mengambil user berdasarkan id.
+pub async fn find_user_by_id<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(user_id).one(conn).await
+}
+
+/// Mengambil user berdasarkan email (case-insensitive).
+pub async fn find_user_by_email(conn: &DatabaseConnection, email: &str) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Expr::expr(Func::lower(Expr::col(Column::Email))).eq(email.to_lowercase()))
+        .one(conn)
+        .await
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `webhook_deliveries` (antrean sekaligus log pengiriman).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub endpoint_id: Uuid,
+    /// Id event; sama untuk semua endpoint yang menerima event yang sama.
+    pub event_id: Uuid,
+    pub event_type: String,
+    /// Body JSON yang dikirim apa adanya di setiap percobaan.
+    pub payload: Json,
+    /// `pending`, `delivered`, atau `dead`.
+    pub status: String,
+    pub attempts: i32,
+    /// Jadwal percobaan berikutnya selama masih `pending`.
+    pub next_attempt_at: DateTime<FixedOffset>,
+    /// Status HTTP dari percobaan terakhir, `None` bila penerima tidak membalas.
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
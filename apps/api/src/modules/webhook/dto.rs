@@ -0,0 +1,231 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::{delivery_entity, entity};
+
+/// Jenis event yang bisa dilanggan endpoint webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEvent {
+    Created,
+    Updated,
+    /// Dikirim bersama `todo.updated` saat todo berganti status menjadi selesai.
+    Completed,
+    Deleted,
+    /// Notifikasi untuk pemilik endpoint (mis. reminder jatuh tempo), lewat `WebhookNotifier`.
+    Notification,
+}
+
+impl WebhookEvent {
+    pub const ALL: [Self; 5] = [
+        Self::Created,
+        Self::Updated,
+        Self::Completed,
+        Self::Deleted,
+        Self::Notification,
+    ];
+
+    /// Nama event di payload, header, dan daftar langganan.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "todo.created",
+            Self::Updated => "todo.updated",
+            Self::Completed => "todo.completed",
+            Self::Deleted => "todo.deleted",
+            Self::Notification => "notification.created",
+        }
+    }
+
+    /// Parsing nama event dari request.
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == raw)
+    }
+}
+
+/// Status pengiriman webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// Menunggu dikirim atau dijadwalkan retry.
+    Pending,
+    Delivered,
+    /// Dead-letter: gagal sampai batas percobaan, bisa dikirim ulang manual.
+    Dead,
+}
+
+impl DeliveryStatus {
+    /// Nilai yang disimpan di kolom `status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Dead => "dead",
+        }
+    }
+
+    /// Parsing nilai kolom `status` atau query string.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "pending" => Some(Self::Pending),
+            "delivered" => Some(Self::Delivered),
+            "dead" => Some(Self::Dead),
+            _ => None,
+        }
+    }
+}
+
+/// Payload request untuk mendaftarkan endpoint webhook.
+#[derive(Debug, Deserialize)]
+pub struct CreateWebhookRequest {
+    /// URL `http`/`https` penerima.
+    pub url: String,
+    /// Minimal satu jenis event, mis. `["todo.created", "todo.completed"]`.
+    pub events: Vec<String>,
+}
+
+/// Payload request untuk mengubah endpoint webhook; field yang tidak dikirim tidak berubah.
+#[derive(Debug, Deserialize)]
+pub struct UpdateWebhookRequest {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub events: Option<Vec<String>>,
+    #[serde(default)]
+    pub active: Option<bool>,
+}
+
+/// Perubahan endpoint yang sudah tervalidasi.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookChanges {
+    pub url: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub active: Option<bool>,
+}
+
+/// Query string log pengiriman.
+#[derive(Debug, Default, Deserialize)]
+pub struct DeliveryQuery {
+    /// Jumlah item per halaman (default 20, maksimal 100).
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// Filter `pending`, `delivered`, atau `dead`.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Pengiriman baru yang dimasukkan ke antrean.
+#[derive(Debug, Clone)]
+pub struct NewDelivery {
+    pub endpoint_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: Value,
+}
+
+/// Hasil satu percobaan kirim yang disimpan kembali oleh worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttemptUpdate {
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Bentuk data endpoint webhook yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    /// Hanya dikirim sekali pada response pembuatan endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl WebhookResponse {
+    /// Response pembuatan endpoint, satu-satunya yang menyertakan secret.
+    pub fn with_secret(value: entity::Model) -> Self {
+        let secret = value.secret.clone();
+        Self {
+            secret: Some(secret),
+            ..Self::from(value)
+        }
+    }
+}
+
+impl From<entity::Model> for WebhookResponse {
+    /// Mapping model database ke response HTTP tanpa secret.
+    fn from(value: entity::Model) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            events: event_names(&value.events),
+            active: value.active,
+            secret: None,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Bentuk satu entri log pengiriman.
+#[derive(Debug, Serialize)]
+pub struct DeliveryResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    /// Hanya terisi selama status masih `pending`.
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<delivery_entity::Model> for DeliveryResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: delivery_entity::Model) -> Self {
+        let pending = value.status == DeliveryStatus::Pending.as_str();
+        Self {
+            id: value.id,
+            event_id: value.event_id,
+            event_type: value.event_type,
+            status: value.status,
+            attempts: value.attempts,
+            next_attempt_at: pending.then_some(value.next_attempt_at),
+            response_status: value.response_status,
+            last_error: value.last_error,
+            delivered_at: value.delivered_at,
+            payload: value.payload,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Satu halaman log pengiriman.
+#[derive(Debug, Serialize)]
+pub struct DeliveryPage {
+    pub items: Vec<DeliveryResponse>,
+    pub total: u64,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+/// Nama event dari kolom JSON `events`; nilai non-string diabaikan.
+pub fn event_names(events: &Value) -> Vec<String> {
+    events
+        .as_array()
+        .map(|items| items.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
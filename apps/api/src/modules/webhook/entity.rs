@@ -0,0 +1,27 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `webhook_endpoints`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_endpoints")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Pemilik endpoint; menerima event untuk todo miliknya.
+    pub user_id: Uuid,
+    pub url: String,
+    /// Kunci HMAC; hanya ditampilkan sekali saat endpoint dibuat.
+    pub secret: String,
+    /// Array JSON jenis event yang dilanggan, mis. `["todo.created"]`.
+    pub events: Json,
+    /// Endpoint nonaktif tidak menerima event baru dan antreannya ditahan.
+    pub active: bool,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
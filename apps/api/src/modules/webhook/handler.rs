@@ -0,0 +1,549 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+
+use super::dto::{CreateWebhookRequest, DeliveryPage, DeliveryQuery, DeliveryResponse, UpdateWebhookRequest, WebhookResponse};
+use super::service::{self, WebhookError};
+
+/// Registrasi route webhook, di-mount di bawah prefix `/webhooks`.
+///
+/// - `POST /` daftarkan endpoint (secret hanya dikembalikan di sini)
+/// - `GET /` daftar endpoint milik user saat ini
+/// - `GET /{id}` detail endpoint
+/// - `PATCH /{id}` ubah URL, langganan event, atau status aktif
+/// - `DELETE /{id}` hapus endpoint beserta log pengirimannya
+/// - `GET /{id}/deliveries` log pengiriman
+/// - `POST /{id}/test` antrekan event `webhook.test`
+/// - `POST /{id}/deliveries/{delivery_id}/redeliver` kirim ulang pengiriman dead-letter
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_webhook).get(list_webhooks))
+        .route(
+            "/{id}",
+            get(get_webhook).patch(update_webhook).delete(delete_webhook),
+        )
+        .route("/{id}/deliveries", get(list_deliveries))
+        .route("/{id}/test", post(send_test_event))
+        .route("/{id}/deliveries/{delivery_id}/redeliver", post(redeliver))
+}
+
+/// POST `/webhooks`
+///
+/// Input: `CreateWebhookRequest`.
+/// Output: `201 Created` + `SuccessResponse<WebhookResponse>` beserta `secret`.
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<WebhookResponse>>), ApiError> {
+    let endpoint = service::create_webhook(&state.db, &state.webhook_destinations, user.id, payload)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint.id, "webhook created");
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: WebhookResponse::with_secret(endpoint),
+        }),
+    ))
+}
+
+/// GET `/webhooks`
+/// Output: `200 OK` + daftar endpoint milik user saat ini (tanpa secret).
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<WebhookResponse>>>, ApiError> {
+    let endpoints = service::list_webhooks(&state.db, user.id)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(count = endpoints.len(), "webhooks listed");
+    Ok(Json(SuccessResponse {
+        data: endpoints.into_iter().map(WebhookResponse::from).collect(),
+    }))
+}
+
+/// GET `/webhooks/{id}`
+/// Output: `200 OK` bila milik user, `404` bila tidak.
+pub async fn get_webhook(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(endpoint_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<WebhookResponse>>, ApiError> {
+    let endpoint = service::get_webhook(&state.db, user.id, endpoint_id)
+        .await
+        .map_err(map_webhook_error)?;
+    Ok(Json(SuccessResponse {
+        data: WebhookResponse::from(endpoint),
+    }))
+}
+
+/// PATCH `/webhooks/{id}`
+///
+/// Input: `UpdateWebhookRequest`.
+/// Output: `200 OK` dengan data endpoint terbaru.
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(endpoint_id): Path<Uuid>,
+    Json(payload): Json<UpdateWebhookRequest>,
+) -> Result<Json<SuccessResponse<WebhookResponse>>, ApiError> {
+    let endpoint = service::update_webhook(&state.db, &state.webhook_destinations, user.id, endpoint_id, payload)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint_id, active = endpoint.active, "webhook updated");
+    Ok(Json(SuccessResponse {
+        data: WebhookResponse::from(endpoint),
+    }))
+}
+
+/// DELETE `/webhooks/{id}`
+/// Output: `204 No Content`.
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(endpoint_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    service::delete_webhook(&state.db, user.id, endpoint_id)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint_id, "webhook deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET `/webhooks/{id}/deliveries`
+/// Query: `limit` (default 20, maks 100), `offset`, `status` opsional.
+/// Output: `200 OK` + `SuccessResponse<DeliveryPage>`, terbaru lebih dulu.
+pub async fn list_deliveries(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(endpoint_id): Path<Uuid>,
+    Query(query): Query<DeliveryQuery>,
+) -> Result<Json<SuccessResponse<DeliveryPage>>, ApiError> {
+    let page = service::list_deliveries(&state.db, user.id, endpoint_id, query)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint_id, count = page.items.len(), "webhook deliveries listed");
+    Ok(Json(SuccessResponse { data: page }))
+}
+
+/// POST `/webhooks/{id}/test`
+/// Output: `202 Accepted` + pengiriman `webhook.test` yang masuk antrean.
+pub async fn send_test_event(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(endpoint_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<SuccessResponse<DeliveryResponse>>), ApiError> {
+    let delivery = service::send_test_event(&state.db, user.id, endpoint_id)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint_id, delivery_id = %delivery.id, "webhook test event queued");
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(SuccessResponse {
+            data: DeliveryResponse::from(delivery),
+        }),
+    ))
+}
+
+/// POST `/webhooks/{id}/deliveries/{delivery_id}/redeliver`
+/// Output: `202 Accepted` + pengiriman yang kembali `pending`; `409` bila belum `dead`.
+pub async fn redeliver(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((endpoint_id, delivery_id)): Path<(Uuid, Uuid)>,
+) -> Result<(StatusCode, Json<SuccessResponse<DeliveryResponse>>), ApiError> {
+    let delivery = service::redeliver(&state.db, user.id, endpoint_id, delivery_id)
+        .await
+        .map_err(map_webhook_error)?;
+    info!(webhook_id = %endpoint_id, delivery_id = %delivery_id, "webhook delivery requeued");
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(SuccessResponse {
+            data: DeliveryResponse::from(delivery),
+        }),
+    ))
+}
+
+/// Mapping error domain webhook ke `ApiError`.
+fn map_webhook_error(err: WebhookError) -> ApiError {
+    match err {
+        WebhookError::Validation(message) => {
+            warn!(reason = %message, "webhook validation failed");
+            ApiError::bad_request(message)
+        }
+        WebhookError::NotFound => {
+            warn!("webhook not found");
+            ApiError::not_found("webhook not found")
+        }
+        WebhookError::DeliveryNotFound => {
+            warn!("webhook delivery not found");
+            ApiError::not_found("webhook delivery not found")
+        }
+        WebhookError::Conflict(message) => {
+            warn!(reason = %message, "webhook delivery action rejected");
+            ApiError::conflict(message)
+        }
+        WebhookError::Database(db_err) => {
+            error!(error = %db_err, "webhook database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi webhook terhadap database dan penerima HTTP lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{HeaderMap, Request},
+    };
+    use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::config::outbox::OutboxSettings;
+    use crate::config::webhook::WebhookSettings;
+    use crate::infrastructure::webhook::{
+        DELIVERY_HEADER, DestinationPolicy, EVENT_HEADER, SIGNATURE_HEADER, WebhookSender, sign_payload,
+    };
+    use crate::modules::outbox::service as outbox_service;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+    use crate::modules::webhook::dto::{NewDelivery, WebhookEvent};
+    use crate::modules::webhook::repository;
+
+    use super::*;
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    // User acak agar endpoint tidak menerima event dari data test lain.
+    async fn build_test_app() -> (Router, DatabaseConnection) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let app = Router::new()
+            .nest("/webhooks", routes())
+            .nest("/todos", todo_handler::routes())
+            .with_state(AppState::for_tests(db.clone(), user_id).with_webhook_destinations(local_destinations()));
+        (app, db)
+    }
+
+    // Penerima test berjalan di loopback, jadi harus di-allowlist secara eksplisit.
+    fn local_destinations() -> DestinationPolicy {
+        DestinationPolicy::new(vec!["127.0.0.1".to_string()])
+    }
+
+    // Penerima lokal: `/ok` mencatat request, `/fail` selalu membalas 500.
+    async fn spawn_receiver() -> (String, Received) {
+        async fn accept(State(received): State<Received>, headers: HeaderMap, body: String) -> StatusCode {
+            received.lock().expect("lock").push((headers, body));
+            StatusCode::OK
+        }
+
+        let received = Received::default();
+        let app = Router::new()
+            .route("/ok", post(accept))
+            .route("/fail", post(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("receiver must bind");
+        let addr = listener.local_addr().expect("receiver must have an address");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://{addr}"), received)
+    }
+
+    async fn send(app: &Router, method: &str, uri: String, body: Option<Value>) -> axum::response::Response {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn webhooks_should_deliver_signed_events_and_dead_letter_failures() {
+        let (app, db) = build_test_app().await;
+        let (base, received) = spawn_receiver().await;
+        let settings = WebhookSettings {
+            max_attempts: 2,
+            ..WebhookSettings::default()
+        };
+        let sender = WebhookSender::new(Duration::from_secs(5), local_destinations());
+
+        let invalid = send(
+            &app,
+            "POST",
+            "/webhooks".to_string(),
+            Some(json!({ "url": format!("{base}/ok"), "events": ["todo.archived"] })),
+        )
+        .await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+        let created = send(
+            &app,
+            "POST",
+            "/webhooks".to_string(),
+            Some(json!({ "url": format!("{base}/ok"), "events": ["todo.created", "todo.completed"] })),
+        )
+        .await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let endpoint = read_json(created).await;
+        let secret = endpoint["data"]["secret"].as_str().expect("secret must be returned").to_string();
+        let listed = read_json(send(&app, "GET", "/webhooks".to_string(), None).await).await;
+        assert!(listed["data"][0].get("secret").is_none());
+
+        let todo = read_json(
+            send(&app, "POST", "/todos".to_string(), Some(json!({ "title": "ship webhooks" }))).await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id").to_string();
+        send(
+            &app,
+            "PATCH",
+            format!("/todos/{todo_id}"),
+            Some(json!({ "iscompleted": true })),
+        )
+        .await;
+        // Event todo masuk antrean webhook lewat relay outbox.
+        let subscribers = outbox_service::default_subscribers();
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("uuid");
+        outbox_service::relay_pending(&db, &OutboxSettings::default(), &subscribers, Some(todo_uuid))
+            .await
+            .expect("relay must succeed");
+        service::deliver_due_webhooks(&db, &settings, &sender)
+            .await
+            .expect("worker run must succeed");
+
+        let requests = received.lock().expect("lock").clone();
+        let events: Vec<&str> = requests
+            .iter()
+            .map(|(headers, _)| headers[EVENT_HEADER].to_str().expect("ascii header"))
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&"todo.created") && events.contains(&"todo.completed"));
+        for (headers, body) in &requests {
+            let signature = headers[SIGNATURE_HEADER].to_str().expect("ascii header");
+            let timestamp: i64 = signature
+                .strip_prefix("t=")
+                .and_then(|rest| rest.split(',').next())
+                .and_then(|raw| raw.parse().ok())
+                .expect("signature must carry a timestamp");
+            assert_eq!(signature, sign_payload(&secret, timestamp, body));
+            let payload: Value = serde_json::from_str(body).expect("body must be json");
+            assert_eq!(payload["data"]["todo"]["id"], todo_id.as_str());
+            assert!(headers.contains_key(DELIVERY_HEADER));
+        }
+
+        let failing = read_json(
+            send(
+                &app,
+                "POST",
+                "/webhooks".to_string(),
+                Some(json!({ "url": format!("{base}/fail"), "events": ["todo.deleted"] })),
+            )
+            .await,
+        )
+        .await;
+        let failing_id = failing["data"]["id"].as_str().expect("webhook id").to_string();
+        let queued = send(&app, "POST", format!("/webhooks/{failing_id}/test"), None).await;
+        assert_eq!(queued.status(), StatusCode::ACCEPTED);
+        let delivery_id = read_json(queued).await["data"]["id"].as_str().expect("delivery id").to_string();
+
+        service::deliver_due_webhooks(&db, &settings, &sender)
+            .await
+            .expect("worker run must succeed");
+        // Retry dimajukan agar tidak menunggu jeda backoff.
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "UPDATE webhook_deliveries SET next_attempt_at = now() WHERE id = $1",
+            [Uuid::parse_str(&delivery_id).expect("uuid").into()],
+        ))
+        .await
+        .expect("reschedule must succeed");
+        service::deliver_due_webhooks(&db, &settings, &sender)
+            .await
+            .expect("worker run must succeed");
+
+        let log = read_json(
+            send(&app, "GET", format!("/webhooks/{failing_id}/deliveries?status=dead"), None).await,
+        )
+        .await;
+        assert_eq!(log["data"]["total"], 1);
+        assert_eq!(log["data"]["items"][0]["event_type"], "webhook.test");
+        assert_eq!(log["data"]["items"][0]["attempts"], 2);
+        assert_eq!(log["data"]["items"][0]["response_status"], 500);
+
+        let requeued = send(
+            &app,
+            "POST",
+            format!("/webhooks/{failing_id}/deliveries/{delivery_id}/redeliver"),
+            None,
+        )
+        .await;
+        assert_eq!(requeued.status(), StatusCode::ACCEPTED);
+        assert_eq!(read_json(requeued).await["data"]["status"], "pending");
+        let again = send(
+            &app,
+            "POST",
+            format!("/webhooks/{failing_id}/deliveries/{delivery_id}/redeliver"),
+            None,
+        )
+        .await;
+        assert_eq!(again.status(), StatusCode::CONFLICT);
+
+        let deleted = send(&app, "DELETE", format!("/webhooks/{failing_id}"), None).await;
+        assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn webhook_url_should_reject_internal_destinations_by_default() {
+        let (allowed, db) = build_test_app().await;
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let strict = Router::new()
+            .nest("/webhooks", routes())
+            .with_state(AppState::for_tests(db, user_id));
+
+        for url in ["http://127.0.0.1:8080/hook", "http://localhost/hook", "http://169.254.169.254/"] {
+            let body = json!({ "url": url, "events": ["todo.created"] });
+            let created = send(&strict, "POST", "/webhooks".to_string(), Some(body)).await;
+            assert_eq!(created.status(), StatusCode::BAD_REQUEST, "{url}");
+        }
+        let body = json!({ "url": "http://127.0.0.1:8080/hook", "events": ["todo.created"] });
+        let created = send(&allowed, "POST", "/webhooks".to_string(), Some(body)).await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+
+        let public = read_json(
+            send(
+                &strict,
+                "POST",
+                "/webhooks".to_string(),
+                Some(json!({ "url": "https://93.184.216.34/hook", "events": ["todo.created"] })),
+            )
+            .await,
+        )
+        .await;
+        let endpoint_id = public["data"]["id"].as_str().expect("webhook id").to_string();
+        let moved = send(
+            &strict,
+            "PATCH",
+            format!("/webhooks/{endpoint_id}"),
+            Some(json!({ "url": "http://10.0.0.8/hook" })),
+        )
+        .await;
+        assert_eq!(moved.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn claimed_delivery_should_hold_lease_until_it_expires() {
+        let (_app, db) = build_test_app().await;
+        let user_id = Uuid::new_v4();
+        todo_repository::ensure_default_user(&db, user_id)
+            .await
+            .expect("user seed must succeed");
+        let endpoint = repository::create_endpoint(
+            &db,
+            user_id,
+            "https://hooks.example.com/lease".to_string(),
+            "whsec_test".to_string(),
+            &[WebhookEvent::Created],
+        )
+        .await
+        .expect("endpoint must be created");
+        let delivery = repository::insert_delivery(
+            &db,
+            NewDelivery {
+                endpoint_id: endpoint.id,
+                event_id: Uuid::new_v4(),
+                event_type: service::TEST_EVENT.to_string(),
+                payload: json!({}),
+            },
+        )
+        .await
+        .expect("delivery must be queued");
+        // Jadwal di masa lalu agar klaim di test ini tidak menyentuh antrean test lain.
+        let at = |day: u32| {
+            chrono::NaiveDate::from_ymd_opt(2000, 1, day)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .expect("valid date")
+                .and_utc()
+                .fixed_offset()
+        };
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "UPDATE webhook_deliveries SET next_attempt_at = $2 WHERE id = $1",
+            [delivery.id.into(), at(1).into()],
+        ))
+        .await
+        .expect("reschedule must succeed");
+        let lease = chrono::TimeDelta::minutes(1);
+
+        let first = repository::claim_due_deliveries(&db, at(2), at(2) + lease, 10)
+            .await
+            .expect("claim must succeed");
+        assert_eq!(first.iter().map(|row| row.id).collect::<Vec<_>>(), vec![delivery.id]);
+        let during_lease = repository::claim_due_deliveries(&db, at(2), at(2) + lease, 10)
+            .await
+            .expect("claim must succeed");
+        assert!(during_lease.is_empty(), "leased delivery must not be claimed twice");
+
+        let second = repository::claim_due_deliveries(&db, at(3), at(3) + lease, 10)
+            .await
+            .expect("claim must succeed");
+        assert_eq!(second.len(), 1, "expired lease must be claimable again");
+        let delivered = service::attempt_update(0, 3, Ok(200), at(3));
+        assert!(
+            !repository::record_attempt(&db, &first[0], delivered.clone())
+                .await
+                .expect("record must succeed"),
+            "stale lease must not record its result"
+        );
+        assert!(
+            repository::record_attempt(&db, &second[0], delivered)
+                .await
+                .expect("record must succeed")
+        );
+        let stored = repository::find_delivery(&db, endpoint.id, delivery.id)
+            .await
+            .expect("lookup must succeed")
+            .expect("delivery must exist");
+        assert_eq!(stored.status, "delivered");
+        assert_eq!(stored.attempts, 1);
+    }
+}
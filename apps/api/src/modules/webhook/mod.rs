@@ -0,0 +1,10 @@
+//! Modul webhook keluar: endpoint per user, antrean pengiriman bertanda tangan HMAC, dan log pengiriman.
+
+pub mod delivery_entity;
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod notifier;
+pub mod repository;
+pub mod service;
+pub mod subscriber;
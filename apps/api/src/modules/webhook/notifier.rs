@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+
+use crate::infrastructure::notifier::{Notification, Notifier, NotifyError};
+
+use super::service;
+
+/// `Notifier` yang memasukkan notifikasi ke antrean pengiriman webhook milik penerima
+/// (event `notification.created`); dikirim worker webhook seperti event todo.
+pub struct WebhookNotifier;
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, txn: &DatabaseTransaction, notification: &Notification) -> Result<(), NotifyError> {
+        service::enqueue_notification(txn, notification)
+            .await
+            .map(|_| ())
+            .map_err(|err| NotifyError(err.to_string()))
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, Statement, sea_query::Expr,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::delivery_entity;
+use super::dto::{AttemptUpdate, DeliveryStatus, NewDelivery, WebhookChanges, WebhookEvent};
+use super::entity::{ActiveModel, Column, Entity, Model};
+
+/// Menyimpan endpoint webhook baru dalam keadaan aktif.
+pub async fn create_endpoint(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    url: String,
+    secret: String,
+    events: &[WebhookEvent],
+) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        url: Set(url),
+        secret: Set(secret),
+        events: Set(events_json(events)),
+        active: Set(true),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengambil endpoint milik user, urut waktu dibuat.
+pub async fn list_endpoints(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Jumlah endpoint milik user.
+pub async fn count_endpoints(conn: &DatabaseConnection, user_id: Uuid) -> Result<u64, DbErr> {
+    Entity::find().filter(Column::UserId.eq(user_id)).count(conn).await
+}
+
+/// Mengambil satu endpoint milik user.
+pub async fn find_endpoint(conn: &DatabaseConnection, user_id: Uuid, endpoint_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Id.eq(endpoint_id))
+        .filter(Column::UserId.eq(user_id))
+        .one(conn)
+        .await
+}
+
+/// Endpoint aktif milik user; dipakai saat memasukkan event ke antrean.
+pub async fn list_active_endpoints<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::Active.eq(true))
+        .all(conn)
+        .await
+}
+
+/// Mengambil endpoint berdasarkan sekumpulan id.
+pub async fn find_endpoints_by_ids<C: ConnectionTrait>(conn: &C, endpoint_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    if endpoint_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Entity::find()
+        .filter(Column::Id.is_in(endpoint_ids.iter().copied()))
+        .all(conn)
+        .await
+}
+
+/// Menerapkan perubahan endpoint yang sudah tervalidasi.
+pub async fn update_endpoint(conn: &DatabaseConnection, existing: Model, changes: WebhookChanges) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = existing.into();
+    if let Some(url) = changes.url {
+        active.url = Set(url);
+    }
+    if let Some(events) = changes.events {
+        active.events = Set(events_json(&events));
+    }
+    if let Some(is_active) = changes.active {
+        active.active = Set(is_active);
+    }
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Menghapus endpoint beserta seluruh log pengirimannya.
+pub async fn delete_endpoint(conn: &DatabaseConnection, endpoint_id: Uuid) -> Result<(), DbErr> {
+    Entity::delete_by_id(endpoint_id).exec(conn).await?;
+    Ok(())
+}
+
+/// Memasukkan satu pengiriman baru ke antrean, siap dikirim segera.
+pub async fn insert_delivery<C: ConnectionTrait>(conn: &C, delivery: NewDelivery) -> Result<delivery_entity::Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let active = delivery_entity::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        endpoint_id: Set(delivery.endpoint_id),
+        event_id: Set(delivery.event_id),
+        event_type: Set(delivery.event_type),
+        payload: Set(delivery.payload),
+        status: Set(DeliveryStatus::Pending.as_str().to_string()),
+        attempts: Set(0),
+        next_attempt_at: Set(now),
+        response_status: Set(None),
+        last_error: Set(None),
+        delivered_at: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    active.insert(conn).await
+}
+
+/// Mengklaim pengiriman `pending` yang jadwalnya sudah tiba pada endpoint aktif, paling lama lebih dulu.
+///
+/// Klaim berupa lease: `next_attempt_at` dimajukan ke `lease_until` dalam satu statement,
+/// sehingga worker lain tidak mengambil baris yang sama selama HTTP dikirim tanpa transaksi
+/// terbuka. Bila worker mati, baris otomatis jatuh tempo lagi setelah lease habis.
+/// Nilai `next_attempt_at` pada hasil menjadi token lease untuk [`record_attempt`].
+pub async fn claim_due_deliveries<C: ConnectionTrait>(
+    conn: &C,
+    now: DateTime<FixedOffset>,
+    lease_until: DateTime<FixedOffset>,
+    limit: u64,
+) -> Result<Vec<delivery_entity::Model>, DbErr> {
+    let sql = "UPDATE webhook_deliveries SET next_attempt_at = $2 \
+               WHERE id IN ( \
+                   SELECT d.id FROM webhook_deliveries d \
+                   JOIN webhook_endpoints e ON e.id = d.endpoint_id \
+                   WHERE d.status = 'pending' AND d.next_attempt_at <= $1 AND e.active \
+                   ORDER BY d.next_attempt_at, d.id \
+                   LIMIT $3 \
+                   FOR UPDATE OF d SKIP LOCKED) \
+               RETURNING *";
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    delivery_entity::Entity::find()
+        .from_raw_sql(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [now.into(), lease_until.into(), limit.into()],
+        ))
+        .all(conn)
+        .await
+}
+
+/// Menyimpan hasil satu percobaan kirim selama lease dari [`claim_due_deliveries`] masih dipegang.
+///
+/// Mengembalikan `false` bila baris sudah tidak `pending` atau `next_attempt_at` berubah
+/// (lease habis lalu diklaim worker lain, atau dijadwalkan ulang manual); hasilnya dibuang.
+pub async fn record_attempt<C: ConnectionTrait>(
+    conn: &C,
+    claimed: &delivery_entity::Model,
+    update: AttemptUpdate,
+) -> Result<bool, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let mut query = delivery_entity::Entity::update_many()
+        .col_expr(delivery_entity::Column::Status, Expr::value(update.status.as_str()))
+        .col_expr(delivery_entity::Column::Attempts, Expr::value(update.attempts))
+        .col_expr(delivery_entity::Column::ResponseStatus, Expr::value(update.response_status))
+        .col_expr(delivery_entity::Column::LastError, Expr::value(update.last_error))
+        .col_expr(delivery_entity::Column::DeliveredAt, Expr::value(update.delivered_at))
+        .col_expr(delivery_entity::Column::UpdatedAt, Expr::value(now));
+    if let Some(next_attempt_at) = update.next_attempt_at {
+        query = query.col_expr(delivery_entity::Column::NextAttemptAt, Expr::value(next_attempt_at));
+    }
+    let result = query
+        .filter(delivery_entity::Column::Id.eq(claimed.id))
+        .filter(delivery_entity::Column::Status.eq(DeliveryStatus::Pending.as_str()))
+        .filter(delivery_entity::Column::NextAttemptAt.eq(claimed.next_attempt_at))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected == 1)
+}
+
+/// Mengambil satu halaman log pengiriman endpoint, terbaru lebih dulu.
+pub async fn list_deliveries(
+    conn: &DatabaseConnection,
+    endpoint_id: Uuid,
+    status: Option<DeliveryStatus>,
+    limit: u64,
+    offset: u64,
+) -> Result<Vec<delivery_entity::Model>, DbErr> {
+    deliveries_query(endpoint_id, status)
+        .order_by(delivery_entity::Column::CreatedAt, Order::Desc)
+        .order_by(delivery_entity::Column::Id, Order::Desc)
+        .limit(limit)
+        .offset(offset)
+        .all(conn)
+        .await
+}
+
+/// Jumlah entri log pengiriman endpoint sesuai filter.
+pub async fn count_deliveries(
+    conn: &DatabaseConnection,
+    endpoint_id: Uuid,
+    status: Option<DeliveryStatus>,
+) -> Result<u64, DbErr> {
+    deliveries_query(endpoint_id, status).count(conn).await
+}
+
+/// Mengambil satu pengiriman dalam scope endpoint tertentu.
+pub async fn find_delivery(
+    conn: &DatabaseConnection,
+    endpoint_id: Uuid,
+    delivery_id: Uuid,
+) -> Result<Option<delivery_entity::Model>, DbErr> {
+    delivery_entity::Entity::find()
+        .filter(delivery_entity::Column::Id.eq(delivery_id))
+        .filter(delivery_entity::Column::EndpointId.eq(endpoint_id))
+        .one(conn)
+        .await
+}
+
+/// Mengembalikan pengiriman dead-letter ke antrean dengan jatah percobaan baru.
+pub async fn requeue_delivery(
+    conn: &DatabaseConnection,
+    existing: delivery_entity::Model,
+) -> Result<delivery_entity::Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let mut active: delivery_entity::ActiveModel = existing.into();
+    active.status = Set(DeliveryStatus::Pending.as_str().to_string());
+    active.attempts = Set(0);
+    active.next_attempt_at = Set(now);
+    active.updated_at = Set(now);
+    active.update(conn).await
+}
+
+fn deliveries_query(endpoint_id: Uuid, status: Option<DeliveryStatus>) -> Select<delivery_entity::Entity> {
+    let query = delivery_entity::Entity::find().filter(delivery_entity::Column::EndpointId.eq(endpoint_id));
+    match status {
+        Some(status) => query.filter(delivery_entity::Column::Status.eq(status.as_str())),
+        None => query,
+    }
+}
+
+fn events_json(events: &[WebhookEvent]) -> Value {
+    Value::from(events.iter().map(|event| event.as_str()).collect::<Vec<_>>())
+}
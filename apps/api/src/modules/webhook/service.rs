@@ -0,0 +1,551 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use futures_util::future::join_all;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::config::webhook::WebhookSettings;
+use crate::infrastructure::notifier::Notification;
+use crate::infrastructure::webhook::destination::DestinationError;
+use crate::infrastructure::webhook::{DestinationPolicy, WebhookError as SendError, WebhookRequest, WebhookSender};
+
+use super::delivery_entity::Model as Delivery;
+use super::dto::{
+    AttemptUpdate, CreateWebhookRequest, DeliveryPage, DeliveryQuery, DeliveryResponse, DeliveryStatus, NewDelivery,
+    UpdateWebhookRequest, WebhookChanges, WebhookEvent, event_names,
+};
+use super::entity::Model;
+use super::repository;
+
+/// Jenis event khusus untuk aksi "kirim event uji"; tidak perlu dilanggan.
+pub const TEST_EVENT: &str = "webhook.test";
+const MAX_ENDPOINTS_PER_USER: u64 = 10;
+const MAX_URL_LEN: usize = 2048;
+const DEFAULT_PAGE_LIMIT: u64 = 20;
+const MAX_PAGE_LIMIT: u64 = 100;
+/// Jeda retry maksimal setelah pengiriman gagal.
+const MAX_RETRY_DELAY_MINUTES: i64 = 60;
+/// Tambahan waktu lease di atas timeout HTTP sebelum klaim pengiriman dianggap kedaluwarsa.
+const LEASE_GRACE_SECS: i64 = 30;
+/// Batas atas timeout yang dihitung ke lease agar konfigurasi ekstrem tidak meluap.
+const MAX_LEASE_TIMEOUT_SECS: i64 = 86_400;
+
+/// Error domain webhook yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum WebhookError {
+    Validation(String),
+    NotFound,
+    DeliveryNotFound,
+    /// Aksi tidak sesuai status pengiriman saat ini.
+    Conflict(String),
+    Database(DbErr),
+}
+
+impl From<DbErr> for WebhookError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Mendaftarkan endpoint webhook baru untuk user; secret dibuat server.
+pub async fn create_webhook(
+    conn: &DatabaseConnection,
+    destinations: &DestinationPolicy,
+    user_id: Uuid,
+    request: CreateWebhookRequest,
+) -> Result<Model, WebhookError> {
+    let url = validate_destination(destinations, &request.url).await?;
+    let events = parse_events(&request.events)?;
+    if repository::count_endpoints(conn, user_id).await? >= MAX_ENDPOINTS_PER_USER {
+        return Err(WebhookError::Validation(format!(
+            "a user can register at most {MAX_ENDPOINTS_PER_USER} webhooks"
+        )));
+    }
+    repository::create_endpoint(conn, user_id, url, generate_secret(), &events)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Mengambil endpoint webhook milik user.
+pub async fn list_webhooks(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>, WebhookError> {
+    repository::list_endpoints(conn, user_id)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Mengambil satu endpoint milik user; `NotFound` untuk endpoint user lain.
+pub async fn get_webhook(conn: &DatabaseConnection, user_id: Uuid, endpoint_id: Uuid) -> Result<Model, WebhookError> {
+    repository::find_endpoint(conn, user_id, endpoint_id)
+        .await?
+        .ok_or(WebhookError::NotFound)
+}
+
+/// Mengubah URL, langganan event, atau status aktif endpoint.
+pub async fn update_webhook(
+    conn: &DatabaseConnection,
+    destinations: &DestinationPolicy,
+    user_id: Uuid,
+    endpoint_id: Uuid,
+    request: UpdateWebhookRequest,
+) -> Result<Model, WebhookError> {
+    let existing = get_webhook(conn, user_id, endpoint_id).await?;
+    let url = match request.url.as_deref() {
+        Some(raw) => Some(validate_destination(destinations, raw).await?),
+        None => None,
+    };
+    let changes = WebhookChanges {
+        url,
+        events: request.events.as_deref().map(parse_events).transpose()?,
+        active: request.active,
+    };
+    repository::update_endpoint(conn, existing, changes)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Menghapus endpoint beserta antrean dan log pengirimannya.
+pub async fn delete_webhook(conn: &DatabaseConnection, user_id: Uuid, endpoint_id: Uuid) -> Result<(), WebhookError> {
+    let existing = get_webhook(conn, user_id, endpoint_id).await?;
+    repository::delete_endpoint(conn, existing.id)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Mengambil satu halaman log pengiriman endpoint.
+pub async fn list_deliveries(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    endpoint_id: Uuid,
+    query: DeliveryQuery,
+) -> Result<DeliveryPage, WebhookError> {
+    let endpoint = get_webhook(conn, user_id, endpoint_id).await?;
+    let (limit, offset) = page_bounds(query.limit, query.offset)?;
+    let status = match query.status.as_deref() {
+        Some(raw) => Some(DeliveryStatus::parse(raw).ok_or_else(|| {
+            WebhookError::Validation("status must be pending, delivered, or dead".to_string())
+        })?),
+        None => None,
+    };
+    let items = repository::list_deliveries(conn, endpoint.id, status, limit, offset).await?;
+    let total = repository::count_deliveries(conn, endpoint.id, status).await?;
+    Ok(DeliveryPage {
+        items: items.into_iter().map(DeliveryResponse::from).collect(),
+        total,
+        limit,
+        offset,
+    })
+}
+
+/// Memasukkan event `webhook.test` ke antrean endpoint, tanpa memandang langganan
+/// maupun status aktifnya; dikirim worker pada polling berikutnya.
+pub async fn send_test_event(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    endpoint_id: Uuid,
+) -> Result<Delivery, WebhookError> {
+    let endpoint = get_webhook(conn, user_id, endpoint_id).await?;
+    let event_id = Uuid::new_v4();
+    let payload = event_payload(event_id, TEST_EVENT, Utc::now().fixed_offset(), json!({ "webhook_id": endpoint.id }));
+    let delivery = NewDelivery {
+        endpoint_id: endpoint.id,
+        event_id,
+        event_type: TEST_EVENT.to_string(),
+        payload,
+    };
+    repository::insert_delivery(conn, delivery)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Mengembalikan pengiriman dead-letter ke antrean dengan jatah percobaan baru.
+pub async fn redeliver(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    endpoint_id: Uuid,
+    delivery_id: Uuid,
+) -> Result<Delivery, WebhookError> {
+    let endpoint = get_webhook(conn, user_id, endpoint_id).await?;
+    let delivery = repository::find_delivery(conn, endpoint.id, delivery_id)
+        .await?
+        .ok_or(WebhookError::DeliveryNotFound)?;
+    if delivery.status != DeliveryStatus::Dead.as_str() {
+        return Err(WebhookError::Conflict(
+            "only dead deliveries can be redelivered".to_string(),
+        ));
+    }
+    repository::requeue_delivery(conn, delivery)
+        .await
+        .map_err(WebhookError::from)
+}
+
+/// Memasukkan event todo ke antrean setiap endpoint aktif milik `owner_id` yang melanggannya.
+/// `event_id` dan `occurred_at` diambil dari event outbox sehingga retry relay tetap menghasilkan
+/// id event yang sama. Mengembalikan jumlah pengiriman yang dibuat.
+pub async fn enqueue_todo_event<C: ConnectionTrait>(
+    conn: &C,
+    event: WebhookEvent,
+    event_id: Uuid,
+    occurred_at: DateTime<FixedOffset>,
+    owner_id: Uuid,
+    todo: Value,
+) -> Result<u64, DbErr> {
+    let endpoints: Vec<Model> = repository::list_active_endpoints(conn, owner_id)
+        .await?
+        .into_iter()
+        .filter(|endpoint| event_names(&endpoint.events).iter().any(|name| name == event.as_str()))
+        .collect();
+    if endpoints.is_empty() {
+        return Ok(0);
+    }
+    let payload = event_payload(event_id, event.as_str(), occurred_at, json!({ "todo": todo }));
+    for endpoint in &endpoints {
+        let delivery = NewDelivery {
+            endpoint_id: endpoint.id,
+            event_id,
+            event_type: event.as_str().to_string(),
+            payload: payload.clone(),
+        };
+        repository::insert_delivery(conn, delivery).await?;
+    }
+    Ok(endpoints.len() as u64)
+}
+
+/// Memasukkan event `notification.created` ke antrean setiap endpoint aktif milik penerima
+/// notifikasi yang melanggannya. Mengembalikan jumlah pengiriman yang dibuat.
+pub async fn enqueue_notification<C: ConnectionTrait>(conn: &C, notification: &Notification) -> Result<u64, DbErr> {
+    let event = WebhookEvent::Notification;
+    let endpoints: Vec<Model> = repository::list_active_endpoints(conn, notification.user_id)
+        .await?
+        .into_iter()
+        .filter(|endpoint| event_names(&endpoint.events).iter().any(|name| name == event.as_str()))
+        .collect();
+    if endpoints.is_empty() {
+        return Ok(0);
+    }
+    let event_id = Uuid::new_v4();
+    let data = json!({
+        "notification": {
+            "user_id": notification.user_id,
+            "kind": notification.kind,
+            "todo_id": notification.todo_id,
+            "actor_id": notification.actor_id,
+            "message": notification.message,
+        }
+    });
+    let payload = event_payload(event_id, event.as_str(), Utc::now().fixed_offset(), data);
+    for endpoint in &endpoints {
+        let delivery = NewDelivery {
+            endpoint_id: endpoint.id,
+            event_id,
+            event_type: event.as_str().to_string(),
+            payload: payload.clone(),
+        };
+        repository::insert_delivery(conn, delivery).await?;
+    }
+    Ok(endpoints.len() as u64)
+}
+
+/// Loop worker webhook untuk dijalankan lewat `tokio::spawn` saat startup.
+/// Aman dijalankan di banyak instance sekaligus karena setiap batch dikunci dengan `SKIP LOCKED`.
+pub async fn run_worker(conn: DatabaseConnection, settings: WebhookSettings) {
+    let sender = WebhookSender::new(Duration::from_secs(settings.timeout_secs), settings.destination_policy());
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.poll_interval_secs));
+    loop {
+        interval.tick().await;
+        match deliver_due_webhooks(&conn, &settings, &sender).await {
+            Ok(0) => {}
+            Ok(processed) => info!(processed, "webhook deliveries processed"),
+            Err(err) => error!(error = %err, "failed to process webhook deliveries"),
+        }
+    }
+}
+
+/// Mengirim seluruh pengiriman yang jadwalnya sudah tiba, batch demi batch.
+///
+/// Setiap batch diklaim sebagai lease (lihat `repository::claim_due_deliveries`), dikirim
+/// paralel tanpa transaksi terbuka, lalu hasilnya disimpan dalam satu transaksi pendek.
+/// Bila proses mati sebelum hasil tersimpan, batch dikirim ulang setelah lease habis
+/// (at-least-once); penerima bisa deduplikasi lewat header `x-todo-delivery`.
+/// Mengembalikan jumlah pengiriman yang dicoba.
+pub async fn deliver_due_webhooks(
+    conn: &DatabaseConnection,
+    settings: &WebhookSettings,
+    sender: &WebhookSender,
+) -> Result<u64, DbErr> {
+    let lease = lease_duration(settings);
+    let mut processed = 0;
+    loop {
+        let now = Utc::now().fixed_offset();
+        let claimed = repository::claim_due_deliveries(conn, now, now + lease, settings.batch_size).await?;
+        let batch_len = claimed.len() as u64;
+        let endpoint_ids: Vec<Uuid> = claimed.iter().map(|delivery| delivery.endpoint_id).collect();
+        let endpoints: HashMap<Uuid, Model> = repository::find_endpoints_by_ids(conn, &endpoint_ids)
+            .await?
+            .into_iter()
+            .map(|endpoint| (endpoint.id, endpoint))
+            .collect();
+        let bodies: Vec<String> = claimed.iter().map(|delivery| delivery.payload.to_string()).collect();
+        let attempts = claimed.iter().zip(&bodies).map(|(delivery, body)| {
+            let endpoint = endpoints.get(&delivery.endpoint_id);
+            async move {
+                let Some(endpoint) = endpoint else {
+                    return Err(SendError {
+                        status: None,
+                        message: "webhook endpoint not found".to_string(),
+                    });
+                };
+                let request = WebhookRequest {
+                    url: &endpoint.url,
+                    secret: &endpoint.secret,
+                    delivery_id: delivery.id,
+                    event_type: &delivery.event_type,
+                    body,
+                };
+                sender.send(request, now.timestamp()).await
+            }
+        });
+        let results = join_all(attempts).await;
+        let finished_at = Utc::now().fixed_offset();
+        let txn = conn.begin().await?;
+        for (delivery, result) in claimed.iter().zip(results) {
+            if let Err(err) = &result {
+                warn!(delivery_id = %delivery.id, error = %err, "webhook delivery failed");
+            }
+            let update = attempt_update(delivery.attempts, settings.max_attempts, result, finished_at);
+            if !repository::record_attempt(&txn, delivery, update).await? {
+                warn!(delivery_id = %delivery.id, "webhook delivery lease lost; attempt result discarded");
+            }
+        }
+        txn.commit().await?;
+        processed += batch_len;
+        if batch_len < settings.batch_size {
+            return Ok(processed);
+        }
+    }
+}
+
+// Lease klaim cukup untuk satu putaran kirim paralel (timeout per request) plus jeda penyimpanan.
+fn lease_duration(settings: &WebhookSettings) -> TimeDelta {
+    let timeout = i64::try_from(settings.timeout_secs).unwrap_or(i64::MAX).min(MAX_LEASE_TIMEOUT_SECS);
+    TimeDelta::seconds(timeout + LEASE_GRACE_SECS)
+}
+
+/// Status berikutnya setelah satu percobaan kirim. Kegagalan dijadwalkan ulang dengan jeda
+/// eksponensial (1, 2, 4, ... menit, maksimal 60) sampai `max_attempts` tercapai, lalu `dead`.
+pub fn attempt_update(
+    attempts: i32,
+    max_attempts: u32,
+    result: Result<u16, SendError>,
+    now: DateTime<FixedOffset>,
+) -> AttemptUpdate {
+    let attempts = attempts.saturating_add(1);
+    match result {
+        Ok(status) => AttemptUpdate {
+            status: DeliveryStatus::Delivered,
+            attempts,
+            next_attempt_at: None,
+            response_status: Some(i32::from(status)),
+            last_error: None,
+            delivered_at: Some(now),
+        },
+        Err(err) if u32::try_from(attempts).unwrap_or(u32::MAX) >= max_attempts => AttemptUpdate {
+            status: DeliveryStatus::Dead,
+            attempts,
+            next_attempt_at: None,
+            response_status: err.status.map(i32::from),
+            last_error: Some(err.message),
+            delivered_at: None,
+        },
+        Err(err) => {
+            let exponent = u32::try_from(attempts - 1).unwrap_or(0).min(6);
+            let delay = (1_i64 << exponent).min(MAX_RETRY_DELAY_MINUTES);
+            AttemptUpdate {
+                status: DeliveryStatus::Pending,
+                attempts,
+                next_attempt_at: Some(now + TimeDelta::minutes(delay)),
+                response_status: err.status.map(i32::from),
+                last_error: Some(err.message),
+                delivered_at: None,
+            }
+        }
+    }
+}
+
+/// Validasi URL penerima ([`validate_url`]) lalu pastikan host-nya, setelah resolusi DNS,
+/// tidak mengarah ke loopback, jaringan private, atau link-local di luar allowlist.
+pub async fn validate_destination(destinations: &DestinationPolicy, raw: &str) -> Result<String, WebhookError> {
+    let url = validate_url(raw)?;
+    let parsed = reqwest::Url::parse(&url)
+        .map_err(|_| WebhookError::Validation("url must be an absolute http or https url".to_string()))?;
+    destinations.check_url(&parsed).await.map_err(|err| match err {
+        DestinationError::Unresolvable(_) => WebhookError::Validation("url host cannot be resolved".to_string()),
+        DestinationError::Internal(_) => WebhookError::Validation(
+            "url must not point to a loopback, private, or link-local address".to_string(),
+        ),
+    })?;
+    Ok(url)
+}
+
+/// Validasi bentuk URL penerima: absolut, `http`/`https`, punya host, dan tidak terlalu panjang.
+/// Alamat tujuan diperiksa terpisah oleh [`validate_destination`].
+pub fn validate_url(raw: &str) -> Result<String, WebhookError> {
+    let url = raw.trim();
+    if url.len() > MAX_URL_LEN {
+        return Err(WebhookError::Validation(format!(
+            "url must be at most {MAX_URL_LEN} characters"
+        )));
+    }
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|_| WebhookError::Validation("url must be an absolute http or https url".to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(WebhookError::Validation(
+            "url must be an absolute http or https url".to_string(),
+        ));
+    }
+    Ok(url.to_string())
+}
+
+/// Validasi daftar langganan: minimal satu, nama dikenal, duplikat diabaikan.
+pub fn parse_events(raw: &[String]) -> Result<Vec<WebhookEvent>, WebhookError> {
+    let mut events = Vec::new();
+    for name in raw {
+        let event = WebhookEvent::parse(name.trim())
+            .ok_or_else(|| WebhookError::Validation(format!("unknown webhook event: {name}")))?;
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+    if events.is_empty() {
+        return Err(WebhookError::Validation(
+            "events must contain at least one event type".to_string(),
+        ));
+    }
+    Ok(events)
+}
+
+// Menghitung limit/offset efektif log pengiriman.
+fn page_bounds(limit: Option<u64>, offset: Option<u64>) -> Result<(u64, u64), WebhookError> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        return Err(WebhookError::Validation(format!(
+            "limit must be between 1 and {MAX_PAGE_LIMIT}"
+        )));
+    }
+    Ok((limit, offset.unwrap_or(0)))
+}
+
+// Amplop event yang sama untuk semua jenis event.
+fn event_payload(event_id: Uuid, event_type: &str, occurred_at: DateTime<FixedOffset>, data: Value) -> Value {
+    json!({
+        "id": event_id,
+        "type": event_type,
+        "created_at": occurred_at,
+        "data": data,
+    })
+}
+
+// Secret HMAC 64 karakter hex dari dua UUID v4 acak.
+fn generate_secret() -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(Uuid::new_v4().as_bytes());
+    hasher.update(Uuid::new_v4().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+// Modul tests unit untuk validasi endpoint dan jadwal retry webhook.
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2026, 2, 20, 9, 0, 0).unwrap().fixed_offset()
+    }
+
+    fn failure(status: Option<u16>) -> Result<u16, SendError> {
+        Err(SendError {
+            status,
+            message: "boom".to_string(),
+        })
+    }
+
+    #[test]
+    fn attempt_update_should_back_off_then_dead_letter() {
+        let delivered = attempt_update(0, 3, Ok(204), now());
+        assert_eq!(delivered.status, DeliveryStatus::Delivered);
+        assert_eq!(delivered.response_status, Some(204));
+        assert_eq!(delivered.delivered_at, Some(now()));
+
+        let first = attempt_update(0, 3, failure(Some(500)), now());
+        assert_eq!(first.status, DeliveryStatus::Pending);
+        assert_eq!(first.next_attempt_at, Some(now() + TimeDelta::minutes(1)));
+        assert_eq!(first.response_status, Some(500));
+        let second = attempt_update(1, 3, failure(None), now());
+        assert_eq!(second.next_attempt_at, Some(now() + TimeDelta::minutes(2)));
+        assert_eq!(second.response_status, None);
+        let capped = attempt_update(9, 20, failure(None), now());
+        assert_eq!(capped.next_attempt_at, Some(now() + TimeDelta::minutes(60)));
+
+        let dead = attempt_update(2, 3, failure(Some(502)), now());
+        assert_eq!(dead.status, DeliveryStatus::Dead);
+        assert_eq!(dead.attempts, 3);
+        assert_eq!(dead.last_error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn validate_url_should_accept_only_http_urls() {
+        assert_eq!(
+            validate_url(" https://hooks.example.com/todo ").unwrap(),
+            "https://hooks.example.com/todo"
+        );
+        for invalid in ["ftp://example.com", "/relative", "not a url"] {
+            assert!(matches!(validate_url(invalid), Err(WebhookError::Validation(_))), "{invalid}");
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_destination_should_reject_internal_addresses_outside_allowlist() {
+        let strict = DestinationPolicy::default();
+        for internal in [
+            "http://127.0.0.1:8080/hook",
+            "http://localhost/hook",
+            "http://10.0.0.8/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[fd00::1]/hook",
+        ] {
+            assert!(
+                matches!(validate_destination(&strict, internal).await, Err(WebhookError::Validation(_))),
+                "{internal}"
+            );
+        }
+        assert_eq!(
+            validate_destination(&strict, "https://93.184.216.34/hook").await.unwrap(),
+            "https://93.184.216.34/hook"
+        );
+        let local = DestinationPolicy::new(vec!["127.0.0.1".to_string()]);
+        assert!(validate_destination(&local, "http://127.0.0.1:8080/hook").await.is_ok());
+    }
+
+    #[test]
+    fn parse_events_should_reject_unknown_and_empty_lists() {
+        let events = parse_events(&[
+            "todo.created".to_string(),
+            "todo.completed".to_string(),
+            "todo.created".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(events, vec![WebhookEvent::Created, WebhookEvent::Completed]);
+        assert!(matches!(parse_events(&[]), Err(WebhookError::Validation(_))));
+        assert!(matches!(
+            parse_events(&["todo.archived".to_string()]),
+            Err(WebhookError::Validation(_))
+        ));
+        assert_eq!(generate_secret().len(), 64);
+    }
+}
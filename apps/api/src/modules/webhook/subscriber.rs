@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+
+use crate::modules::outbox::dto::TodoEventPayload;
+use crate::modules::outbox::entity::Model as OutboxEvent;
+use crate::modules::outbox::subscriber::{OutboxSubscriber, SubscriberError};
+
+use super::dto::WebhookEvent;
+use super::service;
+
+/// Subscriber outbox yang memasukkan event todo ke antrean pengiriman webhook pemilik todo.
+/// Id event outbox dipakai sebagai id event webhook agar penerima bisa deduplikasi.
+pub struct WebhookSubscriber;
+
+#[async_trait]
+impl OutboxSubscriber for WebhookSubscriber {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn handle(&self, txn: &DatabaseTransaction, event: &OutboxEvent) -> Result<(), SubscriberError> {
+        let Some(kind) = WebhookEvent::parse(&event.event_type) else {
+            return Ok(());
+        };
+        let payload: TodoEventPayload = serde_json::from_value(event.payload.clone())?;
+        let owner_id = payload.snapshot()?.user_id;
+        service::enqueue_todo_event(txn, kind, event.id, event.created_at, owner_id, payload.todo).await?;
+        Ok(())
+    }
+}
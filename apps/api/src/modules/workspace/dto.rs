@@ -0,0 +1,197 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::modules::sharing::dto::ShareRole;
+
+use super::{entity, invitation_entity, member_entity};
+
+/// Role user di dalam workspace, urut dari yang paling lemah.
+///
+/// - `guest`: hanya melihat project/todo yang dibagikan langsung kepadanya,
+/// - `member`: editor atas seluruh isi workspace, boleh membuat project/todo,
+/// - `admin`: member + kelola member, undangan, dan seluruh isi workspace,
+/// - `owner`: admin + kelola owner lain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceRole {
+    Guest,
+    Member,
+    Admin,
+    Owner,
+}
+
+impl WorkspaceRole {
+    /// Nilai string yang disimpan di kolom `role`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Guest => "guest",
+            Self::Member => "member",
+            Self::Admin => "admin",
+            Self::Owner => "owner",
+        }
+    }
+
+    /// Kebalikan dari `as_str`; `None` bila nilai tidak dikenal.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "guest" => Some(Self::Guest),
+            "member" => Some(Self::Member),
+            "admin" => Some(Self::Admin),
+            "owner" => Some(Self::Owner),
+            _ => None,
+        }
+    }
+
+    /// Role share efektif atas seluruh project/todo di workspace; guest tidak mendapat akses otomatis.
+    pub fn content_role(self) -> Option<ShareRole> {
+        match self {
+            Self::Guest => None,
+            Self::Member => Some(ShareRole::Editor),
+            Self::Admin | Self::Owner => Some(ShareRole::Owner),
+        }
+    }
+}
+
+/// Status undangan workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Revoked,
+}
+
+impl InvitationStatus {
+    /// Nilai string yang disimpan di kolom `status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Accepted => "accepted",
+            Self::Declined => "declined",
+            Self::Revoked => "revoked",
+        }
+    }
+}
+
+/// Payload request untuk membuat workspace baru.
+#[derive(Debug, Deserialize)]
+pub struct CreateWorkspaceRequest {
+    pub name: String,
+}
+
+/// Payload request untuk mengubah workspace.
+#[derive(Debug, Deserialize)]
+pub struct UpdateWorkspaceRequest {
+    /// Nama baru; `None` berarti tidak diubah.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Payload request untuk mengubah role member.
+#[derive(Debug, Deserialize)]
+pub struct UpdateMemberRequest {
+    /// `owner`, `admin`, `member`, atau `guest`.
+    pub role: String,
+}
+
+/// Query opsional saat mengeluarkan member.
+#[derive(Debug, Default, Deserialize)]
+pub struct RemoveMemberQuery {
+    /// Member penerima todo yang ditugaskan ke member yang dikeluarkan.
+    /// Bila kosong, todo tersebut menjadi tanpa assignee.
+    #[serde(default)]
+    pub reassign_to: Option<Uuid>,
+}
+
+/// Payload request untuk mengundang user ke workspace.
+#[derive(Debug, Deserialize)]
+pub struct CreateInvitationRequest {
+    pub email: String,
+    /// `admin`, `member`, atau `guest`.
+    pub role: String,
+}
+
+/// Payload request untuk menerima/menolak undangan.
+#[derive(Debug, Deserialize)]
+pub struct InvitationTokenRequest {
+    pub token: String,
+}
+
+/// Bentuk data workspace yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct WorkspaceResponse {
+    pub id: Uuid,
+    pub name: String,
+    /// Role user saat ini di workspace.
+    pub role: WorkspaceRole,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl WorkspaceResponse {
+    /// Mapping model database + role user saat ini ke response HTTP.
+    pub fn new(value: entity::Model, role: WorkspaceRole) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            role,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Bentuk data member workspace yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct MemberResponse {
+    pub user_id: Uuid,
+    pub role: String,
+    pub joined_at: DateTime<FixedOffset>,
+}
+
+impl From<member_entity::Model> for MemberResponse {
+    /// Mapping model database ke response HTTP.
+    fn from(value: member_entity::Model) -> Self {
+        Self {
+            user_id: value.user_id,
+            role: value.role,
+            joined_at: value.created_at,
+        }
+    }
+}
+
+/// Bentuk data undangan yang dikirim kembali ke client.
+#[derive(Debug, Serialize)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub email: String,
+    pub role: String,
+    pub status: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<FixedOffset>,
+    pub responded_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    /// Token asli; hanya dikirim sekali saat undangan dibuat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl From<invitation_entity::Model> for InvitationResponse {
+    /// Mapping model database ke response HTTP (tanpa token).
+    fn from(value: invitation_entity::Model) -> Self {
+        Self {
+            id: value.id,
+            workspace_id: value.workspace_id,
+            email: value.email,
+            role: value.role,
+            status: value.status,
+            invited_by: value.invited_by,
+            expires_at: value.expires_at,
+            responded_at: value.responded_at,
+            created_at: value.created_at,
+            token: None,
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, patch, post},
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::common::current_user::CurrentUser;
+use crate::common::error::ApiError;
+use crate::common::response::SuccessResponse;
+use crate::modules::history::dto::AuditContext;
+
+use super::dto::{
+    CreateInvitationRequest, CreateWorkspaceRequest, InvitationResponse, InvitationTokenRequest,
+    MemberResponse, RemoveMemberQuery, UpdateMemberRequest, UpdateWorkspaceRequest, WorkspaceResponse,
+    WorkspaceRole,
+};
+use super::service::{self, WorkspaceError};
+
+/// Registrasi route workspace, di-mount di bawah prefix `/workspaces`.
+///
+/// - `POST /` buat workspace (pembuat menjadi owner)
+/// - `GET /` daftar workspace user
+/// - `GET /{id}` detail workspace
+/// - `PATCH /{id}` ubah workspace (admin)
+/// - `GET /{id}/members` daftar member
+/// - `PATCH /{id}/members/{user_id}` ubah role member (admin)
+/// - `DELETE /{id}/members/{user_id}` keluarkan member atau keluar sendiri
+/// - `POST /{id}/invitations` buat undangan (admin)
+/// - `GET /{id}/invitations` daftar undangan (admin)
+/// - `DELETE /{id}/invitations/{invitation_id}` cabut undangan (admin)
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_workspace).get(list_workspaces))
+        .route("/{id}", get(get_workspace).patch(update_workspace))
+        .route("/{id}/members", get(list_members))
+        .route(
+            "/{id}/members/{user_id}",
+            patch(update_member).delete(remove_member),
+        )
+        .route(
+            "/{id}/invitations",
+            post(create_invitation).get(list_invitations),
+        )
+        .route("/{id}/invitations/{invitation_id}", delete(revoke_invitation))
+}
+
+/// Route tanpa prefix resource:
+/// - `POST /invitations/accept` terima undangan
+/// - `POST /invitations/decline` tolak undangan
+///
+/// Token dikirim lewat body, bukan path, agar tidak ikut tercatat di access log.
+pub fn invitation_routes() -> Router<AppState> {
+    Router::new()
+        .route("/invitations/accept", post(accept_invitation))
+        .route("/invitations/decline", post(decline_invitation))
+}
+
+/// POST `/workspaces`
+///
+/// Input: `CreateWorkspaceRequest`.
+/// Output: `201 Created` + `SuccessResponse<WorkspaceResponse>`.
+pub async fn create_workspace(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<CreateWorkspaceRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<WorkspaceResponse>>), ApiError> {
+    let workspace = service::create_workspace(&state.db, user.id, payload)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace.id, "workspace created");
+    Ok((
+        StatusCode::CREATED,
+        Json(SuccessResponse {
+            data: WorkspaceResponse::new(workspace, WorkspaceRole::Owner),
+        }),
+    ))
+}
+
+/// GET `/workspaces`
+/// Output: `200 OK` + daftar workspace beserta role user.
+pub async fn list_workspaces(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<SuccessResponse<Vec<WorkspaceResponse>>>, ApiError> {
+    let workspaces = service::list_workspaces(&state.db, user.id)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(count = workspaces.len(), "workspaces listed");
+    let data = workspaces
+        .into_iter()
+        .map(|(workspace, role)| WorkspaceResponse::new(workspace, role))
+        .collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// GET `/workspaces/{id}`
+/// Output: `200 OK` bila user member, `404` bila bukan.
+pub async fn get_workspace(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(workspace_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<WorkspaceResponse>>, ApiError> {
+    let (workspace, role) = service::get_workspace(&state.db, user.id, workspace_id)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, "workspace detail fetched");
+    Ok(Json(SuccessResponse {
+        data: WorkspaceResponse::new(workspace, role),
+    }))
+}
+
+/// PATCH `/workspaces/{id}`
+/// Input: `UpdateWorkspaceRequest`.
+/// Output: `200 OK` dengan data workspace terbaru.
+pub async fn update_workspace(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(workspace_id): Path<Uuid>,
+    Json(payload): Json<UpdateWorkspaceRequest>,
+) -> Result<Json<SuccessResponse<WorkspaceResponse>>, ApiError> {
+    let (workspace, role) = service::update_workspace(&state.db, user.id, workspace_id, payload)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, "workspace updated");
+    Ok(Json(SuccessResponse {
+        data: WorkspaceResponse::new(workspace, role),
+    }))
+}
+
+/// GET `/workspaces/{id}/members`
+/// Output: `200 OK` + daftar member.
+pub async fn list_members(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(workspace_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<MemberResponse>>>, ApiError> {
+    let members = service::list_members(&state.db, user.id, workspace_id)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, count = members.len(), "workspace members listed");
+    let data = members.into_iter().map(MemberResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// PATCH `/workspaces/{id}/members/{user_id}`
+/// Input: `UpdateMemberRequest`.
+/// Output: `200 OK` + data member terbaru.
+pub async fn update_member(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((workspace_id, member_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateMemberRequest>,
+) -> Result<Json<SuccessResponse<MemberResponse>>, ApiError> {
+    let member = service::update_member_role(&state.db, user.id, workspace_id, member_id, payload)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, member_id = %member_id, role = %member.role, "workspace member updated");
+    Ok(Json(SuccessResponse { data: member.into() }))
+}
+
+/// DELETE `/workspaces/{id}/members/{user_id}`
+/// Query: `reassign_to` opsional (member penerima todo yang ditugaskan ke member ini).
+/// Output: `204 No Content`.
+pub async fn remove_member(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((workspace_id, member_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<RemoveMemberQuery>,
+) -> Result<StatusCode, ApiError> {
+    let audit = AuditContext::new(user.id, &state.history);
+    service::remove_member(&state.db, workspace_id, member_id, query.reassign_to, &audit)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(
+        workspace_id = %workspace_id,
+        member_id = %member_id,
+        reassign_to = ?query.reassign_to,
+        "workspace member removed"
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST `/workspaces/{id}/invitations`
+/// Input: `CreateInvitationRequest`.
+/// Output: `201 Created` + undangan beserta `token` (hanya dikirim sekali ini).
+pub async fn create_invitation(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(workspace_id): Path<Uuid>,
+    Json(payload): Json<CreateInvitationRequest>,
+) -> Result<(StatusCode, Json<SuccessResponse<InvitationResponse>>), ApiError> {
+    let issued = service::create_invitation(&state.db, &state.workspaces, user.id, workspace_id, payload)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, invitation_id = %issued.invitation.id, "workspace invitation created");
+    let data = InvitationResponse {
+        token: Some(issued.token),
+        ..issued.invitation.into()
+    };
+    Ok((StatusCode::CREATED, Json(SuccessResponse { data })))
+}
+
+/// GET `/workspaces/{id}/invitations`
+/// Output: `200 OK` + daftar undangan (tanpa token).
+pub async fn list_invitations(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(workspace_id): Path<Uuid>,
+) -> Result<Json<SuccessResponse<Vec<InvitationResponse>>>, ApiError> {
+    let invitations = service::list_invitations(&state.db, user.id, workspace_id)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, count = invitations.len(), "workspace invitations listed");
+    let data = invitations.into_iter().map(InvitationResponse::from).collect();
+    Ok(Json(SuccessResponse { data }))
+}
+
+/// DELETE `/workspaces/{id}/invitations/{invitation_id}`
+/// Output: `204 No Content`.
+pub async fn revoke_invitation(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path((workspace_id, invitation_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    service::revoke_invitation(&state.db, user.id, workspace_id, invitation_id)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace_id, invitation_id = %invitation_id, "workspace invitation revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST `/invitations/accept`
+/// Input: `InvitationTokenRequest`.
+/// Output: `200 OK` + workspace yang baru diikuti beserta role user.
+pub async fn accept_invitation(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<InvitationTokenRequest>,
+) -> Result<Json<SuccessResponse<WorkspaceResponse>>, ApiError> {
+    let (workspace, role) = service::accept_invitation(&state.db, user.id, &payload.token)
+        .await
+        .map_err(map_workspace_error)?;
+    info!(workspace_id = %workspace.id, role = role.as_str(), "workspace invitation accepted");
+    Ok(Json(SuccessResponse {
+        data: WorkspaceResponse::new(workspace, role),
+    }))
+}
+
+/// POST `/invitations/decline`
+/// Input: `InvitationTokenRequest`.
+/// Output: `204 No Content`.
+pub async fn decline_invitation(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(payload): Json<InvitationTokenRequest>,
+) -> Result<StatusCode, ApiError> {
+    service::decline_invitation(&state.db, user.id, &payload.token)
+        .await
+        .map_err(map_workspace_error)?;
+    info!("workspace invitation declined");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mapping error domain workspace ke `ApiError`.
+fn map_workspace_error(err: WorkspaceError) -> ApiError {
+    match err {
+        WorkspaceError::Validation(message) => {
+            warn!(reason = %message, "workspace validation failed");
+            ApiError::bad_request(message)
+        }
+        WorkspaceError::NotFound => {
+            warn!("workspace not found");
+            ApiError::not_found("workspace not found")
+        }
+        WorkspaceError::Forbidden => {
+            warn!("workspace action rejected for current role");
+            ApiError::forbidden("workspace role does not allow this action")
+        }
+        WorkspaceError::MemberNotFound => {
+            warn!("workspace member not found");
+            ApiError::not_found("member not found")
+        }
+        WorkspaceError::InvitationNotFound => {
+            warn!("workspace invitation not found");
+            ApiError::not_found("invitation not found")
+        }
+        WorkspaceError::Conflict(message) => {
+            warn!(reason = %message, "workspace invitation conflict");
+            ApiError::conflict(message)
+        }
+        WorkspaceError::Database(db_err) => {
+            error!(error = %db_err, "workspace database operation failed");
+            ApiError::internal("unexpected database error")
+        }
+    }
+}
+
+// Modul tests integrasi endpoint workspace terhadap database lokal.
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::Request,
+    };
+    use sea_orm::{ColumnTrait, Database, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::common::current_user::{USER_ID_HEADER, WORKSPACE_ID_HEADER};
+    use crate::modules::outbox::entity as outbox_entity;
+    use crate::modules::todo::{handler as todo_handler, repository as todo_repository};
+
+    use super::*;
+
+    // Owner, calon member, dan orang luar acak agar tidak bentrok dengan data test lain.
+    async fn build_test_app() -> (Router, DatabaseConnection, Uuid, Uuid, Uuid) {
+        dotenvy::dotenv().ok();
+        let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must exist for integration tests");
+        let db = Database::connect(db_url)
+            .await
+            .expect("database must be reachable for integration tests");
+        let owner_id = Uuid::new_v4();
+        let teammate_id = Uuid::new_v4();
+        let outsider_id = Uuid::new_v4();
+        for user_id in [owner_id, teammate_id, outsider_id] {
+            todo_repository::ensure_default_user(&db, user_id)
+                .await
+                .expect("user seed must succeed");
+        }
+        let app = Router::new()
+            .merge(invitation_routes())
+            .nest("/workspaces", routes())
+            .nest("/todos", todo_handler::routes())
+            .with_state(AppState::for_tests(db.clone(), owner_id));
+        (app, db, owner_id, teammate_id, outsider_id)
+    }
+
+    async fn send(
+        app: &Router,
+        as_user: Uuid,
+        workspace_id: Option<&str>,
+        method: &str,
+        uri: String,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(USER_ID_HEADER, as_user.to_string());
+        if let Some(workspace_id) = workspace_id {
+            builder = builder.header(WORKSPACE_ID_HEADER, workspace_id);
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request should be valid");
+        app.clone().oneshot(request).await.expect("response expected")
+    }
+
+    async fn read_json(response: axum::response::Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body must be readable");
+        serde_json::from_slice(&body).expect("body must be valid json")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn invitation_and_member_removal_flow_should_succeed() {
+        let (app, db, owner, teammate, outsider) = build_test_app().await;
+        let created = send(&app, owner, None, "POST", "/workspaces".to_string(), Some(json!({ "name": "Tim Rilis" }))).await;
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let workspace = read_json(created).await;
+        let workspace_id = workspace["data"]["id"].as_str().expect("workspace id must exist").to_string();
+        let ws = Some(workspace_id.as_str());
+
+        let invited = send(
+            &app,
+            owner,
+            None,
+            "POST",
+            format!("/workspaces/{workspace_id}/invitations"),
+            Some(json!({ "email": format!("Default-{teammate}@local.todo"), "role": "member" })),
+        )
+        .await;
+        assert_eq!(invited.status(), StatusCode::CREATED);
+        let token = read_json(invited).await["data"]["token"]
+            .as_str()
+            .expect("token must be returned once")
+            .to_string();
+
+        let wrong_user = send(&app, outsider, None, "POST", "/invitations/accept".to_string(), Some(json!({ "token": token }))).await;
+        assert_eq!(wrong_user.status(), StatusCode::NOT_FOUND);
+        let accepted = send(&app, teammate, None, "POST", "/invitations/accept".to_string(), Some(json!({ "token": token }))).await;
+        assert_eq!(accepted.status(), StatusCode::OK);
+        assert_eq!(read_json(accepted).await["data"]["role"], "member");
+        let reused = send(&app, teammate, None, "POST", "/invitations/accept".to_string(), Some(json!({ "token": token }))).await;
+        assert_eq!(reused.status(), StatusCode::CONFLICT);
+
+        let todo = read_json(
+            send(
+                &app,
+                owner,
+                ws,
+                "POST",
+                "/todos".to_string(),
+                Some(json!({ "title": "workspace todo", "assignee_id": teammate })),
+            )
+            .await,
+        )
+        .await;
+        let todo_id = todo["data"]["id"].as_str().expect("todo id must exist").to_string();
+        assert_eq!(todo["data"]["workspace_id"], workspace_id.as_str());
+
+        let scoped = read_json(send(&app, teammate, ws, "GET", "/todos".to_string(), None).await).await;
+        assert_eq!(scoped["data"].as_array().map(Vec::len), Some(1));
+        let personal = read_json(send(&app, teammate, None, "GET", "/todos".to_string(), None).await).await;
+        assert_eq!(personal["data"].as_array().map(Vec::len), Some(0));
+        let outside = send(&app, outsider, ws, "GET", "/todos".to_string(), None).await;
+        assert_eq!(outside.status(), StatusCode::NOT_FOUND);
+
+        let removed = send(&app, owner, None, "DELETE", format!("/workspaces/{workspace_id}/members/{teammate}"), None).await;
+        assert_eq!(removed.status(), StatusCode::NO_CONTENT);
+        let orphaned = read_json(send(&app, owner, ws, "GET", format!("/todos/{todo_id}"), None).await).await;
+        assert_eq!(orphaned["data"]["assignee_id"], Value::Null);
+        let todo_uuid = Uuid::parse_str(&todo_id).expect("todo id must be a uuid");
+        let reassigned = outbox_entity::Entity::find()
+            .filter(outbox_entity::Column::AggregateId.eq(todo_uuid))
+            .order_by_desc(outbox_entity::Column::Sequence)
+            .one(&db)
+            .await
+            .expect("outbox query must succeed")
+            .expect("reassignment must be recorded in the outbox");
+        assert_eq!(reassigned.event_type, "todo.updated");
+        assert_eq!(reassigned.payload["previous_assignee_id"], teammate.to_string());
+        let gone = send(&app, teammate, None, "GET", format!("/todos/{todo_id}"), None).await;
+        assert_eq!(gone.status(), StatusCode::NOT_FOUND);
+
+        let last_owner = send(&app, owner, None, "DELETE", format!("/workspaces/{workspace_id}/members/{owner}"), None).await;
+        assert_eq!(last_owner.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running local database"]
+    async fn concurrent_owner_departures_should_keep_one_owner() {
+        let (app, _, owner, teammate, _) = build_test_app().await;
+        for _ in 0..3 {
+            let created = send(&app, owner, None, "POST", "/workspaces".to_string(), Some(json!({ "name": "Dua Owner" }))).await;
+            let workspace_id = read_json(created).await["data"]["id"]
+                .as_str()
+                .expect("workspace id must exist")
+                .to_string();
+            let invited = send(
+                &app,
+                owner,
+                None,
+                "POST",
+                format!("/workspaces/{workspace_id}/invitations"),
+                Some(json!({ "email": format!("Default-{teammate}@local.todo"), "role": "admin" })),
+            )
+            .await;
+            let token = read_json(invited).await["data"]["token"]
+                .as_str()
+                .expect("token must be returned once")
+                .to_string();
+            send(&app, teammate, None, "POST", "/invitations/accept".to_string(), Some(json!({ "token": token }))).await;
+            let promoted = send(
+                &app,
+                owner,
+                None,
+                "PATCH",
+                format!("/workspaces/{workspace_id}/members/{teammate}"),
+                Some(json!({ "role": "owner" })),
+            )
+            .await;
+            assert_eq!(promoted.status(), StatusCode::OK);
+
+            let (left, other_left) = tokio::join!(
+                send(&app, owner, None, "DELETE", format!("/workspaces/{workspace_id}/members/{owner}"), None),
+                send(&app, teammate, None, "DELETE", format!("/workspaces/{workspace_id}/members/{teammate}"), None),
+            );
+            let mut statuses = [left.status(), other_left.status()];
+            statuses.sort();
+            assert_eq!(statuses, [StatusCode::NO_CONTENT, StatusCode::BAD_REQUEST]);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Entity SeaORM untuk tabel `workspace_invitations`.
+/// Token asli tidak disimpan; hanya hash SHA-256 (hex) di `token_hash`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "workspace_invitations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    /// Email tujuan (lowercase); hanya user dengan email ini yang bisa menerima.
+    pub email: String,
+    /// Salah satu dari `admin`, `member`, `guest`.
+    pub role: String,
+    pub token_hash: String,
+    /// Salah satu dari `pending`, `accepted`, `declined`, `revoked`.
+    pub status: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<FixedOffset>,
+    pub responded_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
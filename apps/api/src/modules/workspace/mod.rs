@@ -0,0 +1,9 @@
+//! Modul workspace: workspace tim, membership berbasis role, dan undangan bertoken.
+
+pub mod dto;
+pub mod entity;
+pub mod handler;
+pub mod invitation_entity;
+pub mod member_entity;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,226 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, Statement, TransactionTrait,
+    sea_query::{Query, SelectStatement},
+};
+use uuid::Uuid;
+
+use super::dto::{InvitationStatus, WorkspaceRole};
+use super::entity::{self, ActiveModel, Column, Entity, Model};
+use super::invitation_entity::{self as invitation, Model as Invitation};
+use super::member_entity::{self as member, Model as Member};
+
+/// Data undangan baru siap simpan.
+#[derive(Debug, Clone)]
+pub struct NewInvitation {
+    pub workspace_id: Uuid,
+    pub email: String,
+    pub role: WorkspaceRole,
+    pub token_hash: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<FixedOffset>,
+}
+
+/// Menyimpan workspace baru sekaligus menjadikan `owner_id` sebagai owner-nya.
+pub async fn create_workspace(conn: &DatabaseConnection, owner_id: Uuid, name: String) -> Result<Model, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+    let workspace = ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&txn)
+    .await?;
+    add_member(&txn, workspace.id, owner_id, WorkspaceRole::Owner).await?;
+    txn.commit().await?;
+    Ok(workspace)
+}
+
+/// Mengambil satu workspace berdasarkan id tanpa filter akses.
+pub async fn find_workspace_by_id<C: ConnectionTrait>(conn: &C, workspace_id: Uuid) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(workspace_id).one(conn).await
+}
+
+/// Mengambil workspace berdasarkan daftar id.
+pub async fn find_workspaces_by_ids(conn: &DatabaseConnection, workspace_ids: &[Uuid]) -> Result<Vec<Model>, DbErr> {
+    if workspace_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Entity::find()
+        .filter(Column::Id.is_in(workspace_ids.iter().copied()))
+        .order_by(Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengganti nama workspace dan memperbarui `updated_at`.
+pub async fn rename_workspace(conn: &DatabaseConnection, existing: Model, name: String) -> Result<Model, DbErr> {
+    let mut active: entity::ActiveModel = existing.into();
+    active.name = Set(name);
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Mengambil membership user di workspace.
+pub async fn find_member<C: ConnectionTrait>(
+    conn: &C,
+    workspace_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Member>, DbErr> {
+    member::Entity::find_by_id((workspace_id, user_id)).one(conn).await
+}
+
+/// Mengambil seluruh member workspace, urut paling lama bergabung.
+pub async fn list_members(conn: &DatabaseConnection, workspace_id: Uuid) -> Result<Vec<Member>, DbErr> {
+    member::Entity::find()
+        .filter(member::Column::WorkspaceId.eq(workspace_id))
+        .order_by(member::Column::CreatedAt, Order::Asc)
+        .all(conn)
+        .await
+}
+
+/// Mengambil seluruh membership milik user.
+pub async fn list_memberships_for_user(conn: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Member>, DbErr> {
+    member::Entity::find()
+        .filter(member::Column::UserId.eq(user_id))
+        .all(conn)
+        .await
+}
+
+/// Menghitung member workspace dengan role tertentu (dipakai untuk menjaga owner terakhir).
+pub async fn count_members_with_role<C: ConnectionTrait>(
+    conn: &C,
+    workspace_id: Uuid,
+    role: WorkspaceRole,
+) -> Result<u64, DbErr> {
+    member::Entity::find()
+        .filter(member::Column::WorkspaceId.eq(workspace_id))
+        .filter(member::Column::Role.eq(role.as_str()))
+        .count(conn)
+        .await
+}
+
+/// Menambahkan user sebagai member workspace.
+pub async fn add_member<C: ConnectionTrait>(
+    conn: &C,
+    workspace_id: Uuid,
+    user_id: Uuid,
+    role: WorkspaceRole,
+) -> Result<Member, DbErr> {
+    let now = Utc::now().fixed_offset();
+    member::ActiveModel {
+        workspace_id: Set(workspace_id),
+        user_id: Set(user_id),
+        role: Set(role.as_str().to_string()),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(conn)
+    .await
+}
+
+/// Mengganti role member.
+pub async fn update_member_role<C: ConnectionTrait>(
+    conn: &C,
+    existing: Member,
+    role: WorkspaceRole,
+) -> Result<Member, DbErr> {
+    let mut active: member::ActiveModel = existing.into();
+    active.role = Set(role.as_str().to_string());
+    active.updated_at = Set(Utc::now().fixed_offset());
+    active.update(conn).await
+}
+
+/// Mengambil advisory lock transaksi atas daftar member workspace, agar cek owner terakhir dan
+/// perubahan role/keanggotaan tidak balapan. Lock dilepas otomatis saat transaksi `conn` selesai.
+pub async fn lock_members<C: ConnectionTrait>(conn: &C, workspace_id: Uuid) -> Result<(), DbErr> {
+    let key = format!("workspace_members:{workspace_id}");
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))",
+        [key.into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Mengeluarkan user dari workspace.
+pub async fn delete_member<C: ConnectionTrait>(conn: &C, workspace_id: Uuid, user_id: Uuid) -> Result<(), DbErr> {
+    member::Entity::delete_by_id((workspace_id, user_id)).exec(conn).await?;
+    Ok(())
+}
+
+/// Subquery id workspace tempat user menjadi member non-guest (akses otomatis ke isi workspace).
+pub fn content_workspace_ids_query(user_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(member::Column::WorkspaceId)
+        .from(member::Entity)
+        .and_where(member::Column::UserId.eq(user_id))
+        .and_where(member::Column::Role.ne(WorkspaceRole::Guest.as_str()))
+        .to_owned()
+}
+
+/// Menyimpan undangan baru berstatus `pending`.
+pub async fn create_invitation(conn: &DatabaseConnection, new_invitation: NewInvitation) -> Result<Invitation, DbErr> {
+    invitation::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        workspace_id: Set(new_invitation.workspace_id),
+        email: Set(new_invitation.email),
+        role: Set(new_invitation.role.as_str().to_string()),
+        token_hash: Set(new_invitation.token_hash),
+        status: Set(InvitationStatus::Pending.as_str().to_string()),
+        invited_by: Set(new_invitation.invited_by),
+        expires_at: Set(new_invitation.expires_at),
+        responded_at: Set(None),
+        created_at: Set(Utc::now().fixed_offset()),
+    }
+    .insert(conn)
+    .await
+}
+
+/// Mengambil undangan berdasarkan hash token.
+pub async fn find_invitation_by_token_hash(
+    conn: &DatabaseConnection,
+    token_hash: &str,
+) -> Result<Option<Invitation>, DbErr> {
+    invitation::Entity::find()
+        .filter(invitation::Column::TokenHash.eq(token_hash))
+        .one(conn)
+        .await
+}
+
+/// Mengambil undangan milik workspace tertentu.
+pub async fn find_invitation(
+    conn: &DatabaseConnection,
+    workspace_id: Uuid,
+    invitation_id: Uuid,
+) -> Result<Option<Invitation>, DbErr> {
+    invitation::Entity::find_by_id(invitation_id)
+        .filter(invitation::Column::WorkspaceId.eq(workspace_id))
+        .one(conn)
+        .await
+}
+
+/// Mengambil seluruh undangan workspace, urut terbaru lebih dulu.
+pub async fn list_invitations(conn: &DatabaseConnection, workspace_id: Uuid) -> Result<Vec<Invitation>, DbErr> {
+    invitation::Entity::find()
+        .filter(invitation::Column::WorkspaceId.eq(workspace_id))
+        .order_by(invitation::Column::CreatedAt, Order::Desc)
+        .all(conn)
+        .await
+}
+
+/// Menandai undangan dengan status akhir dan waktu respon.
+pub async fn set_invitation_status<C: ConnectionTrait>(
+    conn: &C,
+    existing: Invitation,
+    status: InvitationStatus,
+) -> Result<Invitation, DbErr> {
+    let mut active: invitation::ActiveModel = existing.into();
+    active.status = Set(status.as_str().to_string());
+    active.responded_at = Set(Some(Utc::now().fixed_offset()));
+    active.update(conn).await
+}
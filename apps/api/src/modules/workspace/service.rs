@@ -0,0 +1,536 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::common::authz::{self, AccessError, Permission, Resource, Role};
+use crate::config::workspace::WorkspaceSettings;
+use crate::modules::history::dto::AuditContext;
+use crate::modules::sharing::repository as sharing_repository;
+use crate::modules::todo::repository as todo_repository;
+use crate::modules::todo::service as todo_service;
+use crate::modules::user::repository as user_repository;
+
+use super::dto::{
+    CreateInvitationRequest, CreateWorkspaceRequest, InvitationStatus, UpdateMemberRequest,
+    UpdateWorkspaceRequest, WorkspaceRole,
+};
+use super::entity::Model;
+use super::invitation_entity::Model as Invitation;
+use super::member_entity::Model as Member;
+use super::repository::{self, NewInvitation};
+
+const MAX_NAME_LEN: usize = 200;
+const MAX_EMAIL_LEN: usize = 255;
+
+/// Error domain workspace yang dipetakan ke HTTP error di layer handler.
+#[derive(Debug)]
+pub enum WorkspaceError {
+    Validation(String),
+    /// Workspace tidak ada atau user bukan member-nya.
+    NotFound,
+    Forbidden,
+    MemberNotFound,
+    InvitationNotFound,
+    /// Undangan sudah direspons, dicabut, atau kedaluwarsa.
+    Conflict(String),
+    Database(DbErr),
+}
+
+impl From<DbErr> for WorkspaceError {
+    fn from(value: DbErr) -> Self {
+        Self::Database(value)
+    }
+}
+
+impl From<AccessError> for WorkspaceError {
+    fn from(value: AccessError) -> Self {
+        match value {
+            AccessError::NotFound => Self::NotFound,
+            AccessError::Forbidden => Self::Forbidden,
+            AccessError::Database(err) => Self::Database(err),
+        }
+    }
+}
+
+/// Undangan baru beserta token aslinya (hanya tersedia saat dibuat).
+#[derive(Debug)]
+pub struct IssuedInvitation {
+    pub invitation: Invitation,
+    pub token: String,
+}
+
+/// Mengambil role user di workspace bila role tersebut memberi `action`.
+/// Bukan member dianggap `NotFound` agar keberadaan workspace tidak bocor.
+pub async fn authorize_workspace<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    workspace_id: Uuid,
+    action: Permission,
+) -> Result<WorkspaceRole, AccessError> {
+    let role = authz::authorize(conn, user_id, action, Resource::Workspace(workspace_id)).await?;
+    Ok(role.workspace_role())
+}
+
+/// Membuat workspace baru dengan user saat ini sebagai owner.
+pub async fn create_workspace(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: CreateWorkspaceRequest,
+) -> Result<Model, WorkspaceError> {
+    let name = normalize_name(request.name)?;
+    repository::create_workspace(conn, user_id, name)
+        .await
+        .map_err(WorkspaceError::from)
+}
+
+/// Mengambil seluruh workspace tempat user menjadi member, beserta role-nya.
+pub async fn list_workspaces(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> Result<Vec<(Model, WorkspaceRole)>, WorkspaceError> {
+    let memberships = repository::list_memberships_for_user(conn, user_id).await?;
+    let workspace_ids: Vec<Uuid> = memberships.iter().map(|member| member.workspace_id).collect();
+    let workspaces = repository::find_workspaces_by_ids(conn, &workspace_ids).await?;
+    let with_roles = workspaces
+        .into_iter()
+        .map(|workspace| {
+            let role = memberships
+                .iter()
+                .find(|member| member.workspace_id == workspace.id)
+                .and_then(|member| WorkspaceRole::parse(&member.role))
+                .unwrap_or(WorkspaceRole::Guest);
+            (workspace, role)
+        })
+        .collect();
+    Ok(with_roles)
+}
+
+/// Mengambil detail workspace; semua member boleh.
+pub async fn get_workspace(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<(Model, WorkspaceRole), WorkspaceError> {
+    let role = authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceRead).await?;
+    let workspace = repository::find_workspace_by_id(conn, workspace_id)
+        .await?
+        .ok_or(WorkspaceError::NotFound)?;
+    Ok((workspace, role))
+}
+
+/// Mengubah workspace; minimal admin.
+pub async fn update_workspace(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Uuid,
+    request: UpdateWorkspaceRequest,
+) -> Result<(Model, WorkspaceRole), WorkspaceError> {
+    let role = authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceManage).await?;
+    let workspace = repository::find_workspace_by_id(conn, workspace_id)
+        .await?
+        .ok_or(WorkspaceError::NotFound)?;
+    let Some(name) = request.name else {
+        return Ok((workspace, role));
+    };
+    let name = normalize_name(name)?;
+    let updated = repository::rename_workspace(conn, workspace, name).await?;
+    Ok((updated, role))
+}
+
+/// Daftar member workspace; semua member boleh.
+pub async fn list_members(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<Vec<Member>, WorkspaceError> {
+    authorize_workspace(conn, user_id, workspace_id, Permission::WorkspaceRead).await?;
+    repository::list_members(conn, workspace_id)
+        .await
+        .map_err(WorkspaceError::from)
+}
+
+/// Mengubah role member. Minimal admin; menyentuh role owner (memberi atau mencabut) hanya owner.
+/// Owner terakhir tidak bisa diturunkan.
+pub async fn update_member_role(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    workspace_id: Uuid,
+    member_id: Uuid,
+    request: UpdateMemberRequest,
+) -> Result<Member, WorkspaceError> {
+    let new_role = parse_role(&request.role)?;
+    let txn = conn.begin().await?;
+    repository::lock_members(&txn, workspace_id).await?;
+    let actor_role = authorize_workspace(&txn, actor_id, workspace_id, Permission::MemberManage).await?;
+    let member = repository::find_member(&txn, workspace_id, member_id)
+        .await?
+        .ok_or(WorkspaceError::MemberNotFound)?;
+    let current_role = WorkspaceRole::parse(&member.role).unwrap_or(WorkspaceRole::Guest);
+    check_role_change(actor_role, current_role, new_role)?;
+    if current_role == WorkspaceRole::Owner && new_role != WorkspaceRole::Owner {
+        ensure_not_last_owner(&txn, workspace_id).await?;
+    }
+    let updated = repository::update_member_role(&txn, member, new_role).await?;
+    txn.commit().await?;
+    Ok(updated)
+}
+
+/// Mengeluarkan member (atau keluar sendiri bila `actor_id == member_id`).
+///
+/// Aturan yang berlaku saat member keluar/dikeluarkan:
+/// - todo workspace yang ditugaskan kepadanya dialihkan ke `reassign_to`, atau dikosongkan
+///   bila `reassign_to` tidak diisi (dicatat sebagai event `assigned`),
+/// - seluruh share grant miliknya atas isi workspace dihapus,
+/// - project/todo miliknya tetap ada di workspace dan dikelola admin; ia tidak lagi punya akses.
+///
+/// Cek role dan owner terakhir, pengalihan todo (beserta event `todo.updated` di outbox),
+/// penghapusan grant, dan penghapusan member berjalan dalam satu transaksi yang mengunci daftar
+/// member workspace, sehingga dua owner terakhir tidak bisa saling mengeluarkan bersamaan.
+pub async fn remove_member(
+    conn: &DatabaseConnection,
+    workspace_id: Uuid,
+    member_id: Uuid,
+    reassign_to: Option<Uuid>,
+    audit: &AuditContext,
+) -> Result<(), WorkspaceError> {
+    let txn = conn.begin().await?;
+    repository::lock_members(&txn, workspace_id).await?;
+    let actor_role = authorize_workspace(&txn, audit.actor_id, workspace_id, Permission::WorkspaceRead).await?;
+    let member = repository::find_member(&txn, workspace_id, member_id)
+        .await?
+        .ok_or(WorkspaceError::MemberNotFound)?;
+    let member_role = WorkspaceRole::parse(&member.role).unwrap_or(WorkspaceRole::Guest);
+    if audit.actor_id != member_id {
+        check_removal(actor_role, member_role)?;
+    }
+    if member_role == WorkspaceRole::Owner {
+        ensure_not_last_owner(&txn, workspace_id).await?;
+    }
+    if let Some(reassign_to) = reassign_to {
+        ensure_reassign_target(&txn, workspace_id, member_id, reassign_to).await?;
+    }
+
+    let assigned = todo_repository::find_workspace_todo_ids_assigned_to(&txn, workspace_id, member_id).await?;
+    for todo_id in assigned {
+        todo_service::reassign_todo(&txn, audit.actor_id, todo_id, reassign_to, audit).await?;
+    }
+    sharing_repository::delete_grants_in_workspace(&txn, member_id, workspace_id).await?;
+    repository::delete_member(&txn, workspace_id, member_id).await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+/// Membuat undangan ke email tertentu; minimal admin.
+/// Token asli dikembalikan sekali, yang disimpan hanya hash-nya.
+pub async fn create_invitation(
+    conn: &DatabaseConnection,
+    settings: &WorkspaceSettings,
+    actor_id: Uuid,
+    workspace_id: Uuid,
+    request: CreateInvitationRequest,
+) -> Result<IssuedInvitation, WorkspaceError> {
+    authorize_workspace(conn, actor_id, workspace_id, Permission::MemberInvite).await?;
+    let email = normalize_email(&request.email)?;
+    let role = parse_role(&request.role)?;
+    if role == WorkspaceRole::Owner {
+        return Err(WorkspaceError::Validation(
+            "role must be one of admin, member, guest".to_string(),
+        ));
+    }
+    if let Some(user) = user_repository::find_user_by_email(conn, &email).await?
+        && repository::find_member(conn, workspace_id, user.id).await?.is_some()
+    {
+        return Err(WorkspaceError::Validation("user is already a member".to_string()));
+    }
+
+    let token = generate_token();
+    let expires_at = Utc::now().fixed_offset() + chrono::Duration::hours(settings.invitation_ttl_hours as i64);
+    let invitation = repository::create_invitation(
+        conn,
+        NewInvitation {
+            workspace_id,
+            email,
+            role,
+            token_hash: hash_token(&token),
+            invited_by: actor_id,
+            expires_at,
+        },
+    )
+    .await?;
+    Ok(IssuedInvitation { invitation, token })
+}
+
+/// Daftar undangan workspace; minimal admin.
+pub async fn list_invitations(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<Vec<Invitation>, WorkspaceError> {
+    authorize_workspace(conn, actor_id, workspace_id, Permission::MemberInvite).await?;
+    repository::list_invitations(conn, workspace_id)
+        .await
+        .map_err(WorkspaceError::from)
+}
+
+/// Mencabut undangan yang masih `pending`; minimal admin.
+pub async fn revoke_invitation(
+    conn: &DatabaseConnection,
+    actor_id: Uuid,
+    workspace_id: Uuid,
+    invitation_id: Uuid,
+) -> Result<Invitation, WorkspaceError> {
+    authorize_workspace(conn, actor_id, workspace_id, Permission::MemberInvite).await?;
+    let invitation = repository::find_invitation(conn, workspace_id, invitation_id)
+        .await?
+        .ok_or(WorkspaceError::InvitationNotFound)?;
+    ensure_pending(&invitation, Utc::now().fixed_offset())?;
+    repository::set_invitation_status(conn, invitation, InvitationStatus::Revoked)
+        .await
+        .map_err(WorkspaceError::from)
+}
+
+/// Menerima undangan: user saat ini menjadi member dengan role dari undangan.
+/// Hanya user dengan email tujuan undangan yang boleh menerima.
+pub async fn accept_invitation(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    token: &str,
+) -> Result<(Model, WorkspaceRole), WorkspaceError> {
+    let invitation = find_invitation_for_user(conn, user_id, token).await?;
+    let workspace_id = invitation.workspace_id;
+    let invited_role = WorkspaceRole::parse(&invitation.role).unwrap_or(WorkspaceRole::Guest);
+
+    let txn = conn.begin().await?;
+    let role = match repository::find_member(&txn, workspace_id, user_id).await? {
+        // Sudah member lewat jalur lain: role yang ada tidak diturunkan.
+        Some(member) => WorkspaceRole::parse(&member.role).unwrap_or(WorkspaceRole::Guest),
+        None => {
+            repository::add_member(&txn, workspace_id, user_id, invited_role).await?;
+            invited_role
+        }
+    };
+    repository::set_invitation_status(&txn, invitation, InvitationStatus::Accepted).await?;
+    txn.commit().await?;
+
+    let workspace = repository::find_workspace_by_id(conn, workspace_id)
+        .await?
+        .ok_or(WorkspaceError::NotFound)?;
+    Ok((workspace, role))
+}
+
+/// Menolak undangan; aturan pengecekan sama dengan `accept_invitation`.
+pub async fn decline_invitation(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    token: &str,
+) -> Result<(), WorkspaceError> {
+    let invitation = find_invitation_for_user(conn, user_id, token).await?;
+    repository::set_invitation_status(conn, invitation, InvitationStatus::Declined).await?;
+    Ok(())
+}
+
+/// Normalisasi dan validasi nama workspace.
+pub fn normalize_name(raw_name: String) -> Result<String, WorkspaceError> {
+    let name = raw_name.trim();
+    if name.is_empty() {
+        return Err(WorkspaceError::Validation("name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(WorkspaceError::Validation(format!(
+            "name must be at most {MAX_NAME_LEN} characters"
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// Normalisasi email undangan (trim + lowercase) dan validasi bentuk dasarnya.
+pub fn normalize_email(raw_email: &str) -> Result<String, WorkspaceError> {
+    let email = raw_email.trim().to_lowercase();
+    let valid = email
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty());
+    if !valid || email.chars().count() > MAX_EMAIL_LEN {
+        return Err(WorkspaceError::Validation("email must be a valid address".to_string()));
+    }
+    Ok(email)
+}
+
+/// Validasi nilai role dari request.
+pub fn parse_role(raw: &str) -> Result<WorkspaceRole, WorkspaceError> {
+    WorkspaceRole::parse(raw.trim()).ok_or_else(|| {
+        WorkspaceError::Validation("role must be one of owner, admin, member, guest".to_string())
+    })
+}
+
+/// Aturan ubah role: butuh `member.manage`, dan memberi/mencabut owner butuh `owner.manage`.
+pub fn check_role_change(
+    actor_role: WorkspaceRole,
+    current_role: WorkspaceRole,
+    new_role: WorkspaceRole,
+) -> Result<(), WorkspaceError> {
+    let touches_owner = current_role == WorkspaceRole::Owner || new_role == WorkspaceRole::Owner;
+    let required = if touches_owner { Permission::OwnerManage } else { Permission::MemberManage };
+    if !Role::Workspace(actor_role).allows(required) {
+        return Err(WorkspaceError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Aturan mengeluarkan member lain: butuh `member.manage`, dan mengeluarkan owner butuh `owner.manage`.
+pub fn check_removal(actor_role: WorkspaceRole, member_role: WorkspaceRole) -> Result<(), WorkspaceError> {
+    let required = if member_role == WorkspaceRole::Owner {
+        Permission::OwnerManage
+    } else {
+        Permission::MemberManage
+    };
+    if !Role::Workspace(actor_role).allows(required) {
+        return Err(WorkspaceError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Undangan hanya bisa direspons selama masih `pending` dan belum kedaluwarsa.
+pub fn ensure_pending(invitation: &Invitation, now: DateTime<FixedOffset>) -> Result<(), WorkspaceError> {
+    if invitation.status != InvitationStatus::Pending.as_str() {
+        return Err(WorkspaceError::Conflict(format!(
+            "invitation is already {}",
+            invitation.status
+        )));
+    }
+    if invitation.expires_at <= now {
+        return Err(WorkspaceError::Conflict("invitation has expired".to_string()));
+    }
+    Ok(())
+}
+
+/// Hash SHA-256 (hex) dari token undangan; hanya nilai ini yang disimpan di database.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+// Token acak 256-bit dari dua UUID v4, dikirim ke client dalam bentuk hex.
+fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+// Undangan dicari lewat hash token lalu dicocokkan dengan email user saat ini.
+// Undangan untuk email lain dianggap tidak ada agar token bocor tidak bisa dipakai orang lain.
+async fn find_invitation_for_user(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    token: &str,
+) -> Result<Invitation, WorkspaceError> {
+    let invitation = repository::find_invitation_by_token_hash(conn, &hash_token(token))
+        .await?
+        .ok_or(WorkspaceError::InvitationNotFound)?;
+    let user = user_repository::find_user_by_id(conn, user_id)
+        .await?
+        .ok_or(WorkspaceError::InvitationNotFound)?;
+    if user.email.to_lowercase() != invitation.email {
+        return Err(WorkspaceError::InvitationNotFound);
+    }
+    ensure_pending(&invitation, Utc::now().fixed_offset())?;
+    Ok(invitation)
+}
+
+// Workspace wajib selalu punya minimal satu owner.
+async fn ensure_not_last_owner<C: ConnectionTrait>(conn: &C, workspace_id: Uuid) -> Result<(), WorkspaceError> {
+    if repository::count_members_with_role(conn, workspace_id, WorkspaceRole::Owner).await? <= 1 {
+        return Err(WorkspaceError::Validation(
+            "workspace must keep at least one owner".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Penerima alih tugas harus member lain yang bisa mengedit isi workspace.
+async fn ensure_reassign_target<C: ConnectionTrait>(
+    conn: &C,
+    workspace_id: Uuid,
+    member_id: Uuid,
+    reassign_to: Uuid,
+) -> Result<(), WorkspaceError> {
+    let target_role = repository::find_member(conn, workspace_id, reassign_to)
+        .await?
+        .and_then(|member| WorkspaceRole::parse(&member.role));
+    let can_edit = target_role.is_some_and(|role| Role::Workspace(role).allows(Permission::WorkspaceWrite));
+    if reassign_to == member_id || !can_edit {
+        return Err(WorkspaceError::Validation(
+            "reassign_to must be another member with at least member role".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_invitation(status: InvitationStatus, expires_in_secs: i64) -> Invitation {
+        let now = Utc::now().fixed_offset();
+        Invitation {
+            id: Uuid::new_v4(),
+            workspace_id: Uuid::new_v4(),
+            email: "teammate@example.com".to_string(),
+            role: "member".to_string(),
+            token_hash: hash_token("token"),
+            status: status.as_str().to_string(),
+            invited_by: Uuid::new_v4(),
+            expires_at: now + chrono::Duration::seconds(expires_in_secs),
+            responded_at: None,
+            created_at: now,
+        }
+    }
+
+    #[test]
+    fn normalize_email_should_lowercase_and_validate() {
+        assert_eq!(
+            normalize_email("  Teammate@Example.COM ").ok(),
+            Some("teammate@example.com".to_string())
+        );
+        assert!(matches!(normalize_email("no-at-sign"), Err(WorkspaceError::Validation(_))));
+        assert!(matches!(normalize_email("@example.com"), Err(WorkspaceError::Validation(_))));
+    }
+
+    #[test]
+    fn check_role_change_should_reserve_owner_role_for_owners() {
+        use WorkspaceRole::{Admin, Guest, Member, Owner};
+        assert!(check_role_change(Admin, Member, Guest).is_ok());
+        assert!(check_role_change(Owner, Admin, Owner).is_ok());
+        assert!(matches!(check_role_change(Admin, Member, Owner), Err(WorkspaceError::Forbidden)));
+        assert!(matches!(check_role_change(Admin, Owner, Admin), Err(WorkspaceError::Forbidden)));
+        assert!(matches!(check_role_change(Member, Guest, Member), Err(WorkspaceError::Forbidden)));
+    }
+
+    #[test]
+    fn check_removal_should_require_admin_and_protect_owners() {
+        use WorkspaceRole::{Admin, Member, Owner};
+        assert!(check_removal(Admin, Member).is_ok());
+        assert!(check_removal(Owner, Owner).is_ok());
+        assert!(matches!(check_removal(Admin, Owner), Err(WorkspaceError::Forbidden)));
+        assert!(matches!(check_removal(Member, Member), Err(WorkspaceError::Forbidden)));
+    }
+
+    #[test]
+    fn ensure_pending_should_reject_responded_and_expired_invitations() {
+        let now = Utc::now().fixed_offset();
+        assert!(ensure_pending(&sample_invitation(InvitationStatus::Pending, 60), now).is_ok());
+        assert!(matches!(
+            ensure_pending(&sample_invitation(InvitationStatus::Accepted, 60), now),
+            Err(WorkspaceError::Conflict(_))
+        ));
+        assert!(matches!(
+            ensure_pending(&sample_invitation(InvitationStatus::Pending, -1), now),
+            Err(WorkspaceError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn hash_token_should_be_stable_hex() {
+        let hash = hash_token("abc");
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, hash_token(" abc "));
+        assert_ne!(hash, hash_token("abd"));
+    }
+}